@@ -15,7 +15,7 @@ fn main() {
         println!("cargo:rustc-cfg=has_arrow2_0_{version}");
     }
 
-    let max_arrow_version: Option<usize> = [
+    let arrow_versions: Vec<usize> = vec![
         // arrow-version:insert: #[cfg(feature = "arrow-{version}")]{\n}{version},
         #[cfg(feature = "arrow-49")]
         49,
@@ -43,12 +43,31 @@ fn main() {
         38,
         #[cfg(feature = "arrow-37")]
         37,
-    ]
-    .into_iter()
-    .max();
+    ];
+
+    // every enabled arrow version is exposed in its own module
+    for version in &arrow_versions {
+        println!("cargo:rustc-cfg=has_arrow_module_{version}");
+    }
 
-    if let Some(version) = max_arrow_version {
+    if let Some(version) = arrow_versions.into_iter().max() {
         println!("cargo:rustc-cfg=has_arrow");
         println!("cargo:rustc-cfg=has_arrow_{version}");
     }
+
+    // declare the cfgs of all supported versions, including disabled ones
+    let all_arrow_versions: &[usize] = &[
+        // arrow-version:insert: {version},
+        49, 48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37,
+    ];
+
+    println!("cargo::rustc-check-cfg=cfg(has_arrow2)");
+    for version in [18, 17, 16] {
+        println!("cargo::rustc-check-cfg=cfg(has_arrow2_0_{version})");
+    }
+    println!("cargo::rustc-check-cfg=cfg(has_arrow)");
+    for version in all_arrow_versions {
+        println!("cargo::rustc-check-cfg=cfg(has_arrow_{version})");
+        println!("cargo::rustc-check-cfg=cfg(has_arrow_module_{version})");
+    }
 }
@@ -2,18 +2,37 @@
 //!
 //! Functions to convert Rust objects into Arrow arrays and back.
 //!
-use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    _impl::arrow2::{array::Array, datatypes::Field},
+    _impl::arrow2::{
+        array::{Array, MutableArray, StructArray},
+        datatypes::Field,
+    },
     internal::{
-        error::Result,
+        builder::BuilderField,
+        common::{BufferExtract, Buffers},
+        deserialization,
+        display::{format_table, FieldLike},
+        error::{fail, Result, RowError},
         generic,
-        schema::GenericField,
-        serialization::{compile_serialization, CompilationOptions, Interpreter},
+        instrument::span,
+        metrics::DeserializationMetrics,
+        options::{DeserializationOptions, FailedItems, SerializationOptions},
+        rows_view::RowsView,
+        schema::{GenericField, SchemaLike, Sealed},
+        serialization::{
+            compile_serialization, statistics::ColumnStatistics, CompilationOptions, Interpreter,
+        },
         sink::serialize_into_sink,
-        source::deserialize_from_source,
+        source::{
+            deserialize_from_source_with_options, deserialize_into_vec_from_source_with_options,
+            deserialize_rows_from_source_with_options, RowIter,
+        },
         tracing::{Tracer, TracingOptions},
+        value::Value,
     },
 };
 
@@ -65,7 +84,7 @@ pub struct Arrow2Builder(generic::GenericBuilder);
 
 impl std::fmt::Debug for Arrow2Builder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Arrow2Builder<...>")
+        self.0 .0.fmt_progress("Arrow2Builder", f)
     }
 }
 
@@ -83,6 +102,36 @@ impl Arrow2Builder {
         Ok(Self(generic::GenericBuilder::new_for_arrays(&fields)?))
     }
 
+    /// Build a new Arrow2Builder for the given fields with custom serialization
+    /// options
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// use arrow2::datatypes::{DataType, Field};
+    /// use serde_arrow::{
+    ///     utils::{Item, Overflow, SerializationOptions},
+    ///     Arrow2Builder,
+    /// };
+    ///
+    /// let mut builder = Arrow2Builder::with_options(
+    ///     &[Field::new("item", DataType::UInt8, false)],
+    ///     SerializationOptions::default().overflow(Overflow::Saturate),
+    /// )?;
+    /// builder.push(&Item(300_u32))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(fields: &[Field], options: SerializationOptions) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(generic::GenericBuilder::new_for_arrays_with_options(
+            &fields, &options,
+        )?))
+    }
+
     /// Add a single record to the arrays
     ///
     pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
@@ -95,6 +144,37 @@ impl Arrow2Builder {
         self.0.extend(items)
     }
 
+    /// Add the records of a slice to the arrays
+    ///
+    /// The result is identical to [`extend`][Arrow2Builder::extend]. For records
+    /// whose fields all map to non-nullable primitive columns in order (e.g.,
+    /// structs of integers, floats and bools), the fields are written
+    /// directly into the buffers, which is considerably faster. Other records
+    /// are serialized as in `extend`.
+    ///
+    pub fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        self.0.extend_from_slice(items)
+    }
+
+    /// The approximate size in bytes of the arrays built from the rows pushed
+    /// so far, see
+    /// [`ArrowBuilder::estimated_size`][crate::ArrowBuilder::estimated_size]
+    ///
+    pub fn estimated_size(&self) -> usize {
+        self.0.estimated_size()
+    }
+
+    /// Invoke `callback` once the [estimated size][Arrow2Builder::estimated_size]
+    /// exceeds `budget` bytes, see
+    /// [`ArrowBuilder::on_size_exceeded`][crate::ArrowBuilder::on_size_exceeded]
+    ///
+    pub fn on_size_exceeded<F>(&mut self, budget: usize, callback: F)
+    where
+        F: FnMut(usize) + Send + Sync + 'static,
+    {
+        self.0.on_size_exceeded(budget, callback)
+    }
+
     /// Build the arrays from the rows pushed to far.
     ///
     /// This operation will reset the underlying buffers and start a new batch.
@@ -102,6 +182,336 @@ impl Arrow2Builder {
     pub fn build_arrays(&mut self) -> Result<Vec<Box<dyn Array>>> {
         self.0 .0.build_arrow2_arrays()
     }
+
+    /// Build mutable arrays from the rows pushed so far
+    ///
+    /// The returned arrays are `arrow2` mutable arrays, e.g.,
+    /// `MutablePrimitiveArray<T>`, and can be extended by other code managing
+    /// `arrow2` mutable arrays. Only fields without children are supported:
+    /// booleans, primitives (including dates, timestamps and decimals) and
+    /// strings. As [`build_arrays`][Arrow2Builder::build_arrays], this
+    /// operation will reset the underlying buffers and start a new batch.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// use arrow2::{
+    ///     array::{MutableArray, MutablePrimitiveArray},
+    ///     datatypes::{DataType, Field},
+    /// };
+    /// use serde_arrow::{utils::Item, Arrow2Builder};
+    ///
+    /// let mut builder = Arrow2Builder::new(&[Field::new("item", DataType::UInt8, false)])?;
+    /// builder.extend(&[Item(1_u8), Item(2)])?;
+    ///
+    /// let mut arrays = builder.build_mutable_arrays()?;
+    /// let array = arrays[0]
+    ///     .as_mut_any()
+    ///     .downcast_mut::<MutablePrimitiveArray<u8>>()
+    ///     .unwrap();
+    /// array.push(Some(3));
+    /// assert_eq!(array.values().as_slice(), &[1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn build_mutable_arrays(&mut self) -> Result<Vec<Box<dyn MutableArray>>> {
+        self.0 .0.build_arrow2_mutable_arrays()
+    }
+
+    /// Append the rows pushed so far to existing mutable arrays
+    ///
+    /// The mutable arrays must be given in the order of the fields and must be
+    /// of the types returned by
+    /// [`build_mutable_arrays`][Arrow2Builder::build_mutable_arrays] with the
+    /// data types of the fields. This operation will reset the underlying
+    /// buffers and start a new batch.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// use arrow2::{
+    ///     array::{MutableArray, MutablePrimitiveArray, MutableUtf8Array},
+    ///     datatypes::{DataType, Field},
+    /// };
+    /// use serde::Serialize;
+    /// use serde_arrow::Arrow2Builder;
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     a: Option<f32>,
+    ///     b: String,
+    /// }
+    ///
+    /// let mut a = MutablePrimitiveArray::<f32>::new();
+    /// let mut b = MutableUtf8Array::<i64>::new();
+    /// a.push(Some(1.0));
+    /// b.push(Some("x"));
+    ///
+    /// let mut builder = Arrow2Builder::new(&[
+    ///     Field::new("a", DataType::Float32, true),
+    ///     Field::new("b", DataType::LargeUtf8, false),
+    /// ])?;
+    /// builder.push(&Record { a: None, b: String::from("y") })?;
+    /// builder.append_to_mutable_arrays(&mut [&mut a, &mut b])?;
+    ///
+    /// assert_eq!(a.len(), 2);
+    /// assert_eq!(b.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn append_to_mutable_arrays(&mut self, arrays: &mut [&mut dyn MutableArray]) -> Result<()> {
+        self.0 .0.append_to_arrow2_mutable_arrays(arrays)
+    }
+
+    /// The statistics of the columns built by the last call to
+    /// [`build_arrays`][Arrow2Builder::build_arrays]
+    ///
+    /// The statistics are only collected, if the
+    /// [`column_statistics`][crate::utils::SerializationOptions::column_statistics]
+    /// option is set. Otherwise, the returned slice is empty.
+    ///
+    pub fn column_statistics(&self) -> &[ColumnStatistics] {
+        &self.0 .0.statistics
+    }
+
+    /// The fields of the arrays built by this builder
+    ///
+    /// The fields reflect any changes made with
+    /// [`rename_field`][Arrow2Builder::rename_field] and
+    /// [`with_field_order`][Arrow2Builder::with_field_order].
+    ///
+    pub fn fields(&self) -> Result<Vec<Field>> {
+        self.0.fields().iter().map(Field::try_from).collect()
+    }
+
+    /// Rename the column `old` to `new`
+    ///
+    /// Rows already pushed are kept. Records are still matched by the original
+    /// field name, only the name of the built column changes. Only top-level
+    /// fields can be renamed.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// use arrow2::datatypes::{DataType, Field};
+    /// use serde::Serialize;
+    /// use serde_arrow::Arrow2Builder;
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     a: u8,
+    ///     b: u8,
+    /// }
+    ///
+    /// let mut builder = Arrow2Builder::new(&[
+    ///     Field::new("a", DataType::UInt8, false),
+    ///     Field::new("b", DataType::UInt8, false),
+    /// ])?;
+    /// builder.push(&Record { a: 1, b: 2 })?;
+    ///
+    /// builder.rename_field("a", "x")?;
+    /// builder.with_field_order(&["b", "x"])?;
+    /// builder.push(&Record { a: 3, b: 4 })?;
+    ///
+    /// let fields = builder.fields()?;
+    /// assert_eq!(fields[0].name, "b");
+    /// assert_eq!(fields[1].name, "x");
+    ///
+    /// let arrays = builder.build_arrays()?;
+    /// assert_eq!(arrays[0].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_field(&mut self, old: &str, new: &str) -> Result<()> {
+        self.0.rename_field(old, new)
+    }
+
+    /// Change the order of the columns
+    ///
+    /// `names` must contain the name of each column exactly once. Rows already
+    /// pushed are kept and records can still be given with their fields in any
+    /// order. See [`rename_field`][Arrow2Builder::rename_field] for an example.
+    ///
+    pub fn with_field_order(&mut self, names: &[&str]) -> Result<()> {
+        self.0.reorder_fields(names)
+    }
+
+    /// Split the builder into one builder per column
+    ///
+    /// Each returned builder contains a single column and keeps the rows
+    /// pushed so far. Records pushed into the column builders must be structs
+    /// with a single field named as the column. The columns can be combined
+    /// again with [`from_columns`][Arrow2Builder::from_columns], e.g., to serialize
+    /// different columns from different sources into a single batch.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow2;
+    /// use arrow2::datatypes::{DataType, Field};
+    /// use serde::Serialize;
+    /// use serde_arrow::Arrow2Builder;
+    ///
+    /// ##[derive(Serialize)]
+    /// struct A {
+    ///     a: u8,
+    /// }
+    ///
+    /// ##[derive(Serialize)]
+    /// struct B {
+    ///     b: f32,
+    /// }
+    ///
+    /// let builder = Arrow2Builder::new(&[
+    ///     Field::new("a", DataType::UInt8, false),
+    ///     Field::new("b", DataType::Float32, false),
+    /// ])?;
+    ///
+    /// let mut columns = builder.into_columns()?;
+    /// columns[0].extend(&[A { a: 1 }, A { a: 2 }])?;
+    /// columns[1].extend(&[B { b: 3.0 }, B { b: 4.0 }])?;
+    ///
+    /// let mut builder = Arrow2Builder::from_columns(columns)?;
+    /// let arrays = builder.build_arrays()?;
+    /// assert_eq!(arrays.len(), 2);
+    /// assert_eq!(arrays[0].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_columns(self) -> Result<Vec<Self>> {
+        Ok(self.0.into_columns()?.into_iter().map(Self).collect())
+    }
+
+    /// Combine builders into a single builder with the columns of all of them
+    ///
+    /// The columns keep the rows pushed so far and are ordered as the builders.
+    /// All builders must contain the same number of rows and should use the
+    /// same options. The options of the first builder are used for the
+    /// combined builder. See [`into_columns`][Arrow2Builder::into_columns] for an
+    /// example.
+    ///
+    pub fn from_columns(columns: Vec<Self>) -> Result<Self> {
+        let columns = columns.into_iter().map(|column| column.0).collect();
+        Ok(Self(generic::GenericBuilder::from_columns(columns)?))
+    }
+}
+
+/// Build arrow2 arrays for each partition of a stream of records (*requires
+/// one of the `arrow2-*` features*)
+///
+/// Each record is pushed together with the key of its partition and a
+/// separate batch is built for each key. All partitions share the fields and
+/// the options. The partitions are returned in order of their first record.
+/// See [`PartitionedBuilder`][crate::PartitionedBuilder] for an example.
+pub struct Arrow2PartitionedBuilder<K> {
+    builder: generic::GenericPartitionedBuilder<K>,
+    /// The partitions built by a failed call to `finish`
+    built: Vec<(K, Vec<Box<dyn Array>>)>,
+}
+
+impl<K: Hash + Eq + Clone> Arrow2PartitionedBuilder<K> {
+    /// Build a new Arrow2PartitionedBuilder for the given fields
+    pub fn new(fields: &[Field]) -> Result<Self> {
+        Self::with_options(fields, SerializationOptions::default())
+    }
+
+    /// Build a new Arrow2PartitionedBuilder for the given fields with custom
+    /// serialization options, used for the builders of all partitions
+    pub fn with_options(fields: &[Field], options: SerializationOptions) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            builder: generic::GenericPartitionedBuilder::new(fields, options)?,
+            built: Vec::new(),
+        })
+    }
+
+    /// Add a single record to the partition with the given key
+    pub fn push<T: Serialize + ?Sized>(&mut self, key: K, item: &T) -> Result<()> {
+        self.builder.push(key, item)
+    }
+
+    /// The number of partitions with at least one record
+    pub fn num_partitions(&self) -> usize {
+        self.builder.num_partitions()
+    }
+
+    /// Build the arrays of all partitions
+    ///
+    /// This operation removes all partitions. Records pushed afterwards start
+    /// new partitions.
+    ///
+    /// If building a partition fails, its records are discarded and the error
+    /// is returned. The partitions built before are returned by the next call
+    /// to `finish`, together with the remaining partitions. In this case, a key
+    /// may be returned twice, if records with it were pushed in between.
+    #[allow(clippy::type_complexity)]
+    pub fn finish(&mut self) -> Result<Vec<(K, Vec<Box<dyn Array>>)>> {
+        self.builder
+            .finish_with(&mut self.built, |builder| builder.0.build_arrow2_arrays())
+    }
+}
+
+/// Build arrow2 arrays for each variant of an enum (*requires one of the
+/// `arrow2-*` features*)
+///
+/// Each variant is given its own fields and the content of each pushed value
+/// is appended to the batch of its variant. See
+/// [`VariantBuilder`][crate::VariantBuilder] for an example.
+pub struct Arrow2VariantBuilder {
+    builder: generic::GenericVariantBuilder,
+    /// The variants built by a failed call to `finish`
+    built: HashMap<String, Vec<Box<dyn Array>>>,
+}
+
+impl Arrow2VariantBuilder {
+    /// Build a new Arrow2VariantBuilder for the given variants and their
+    /// fields
+    pub fn new(variants: &[(&str, Vec<Field>)]) -> Result<Self> {
+        Self::with_options(variants, SerializationOptions::default())
+    }
+
+    /// Build a new Arrow2VariantBuilder for the given variants and their
+    /// fields with custom serialization options, used for the builders of all
+    /// variants
+    pub fn with_options(
+        variants: &[(&str, Vec<Field>)],
+        options: SerializationOptions,
+    ) -> Result<Self> {
+        let mut generic_variants = Vec::new();
+        for (name, fields) in variants {
+            let fields = fields
+                .iter()
+                .map(GenericField::try_from)
+                .collect::<Result<Vec<_>>>()?;
+            generic_variants.push((name.to_string(), fields));
+        }
+        Ok(Self {
+            builder: generic::GenericVariantBuilder::new(generic_variants, options)?,
+            built: HashMap::new(),
+        })
+    }
+
+    /// Add a single value to the batch of its variant
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.builder.push(item)
+    }
+
+    /// Build the arrays of all variants, keyed by the variant name
+    ///
+    /// Variants without values result in empty arrays. This operation clears
+    /// the builder, which can be reused afterwards.
+    ///
+    /// If building a variant fails, its values are discarded and the error is
+    /// returned. The variants built before are returned by the next call to
+    /// `finish`, values pushed to them in between by the call after it.
+    pub fn finish(&mut self) -> Result<HashMap<String, Vec<Box<dyn Array>>>> {
+        self.builder
+            .finish_with(&mut self.built, |builder| builder.0.build_arrow2_arrays())
+    }
 }
 
 /// Build arrow2 arrays from the given items  (*requires one of the `arrow2-*`
@@ -150,10 +560,173 @@ where
 
     let program = compile_serialization(&fields, CompilationOptions::default())?;
     let mut interpreter = Interpreter::new(program);
-    serialize_into_sink(&mut interpreter, items)?;
+    {
+        span!("serialize");
+        serialize_into_sink(&mut interpreter, items)?;
+    }
 
     interpreter.build_arrow2_arrays()
 }
+/// Build arrow2 arrays from the given items with custom serialization options
+/// (*requires one of the `arrow2-*` features*)
+///
+/// The same as [`to_arrow2`][crate::to_arrow2], but the behavior can be configured
+/// with [`SerializationOptions`][crate::utils::SerializationOptions], e.g.,
+/// the policy for integers that overflow the type of their field.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::utils::{Overflow, SerializationOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = vec![Field::new("a", DataType::UInt8, false)];
+/// let options = SerializationOptions::default().overflow(Overflow::Saturate);
+/// let arrays = serde_arrow::to_arrow2_with_options(&fields, &[Record { a: 300 }], &options)?;
+/// # assert_eq!(arrays[0].len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow2_with_options<T>(
+    fields: &[Field],
+    items: &T,
+    options: &SerializationOptions,
+) -> Result<Vec<Box<dyn Array>>>
+where
+    T: Serialize + ?Sized,
+{
+    let mut builder = Arrow2Builder::with_options(fields, options.clone())?;
+    builder.extend(items)?;
+    builder.build_arrays()
+}
+
+/// Trace the fields from the given items and build arrow2 arrays in one call
+/// (*requires one of the `arrow2-*` features*)
+///
+/// The fields are traced with
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples] and
+/// the items serialized with [`to_arrow2`][crate::to_arrow2]. The items are
+/// serialized twice. To reuse the fields across multiple calls, trace them
+/// once and call [`to_arrow2`][crate::to_arrow2] directly.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::DataType;
+/// use serde::Serialize;
+/// use serde_arrow::schema::TracingOptions;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2 },
+///     Record { a: None, b: 3 },
+/// ];
+///
+/// let (fields, arrays) = serde_arrow::to_arrow2_auto(&items, TracingOptions::default())?;
+/// assert_eq!(fields[0].data_type, DataType::Float32);
+/// assert_eq!(fields[1].data_type, DataType::UInt64);
+/// assert_eq!(arrays[0].null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[allow(clippy::type_complexity)]
+pub fn to_arrow2_auto<T: Serialize>(
+    items: &T,
+    options: TracingOptions,
+) -> Result<(Vec<Field>, Vec<Box<dyn Array>>)> {
+    let fields = Vec::<Field>::from_samples(items, options)?;
+    let arrays = to_arrow2(&fields, items)?;
+    Ok((fields, arrays))
+}
+
+/// Build arrow2 arrays from items that may have failed before serialization
+/// (*requires one of the `arrow2-*` features*)
+///
+/// In contrast to [`to_arrow2`][crate::to_arrow2], the items are given as an iterator
+/// of results, e.g., records parsed from an upstream source. `Err` items do
+/// not abort the conversion, but are skipped or written as rows with all fields
+/// set to null, depending on `failed_items`. Errors raised while serializing
+/// `Ok` items are still returned as errors.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::FailedItems,
+/// };
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<u32>,
+/// }
+///
+/// let items = ["1", "x", "3"].into_iter().map(|s| s.parse().map(|a| Record { a: Some(a) }));
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2_filtered(&fields, items, FailedItems::Null)?;
+/// assert_eq!(arrays[0].len(), 3);
+/// assert_eq!(arrays[0].null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow2_filtered<T, E, I>(
+    fields: &[Field],
+    items: I,
+    failed_items: FailedItems,
+) -> Result<Vec<Box<dyn Array>>>
+where
+    T: Serialize,
+    I: IntoIterator<Item = std::result::Result<T, E>>,
+{
+    let mut builder = Arrow2Builder::new(fields)?;
+    builder.0.extend_filtered(items, failed_items)?;
+    builder.build_arrays()
+}
+
+/// Build empty arrow2 arrays for the given fields (*requires one of the
+/// `arrow2-*` features*)
+///
+/// The arrays have zero rows and the data types of the fields, e.g., to emit
+/// chunks that only carry the schema.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let fields = vec![
+///     Field::new("a", DataType::UInt8, false),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let arrays = serde_arrow::empty_arrow2_arrays(&fields)?;
+///
+/// assert_eq!(arrays.len(), 2);
+/// assert_eq!(arrays[0].len(), 0);
+/// assert_eq!(arrays[1].data_type(), &DataType::Utf8);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn empty_arrow2_arrays(fields: &[Field]) -> Result<Vec<Box<dyn Array>>> {
+    Arrow2Builder::new(fields)?.build_arrays()
+}
 
 /// Deserialize items from the given arrow2 arrays  (*requires* one of the
 /// `arrow2-*` features)
@@ -189,21 +762,484 @@ where
     T: Deserialize<'de>,
     A: AsRef<dyn Array>,
 {
-    use crate::internal::{
-        common::{BufferExtract, Buffers},
-        deserialization,
-    };
+    from_arrow2_with_options(fields, arrays, &DeserializationOptions::default())
+}
 
+/// Deserialize items from arrow2 arrays with the given options (*requires one
+/// of the `arrow2-*` features*)
+///
+/// See [`from_arrow2`][crate::from_arrow2] for details. The options allow, e.g., to reject
+/// columns that are not declared by the target struct:
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::{DeserializationOptions, UnknownColumns},
+/// };
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+///     b: u64,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct PartialRecord {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &[Record { a: 1, b: 2 }])?;
+///
+/// let options = DeserializationOptions::default().unknown_columns(UnknownColumns::Error);
+/// let res = serde_arrow::from_arrow2_with_options::<Vec<PartialRecord>, _>(&fields, &arrays, &options);
+/// assert!(res.is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_with_options<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+    options: &DeserializationOptions,
+) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    deserialize_arrays(fields, arrays, options, |source| {
+        deserialize_from_source_with_options(source, options)
+    })
+}
+
+/// Deserialize items from arrow2 arrays and append them to an existing vector
+/// (*requires one of the `arrow2-*` features*)
+///
+/// See [`from_arrow2`][crate::from_arrow2] for details. Reusing the vector, e.g., by
+/// calling [`Vec::clear`] between batches, avoids repeated allocations when
+/// deserializing many batches. If an error occurs, the items deserialized
+/// before the error are kept in the vector.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let batches = [
+///     serde_arrow::to_arrow2(&fields, &[Record { a: 1 }, Record { a: 2 }])?,
+///     serde_arrow::to_arrow2(&fields, &[Record { a: 3 }])?,
+/// ];
+///
+/// let mut items = Vec::<Record>::new();
+/// for arrays in &batches {
+///     items.clear();
+///     serde_arrow::from_arrow2_into(&fields, arrays, &mut items)?;
+/// }
+/// assert_eq!(items, vec![Record { a: 3 }]);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_into<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+    items: &mut Vec<T>,
+) -> Result<()>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    deserialize_arrays(fields, arrays, &options, |source| {
+        deserialize_into_vec_from_source_with_options(source, &options, items)
+    })
+}
+
+/// Deserialize rows from arrow2 arrays, collecting the errors of individual
+/// rows (*requires one of the `arrow2-*` features*)
+///
+/// In contrast to [`from_arrow2`][crate::from_arrow2], rows that fail to
+/// deserialize do not abort the deserialization. Instead, they are skipped
+/// and reported as [`RowError`][crate::RowError] with the index of the row
+/// and the path of the failing field. Errors that affect all rows, e.g.,
+/// invalid fields, are still returned as errors.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<u32>,
+/// }
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct RequiredRecord {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &[Record { a: Some(1) }, Record { a: None }])?;
+///
+/// let (rows, errors) = serde_arrow::from_arrow2_collect_errors::<RequiredRecord, _>(&fields, &arrays)?;
+/// assert_eq!(rows, vec![RequiredRecord { a: 1 }]);
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].row, 1);
+/// assert_eq!(errors[0].path, "a");
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_collect_errors<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+) -> Result<(Vec<T>, Vec<RowError>)>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    from_arrow2_collect_errors_with_options(fields, arrays, &DeserializationOptions::default())
+}
+
+/// Same as [`from_arrow2_collect_errors`], but with custom options
+pub(crate) fn from_arrow2_collect_errors_with_options<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+    options: &DeserializationOptions,
+) -> Result<(Vec<T>, Vec<RowError>)>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    deserialize_arrays(fields, arrays, options, |source| {
+        deserialize_rows_from_source_with_options(source, options)
+    })
+}
+
+/// Deserialize the rows of arrow2 arrays lazily (*requires one of the
+/// `arrow2-*` features*)
+///
+/// The given function is called with a [`RowIter`][crate::RowIter] over the
+/// rows. Each row is only deserialized when requested, therefore stopping the
+/// iteration early, e.g., to inspect the first rows of a large batch, does not
+/// process the remaining rows. Rows that fail to deserialize are returned as
+/// [`RowError`][crate::RowError] and do not end the iteration.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = (0..1000).map(|a| Record { a }).collect::<Vec<_>>();
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &items)?;
+///
+/// let head = serde_arrow::from_arrow2_iter(&fields, &arrays, |rows| {
+///     rows.take(2).collect::<Result<Vec<Record>, _>>().map_err(|err| err.error)
+/// })?;
+/// assert_eq!(head, vec![Record { a: 0 }, Record { a: 1 }]);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_iter<T, A, R>(
+    fields: &[Field],
+    arrays: &[A],
+    func: impl FnOnce(RowIter<'_, T>) -> Result<R>,
+) -> Result<R>
+where
+    T: DeserializeOwned,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    deserialize_arrays(fields, arrays, &options, |source| {
+        func(RowIter::new(source, &options)?)
+    })
+}
+
+/// Deserialize single rows of arrow2 arrays on demand (*requires one of the
+/// `arrow2-*` features*)
+///
+/// The returned [`RowsView`][crate::RowsView] supports random access to the
+/// rows via [`get`][crate::RowsView::get]. Each access only deserializes the
+/// requested row, therefore reading a few rows of a large batch does not
+/// process the remaining rows. The schema is checked when the view is created.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = (0..1000).map(|a| Record { a }).collect::<Vec<_>>();
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &items)?;
+///
+/// let view = serde_arrow::from_arrow2_view::<Record, _>(&fields, &arrays)?;
+/// assert_eq!(view.len(), 1000);
+/// assert_eq!(view.get(42)?, Record { a: 42 });
+/// assert!(view.get(1000).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_arrow2_view<'a, T, A>(fields: &'a [Field], arrays: &'a [A]) -> Result<RowsView<'a, T>>
+where
+    T: DeserializeOwned,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
     let fields = fields
         .iter()
         .map(GenericField::try_from)
         .collect::<Result<Vec<_>>>()?;
 
+    let len = arrays
+        .iter()
+        .map(|array| array.as_ref().len())
+        .min()
+        .unwrap_or_default();
+
+    let mut buffers = Buffers::new();
+    let mut mappings = Vec::new();
+    for (field, array) in fields.iter().zip(arrays.iter()) {
+        mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
+    }
+
+    // the field names are copied into the program, the mappings are dropped
+    let interpreter = deserialization::compile_detached_deserialization(
+        len,
+        &mappings,
+        buffers,
+        deserialization::CompilationOptions::default()
+            .timezone_conversion(options.timezone_conversion)
+            .field_timezone_conversions(options.field_timezone_conversions.clone())
+            .empty_lists(options.empty_lists),
+    )?;
+
+    Ok(RowsView::new(len, interpreter, options))
+}
+
+/// Deserialize the rows of a struct array, e.g., with a validity of its own
+/// (*requires one of the `arrow2-*` features*)
+///
+/// The field must be of data type `Struct`. Each row of the array is
+/// deserialized as a record, null rows are deserialized as `None`. Therefore,
+/// the type should be a list of optional records (e.g., `Vec<Option<T>>`) for
+/// nullable struct arrays.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Items,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = [Some(Record { a: 1 }), None, Some(Record { a: 2 })];
+/// let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &Items(&items))?;
+///
+/// let rows: Vec<Option<Record>> = serde_arrow::from_arrow2_struct(&fields[0], &arrays[0])?;
+/// assert_eq!(rows, items);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_struct<'de, T, A>(field: &'de Field, array: &'de A) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array> + 'de + ?Sized,
+{
+    generic::deserialize_from_struct_array(field, array.as_ref())
+}
+
+/// Deserialize the rows of a struct array given as a `StructArray` (*requires
+/// one of the `arrow2-*` features*)
+///
+/// This function behaves as [`from_arrow2_struct`][crate::from_arrow2_struct], but accepts
+/// the concrete struct array, e.g., a nested struct column extracted from a
+/// larger batch by downcasting.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::{array::StructArray, datatypes::Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Items,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = [Record { a: 1 }, Record { a: 2 }];
+/// let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow2(&fields, &Items(&items))?;
+///
+/// let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+/// let rows: Vec<Record> = serde_arrow::from_arrow2_struct_array(&fields[0], array)?;
+/// assert_eq!(rows, items);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_struct_array<'de, T>(field: &'de Field, array: &'de StructArray) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    generic::deserialize_from_struct_array(field, array as &dyn Array)
+}
+
+/// Serialize items into a single struct array (*requires one of the `arrow2-*`
+/// features*)
+///
+/// The field must be of data type `Struct`. Each item is serialized as one row
+/// of the array, e.g., to embed the struct as a column of a batch built
+/// elsewhere. For nullable fields, `None` items are serialized as null rows.
+/// This function is the inverse of [`from_arrow2_struct_array`][crate::from_arrow2_struct_array].
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::{array::Array, datatypes::{DataType, Field}};
+/// use serde::Serialize;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let field = Field::new(
+///     "record",
+///     DataType::Struct(vec![Field::new("a", DataType::UInt32, false)].into()),
+///     true,
+/// );
+/// let items = [Some(Record { a: 1 }), None, Some(Record { a: 2 })];
+/// let array = serde_arrow::to_arrow2_struct_array(&field, &items)?;
+///
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow2_struct_array<T>(field: &Field, items: &T) -> Result<StructArray>
+where
+    T: Serialize + ?Sized,
+{
+    let mut builder = generic::GenericBuilder::new_for_struct_array(field.try_into()?)?;
+    builder.extend(items)?;
+    let array = builder.0.build_arrow2_array()?;
+    let Some(array) = array.as_any().downcast_ref::<StructArray>() else {
+        fail!("Invalid array: expected a struct array");
+    };
+    Ok(array.clone())
+}
+
+impl Sealed for Field {}
+
+impl BuilderField for Field {
+    type Array = Box<dyn Array>;
+
+    fn to_generic_field(&self) -> Result<GenericField> {
+        GenericField::try_from(self)
+    }
+
+    fn from_generic_field(field: &GenericField) -> Result<Self> {
+        Field::try_from(field)
+    }
+
+    fn build_arrays(interpreter: &mut Interpreter) -> Result<Vec<Self::Array>> {
+        interpreter.build_arrow2_arrays()
+    }
+}
+
+impl<A: AsRef<dyn Array>> FieldLike<A> for Field {
+    fn pretty(fields: &[Self], arrays: &[A], limit: usize) -> Result<String> {
+        let num_rows = arrays
+            .iter()
+            .map(|array| array.as_ref().len())
+            .min()
+            .unwrap_or_default();
+
+        // only deserialize the displayed rows
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_ref().sliced(0, limit.min(num_rows)))
+            .collect::<Vec<_>>();
+        let rows: Vec<Value> = from_arrow2(fields, &arrays)?;
+
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        format_table(&fields, &rows, num_rows)
+    }
+}
+
+/// Compile the deserialization of the given arrays and pass the events to
+/// `func`
+fn deserialize_arrays<A, R>(
+    fields: &[Field],
+    arrays: &[A],
+    options: &DeserializationOptions,
+    func: impl FnOnce(deserialization::ArraySource<'_>) -> Result<R>,
+) -> Result<R>
+where
+    A: AsRef<dyn Array>,
+{
+    let mut fields = fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    options.rename_fields(&mut fields);
+
     let num_items = arrays
         .iter()
         .map(|a| a.as_ref().len())
         .min()
         .unwrap_or_default();
+    span!("deserialize", num_items, num_columns = arrays.len());
+
+    let mut metrics = DeserializationMetrics::start(options.metrics.as_ref());
 
     let mut buffers = Buffers::new();
     let mut mappings = Vec::new();
@@ -211,13 +1247,22 @@ where
         mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
     }
 
-    let interpreter = deserialization::compile_deserialization(
+    let (res, num_rows) = deserialization::with_array_source(
         num_items,
         &mappings,
         buffers,
-        deserialization::CompilationOptions::default(),
+        deserialization::CompilationOptions::default()
+            .timezone_conversion(options.timezone_conversion)
+            .field_timezone_conversions(options.field_timezone_conversions.clone())
+            .empty_lists(options.empty_lists),
+        options.column_threads,
+        |source| {
+            metrics.compiled();
+            func(source)
+        },
     )?;
-    deserialize_from_source(interpreter)
+    metrics.report(num_rows);
+    Ok(res)
 }
 
 /// Replaced by
@@ -1,17 +1,20 @@
 use crate::{
     _impl::arrow2::{
         array::{
-            Array, BooleanArray, DictionaryArray, ListArray, MapArray, PrimitiveArray, StructArray,
-            UnionArray, Utf8Array,
+            Array, BooleanArray, DictionaryArray, FixedSizeListArray, ListArray, MapArray,
+            PrimitiveArray, StructArray, UnionArray, Utf8Array,
         },
-        datatypes::DataType,
+        datatypes::{DataType, Field},
         types::f16,
     },
     internal::common::{DictionaryIndex, DictionaryValue},
 };
 use crate::{
     internal::{
-        common::{check_supported_list_layout, ArrayMapping, BitBuffer, BufferExtract, Buffers},
+        common::{
+            check_array_data_type, check_supported_list_layout, ArrayMapping, BitBuffer,
+            BufferExtract, Buffers,
+        },
         error::{error, fail},
         schema::{GenericDataType, GenericField},
     },
@@ -111,6 +114,10 @@ impl BufferExtract for dyn Array {
 
         use {ArrayMapping as M, GenericDataType as T};
 
+        let data_type = self.data_type();
+        let array_field = GenericField::try_from(&Field::new(&field.name, data_type.clone(), true));
+        check_array_data_type(field, array_field, data_type)?;
+
         match &field.data_type {
             T::Null => {
                 if !matches!(self.data_type(), DataType::Null) {
@@ -156,10 +163,32 @@ impl BufferExtract for dyn Array {
             T::F64 => convert_primitive!(f64, F64, push_u64_cast),
             T::Date64 => convert_primitive!(i64, Date64, push_u64_cast),
             T::Timestamp(_, _) => convert_primitive!(i64, Date64, push_u64_cast),
+            T::Decimal128(_, _) => convert_primitive!(i128, Decimal128, push_u128_cast),
             T::Utf8 => convert_utf8!(i32, Utf8, push_u32_cast),
             T::LargeUtf8 => convert_utf8!(i64, LargeUtf8, push_u64_cast),
             T::List => convert_list!(i32, List, push_u32_cast),
             T::LargeList => convert_list!(i64, LargeList, push_u64_cast),
+            T::FixedSizeList(_) => {
+                let Some(typed) = self.as_any().downcast_ref::<FixedSizeListArray>() else {
+                    fail!("cannot interpret array as FixedSizeList array");
+                };
+
+                let count = buffers.push_u0(typed.len());
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                let Some(item_field) = field.children.first() else {
+                    fail!("cannot get first child of list array")
+                };
+                let item = typed.values().extract_buffers(item_field, buffers)?;
+
+                Ok(M::FixedSizeList {
+                    field: field.clone(),
+                    item: Box::new(item),
+                    validity,
+                    n: typed.size(),
+                    count,
+                })
+            }
             T::Struct => {
                 let typed = self
                     .as_any()
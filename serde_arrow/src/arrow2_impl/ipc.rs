@@ -0,0 +1,514 @@
+//! Encode and decode rows as arrow2 IPC streams
+//!
+use std::{
+    collections::VecDeque,
+    io::{Cursor, Read, Write},
+    sync::{Arc, Mutex},
+};
+
+use arrow_format::ipc::{planus::ReadAsRoot, MessageRef};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    _impl::arrow2::{
+        array::Array,
+        chunk::Chunk,
+        datatypes::{Field, Schema},
+        io::ipc::{
+            read::{read_stream_metadata, StreamReader, StreamState},
+            write::{StreamWriter, WriteOptions},
+        },
+    },
+    internal::{
+        error::{error, fail, Result, RowError},
+        options::{DeserializationOptions, SerializationOptions},
+    },
+    Arrow2Builder,
+};
+
+/// The marker preceding the length of each message in the IPC stream format
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Encode pushed records as an Arrow IPC stream (*requires the `arrow2-ipc`
+/// feature and one of the `arrow2-*` features*)
+///
+/// Records are accumulated into record batches of
+/// [`batch_size`][Arrow2StreamWriter::with_batch_size] rows. Each complete
+/// batch is encoded and written to the underlying writer right away. Only a
+/// single batch is kept in memory, independent of the total number of records.
+/// The schema is written when the writer is constructed.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{Arrow2StreamDecoder, Arrow2StreamWriter};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = vec![Field::new("a", DataType::UInt32, false)];
+///
+/// let mut writer = Arrow2StreamWriter::new(Vec::<u8>::new(), &fields)?.with_batch_size(1_000);
+/// for a in 0..10_000 {
+///     writer.push(&Record { a })?;
+/// }
+/// let bytes = writer.finish()?;
+///
+/// let mut decoder = Arrow2StreamDecoder::new();
+/// let rows = decoder.feed::<Record>(&bytes)?;
+/// assert_eq!(rows.len(), 10_000);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Arrow2StreamWriter<W: Write> {
+    writer: StreamWriter<W>,
+    fields: Vec<Field>,
+    builder: Arrow2Builder,
+    batch_size: usize,
+    /// The number of records pushed to the builder, but not yet written
+    buffered_rows: usize,
+}
+
+impl<W: Write> std::fmt::Debug for Arrow2StreamWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Arrow2StreamWriter")
+            .field("fields", &self.fields)
+            .field("batch_size", &self.batch_size)
+            .field("buffered_rows", &self.buffered_rows)
+            .finish()
+    }
+}
+
+impl<W: Write> Arrow2StreamWriter<W> {
+    /// Build a new writer and write the schema of the stream
+    pub fn new(writer: W, fields: &[Field]) -> Result<Self> {
+        Self::with_options(writer, fields, SerializationOptions::default())
+    }
+
+    /// Build a new writer with custom serialization options and write the
+    /// schema of the stream
+    pub fn with_options(
+        writer: W,
+        fields: &[Field],
+        options: SerializationOptions,
+    ) -> Result<Self> {
+        let builder = Arrow2Builder::with_options(fields, options)?;
+        // the fields of the builder reflect the null policy
+        let fields = builder.fields()?;
+        let mut writer = StreamWriter::new(writer, WriteOptions { compression: None });
+        writer.start(&Schema::from(fields.clone()), None)?;
+
+        Ok(Self {
+            writer,
+            fields,
+            builder,
+            batch_size: 65_536,
+            buffered_rows: 0,
+        })
+    }
+
+    /// Set the number of records per record batch (default: 65536)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Add a single record, writing the current batch if it is complete
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.builder.push(item)?;
+        self.buffered_rows += 1;
+        self.write_full_batch()
+    }
+
+    /// Add the records of a slice, writing each batch once it is complete
+    pub fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        let mut items = items;
+        while !items.is_empty() {
+            // fill the current batch first, the buffered rows are always
+            // fewer than the batch size
+            let len = (self.batch_size - self.buffered_rows).min(items.len());
+            let (chunk, rest) = items.split_at(len);
+            self.builder.extend_from_slice(chunk)?;
+            self.buffered_rows += chunk.len();
+            self.write_full_batch()?;
+            items = rest;
+        }
+        Ok(())
+    }
+
+    /// Write the buffered records as a record batch, even if it is not
+    /// complete
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffered_rows > 0 {
+            self.write_batch()?;
+        }
+        Ok(())
+    }
+
+    /// Write the remaining records, end the stream and return the underlying
+    /// writer
+    pub fn finish(mut self) -> Result<W> {
+        self.flush()?;
+        self.writer.finish()?;
+        Ok(self.writer.into_inner())
+    }
+
+    fn write_full_batch(&mut self) -> Result<()> {
+        if self.buffered_rows < self.batch_size {
+            return Ok(());
+        }
+        self.write_batch()
+    }
+
+    fn write_batch(&mut self) -> Result<()> {
+        let chunk = Chunk::try_new(self.builder.build_arrays()?)?;
+        self.buffered_rows = 0;
+        self.writer.write(&chunk, None)?;
+        Ok(())
+    }
+}
+
+/// Decode rows from an Arrow IPC stream fed message by message (*requires the
+/// `arrow2-ipc` feature and one of the `arrow2-*` features*)
+///
+/// The decoder accepts the bytes of the stream in arbitrary pieces, e.g., the
+/// frames of a network protocol. Each call to
+/// [`feed`][Arrow2StreamDecoder::feed] returns the rows of the record batches
+/// completed by the given bytes. The first message of the stream must be the
+/// schema. Dictionary batches are supported.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::{
+///     chunk::Chunk,
+///     datatypes::{DataType, Field, Schema},
+///     io::ipc::write::{StreamWriter, WriteOptions},
+/// };
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::Arrow2StreamDecoder;
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = vec![Field::new("a", DataType::UInt32, false)];
+/// let arrays = serde_arrow::to_arrow2(&fields, &[Record { a: 1 }, Record { a: 2 }])?;
+///
+/// let mut bytes = Vec::new();
+/// let mut writer = StreamWriter::new(&mut bytes, WriteOptions { compression: None });
+/// writer.start(&Schema::from(fields), None)?;
+/// writer.write(&Chunk::new(arrays), None)?;
+/// writer.finish()?;
+///
+/// let mut decoder = Arrow2StreamDecoder::new();
+/// let mut rows = Vec::<Record>::new();
+/// for frame in bytes.chunks(16) {
+///     rows.extend(decoder.feed::<Record>(frame)?);
+/// }
+///
+/// assert_eq!(rows, vec![Record { a: 1 }, Record { a: 2 }]);
+/// assert!(decoder.is_finished());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Arrow2StreamDecoder {
+    /// The bytes fed to the decoder that do not yet form a complete message
+    buffer: Vec<u8>,
+    /// The complete messages not yet consumed by the reader
+    messages: MessageQueue,
+    reader: Option<StreamReader<MessageQueue>>,
+    fields: Vec<Field>,
+    options: DeserializationOptions,
+    /// The number of rows decoded so far
+    num_rows: usize,
+    finished: bool,
+    /// Whether a previous call to `feed` failed to read the stream
+    poisoned: bool,
+}
+
+impl std::fmt::Debug for Arrow2StreamDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Arrow2StreamDecoder")
+            .field("buffered_bytes", &self.buffer.len())
+            .field("fields", &self.fields)
+            .field("finished", &self.finished)
+            .field("poisoned", &self.poisoned)
+            .finish()
+    }
+}
+
+impl Default for Arrow2StreamDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Arrow2StreamDecoder {
+    /// Build a new decoder
+    pub fn new() -> Self {
+        Self::with_options(DeserializationOptions::default())
+    }
+
+    /// Build a new decoder with custom deserialization options
+    pub fn with_options(options: DeserializationOptions) -> Self {
+        Self {
+            buffer: Vec::new(),
+            messages: MessageQueue::default(),
+            reader: None,
+            fields: Vec::new(),
+            options,
+            num_rows: 0,
+            finished: false,
+            poisoned: false,
+        }
+    }
+
+    /// Feed the next bytes of the stream and return the decoded rows
+    ///
+    /// The bytes may end in the middle of a message. Incomplete messages are
+    /// buffered until the remaining bytes are fed. Bytes fed after the end of
+    /// the stream are ignored.
+    ///
+    /// If a row fails to deserialize, the error is returned and the rows
+    /// decoded in the same call are discarded. The decoder stays usable: the
+    /// messages following the failing one are decoded by the next call. Use
+    /// [`feed_collect_errors`][Self::feed_collect_errors] to keep the other
+    /// rows. If the stream itself is invalid, e.g., a malformed message, the
+    /// decoder is poisoned and any further call to `feed` returns an error.
+    pub fn feed<T: DeserializeOwned>(&mut self, bytes: &[u8]) -> Result<Vec<T>> {
+        let mut rows = Vec::new();
+        self.feed_chunks(bytes, |fields, arrays, options, _| {
+            let batch: Vec<T> = crate::from_arrow2_with_options(fields, arrays, options)?;
+            rows.extend(batch);
+            Ok(())
+        })?;
+        Ok(rows)
+    }
+
+    /// Feed the next bytes of the stream and return the decoded rows together
+    /// with the errors of individual rows
+    ///
+    /// In contrast to [`feed`][Self::feed], rows that fail to deserialize are
+    /// skipped and reported as [`RowError`][crate::RowError]. The row index
+    /// counts the rows since the start of the stream. Errors of the stream
+    /// itself are still returned as errors and poison the decoder.
+    pub fn feed_collect_errors<T: DeserializeOwned>(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(Vec<T>, Vec<RowError>)> {
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+        self.feed_chunks(bytes, |fields, arrays, options, first_row| {
+            let (batch, batch_errors) =
+                crate::arrow2_impl::api::from_arrow2_collect_errors_with_options::<T, _>(
+                    fields, arrays, options,
+                )?;
+            rows.extend(batch);
+            errors.extend(batch_errors.into_iter().map(|mut err| {
+                err.row += first_row;
+                err
+            }));
+            Ok(())
+        })?;
+        Ok((rows, errors))
+    }
+
+    fn feed_chunks(
+        &mut self,
+        bytes: &[u8],
+        mut on_chunk: impl FnMut(
+            &[Field],
+            &[Box<dyn Array>],
+            &DeserializationOptions,
+            usize,
+        ) -> Result<()>,
+    ) -> Result<()> {
+        if self.poisoned {
+            fail!("Cannot feed a stream decoder after a previous error");
+        }
+        if self.finished {
+            return Ok(());
+        }
+        self.buffer.extend_from_slice(bytes);
+
+        let mut consumed = 0;
+        let res = self.decode_messages(&mut consumed, &mut on_chunk);
+
+        // drop the consumed messages even on errors, as they were already
+        // passed to the reader
+        self.buffer.drain(..consumed);
+        res
+    }
+
+    fn decode_messages(
+        &mut self,
+        consumed: &mut usize,
+        on_chunk: &mut impl FnMut(
+            &[Field],
+            &[Box<dyn Array>],
+            &DeserializationOptions,
+            usize,
+        ) -> Result<()>,
+    ) -> Result<()> {
+        loop {
+            let message = next_message(&self.buffer[*consumed..]);
+            let Some(message) = self.poison_on_error(message)? else {
+                break;
+            };
+            let message_bytes = &self.buffer[*consumed..*consumed + message.len];
+            *consumed += message.len;
+
+            if message.end_of_stream {
+                self.finished = true;
+                break;
+            }
+
+            let Some(reader) = self.reader.as_mut() else {
+                let metadata = match read_stream_metadata(&mut Cursor::new(message_bytes)) {
+                    Ok(metadata) => metadata,
+                    Err(err) => {
+                        self.poisoned = true;
+                        return Err(err.into());
+                    }
+                };
+                self.fields = metadata.schema.fields.clone();
+                self.reader = Some(StreamReader::new(self.messages.clone(), metadata, None));
+                continue;
+            };
+
+            if let Err(err) = self.messages.push(message_bytes) {
+                self.poisoned = true;
+                return Err(err);
+            }
+            match reader.next() {
+                Some(Ok(StreamState::Some(chunk))) => {
+                    let first_row = self.num_rows;
+                    self.num_rows += chunk.len();
+                    on_chunk(&self.fields, chunk.arrays(), &self.options, first_row)?;
+                }
+                // dictionary batches do not contain rows
+                Some(Ok(StreamState::Waiting)) => {}
+                Some(Err(err)) => {
+                    self.poisoned = true;
+                    return Err(err.into());
+                }
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Poison the decoder if the stream could not be read
+    fn poison_on_error<T>(&mut self, res: Result<T>) -> Result<T> {
+        if res.is_err() {
+            self.poisoned = true;
+        }
+        res
+    }
+
+    /// The fields of the stream, empty until the schema message was decoded
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Whether the end of the stream was reached
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// The framing of a complete message at the start of the buffer
+struct Message {
+    len: usize,
+    end_of_stream: bool,
+}
+
+/// Determine the length of the first message, if it is complete
+///
+/// Both the current format (continuation marker followed by the metadata
+/// length) and the legacy format (metadata length only) are supported.
+fn next_message(buffer: &[u8]) -> Result<Option<Message>> {
+    let Some(prefix) = buffer.get(..4) else {
+        return Ok(None);
+    };
+    let (prefix_len, meta_len) = if prefix == CONTINUATION_MARKER {
+        let Some(meta_len) = buffer.get(4..8) else {
+            return Ok(None);
+        };
+        (8, meta_len)
+    } else {
+        (4, prefix)
+    };
+
+    let meta_len = i32::from_le_bytes(meta_len.try_into().unwrap());
+    let Ok(meta_len) = usize::try_from(meta_len) else {
+        fail!("Invalid IPC message: negative metadata length {meta_len}");
+    };
+    if meta_len == 0 {
+        return Ok(Some(Message {
+            len: prefix_len,
+            end_of_stream: true,
+        }));
+    }
+
+    let Some(meta) = buffer.get(prefix_len..prefix_len + meta_len) else {
+        return Ok(None);
+    };
+    let message =
+        MessageRef::read_as_root(meta).map_err(|err| error!("Invalid IPC message: {err}"))?;
+    let body_len = message
+        .body_length()
+        .map_err(|err| error!("Invalid IPC message: {err}"))?;
+    let Ok(body_len) = usize::try_from(body_len) else {
+        fail!("Invalid IPC message: negative body length {body_len}");
+    };
+
+    let Some(len) = prefix_len
+        .checked_add(meta_len)
+        .and_then(|len| len.checked_add(body_len))
+    else {
+        fail!("Invalid IPC message: message too large ({body_len} body bytes)");
+    };
+    if buffer.len() < len {
+        return Ok(None);
+    }
+    Ok(Some(Message {
+        len,
+        end_of_stream: false,
+    }))
+}
+
+/// The complete messages passed to the stream reader
+///
+/// The reader only ever sees complete messages. Once all messages are
+/// consumed, it reports an end of file and waits for the next message.
+#[derive(Debug, Clone, Default)]
+struct MessageQueue(Arc<Mutex<VecDeque<u8>>>);
+
+impl MessageQueue {
+    fn push(&self, message: &[u8]) -> Result<()> {
+        let Ok(mut queue) = self.0.lock() else {
+            fail!("Poisoned message queue");
+        };
+        queue.extend(message);
+        Ok(())
+    }
+}
+
+impl Read for MessageQueue {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Ok(mut queue) = self.0.lock() else {
+            return Err(std::io::Error::other("Poisoned message queue"));
+        };
+        queue.read(buf)
+    }
+}
@@ -5,6 +5,10 @@
 #![deny(missing_docs)]
 pub(crate) mod api;
 pub(crate) mod deserialization;
+#[cfg(feature = "arrow2-ipc")]
+pub(crate) mod ipc;
+#[cfg(feature = "object-store")]
+pub(crate) mod object_store;
 pub(crate) mod schema;
 pub(crate) mod serialization;
 mod type_support;
@@ -0,0 +1,253 @@
+//! Upload records to object stores as arrow2 IPC files
+//!
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use object_store::{path::Path, ObjectStore};
+use serde::Serialize;
+
+use crate::{
+    _impl::arrow2::{
+        chunk::Chunk,
+        datatypes::{Field, Schema},
+        io::ipc::write::{FileWriter, WriteOptions},
+    },
+    internal::{
+        error::{Error, Result},
+        options::SerializationOptions,
+    },
+    Arrow2Builder,
+};
+
+/// An async sink that uploads pushed records to an object store as IPC files
+/// (*requires the `object-store` feature and one of the `arrow2-*` features*)
+///
+/// Records are accumulated into record batches of
+/// [`batch_size`][Arrow2ObjectStoreSink::with_batch_size] rows, which are
+/// encoded into the current part, an Arrow IPC file. Once the encoded part
+/// reaches [`part_size`][Arrow2ObjectStoreSink::with_part_size] bytes, it is
+/// uploaded as `{prefix}/part-{index:05}.arrow` and a new part is started.
+/// The sink works with any [`ObjectStore`], e.g., S3, GCS, or Azure.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// # futures::executor::block_on(async {
+/// # use serde_arrow::_impl::arrow2;
+/// use std::sync::Arc;
+///
+/// use arrow2::datatypes::{DataType, Field};
+/// use object_store::{memory::InMemory, path::Path, ObjectStore};
+/// use serde::Serialize;
+/// use serde_arrow::Arrow2ObjectStoreSink;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let store = Arc::new(InMemory::new());
+/// let fields = vec![Field::new("a", DataType::UInt32, false)];
+///
+/// let mut sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("records"), &fields)?
+///     .with_batch_size(1_000);
+/// for a in 0..10_000 {
+///     sink.push(&Record { a }).await?;
+/// }
+/// let parts = sink.finish().await?;
+///
+/// assert_eq!(parts, vec![Path::from("records/part-00000.arrow")]);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct Arrow2ObjectStoreSink {
+    store: Arc<dyn ObjectStore>,
+    prefix: Path,
+    fields: Vec<Field>,
+    builder: Arrow2Builder,
+    batch_size: usize,
+    part_size: usize,
+    /// The number of records pushed to the builder, but not yet written
+    buffered_rows: usize,
+    /// The writer of the current part, if any batch was written to it
+    writer: Option<FileWriter<PartBuffer>>,
+    /// The number of bytes written to the current part
+    part_len: Arc<AtomicUsize>,
+    parts: Vec<Path>,
+}
+
+impl std::fmt::Debug for Arrow2ObjectStoreSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Arrow2ObjectStoreSink")
+            .field("store", &self.store)
+            .field("prefix", &self.prefix)
+            .field("fields", &self.fields)
+            .field("batch_size", &self.batch_size)
+            .field("part_size", &self.part_size)
+            .field("buffered_rows", &self.buffered_rows)
+            .field("parts", &self.parts)
+            .finish()
+    }
+}
+
+impl Arrow2ObjectStoreSink {
+    /// Build a new sink uploading parts below `prefix`
+    ///
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: Path, fields: &[Field]) -> Result<Self> {
+        Self::with_options(store, prefix, fields, SerializationOptions::default())
+    }
+
+    /// Build a new sink with custom serialization options
+    ///
+    pub fn with_options(
+        store: Arc<dyn ObjectStore>,
+        prefix: Path,
+        fields: &[Field],
+        options: SerializationOptions,
+    ) -> Result<Self> {
+        let builder = Arrow2Builder::with_options(fields, options)?;
+        Ok(Self {
+            store,
+            prefix,
+            // the fields of the builder reflect the null policy
+            fields: builder.fields()?,
+            builder,
+            batch_size: 65_536,
+            part_size: 128 * 1024 * 1024,
+            buffered_rows: 0,
+            writer: None,
+            part_len: Arc::default(),
+            parts: Vec::new(),
+        })
+    }
+
+    /// Set the number of records per record batch (default: 65536)
+    ///
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the size in bytes after which a part is uploaded (default: 128 MiB)
+    ///
+    /// Parts are only rotated between record batches, therefore parts
+    /// exceed this size by up to one record batch.
+    pub fn with_part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Add a single record, uploading the current part if it is complete
+    ///
+    pub async fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.builder.push(item)?;
+        self.buffered_rows += 1;
+        self.write_full_batch().await
+    }
+
+    /// Add the records of a slice, uploading the current part if it is
+    /// complete
+    ///
+    pub async fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        let mut items = items;
+        while !items.is_empty() {
+            // fill the current batch first, the buffered rows are always
+            // fewer than the batch size
+            let len = (self.batch_size - self.buffered_rows).min(items.len());
+            let (chunk, rest) = items.split_at(len);
+            self.builder.extend_from_slice(chunk)?;
+            self.buffered_rows += chunk.len();
+            self.write_full_batch().await?;
+            items = rest;
+        }
+        Ok(())
+    }
+
+    /// Write the remaining records, upload the last part and return the paths
+    /// of all uploaded parts
+    ///
+    pub async fn finish(mut self) -> Result<Vec<Path>> {
+        if self.buffered_rows > 0 {
+            self.write_batch()?;
+        }
+        self.upload_part().await?;
+        Ok(self.parts)
+    }
+
+    async fn write_full_batch(&mut self) -> Result<()> {
+        if self.buffered_rows < self.batch_size {
+            return Ok(());
+        }
+        self.write_batch()?;
+        if self.part_len.load(Ordering::Relaxed) >= self.part_size {
+            self.upload_part().await?;
+        }
+        Ok(())
+    }
+
+    fn write_batch(&mut self) -> Result<()> {
+        let chunk = Chunk::try_new(self.builder.build_arrays()?)?;
+        self.buffered_rows = 0;
+
+        let writer = match self.writer.as_mut() {
+            Some(writer) => writer,
+            None => self.writer.insert(FileWriter::try_new(
+                PartBuffer {
+                    bytes: Vec::new(),
+                    len: Arc::clone(&self.part_len),
+                },
+                Schema::from(self.fields.clone()),
+                None,
+                WriteOptions { compression: None },
+            )?),
+        };
+        writer.write(&chunk, None)?;
+        Ok(())
+    }
+
+    async fn upload_part(&mut self) -> Result<()> {
+        let Some(mut writer) = self.writer.take() else {
+            return Ok(());
+        };
+        writer.finish()?;
+        self.part_len.store(0, Ordering::Relaxed);
+
+        let path = self
+            .prefix
+            .child(format!("part-{:05}.arrow", self.parts.len()));
+        self.store
+            .put(&path, writer.into_inner().bytes.into())
+            .await?;
+        self.parts.push(path);
+        Ok(())
+    }
+}
+
+impl From<object_store::Error> for Error {
+    fn from(err: object_store::Error) -> Self {
+        Self::custom_from(format!("object_store::Error: {err}"), err)
+    }
+}
+
+/// The encoded bytes of a part, sharing their length with the sink
+struct PartBuffer {
+    bytes: Vec<u8>,
+    len: Arc<AtomicUsize>,
+}
+
+impl Write for PartBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.bytes.extend_from_slice(buf);
+        self.len.store(self.bytes.len(), Ordering::Relaxed);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     _impl::arrow2::datatypes::{DataType, Field, IntegerType, TimeUnit, UnionMode},
     internal::{
-        error::{error, fail, Error, Result},
+        error::{error, fail, Error, ErrorKind, Result},
         schema::{
             GenericDataType, GenericField, GenericTimeUnit, SchemaLike, Sealed, SerdeArrowSchema,
-            Strategy, STRATEGY_KEY,
+            Strategy, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, LAYOUT_HINTS_KEY,
+            NULL_DEFAULT_KEY, STRATEGY_KEY,
         },
     },
 };
@@ -86,6 +87,10 @@ impl TryFrom<&Field> for GenericField {
             Some(strategy_str) => Some(strategy_str.parse::<Strategy>()?),
             None => None,
         };
+        let null_default = field.metadata.get(NULL_DEFAULT_KEY).cloned();
+        let extension_name = field.metadata.get(EXTENSION_NAME_KEY).cloned();
+        let extension_metadata = field.metadata.get(EXTENSION_METADATA_KEY).cloned();
+        let layout_hints = field.metadata.get(LAYOUT_HINTS_KEY).cloned();
         let name = field.name.to_owned();
         let nullable = field.is_nullable;
 
@@ -119,6 +124,9 @@ impl TryFrom<&Field> for GenericField {
             DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
                 GenericDataType::Timestamp(GenericTimeUnit::Nanosecond, tz.clone())
             }
+            DataType::Decimal(precision, scale) => {
+                GenericDataType::Decimal128((*precision).try_into()?, (*scale).try_into()?)
+            }
             DataType::List(field) => {
                 children.push(GenericField::try_from(field.as_ref())?);
                 GenericDataType::List
@@ -127,6 +135,10 @@ impl TryFrom<&Field> for GenericField {
                 children.push(field.as_ref().try_into()?);
                 GenericDataType::LargeList
             }
+            DataType::FixedSizeList(field, n) => {
+                children.push(field.as_ref().try_into()?);
+                GenericDataType::FixedSizeList((*n).try_into()?)
+            }
             DataType::Struct(fields) => {
                 for field in fields {
                     children.push(field.try_into()?);
@@ -168,7 +180,12 @@ impl TryFrom<&Field> for GenericField {
                 children.push((&Field::new("", data_type.as_ref().clone(), false)).try_into()?);
                 GenericDataType::Dictionary
             }
-            dt => fail!("Cannot convert data type {dt:?}"),
+            dt => fail!(
+                kind = ErrorKind::UnsupportedDataType {
+                    data_type: format!("{dt:?}")
+                },
+                "Cannot convert data type {dt:?}"
+            ),
         };
 
         let field = GenericField {
@@ -177,6 +194,10 @@ impl TryFrom<&Field> for GenericField {
             strategy,
             children,
             nullable,
+            null_default,
+            extension_name,
+            extension_metadata,
+            layout_hints,
         };
         field.validate()?;
 
@@ -215,6 +236,9 @@ impl TryFrom<&GenericField> for Field {
             GenericDataType::Timestamp(GenericTimeUnit::Nanosecond, tz) => {
                 DataType::Timestamp(TimeUnit::Nanosecond, tz.clone())
             }
+            GenericDataType::Decimal128(precision, scale) => {
+                DataType::Decimal((*precision).into(), (*scale).try_into()?)
+            }
             GenericDataType::Utf8 => DataType::Utf8,
             GenericDataType::LargeUtf8 => DataType::LargeUtf8,
             GenericDataType::List => DataType::List(Box::new(
@@ -231,6 +255,16 @@ impl TryFrom<&GenericField> for Field {
                     .ok_or_else(|| error!("List must a single child"))?
                     .try_into()?,
             )),
+            GenericDataType::FixedSizeList(n) => DataType::FixedSizeList(
+                Box::new(
+                    value
+                        .children
+                        .first()
+                        .ok_or_else(|| error!("List must a single child"))?
+                        .try_into()?,
+                ),
+                (*n).try_into()?,
+            ),
             GenericDataType::Struct => DataType::Struct(
                 value
                     .children
@@ -282,9 +316,7 @@ impl TryFrom<&GenericField> for Field {
         };
 
         let mut field = Field::new(&value.name, data_type, value.nullable);
-        if let Some(strategy) = value.strategy.as_ref() {
-            field.metadata = strategy.clone().into();
-        }
+        field.metadata = value.get_metadata();
 
         Ok(field)
     }
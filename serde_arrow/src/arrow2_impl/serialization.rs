@@ -3,8 +3,10 @@
 use crate::{
     _impl::arrow2::{
         array::{
-            Array, BooleanArray, DictionaryArray, ListArray, MapArray, NullArray, PrimitiveArray,
-            StructArray, UnionArray, Utf8Array,
+            growable::make_growable, Array, BooleanArray, DictionaryArray, FixedSizeListArray,
+            ListArray, MapArray, MutableArray, MutableBooleanArray, MutablePrimitiveArray,
+            MutableUtf8Array, NullArray, PrimitiveArray, StructArray, TryExtendFromSelf,
+            UnionArray, Utf8Array,
         },
         bitmap::Bitmap,
         buffer::Buffer,
@@ -19,18 +21,42 @@ use crate::internal::{
     common::{ArrayMapping, DictionaryIndex, DictionaryValue},
     conversions::ToBytes,
     error::Result,
-    serialization::{interpreter::MutableBuffers, Interpreter},
+    instrument::{span, ColumnTimer},
+    metrics::{ColumnMetrics, Instant},
+    schema::GenericField,
+    serialization::{interpreter::MutableBuffers, sort::index_runs, Interpreter},
 };
 
 impl Interpreter {
     /// Build the arrow2 arrays
     pub fn build_arrow2_arrays(&mut self) -> Result<Vec<Box<dyn Array>>> {
+        span!(
+            "build_arrays",
+            num_columns = self.structure.array_mapping.len(),
+            num_rows = self.buffers.num_rows,
+        );
+        self.apply_empty_list_policy();
+        self.collect_statistics();
+        let sort_indices = self.sort_indices()?;
+        let start = self.metrics.as_ref().map(|_| Instant::now());
+        let num_rows = self.buffers.num_rows;
+
         let mut res = Vec::new();
+        let mut columns = Vec::new();
         for mapping in &self.structure.array_mapping {
+            let timer = ColumnTimer::start(&self.buffers);
+            let size = start.map(|_| self.buffers.estimated_size());
             let array = build_array(&mut self.buffers, mapping)?;
             res.push(array);
+            timer.finish(mapping.get_field(), &self.buffers);
+            if let Some(size) = size {
+                columns.push(ColumnMetrics {
+                    name: mapping.get_field().name.clone(),
+                    bytes: size.saturating_sub(self.buffers.estimated_size()),
+                });
+            }
         }
-        self.buffers.clear();
+        self.clear_buffers();
 
         let max_len = res.iter().map(|a| a.len()).max().unwrap_or_default();
         for (arr, mapping) in res.iter().zip(&self.structure.array_mapping) {
@@ -43,6 +69,25 @@ impl Interpreter {
             }
         }
 
+        if let Some(indices) = sort_indices {
+            let runs = index_runs(&indices);
+            res = res
+                .iter()
+                .map(|arr| take_array(arr.as_ref(), &runs))
+                .collect();
+        }
+
+        if self.structure.validate_arrays {
+            for (arr, mapping) in res.iter().zip(&self.structure.array_mapping) {
+                validate_array(arr.as_ref(), mapping.get_field())?;
+            }
+        }
+
+        if let (Some(metrics), Some(start)) = (self.metrics.as_mut(), start) {
+            metrics.add_execute_time(start.elapsed());
+            metrics.report(num_rows, columns);
+        }
+
         Ok(res)
     }
 
@@ -53,6 +98,182 @@ impl Interpreter {
         }
         Ok(arrays.into_iter().next().unwrap())
     }
+
+    /// Build the arrow2 arrays as mutable arrays
+    ///
+    /// Only arrays without children (booleans, primitives and strings) are
+    /// supported.
+    pub fn build_arrow2_mutable_arrays(&mut self) -> Result<Vec<Box<dyn MutableArray>>> {
+        let arrays = self.build_arrow2_arrays()?;
+        let mut res = Vec::new();
+        for (array, mapping) in arrays.into_iter().zip(&self.structure.array_mapping) {
+            res.push(into_mutable_array(array, mapping.get_field())?);
+        }
+        Ok(res)
+    }
+
+    /// Build the arrow2 arrays and append them to the given mutable arrays
+    ///
+    /// The mutable arrays must be of the types returned by
+    /// [`build_arrow2_mutable_arrays`][Interpreter::build_arrow2_mutable_arrays].
+    pub fn append_to_arrow2_mutable_arrays(
+        &mut self,
+        targets: &mut [&mut dyn MutableArray],
+    ) -> Result<()> {
+        if targets.len() != self.structure.array_mapping.len() {
+            fail!(
+                "Cannot append {} arrays to {} mutable arrays",
+                self.structure.array_mapping.len(),
+                targets.len(),
+            );
+        }
+
+        // check all targets before building, to keep the rows on errors
+        for (target, mapping) in targets.iter().zip(&self.structure.array_mapping) {
+            check_mutable_target(&**target, mapping.get_field())?;
+        }
+
+        let arrays = self.build_arrow2_mutable_arrays()?;
+        for ((array, target), mapping) in arrays
+            .iter()
+            .zip(targets.iter_mut())
+            .zip(&self.structure.array_mapping)
+        {
+            append_to_mutable_array(array.as_ref(), &mut **target, mapping.get_field())?;
+        }
+        Ok(())
+    }
+}
+
+/// Call `$func!(ArrayType, MutableArrayType)` for all arrays that can be
+/// converted into mutable arrays
+macro_rules! dispatch_mutable_arrays {
+    ($func:ident) => {
+        $func!(BooleanArray, MutableBooleanArray);
+        $func!(PrimitiveArray<i8>, MutablePrimitiveArray<i8>);
+        $func!(PrimitiveArray<i16>, MutablePrimitiveArray<i16>);
+        $func!(PrimitiveArray<i32>, MutablePrimitiveArray<i32>);
+        $func!(PrimitiveArray<i64>, MutablePrimitiveArray<i64>);
+        $func!(PrimitiveArray<i128>, MutablePrimitiveArray<i128>);
+        $func!(PrimitiveArray<u8>, MutablePrimitiveArray<u8>);
+        $func!(PrimitiveArray<u16>, MutablePrimitiveArray<u16>);
+        $func!(PrimitiveArray<u32>, MutablePrimitiveArray<u32>);
+        $func!(PrimitiveArray<u64>, MutablePrimitiveArray<u64>);
+        $func!(PrimitiveArray<f16>, MutablePrimitiveArray<f16>);
+        $func!(PrimitiveArray<f32>, MutablePrimitiveArray<f32>);
+        $func!(PrimitiveArray<f64>, MutablePrimitiveArray<f64>);
+        $func!(Utf8Array<i32>, MutableUtf8Array<i32>);
+        $func!(Utf8Array<i64>, MutableUtf8Array<i64>);
+    };
+}
+
+fn into_mutable_array(
+    mut array: Box<dyn Array>,
+    field: &GenericField,
+) -> Result<Box<dyn MutableArray>> {
+    macro_rules! into_mutable {
+        ($array_ty:ty, $mutable_ty:ty) => {
+            if let Some(array) = array.as_mut().as_any_mut().downcast_mut::<$array_ty>() {
+                let empty = <$array_ty>::new_empty(array.data_type().clone());
+                let array = std::mem::replace(array, empty);
+                // the buffers were just built and are not shared
+                let Some(array) = array.into_mut().right() else {
+                    fail!(
+                        "Cannot convert the array of field {name} into a mutable array: its buffers are shared",
+                        name = field.name,
+                    );
+                };
+                return Ok(Box::new(array));
+            }
+        };
+    }
+    dispatch_mutable_arrays!(into_mutable);
+
+    fail!(
+        "Cannot convert the array of field {name} with data type {data_type:?} into a mutable array",
+        name = field.name,
+        data_type = array.data_type(),
+    );
+}
+
+/// Check that the arrays built for the field can be appended to the target
+fn check_mutable_target(target: &dyn MutableArray, field: &GenericField) -> Result<()> {
+    let expected = Field::try_from(field)?.data_type;
+    if &expected != target.data_type() {
+        fail!(
+            "Cannot append the array of field {name}: expected a mutable array with data type {expected:?}, found {actual:?}",
+            name = field.name,
+            actual = target.data_type(),
+        );
+    }
+
+    macro_rules! check {
+        ($array_ty:ty, $mutable_ty:ty) => {
+            if target.as_any().is::<$mutable_ty>() {
+                return Ok(());
+            }
+        };
+    }
+    dispatch_mutable_arrays!(check);
+
+    fail!(
+        "Cannot append the array of field {name}: unsupported mutable array",
+        name = field.name,
+    );
+}
+
+fn append_to_mutable_array(
+    array: &dyn MutableArray,
+    target: &mut dyn MutableArray,
+    field: &GenericField,
+) -> Result<()> {
+    macro_rules! append {
+        ($array_ty:ty, $mutable_ty:ty) => {
+            if let Some(array) = array.as_any().downcast_ref::<$mutable_ty>() {
+                let Some(target) = target.as_mut_any().downcast_mut::<$mutable_ty>() else {
+                    fail!(
+                        "Cannot append the array of field {name}: expected a {ty}",
+                        name = field.name,
+                        ty = stringify!($mutable_ty),
+                    );
+                };
+                target.try_extend_from_self(array)?;
+                return Ok(());
+            }
+        };
+    }
+    dispatch_mutable_arrays!(append);
+
+    fail!(
+        "Cannot append the array of field {name}: unsupported mutable array",
+        name = field.name,
+    );
+}
+
+/// Copy the given runs of rows, given as `(start, len)`, into a new array
+fn take_array(array: &dyn Array, runs: &[(usize, usize)]) -> Box<dyn Array> {
+    let mut res = make_growable(&[array], false, array.len());
+    for &(start, len) in runs {
+        res.extend(0, start, len);
+    }
+    res.as_box()
+}
+
+/// Check the data type of the array
+///
+/// Note: arrow2 arrays validate their buffers on construction. Therefore only
+/// the data type has to be checked.
+fn validate_array(array: &dyn Array, field: &GenericField) -> Result<()> {
+    let expected = Field::try_from(field)?;
+    if array.data_type() != expected.data_type() {
+        fail!(
+            "Invalid array for field {name}: expected data type {expected:?}, found {actual:?}",
+            name = field.name,
+            expected = expected.data_type(),
+            actual = array.data_type(),
+        );
+    }
+    Ok(())
 }
 
 macro_rules! build_array_primitive {
@@ -170,6 +391,12 @@ fn build_array(buffers: &mut MutableBuffers, mapping: &ArrayMapping) -> Result<B
             validity,
             ..
         } => build_array_primitive!(buffers, i64, u64, field, *buffer, *validity),
+        M::Decimal128 {
+            field,
+            buffer,
+            validity,
+            ..
+        } => build_array_primitive!(buffers, i128, u128, field, *buffer, *validity),
         M::Utf8 {
             buffer,
             offsets,
@@ -289,6 +516,22 @@ fn build_array(buffers: &mut MutableBuffers, mapping: &ArrayMapping) -> Result<B
                 data_type, offsets, values, validity,
             )?))
         }
+        M::FixedSizeList {
+            field,
+            item,
+            count,
+            validity,
+            ..
+        } => {
+            let data_type = Field::try_from(field)?.data_type;
+            let values = build_array(buffers, item)?;
+            let validity = build_validity(buffers, *validity);
+            buffers.u0[*count].clear();
+
+            Ok(Box::new(FixedSizeListArray::try_new(
+                data_type, values, validity,
+            )?))
+        }
         M::Union {
             field,
             fields,
@@ -1,20 +1,41 @@
 #![deny(missing_docs)]
-use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash, sync::Arc};
 
-use crate::{
-    _impl::arrow::{
-        array::{Array, ArrayRef},
-        datatypes::Field,
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{
+    arrow::{
+        _raw::array::new_null_array,
+        array::{Array, ArrayData, ArrayRef, StructArray},
+        datatypes::{Field, Schema},
+        record_batch::{RecordBatch, RecordBatchOptions},
+    },
+    coercion::widen_array,
+    serialization::{build_array, build_arrays, build_arrays_data},
+};
+use crate::internal::{
+    builder::BuilderField,
+    coercion::Coercion,
+    common::{BufferExtract, Buffers},
+    deserialization,
+    display::{format_table, FieldLike},
+    error::{Result, RowError},
+    generic,
+    instrument::span,
+    metrics::DeserializationMetrics,
+    options::{DeserializationOptions, FailedItems, SerializationOptions},
+    rows_view::RowsView,
+    schema::{GenericField, SchemaLike, Sealed},
+    serialization::{
+        compile_serialization, statistics::ColumnStatistics, CompilationOptions, Interpreter,
     },
-    internal::{
-        error::Result,
-        generic,
-        schema::GenericField,
-        serialization::{compile_serialization, CompilationOptions, Interpreter},
-        sink::serialize_into_sink,
-        source::deserialize_from_source,
-        tracing::{Tracer, TracingOptions},
+    sink::serialize_into_sink,
+    source::{
+        deserialize_from_source_with_options, deserialize_into_vec_from_source_with_options,
+        deserialize_rows_from_source_with_options, RowIter,
     },
+    tracing::{Tracer, TracingOptions},
+    value::Value,
 };
 
 /// Build arrow arrays record by record (*requires one of the `arrow-*`
@@ -65,7 +86,7 @@ pub struct ArrowBuilder(generic::GenericBuilder);
 
 impl std::fmt::Debug for ArrowBuilder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ArrowBuilder<...>")
+        self.0 .0.fmt_progress("ArrowBuilder", f)
     }
 }
 
@@ -83,6 +104,36 @@ impl ArrowBuilder {
         Ok(Self(generic::GenericBuilder::new_for_arrays(&fields)?))
     }
 
+    /// Build a new ArrowBuilder for the given fields with custom serialization
+    /// options
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde_arrow::{
+    ///     utils::{Item, Overflow, SerializationOptions},
+    ///     ArrowBuilder,
+    /// };
+    ///
+    /// let mut builder = ArrowBuilder::with_options(
+    ///     &[Field::new("item", DataType::UInt8, false)],
+    ///     SerializationOptions::default().overflow(Overflow::Saturate),
+    /// )?;
+    /// builder.push(&Item(300_u32))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(fields: &[Field], options: SerializationOptions) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(generic::GenericBuilder::new_for_arrays_with_options(
+            &fields, &options,
+        )?))
+    }
+
     /// Add a single record to the arrays
     ///
     pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
@@ -95,12 +146,435 @@ impl ArrowBuilder {
         self.0.extend(items)
     }
 
+    /// Add the records of a slice to the arrays
+    ///
+    /// The result is identical to [`extend`][ArrowBuilder::extend]. For records
+    /// whose fields all map to non-nullable primitive columns in order (e.g.,
+    /// structs of integers, floats and bools), the fields are written
+    /// directly into the buffers, which is considerably faster. Other records
+    /// are serialized as in `extend`.
+    ///
+    pub fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        self.0.extend_from_slice(items)
+    }
+
+    /// The approximate size in bytes of the arrays built from the rows pushed
+    /// so far
+    ///
+    /// The size includes the values, offsets and validity bits of all
+    /// columns, but no metadata or padding. It can be used to size batches,
+    /// e.g., to target the size of IPC messages or Parquet pages.
+    ///
+    pub fn estimated_size(&self) -> usize {
+        self.0.estimated_size()
+    }
+
+    /// Invoke `callback` once the [estimated size][ArrowBuilder::estimated_size]
+    /// exceeds `budget` bytes
+    ///
+    /// The size is checked after each call to `push`, `extend` and
+    /// `extend_from_slice`. The callback receives the estimated size and is
+    /// invoked once per batch, i.e., it is invoked again only after the
+    /// arrays were built. A previously registered callback is replaced.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde_arrow::{utils::Item, ArrowBuilder};
+    ///
+    /// let full = Arc::new(AtomicBool::new(false));
+    /// let mut builder = ArrowBuilder::new(&[Field::new("item", DataType::UInt64, false)])?;
+    /// builder.on_size_exceeded(1024, {
+    ///     let full = Arc::clone(&full);
+    ///     move |_size| full.store(true, Ordering::Relaxed)
+    /// });
+    ///
+    /// let mut batches = Vec::new();
+    /// for item in 0..1_000_u64 {
+    ///     builder.push(&Item(item))?;
+    ///     if full.swap(false, Ordering::Relaxed) {
+    ///         batches.push(builder.build_arrays()?);
+    ///     }
+    /// }
+    /// assert_eq!(batches.len(), 7);
+    /// assert_eq!(batches[0][0].len(), 129);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_size_exceeded<F>(&mut self, budget: usize, callback: F)
+    where
+        F: FnMut(usize) + Send + Sync + 'static,
+    {
+        self.0.on_size_exceeded(budget, callback)
+    }
+
     /// Build the arrays from the rows pushed to far.
     ///
     /// This operation will reset the underlying buffers and start a new batch.
     ///
     pub fn build_arrays(&mut self) -> Result<Vec<ArrayRef>> {
-        self.0 .0.build_arrow_arrays()
+        build_arrays(&mut self.0 .0)
+    }
+
+    /// Build the data of the arrays from the rows pushed so far
+    ///
+    /// In contrast to [`build_arrays`][ArrowBuilder::build_arrays], no typed
+    /// arrays are constructed. The returned `ArrayData` shares the buffers
+    /// written by the builder, e.g., to slice the data before building arrays
+    /// or to pass it to APIs consuming `ArrayData`. As `build_arrays`, this
+    /// operation will reset the underlying buffers and start a new batch.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::{
+    ///     array::make_array,
+    ///     datatypes::{DataType, Field},
+    /// };
+    /// use serde_arrow::{utils::Item, ArrowBuilder};
+    ///
+    /// let mut builder = ArrowBuilder::new(&[Field::new("item", DataType::UInt8, false)])?;
+    /// builder.extend(&[Item(1_u8), Item(2), Item(3)])?;
+    ///
+    /// let data = builder.build_array_data()?;
+    /// let array = make_array(data[0].slice(1, 2));
+    /// assert_eq!(array.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn build_array_data(&mut self) -> Result<Vec<ArrayData>> {
+        build_arrays_data(&mut self.0 .0)
+    }
+
+    /// The statistics of the columns built by the last call to
+    /// [`build_arrays`][ArrowBuilder::build_arrays]
+    ///
+    /// The statistics are only collected, if the
+    /// [`column_statistics`][crate::utils::SerializationOptions::column_statistics]
+    /// option is set. Otherwise, the returned slice is empty.
+    ///
+    pub fn column_statistics(&self) -> &[ColumnStatistics] {
+        &self.0 .0.statistics
+    }
+
+    /// The fields of the arrays built by this builder
+    ///
+    /// The fields reflect any changes made with
+    /// [`rename_field`][ArrowBuilder::rename_field] and
+    /// [`with_field_order`][ArrowBuilder::with_field_order].
+    ///
+    pub fn fields(&self) -> Result<Vec<Field>> {
+        self.0.fields().iter().map(Field::try_from).collect()
+    }
+
+    /// Rename the column `old` to `new`
+    ///
+    /// Rows already pushed are kept. Records are still matched by the original
+    /// field name, only the name of the built column changes. Only top-level
+    /// fields can be renamed.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde::Serialize;
+    /// use serde_arrow::ArrowBuilder;
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     a: u8,
+    ///     b: u8,
+    /// }
+    ///
+    /// let mut builder = ArrowBuilder::new(&[
+    ///     Field::new("a", DataType::UInt8, false),
+    ///     Field::new("b", DataType::UInt8, false),
+    /// ])?;
+    /// builder.push(&Record { a: 1, b: 2 })?;
+    ///
+    /// builder.rename_field("a", "x")?;
+    /// builder.with_field_order(&["b", "x"])?;
+    /// builder.push(&Record { a: 3, b: 4 })?;
+    ///
+    /// let fields = builder.fields()?;
+    /// assert_eq!(fields[0].name(), "b");
+    /// assert_eq!(fields[1].name(), "x");
+    ///
+    /// let arrays = builder.build_arrays()?;
+    /// assert_eq!(arrays[0].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_field(&mut self, old: &str, new: &str) -> Result<()> {
+        self.0.rename_field(old, new)
+    }
+
+    /// Change the order of the columns
+    ///
+    /// `names` must contain the name of each column exactly once. Rows already
+    /// pushed are kept and records can still be given with their fields in any
+    /// order. See [`rename_field`][ArrowBuilder::rename_field] for an example.
+    ///
+    pub fn with_field_order(&mut self, names: &[&str]) -> Result<()> {
+        self.0.reorder_fields(names)
+    }
+
+    /// Split the builder into one builder per column
+    ///
+    /// Each returned builder contains a single column and keeps the rows
+    /// pushed so far. Records pushed into the column builders must be structs
+    /// with a single field named as the column. The columns can be combined
+    /// again with [`from_columns`][ArrowBuilder::from_columns], e.g., to serialize
+    /// different columns from different sources into a single batch.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde::Serialize;
+    /// use serde_arrow::ArrowBuilder;
+    ///
+    /// ##[derive(Serialize)]
+    /// struct A {
+    ///     a: u8,
+    /// }
+    ///
+    /// ##[derive(Serialize)]
+    /// struct B {
+    ///     b: f32,
+    /// }
+    ///
+    /// let builder = ArrowBuilder::new(&[
+    ///     Field::new("a", DataType::UInt8, false),
+    ///     Field::new("b", DataType::Float32, false),
+    /// ])?;
+    ///
+    /// let mut columns = builder.into_columns()?;
+    /// columns[0].extend(&[A { a: 1 }, A { a: 2 }])?;
+    /// columns[1].extend(&[B { b: 3.0 }, B { b: 4.0 }])?;
+    ///
+    /// let mut builder = ArrowBuilder::from_columns(columns)?;
+    /// let arrays = builder.build_arrays()?;
+    /// assert_eq!(arrays.len(), 2);
+    /// assert_eq!(arrays[0].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_columns(self) -> Result<Vec<Self>> {
+        Ok(self.0.into_columns()?.into_iter().map(Self).collect())
+    }
+
+    /// Combine builders into a single builder with the columns of all of them
+    ///
+    /// The columns keep the rows pushed so far and are ordered as the builders.
+    /// All builders must contain the same number of rows and should use the
+    /// same options. The options of the first builder are used for the
+    /// combined builder. See [`into_columns`][ArrowBuilder::into_columns] for an
+    /// example.
+    ///
+    pub fn from_columns(columns: Vec<Self>) -> Result<Self> {
+        let columns = columns.into_iter().map(|column| column.0).collect();
+        Ok(Self(generic::GenericBuilder::from_columns(columns)?))
+    }
+}
+
+/// Build arrow arrays for each partition of a stream of records (*requires
+/// one of the `arrow-*` features*)
+///
+/// Each record is pushed together with the key of its partition, e.g., the
+/// values of the partition columns of a hive-partitioned dataset. A separate
+/// batch is built for each key. All partitions share the fields and the
+/// options. The partitions are returned in order of their first record.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::PartitionedBuilder;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     year: u16,
+///     value: f64,
+/// }
+///
+/// let mut builder = PartitionedBuilder::new(&[Field::new("value", DataType::Float64, false)])?;
+///
+/// for record in [
+///     Record { year: 2023, value: 1.0 },
+///     Record { year: 2024, value: 2.0 },
+///     Record { year: 2023, value: 3.0 },
+/// ] {
+///     builder.push(record.year, &record)?;
+/// }
+///
+/// let partitions = builder.finish()?;
+/// assert_eq!(partitions.len(), 2);
+/// assert_eq!(partitions[0].0, 2023);
+/// assert_eq!(partitions[0].1[0].len(), 2);
+/// assert_eq!(partitions[1].0, 2024);
+/// assert_eq!(partitions[1].1[0].len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Fields of the records not part of the schema are ignored, as the partition
+/// columns in the example.
+pub struct PartitionedBuilder<K> {
+    builder: generic::GenericPartitionedBuilder<K>,
+    /// The partitions built by a failed call to `finish`
+    built: Vec<(K, Vec<ArrayRef>)>,
+}
+
+impl<K: Hash + Eq + Clone> PartitionedBuilder<K> {
+    /// Build a new PartitionedBuilder for the given fields
+    pub fn new(fields: &[Field]) -> Result<Self> {
+        Self::with_options(fields, SerializationOptions::default())
+    }
+
+    /// Build a new PartitionedBuilder for the given fields with custom
+    /// serialization options, used for the builders of all partitions
+    pub fn with_options(fields: &[Field], options: SerializationOptions) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            builder: generic::GenericPartitionedBuilder::new(fields, options)?,
+            built: Vec::new(),
+        })
+    }
+
+    /// Add a single record to the partition with the given key
+    pub fn push<T: Serialize + ?Sized>(&mut self, key: K, item: &T) -> Result<()> {
+        self.builder.push(key, item)
+    }
+
+    /// The number of partitions with at least one record
+    pub fn num_partitions(&self) -> usize {
+        self.builder.num_partitions()
+    }
+
+    /// Build the arrays of all partitions
+    ///
+    /// This operation removes all partitions. Records pushed afterwards start
+    /// new partitions.
+    ///
+    /// If building a partition fails, its records are discarded and the error
+    /// is returned. The partitions built before are returned by the next call
+    /// to `finish`, together with the remaining partitions. In this case, a key
+    /// may be returned twice, if records with it were pushed in between.
+    pub fn finish(&mut self) -> Result<Vec<(K, Vec<ArrayRef>)>> {
+        self.builder
+            .finish_with(&mut self.built, |builder| build_arrays(&mut builder.0))
+    }
+}
+
+/// Build arrow arrays for each variant of an enum (*requires one of the
+/// `arrow-*` features*)
+///
+/// This builder is helpful for event logs with different record types. Each
+/// variant is given its own fields and the content of each pushed value, e.g.,
+/// the record of a newtype variant or the fields of a struct variant, is
+/// appended to the batch of its variant.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::VariantBuilder;
+///
+/// ##[derive(Serialize)]
+/// struct Click {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// ##[derive(Serialize)]
+/// enum Event {
+///     Click(Click),
+///     Scroll { delta: f64 },
+/// }
+///
+/// let mut builder = VariantBuilder::new(&[
+///     (
+///         "Click",
+///         vec![
+///             Field::new("x", DataType::Int32, false),
+///             Field::new("y", DataType::Int32, false),
+///         ],
+///     ),
+///     ("Scroll", vec![Field::new("delta", DataType::Float64, false)]),
+/// ])?;
+///
+/// builder.push(&Event::Click(Click { x: 1, y: 2 }))?;
+/// builder.push(&Event::Scroll { delta: 0.5 })?;
+/// builder.push(&Event::Click(Click { x: 3, y: 4 }))?;
+///
+/// let batches = builder.finish()?;
+/// assert_eq!(batches["Click"].len(), 2);
+/// assert_eq!(batches["Click"][0].len(), 2);
+/// assert_eq!(batches["Scroll"][0].len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct VariantBuilder {
+    builder: generic::GenericVariantBuilder,
+    /// The variants built by a failed call to `finish`
+    built: HashMap<String, Vec<ArrayRef>>,
+}
+
+impl VariantBuilder {
+    /// Build a new VariantBuilder for the given variants and their fields
+    pub fn new(variants: &[(&str, Vec<Field>)]) -> Result<Self> {
+        Self::with_options(variants, SerializationOptions::default())
+    }
+
+    /// Build a new VariantBuilder for the given variants and their fields with
+    /// custom serialization options, used for the builders of all variants
+    pub fn with_options(
+        variants: &[(&str, Vec<Field>)],
+        options: SerializationOptions,
+    ) -> Result<Self> {
+        let mut generic_variants = Vec::new();
+        for (name, fields) in variants {
+            let fields = fields
+                .iter()
+                .map(GenericField::try_from)
+                .collect::<Result<Vec<_>>>()?;
+            generic_variants.push((name.to_string(), fields));
+        }
+        Ok(Self {
+            builder: generic::GenericVariantBuilder::new(generic_variants, options)?,
+            built: HashMap::new(),
+        })
+    }
+
+    /// Add a single value to the batch of its variant
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.builder.push(item)
+    }
+
+    /// Build the arrays of all variants, keyed by the variant name
+    ///
+    /// Variants without values result in empty arrays. This operation clears
+    /// the builder, which can be reused afterwards.
+    ///
+    /// If building a variant fails, its values are discarded and the error is
+    /// returned. The variants built before are returned by the next call to
+    /// `finish`, values pushed to them in between by the call after it.
+    pub fn finish(&mut self) -> Result<HashMap<String, Vec<ArrayRef>>> {
+        self.builder
+            .finish_with(&mut self.built, |builder| build_arrays(&mut builder.0))
     }
 }
 
@@ -149,8 +623,208 @@ pub fn to_arrow<T: Serialize + ?Sized>(fields: &[Field], items: &T) -> Result<Ve
 
     let program = compile_serialization(&fields, CompilationOptions::default())?;
     let mut interpreter = Interpreter::new(program);
-    serialize_into_sink(&mut interpreter, items)?;
-    interpreter.build_arrow_arrays()
+    {
+        span!("serialize");
+        serialize_into_sink(&mut interpreter, items)?;
+    }
+    build_arrays(&mut interpreter)
+}
+
+/// Build arrow arrays from the given items with custom serialization options
+/// (*requires one of the `arrow-*` features*)
+///
+/// The same as [`to_arrow`][crate::to_arrow], but the behavior can be configured
+/// with [`SerializationOptions`][crate::utils::SerializationOptions], e.g.,
+/// the policy for integers that overflow the type of their field.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::utils::{Overflow, SerializationOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = vec![Field::new("a", DataType::UInt8, false)];
+/// let options = SerializationOptions::default().overflow(Overflow::Saturate);
+/// let arrays = serde_arrow::to_arrow_with_options(&fields, &[Record { a: 300 }], &options)?;
+/// # assert_eq!(arrays[0].len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow_with_options<T>(
+    fields: &[Field],
+    items: &T,
+    options: &SerializationOptions,
+) -> Result<Vec<ArrayRef>>
+where
+    T: Serialize + ?Sized,
+{
+    let mut builder = ArrowBuilder::with_options(fields, options.clone())?;
+    builder.extend(items)?;
+    builder.build_arrays()
+}
+
+/// Trace the fields from the given items and build arrow arrays in one call
+/// (*requires one of the `arrow-*` features*)
+///
+/// The fields are traced with
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples] and
+/// the items serialized with [`to_arrow`][crate::to_arrow]. The items are
+/// serialized twice. To reuse the fields across multiple calls, trace them
+/// once and call [`to_arrow`][crate::to_arrow] directly.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::DataType;
+/// use serde::Serialize;
+/// use serde_arrow::schema::TracingOptions;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2 },
+///     Record { a: None, b: 3 },
+/// ];
+///
+/// let (fields, arrays) = serde_arrow::to_arrow_auto(&items, TracingOptions::default())?;
+/// assert_eq!(fields[0].data_type(), &DataType::Float32);
+/// assert_eq!(fields[1].data_type(), &DataType::UInt64);
+/// assert_eq!(arrays[0].null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow_auto<T: Serialize>(
+    items: &T,
+    options: TracingOptions,
+) -> Result<(Vec<Field>, Vec<ArrayRef>)> {
+    let fields = Vec::<Field>::from_samples(items, options)?;
+    let arrays = to_arrow(&fields, items)?;
+    Ok((fields, arrays))
+}
+
+/// Build arrow arrays from items that may have failed before serialization
+/// (*requires one of the `arrow-*` features*)
+///
+/// In contrast to [`to_arrow`][crate::to_arrow], the items are given as an iterator
+/// of results, e.g., records parsed from an upstream source. `Err` items do
+/// not abort the conversion, but are skipped or written as rows with all fields
+/// set to null, depending on `failed_items`. Errors raised while serializing
+/// `Ok` items are still returned as errors.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::FailedItems,
+/// };
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<u32>,
+/// }
+///
+/// let items = ["1", "x", "3"].into_iter().map(|s| s.parse().map(|a| Record { a: Some(a) }));
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow_filtered(&fields, items, FailedItems::Null)?;
+/// assert_eq!(arrays[0].len(), 3);
+/// assert_eq!(arrays[0].null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow_filtered<T, E, I>(
+    fields: &[Field],
+    items: I,
+    failed_items: FailedItems,
+) -> Result<Vec<ArrayRef>>
+where
+    T: Serialize,
+    I: IntoIterator<Item = std::result::Result<T, E>>,
+{
+    let mut builder = ArrowBuilder::new(fields)?;
+    builder.0.extend_filtered(items, failed_items)?;
+    builder.build_arrays()
+}
+
+/// Build empty arrow arrays for the given fields (*requires one of the
+/// `arrow-*` features*)
+///
+/// The arrays have zero rows and the data types of the fields, e.g., to emit
+/// batches that only carry the schema.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+///
+/// let fields = vec![
+///     Field::new("a", DataType::UInt8, false),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let arrays = serde_arrow::empty_arrays(&fields)?;
+///
+/// assert_eq!(arrays.len(), 2);
+/// assert_eq!(arrays[0].len(), 0);
+/// assert_eq!(arrays[1].data_type(), &DataType::Utf8);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn empty_arrays(fields: &[Field]) -> Result<Vec<ArrayRef>> {
+    ArrowBuilder::new(fields)?.build_arrays()
+}
+
+/// Build an empty record batch for the given schema (*requires one of the
+/// `arrow-*` features*)
+///
+/// The record batch has zero rows and one empty column per field of the
+/// schema. See [`empty_arrays`][crate::empty_arrays] for details.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field, Schema};
+///
+/// let schema = Schema::new(vec![
+///     Field::new("a", DataType::UInt8, false),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+/// let batch = serde_arrow::empty_record_batch(&schema)?;
+///
+/// assert_eq!(batch.num_rows(), 0);
+/// assert_eq!(batch.num_columns(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn empty_record_batch(schema: &Schema) -> Result<RecordBatch> {
+    // NOTE: newer arrow versions store the fields as `Arc<Field>`
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| Field::clone(field))
+        .collect::<Vec<_>>();
+    let arrays = empty_arrays(&fields)?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(0));
+    let batch = RecordBatch::try_new_with_options(Arc::new(schema.clone()), arrays, &options)?;
+    Ok(batch)
 }
 
 /// Deserialize items from arrow arrays (*requires one of the `arrow-*`
@@ -187,21 +861,588 @@ where
     T: Deserialize<'de>,
     A: AsRef<dyn Array>,
 {
-    use crate::internal::{
-        common::{BufferExtract, Buffers},
-        deserialization,
-    };
-
-    let fields = fields
-        .iter()
-        .map(GenericField::try_from)
-        .collect::<Result<Vec<_>>>()?;
+    from_arrow_with_options(fields, arrays, &DeserializationOptions::default())
+}
 
-    let num_items = arrays
-        .iter()
-        .map(|a| a.as_ref().len())
+/// Deserialize items from arrow arrays with the given options (*requires one
+/// of the `arrow-*` features*)
+///
+/// See [`from_arrow`][crate::from_arrow] for details. The options allow, e.g., to reject
+/// columns that are not declared by the target struct:
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::{DeserializationOptions, UnknownColumns},
+/// };
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+///     b: u64,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct PartialRecord {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &[Record { a: 1, b: 2 }])?;
+///
+/// let options = DeserializationOptions::default().unknown_columns(UnknownColumns::Error);
+/// let res = serde_arrow::from_arrow_with_options::<Vec<PartialRecord>, _>(&fields, &arrays, &options);
+/// assert!(res.is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_with_options<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+    options: &DeserializationOptions,
+) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    deserialize_arrays(fields, arrays, options, |source| {
+        deserialize_from_source_with_options(source, options)
+    })
+}
+
+/// Deserialize the rows of a record batch, coercing the columns to the target
+/// type (*requires one of the `arrow-*` features*)
+///
+/// This function is meant for exploratory use against arbitrary batches. The
+/// fields of the target type are traced with
+/// [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type] and the
+/// columns are matched by name:
+///
+/// - Columns are widened losslessly to the type of their field, if required:
+///   integers into larger integers (`Int32` into `Int64`, `UInt8` into
+///   `Int16`) and into floats that can represent all values (`Int16` into
+///   `Float32`, `UInt32` into `Float64`), and floats into larger floats
+///   (`Float32` into `Float64`)
+/// - Missing columns of nullable fields are filled with nulls
+/// - Columns not declared by the target type are ignored
+///
+/// Other type mismatches are deserialized as with
+/// [`from_arrow`][crate::from_arrow]. Missing columns of non-nullable fields
+/// result in an error. The applied coercions are returned next to the items.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use std::sync::Arc;
+///
+/// use arrow::{
+///     array::{ArrayRef, PrimitiveArray},
+///     datatypes::{DataType, Field, Int32Type, Schema},
+///     record_batch::RecordBatch,
+/// };
+/// use serde::Deserialize;
+/// use serde_arrow::utils::Coercion;
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct Record {
+///     a: f64,
+///     b: Option<String>,
+/// }
+///
+/// let schema = Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+///     Field::new("c", DataType::Int32, false),
+/// ]);
+/// let columns: Vec<ArrayRef> = vec![
+///     Arc::new(PrimitiveArray::<Int32Type>::from(vec![1, 2])),
+///     Arc::new(PrimitiveArray::<Int32Type>::from(vec![3, 4])),
+/// ];
+/// let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+///
+/// let (items, coercions) = serde_arrow::from_record_batch_auto::<Record>(&batch)?;
+/// assert_eq!(items, [Record { a: 1.0, b: None }, Record { a: 2.0, b: None }]);
+/// assert_eq!(coercions.len(), 3);
+/// assert_eq!(coercions[0].to_string(), "widened column \"a\" from Int32 to Float64");
+/// assert_eq!(coercions[1], Coercion::MissingAsNull { column: String::from("b") });
+/// assert_eq!(coercions[2], Coercion::Ignored { column: String::from("c") });
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_record_batch_auto<T>(batch: &RecordBatch) -> Result<(Vec<T>, Vec<Coercion>)>
+where
+    T: DeserializeOwned,
+{
+    let options = TracingOptions::default().allow_null_fields(true);
+    let targets = Vec::<Field>::from_type::<T>(options)?;
+
+    let schema = batch.schema();
+    let mut coercions = Vec::new();
+    let mut fields = Vec::new();
+    let mut arrays = Vec::new();
+
+    for target in &targets {
+        let Ok(idx) = schema.index_of(target.name()) else {
+            if target.is_nullable() {
+                coercions.push(Coercion::MissingAsNull {
+                    column: target.name().to_owned(),
+                });
+                fields.push(target.clone());
+                arrays.push(new_null_array(target.data_type(), batch.num_rows()));
+            }
+            // missing non-nullable fields are reported by the deserializer
+            continue;
+        };
+
+        let field = schema.field(idx);
+        let array = batch.column(idx);
+        match widen_array(array, target.data_type()) {
+            Some(array) => {
+                coercions.push(Coercion::Widened {
+                    column: field.name().to_owned(),
+                    from: format!("{:?}", field.data_type()),
+                    to: format!("{:?}", target.data_type()),
+                });
+                fields.push(Field::new(
+                    field.name(),
+                    target.data_type().clone(),
+                    field.is_nullable(),
+                ));
+                arrays.push(array);
+            }
+            None => {
+                fields.push(field.clone());
+                arrays.push(array.clone());
+            }
+        }
+    }
+
+    for field in schema.fields() {
+        if !targets.iter().any(|target| target.name() == field.name()) {
+            coercions.push(Coercion::Ignored {
+                column: field.name().to_owned(),
+            });
+        }
+    }
+
+    let items = from_arrow(&fields, &arrays)?;
+    Ok((items, coercions))
+}
+
+/// Deserialize items from arrow arrays and append them to an existing vector
+/// (*requires one of the `arrow-*` features*)
+///
+/// See [`from_arrow`][crate::from_arrow] for details. Reusing the vector, e.g., by
+/// calling [`Vec::clear`] between batches, avoids repeated allocations when
+/// deserializing many batches. If an error occurs, the items deserialized
+/// before the error are kept in the vector.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let batches = [
+///     serde_arrow::to_arrow(&fields, &[Record { a: 1 }, Record { a: 2 }])?,
+///     serde_arrow::to_arrow(&fields, &[Record { a: 3 }])?,
+/// ];
+///
+/// let mut items = Vec::<Record>::new();
+/// for arrays in &batches {
+///     items.clear();
+///     serde_arrow::from_arrow_into(&fields, arrays, &mut items)?;
+/// }
+/// assert_eq!(items, vec![Record { a: 3 }]);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_into<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+    items: &mut Vec<T>,
+) -> Result<()>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    deserialize_arrays(fields, arrays, &options, |source| {
+        deserialize_into_vec_from_source_with_options(source, &options, items)
+    })
+}
+
+/// Deserialize rows from arrow arrays, collecting the errors of individual
+/// rows (*requires one of the `arrow-*` features*)
+///
+/// In contrast to [`from_arrow`][crate::from_arrow], rows that fail to
+/// deserialize do not abort the deserialization. Instead, they are skipped
+/// and reported as [`RowError`][crate::RowError] with the index of the row
+/// and the path of the failing field. Errors that affect all rows, e.g.,
+/// invalid fields, are still returned as errors.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<u32>,
+/// }
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct RequiredRecord {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &[Record { a: Some(1) }, Record { a: None }])?;
+///
+/// let (rows, errors) = serde_arrow::from_arrow_collect_errors::<RequiredRecord, _>(&fields, &arrays)?;
+/// assert_eq!(rows, vec![RequiredRecord { a: 1 }]);
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].row, 1);
+/// assert_eq!(errors[0].path, "a");
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_collect_errors<'de, T, A>(
+    fields: &'de [Field],
+    arrays: &'de [A],
+) -> Result<(Vec<T>, Vec<RowError>)>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    deserialize_arrays(fields, arrays, &options, |source| {
+        deserialize_rows_from_source_with_options(source, &options)
+    })
+}
+
+/// Deserialize the rows of arrow arrays lazily (*requires one of the
+/// `arrow-*` features*)
+///
+/// The given function is called with a [`RowIter`][crate::RowIter] over the
+/// rows. Each row is only deserialized when requested, therefore stopping the
+/// iteration early, e.g., to inspect the first rows of a large batch, does not
+/// process the remaining rows. Rows that fail to deserialize are returned as
+/// [`RowError`][crate::RowError] and do not end the iteration.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = (0..1000).map(|a| Record { a }).collect::<Vec<_>>();
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+///
+/// let head = serde_arrow::from_arrow_iter(&fields, &arrays, |rows| {
+///     rows.take(2).collect::<Result<Vec<Record>, _>>().map_err(|err| err.error)
+/// })?;
+/// assert_eq!(head, vec![Record { a: 0 }, Record { a: 1 }]);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_iter<T, A, R>(
+    fields: &[Field],
+    arrays: &[A],
+    func: impl FnOnce(RowIter<'_, T>) -> Result<R>,
+) -> Result<R>
+where
+    T: DeserializeOwned,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    deserialize_arrays(fields, arrays, &options, |source| {
+        func(RowIter::new(source, &options)?)
+    })
+}
+
+/// Deserialize single rows of arrow arrays on demand (*requires one of the
+/// `arrow-*` features*)
+///
+/// The returned [`RowsView`][crate::RowsView] supports random access to the
+/// rows via [`get`][crate::RowsView::get]. Each access only deserializes the
+/// requested row, therefore reading a few rows of a large batch does not
+/// process the remaining rows. The schema is checked when the view is created.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = (0..1000).map(|a| Record { a }).collect::<Vec<_>>();
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+///
+/// let view = serde_arrow::from_arrow_view::<Record, _>(&fields, &arrays)?;
+/// assert_eq!(view.len(), 1000);
+/// assert_eq!(view.get(42)?, Record { a: 42 });
+/// assert!(view.get(1000).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_arrow_view<'a, T, A>(fields: &'a [Field], arrays: &'a [A]) -> Result<RowsView<'a, T>>
+where
+    T: DeserializeOwned,
+    A: AsRef<dyn Array>,
+{
+    let options = DeserializationOptions::default();
+    let fields = fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    let len = arrays
+        .iter()
+        .map(|array| array.as_ref().len())
+        .min()
+        .unwrap_or_default();
+
+    let mut buffers = Buffers::new();
+    let mut mappings = Vec::new();
+    for (field, array) in fields.iter().zip(arrays.iter()) {
+        mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
+    }
+
+    // the field names are copied into the program, the mappings are dropped
+    let interpreter = deserialization::compile_detached_deserialization(
+        len,
+        &mappings,
+        buffers,
+        deserialization::CompilationOptions::default()
+            .timezone_conversion(options.timezone_conversion)
+            .field_timezone_conversions(options.field_timezone_conversions.clone())
+            .empty_lists(options.empty_lists),
+    )?;
+
+    Ok(RowsView::new(len, interpreter, options))
+}
+
+/// Deserialize the rows of a struct array, e.g., with a validity of its own
+/// (*requires one of the `arrow-*` features*)
+///
+/// The field must be of data type `Struct`. Each row of the array is
+/// deserialized as a record, null rows are deserialized as `None`. Therefore,
+/// the type should be a list of optional records (e.g., `Vec<Option<T>>`) for
+/// nullable struct arrays.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Items,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = [Some(Record { a: 1 }), None, Some(Record { a: 2 })];
+/// let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &Items(&items))?;
+///
+/// let rows: Vec<Option<Record>> = serde_arrow::from_arrow_struct(&fields[0], &arrays[0])?;
+/// assert_eq!(rows, items);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_struct<'de, T, A>(field: &'de Field, array: &'de A) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array> + 'de + ?Sized,
+{
+    generic::deserialize_from_struct_array(field, array.as_ref())
+}
+
+/// Deserialize the rows of a struct array given as a `StructArray` (*requires
+/// one of the `arrow-*` features*)
+///
+/// A thin wrapper of [`from_arrow_struct`][crate::from_arrow_struct], that
+/// accepts the concrete struct array, e.g., a nested struct column extracted
+/// from a larger batch by downcasting.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::{array::StructArray, datatypes::Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Items,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = [Record { a: 1 }, Record { a: 2 }];
+/// let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &Items(&items))?;
+///
+/// let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+/// let rows: Vec<Record> = serde_arrow::from_arrow_struct_array(&fields[0], array)?;
+/// assert_eq!(rows, items);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_struct_array<'de, T>(field: &'de Field, array: &'de StructArray) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    generic::deserialize_from_struct_array(field, array as &dyn Array)
+}
+
+/// Serialize items into a single struct array (*requires one of the `arrow-*`
+/// features*)
+///
+/// The field must be of data type `Struct`. Each item is serialized as one row
+/// of the array, e.g., to embed the struct as a column of a batch built
+/// elsewhere. For nullable fields, `None` items are serialized as null rows.
+/// This function is the inverse of [`from_arrow_struct_array`][crate::from_arrow_struct_array].
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::{array::Array, datatypes::{DataType, Field}};
+/// use serde::Serialize;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let field = Field::new(
+///     "record",
+///     DataType::Struct(vec![Field::new("a", DataType::UInt32, false)].into()),
+///     true,
+/// );
+/// let items = [Some(Record { a: 1 }), None, Some(Record { a: 2 })];
+/// let array = serde_arrow::to_arrow_struct_array(&field, &items)?;
+///
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow_struct_array<T>(field: &Field, items: &T) -> Result<StructArray>
+where
+    T: Serialize + ?Sized,
+{
+    let mut builder = generic::GenericBuilder::new_for_struct_array(field.try_into()?)?;
+    builder.extend(items)?;
+    let array = build_array(&mut builder.0)?;
+    Ok(StructArray::from(array.to_data()))
+}
+
+impl Sealed for Field {}
+
+impl BuilderField for Field {
+    type Array = ArrayRef;
+
+    fn to_generic_field(&self) -> Result<GenericField> {
+        GenericField::try_from(self)
+    }
+
+    fn from_generic_field(field: &GenericField) -> Result<Self> {
+        Field::try_from(field)
+    }
+
+    fn build_arrays(interpreter: &mut Interpreter) -> Result<Vec<Self::Array>> {
+        build_arrays(interpreter)
+    }
+}
+
+impl<A: AsRef<dyn Array>> FieldLike<A> for Field {
+    fn pretty(fields: &[Self], arrays: &[A], limit: usize) -> Result<String> {
+        let num_rows = arrays
+            .iter()
+            .map(|array| array.as_ref().len())
+            .min()
+            .unwrap_or_default();
+
+        // only deserialize the displayed rows
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_ref().slice(0, limit.min(num_rows)))
+            .collect::<Vec<_>>();
+        let rows: Vec<Value> = from_arrow(fields, &arrays)?;
+
+        let fields = fields
+            .iter()
+            .map(GenericField::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        format_table(&fields, &rows, num_rows)
+    }
+}
+
+/// Compile the deserialization of the given arrays and pass the events to
+/// `func`
+pub(crate) fn deserialize_arrays<A, R>(
+    fields: &[Field],
+    arrays: &[A],
+    options: &DeserializationOptions,
+    func: impl FnOnce(deserialization::ArraySource<'_>) -> Result<R>,
+) -> Result<R>
+where
+    A: AsRef<dyn Array>,
+{
+    let mut fields = fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    options.rename_fields(&mut fields);
+
+    let num_items = arrays
+        .iter()
+        .map(|a| a.as_ref().len())
         .min()
         .unwrap_or_default();
+    span!("deserialize", num_items, num_columns = arrays.len());
+
+    let mut metrics = DeserializationMetrics::start(options.metrics.as_ref());
 
     let mut buffers = Buffers::new();
     let mut mappings = Vec::new();
@@ -209,13 +1450,22 @@ where
         mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
     }
 
-    let interpreter = deserialization::compile_deserialization(
+    let (res, num_rows) = deserialization::with_array_source(
         num_items,
         &mappings,
         buffers,
-        deserialization::CompilationOptions::default(),
+        deserialization::CompilationOptions::default()
+            .timezone_conversion(options.timezone_conversion)
+            .field_timezone_conversions(options.field_timezone_conversions.clone())
+            .empty_lists(options.empty_lists),
+        options.column_threads,
+        |source| {
+            metrics.compiled();
+            func(source)
+        },
     )?;
-    deserialize_from_source(interpreter)
+    metrics.report(num_rows);
+    Ok(res)
 }
 
 /// Replaced by
@@ -251,7 +1501,7 @@ where
     tracer.trace_samples(items)?;
 
     let schema = tracer.to_schema()?;
-    schema.to_arrow_fields()
+    schema.try_into()
 }
 
 /// Replaced by
@@ -292,7 +1542,7 @@ pub fn serialize_into_arrays<T: Serialize + ?Sized>(
     fields: &[Field],
     items: &T,
 ) -> Result<Vec<ArrayRef>> {
-    crate::to_arrow(fields, items)
+    to_arrow(fields, items)
 }
 
 /// Renamed to [`serde_arrow::from_arrow`][crate::from_arrow]
@@ -302,7 +1552,7 @@ where
     T: Deserialize<'de>,
     A: AsRef<dyn Array>,
 {
-    crate::from_arrow(fields, arrays)
+    from_arrow(fields, arrays)
 }
 
 /// Replaced by [`serde_arrow::to_arrow`][crate::to_arrow] and
@@ -334,7 +1584,7 @@ where
     )?;
     let mut interpreter = Interpreter::new(program);
     serialize_into_sink(&mut interpreter, items)?;
-    interpreter.build_arrow_array()
+    build_array(&mut interpreter)
 }
 
 /// Replaced by [`serde_arrow::to_arrow`][crate::from_arrow] and
@@ -413,6 +1663,6 @@ impl ArrayBuilder {
 
     /// Build the array from the rows pushed to far.
     pub fn build_array(&mut self) -> Result<ArrayRef> {
-        self.0 .0.build_arrow_array()
+        build_array(&mut self.0 .0)
     }
 }
@@ -0,0 +1,48 @@
+//! Lossless casts between the columns of record batches and traced fields
+use std::sync::Arc;
+
+use super::arrow::{
+    array::{Array, ArrayRef, PrimitiveArray},
+    datatypes::{
+        ArrowPrimitiveType, DataType, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type,
+        Int64Type, Int8Type, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+};
+
+/// Cast the array losslessly to the given data type, if supported
+///
+/// Only integers and floats are widened, following the lossless conversions
+/// of the standard library, e.g., `Int32` into `Int64` or `Float64`, but not
+/// `Int64` into `Float64`. Returns `None` for all other combinations.
+pub fn widen_array(array: &ArrayRef, data_type: &DataType) -> Option<ArrayRef> {
+    macro_rules! widen {
+        ($($from:ty => [$($to:ty),*];)*) => {
+            $(
+                if let Some(array) = array.as_any().downcast_ref::<PrimitiveArray<$from>>() {
+                    $(
+                        if data_type == &<$to as ArrowPrimitiveType>::DATA_TYPE {
+                            let array: PrimitiveArray<$to> = array.unary(|val| val.into());
+                            return Some(Arc::new(array));
+                        }
+                    )*
+                    return None;
+                }
+            )*
+        };
+    }
+
+    widen!(
+        Int8Type => [Int16Type, Int32Type, Int64Type, Float32Type, Float64Type];
+        Int16Type => [Int32Type, Int64Type, Float32Type, Float64Type];
+        Int32Type => [Int64Type, Float64Type];
+        UInt8Type => [
+            UInt16Type, UInt32Type, UInt64Type, Int16Type, Int32Type, Int64Type, Float32Type,
+            Float64Type
+        ];
+        UInt16Type => [UInt32Type, UInt64Type, Int32Type, Int64Type, Float32Type, Float64Type];
+        UInt32Type => [UInt64Type, Int64Type, Float64Type];
+        Float16Type => [Float32Type, Float64Type];
+        Float32Type => [Float64Type];
+    );
+    None
+}
@@ -0,0 +1,152 @@
+//! Typed views of the columns of record batches
+//!
+use super::arrow::{
+    _raw::array::{
+        types::UInt32Type, BooleanArray, DictionaryArray, Float16Array, Float32Array, Float64Array,
+        Int16Array, Int32Array, Int64Array, Int8Array, LargeListArray, LargeStringArray,
+        TimestampMillisecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+    },
+    array::Array,
+    record_batch::RecordBatch,
+};
+use crate::internal::{
+    error::{fail, Result},
+    markers::{AsDictionary, BFloat16, TimestampMillis},
+};
+
+/// Records with typed views of their columns (*requires one of the `arrow-*`
+/// features*)
+///
+/// The trait is usually implemented with `#[derive(ArrowColumns)]` (*requires
+/// the `derive` feature*). The derive generates a companion struct named
+/// `{Name}Columns` with one field per field of the record. Each field is a
+/// reference to the concrete array type of the column, as given by
+/// [`ArrowColumnType`]. The columns are looked up by their serialized name,
+/// honoring `#[serde(rename = "..")]` and `#[serde(rename_all = "..")]`, and
+/// skipping fields marked with `#[serde(skip)]`. Flattened fields
+/// (`#[serde(flatten)]`) are rejected. The array type of a field can be
+/// overwritten with `#[arrow_columns(array = ArrayType)]`.
+///
+/// ```rust
+/// # #[cfg(all(has_arrow, feature = "derive"))]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use std::sync::Arc;
+///
+/// use arrow::{array::Array, datatypes::{Field, Schema}, record_batch::RecordBatch};
+/// use serde::Serialize;
+/// use serde_arrow::{schema::{SchemaLike, TracingOptions}, ArrowColumns};
+///
+/// ##[derive(Serialize, ArrowColumns)]
+/// struct Record {
+///     price: f64,
+///     quantity: Option<u32>,
+///     symbol: String,
+/// }
+///
+/// let items = vec![
+///     Record { price: 1.5, quantity: Some(2), symbol: String::from("A") },
+///     Record { price: 3.0, quantity: None, symbol: String::from("B") },
+/// ];
+/// let fields = Vec::<Field>::from_samples(&items, TracingOptions::default())?;
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)?;
+///
+/// // the columns are `&Float64Array`, `&UInt32Array` and `&LargeStringArray`
+/// let columns = RecordColumns::from_record_batch(&batch)?;
+/// let total: f64 = columns.price.values().iter().sum();
+/// assert_eq!(total, 4.5);
+/// assert_eq!(columns.quantity.null_count(), 1);
+/// assert_eq!(columns.symbol.value(1), "B");
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(has_arrow, feature = "derive")))]
+/// # fn main() { }
+/// ```
+pub trait ArrowColumns {
+    /// The typed views of the columns
+    type Columns<'a>;
+
+    /// Extract the columns from a record batch by name
+    fn columns(batch: &RecordBatch) -> Result<Self::Columns<'_>>;
+}
+
+/// The array type used to store values of a Rust type (*requires one of the
+/// `arrow-*` features*)
+///
+/// The array types follow the data types traced by
+/// [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type] with the
+/// default options, e.g., strings are stored in `LargeStringArray`s and
+/// vectors in `LargeListArray`s. Options are stored in the array of the inner
+/// type. Used by `#[derive(ArrowColumns)]` to determine the array types of
+/// the columns.
+pub trait ArrowColumnType {
+    /// The concrete array type
+    type Array: Array + 'static;
+}
+
+macro_rules! impl_arrow_column_type {
+    ($($ty:ty => $array:ty),* $(,)?) => {
+        $(
+            impl ArrowColumnType for $ty {
+                type Array = $array;
+            }
+        )*
+    };
+}
+
+impl_arrow_column_type!(
+    bool => BooleanArray,
+    i8 => Int8Array,
+    i16 => Int16Array,
+    i32 => Int32Array,
+    i64 => Int64Array,
+    u8 => UInt8Array,
+    u16 => UInt16Array,
+    u32 => UInt32Array,
+    u64 => UInt64Array,
+    half::f16 => Float16Array,
+    f32 => Float32Array,
+    f64 => Float64Array,
+    char => UInt32Array,
+    String => LargeStringArray,
+);
+
+impl<T: ArrowColumnType> ArrowColumnType for Option<T> {
+    type Array = T::Array;
+}
+
+impl<T: ArrowColumnType> ArrowColumnType for Box<T> {
+    type Array = T::Array;
+}
+
+impl<T> ArrowColumnType for Vec<T> {
+    type Array = LargeListArray;
+}
+
+impl<T> ArrowColumnType for TimestampMillis<T> {
+    type Array = TimestampMillisecondArray;
+}
+
+impl<T> ArrowColumnType for AsDictionary<T> {
+    type Array = DictionaryArray<UInt32Type>;
+}
+
+impl ArrowColumnType for BFloat16 {
+    type Array = UInt16Array;
+}
+
+/// Look up a column by name and downcast it to the given array type
+pub fn column<'a, A: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a A> {
+    let Some(array) = batch.column_by_name(name) else {
+        fail!("The record batch has no column {name:?}");
+    };
+    let Some(array) = array.as_any().downcast_ref::<A>() else {
+        fail!(
+            "Column {name:?} with data type {} cannot be viewed as {}",
+            array.data_type(),
+            std::any::type_name::<A>(),
+        );
+    };
+    Ok(array)
+}
@@ -1,21 +1,23 @@
-use crate::_impl::arrow::array::Array;
+use super::arrow::array::Array;
 use crate::internal::common::{BitBuffer, DictionaryIndex, DictionaryValue};
 use crate::internal::{
-    common::{check_supported_list_layout, ArrayMapping, BufferExtract, Buffers},
+    common::{
+        check_array_data_type, check_supported_list_layout, ArrayMapping, BufferExtract, Buffers,
+    },
     error::{error, fail, Result},
     schema::{GenericDataType, GenericField, GenericTimeUnit},
 };
 
-use crate::_impl::arrow::{
+use super::arrow::{
     array::{
-        BooleanArray, DictionaryArray, GenericListArray, LargeStringArray, MapArray,
-        PrimitiveArray, StringArray, StructArray,
+        BooleanArray, DictionaryArray, FixedSizeListArray, GenericListArray, LargeStringArray,
+        MapArray, PrimitiveArray, StringArray, StructArray,
     },
     datatypes::{
-        DataType, Date64Type, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type,
-        Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType,
-        TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
-        UInt8Type,
+        DataType, Date64Type, Decimal128Type, Field, Float16Type, Float32Type, Float64Type,
+        Int16Type, Int32Type, Int64Type, Int8Type, TimestampMicrosecondType,
+        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
     },
 };
 
@@ -100,6 +102,10 @@ impl BufferExtract for dyn Array {
 
         use {ArrayMapping as M, GenericDataType as T, GenericTimeUnit as U};
 
+        let data_type = self.data_type();
+        let array_field = GenericField::try_from(&Field::new(&field.name, data_type.clone(), true));
+        check_array_data_type(field, array_field, data_type)?;
+
         match &field.data_type {
             T::Null => {
                 if !matches!(self.data_type(), DataType::Null) {
@@ -155,10 +161,33 @@ impl BufferExtract for dyn Array {
             T::Timestamp(U::Nanosecond, _) => {
                 convert_primitive!(TimestampNanosecondType, Date64, push_u64_cast)
             }
+            T::Decimal128(_, _) => convert_primitive!(Decimal128Type, Decimal128, push_u128_cast),
             T::Utf8 => convert_utf8!(StringArray, Utf8, push_u32_cast),
             T::LargeUtf8 => convert_utf8!(LargeStringArray, LargeUtf8, push_u64_cast),
             T::List => convert_list!(i32, List, push_u32_cast),
             T::LargeList => convert_list!(i64, LargeList, push_u64_cast),
+            T::FixedSizeList(_) => {
+                let Some(typed) = self.as_any().downcast_ref::<FixedSizeListArray>() else {
+                    fail!("cannot convert array into FixedSizeListArray");
+                };
+                let n = usize::try_from(typed.value_length())?;
+
+                let count = buffers.push_u0(typed.len());
+                let validity = get_validity(self).map(|v| buffers.push_u1(v));
+
+                let Some(item_field) = field.children.first() else {
+                    fail!("cannot get first child of list array");
+                };
+                let item = typed.values().extract_buffers(item_field, buffers)?;
+
+                Ok(M::FixedSizeList {
+                    field: field.clone(),
+                    item: Box::new(item),
+                    validity,
+                    n,
+                    count,
+                })
+            }
             T::Struct => {
                 let typed = self
                     .as_any()
@@ -273,7 +302,7 @@ impl BufferExtract for dyn Array {
                 }
             }
             T::Union => {
-                use crate::_impl::arrow::array::UnionArray;
+                use super::arrow::array::UnionArray;
 
                 // TODO: test assumptions
                 let typed = self
@@ -4,8 +4,18 @@
 //! `arrow` arrays to Rust objects is not yet supported.
 //!
 #![deny(missing_docs)]
+// NOTE: the module is included once per enabled arrow version, the parent
+// module selects the arrow crate to use
+#![allow(clippy::duplicate_mod)]
+
+use super::_impl::arrow;
+
 pub(crate) mod api;
+mod coercion;
+pub mod columns;
 mod deserialization;
+#[cfg(feature = "pyo3")]
+pub(crate) mod pyarrow;
 mod schema;
 pub(crate) mod serialization;
 mod type_support;
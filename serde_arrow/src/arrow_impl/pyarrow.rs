@@ -0,0 +1,66 @@
+//! Hand arrow arrays to Python via the C Data Interface
+//!
+use std::sync::Arc;
+
+use pyo3::{PyObject, Python};
+
+use super::arrow::{
+    array::{Array, ArrayRef, StructArray},
+    datatypes::{DataType, Field, Schema},
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+    record_batch::RecordBatch,
+};
+use crate::internal::error::Result;
+
+/// Convert arrow arrays into a `pyarrow.RecordBatch` (*requires the `pyo3`
+/// feature and one of the `arrow-*` features*)
+///
+/// The arrays are handed to `pyarrow` via the [Arrow C Data
+/// Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+/// without copying the underlying buffers. The fields and arrays are
+/// typically the result of [`to_arrow`][crate::to_arrow] or
+/// [`ArrowBuilder::build_arrays`][crate::ArrowBuilder::build_arrays]. The
+/// returned record batch can be passed on to pandas (`batch.to_pandas()`) or
+/// polars (`polars.from_arrow(batch)`). It requires `pyarrow` to be
+/// installed in the Python environment.
+///
+/// ```rust,ignore
+/// use arrow::datatypes::Field;
+/// use pyo3::prelude::*;
+/// use serde::Serialize;
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// #[derive(Serialize)]
+/// struct Record {
+///     a: f32,
+///     b: u64,
+/// }
+///
+/// #[pyfunction]
+/// fn records(py: Python<'_>) -> PyResult<PyObject> {
+///     let items = vec![Record { a: 1.0, b: 2 }, Record { a: 3.0, b: 4 }];
+///     let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+///     let arrays = serde_arrow::to_arrow(&fields, &items)?;
+///     Ok(serde_arrow::to_pyarrow(py, &fields, arrays)?)
+/// }
+/// ```
+///
+pub fn to_pyarrow(py: Python<'_>, fields: &[Field], arrays: Vec<ArrayRef>) -> Result<PyObject> {
+    let schema = Arc::new(Schema::new(fields.to_vec()));
+    let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+    let data = StructArray::from(batch).to_data();
+
+    let mut ffi_array = FFI_ArrowArray::new(&data);
+    let mut ffi_schema = FFI_ArrowSchema::try_from(DataType::Struct(schema.fields().clone()))?;
+
+    // NOTE: pyarrow moves the data out of the structs, releasing them only
+    // once it no longer uses them. On errors, the structs are released on drop
+    let batch = py.import("pyarrow")?.getattr("RecordBatch")?.call_method1(
+        "_import_from_c",
+        (
+            std::ptr::addr_of_mut!(ffi_array) as usize,
+            std::ptr::addr_of_mut!(ffi_schema) as usize,
+        ),
+    )?;
+    Ok(batch.into())
+}
@@ -1,19 +1,20 @@
-use super::type_support::FieldRef;
-use crate::{
-    _impl::arrow::datatypes::{DataType, Field, TimeUnit, UnionMode},
-    internal::{
-        error::{error, fail, Error, Result},
-        schema::{
-            GenericDataType, GenericField, GenericTimeUnit, SchemaLike, Sealed, SerdeArrowSchema,
-            Strategy, STRATEGY_KEY,
-        },
+use super::{
+    arrow::datatypes::{DataType, Field, TimeUnit, UnionMode},
+    type_support::FieldRef,
+};
+use crate::internal::{
+    error::{error, fail, Error, ErrorKind, Result},
+    schema::{
+        GenericDataType, GenericField, GenericTimeUnit, SchemaLike, Sealed, SerdeArrowSchema,
+        Strategy, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, LAYOUT_HINTS_KEY, NULL_DEFAULT_KEY,
+        STRATEGY_KEY,
     },
 };
 
-/// Support for arrow types (*requires one of the `arrow-*` features*)
-impl SerdeArrowSchema {
-    /// Build a new Schema object from fields
-    pub fn from_arrow_fields(fields: &[Field]) -> Result<Self> {
+impl TryFrom<&[Field]> for SerdeArrowSchema {
+    type Error = Error;
+
+    fn try_from(fields: &[Field]) -> Result<Self> {
         Ok(Self {
             fields: fields
                 .iter()
@@ -21,38 +22,13 @@ impl SerdeArrowSchema {
                 .collect::<Result<_>>()?,
         })
     }
-
-    /// This method is deprecated. Use
-    /// [`to_arrow_fields`][SerdeArrowSchema::to_arrow_fields] instead:
-    ///
-    /// ```rust
-    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
-    /// # use serde_arrow::schema::{SerdeArrowSchema, SchemaLike, TracingOptions};
-    /// # #[derive(serde::Deserialize)]
-    /// # struct Item { a: u32 }
-    /// # let schema = SerdeArrowSchema::from_type::<Item>(TracingOptions::default()).unwrap();
-    /// # let fields =
-    /// schema.to_arrow_fields()?
-    /// # ;
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[deprecated = "The method `get_arrow_fields` is deprecated. Use `to_arrow_fields` instead"]
-    pub fn get_arrow_fields(&self) -> Result<Vec<Field>> {
-        self.to_arrow_fields()
-    }
-
-    /// Build a vec of fields from a Schema object
-    pub fn to_arrow_fields(&self) -> Result<Vec<Field>> {
-        self.fields.iter().map(Field::try_from).collect()
-    }
 }
 
 impl TryFrom<SerdeArrowSchema> for Vec<Field> {
     type Error = Error;
 
     fn try_from(value: SerdeArrowSchema) -> Result<Self> {
-        value.to_arrow_fields()
+        value.fields.iter().map(Field::try_from).collect()
     }
 }
 
@@ -62,20 +38,20 @@ impl Sealed for Vec<Field> {}
 /// `arrow-*` features*)
 impl SchemaLike for Vec<Field> {
     fn from_value<T: serde::Serialize>(value: &T) -> Result<Self> {
-        SerdeArrowSchema::from_value(value)?.to_arrow_fields()
+        SerdeArrowSchema::from_value(value)?.try_into()
     }
 
     fn from_type<'de, T: serde::Deserialize<'de>>(
         options: crate::schema::TracingOptions,
     ) -> Result<Self> {
-        SerdeArrowSchema::from_type::<T>(options)?.to_arrow_fields()
+        SerdeArrowSchema::from_type::<T>(options)?.try_into()
     }
 
     fn from_samples<T: serde::Serialize>(
         samples: &T,
         options: crate::schema::TracingOptions,
     ) -> Result<Self> {
-        SerdeArrowSchema::from_samples(samples, options)?.to_arrow_fields()
+        SerdeArrowSchema::from_samples(samples, options)?.try_into()
     }
 }
 
@@ -116,7 +92,15 @@ impl TryFrom<&DataType> for GenericDataType {
                 GenericTimeUnit::Nanosecond,
                 tz.as_ref().map(|s| s.to_string()),
             )),
-            _ => fail!("Only primitive data types can be converted to GenericDataType"),
+            DataType::Decimal128(precision, scale) => {
+                Ok(GenericDataType::Decimal128(*precision, *scale))
+            }
+            dt => fail!(
+                kind = ErrorKind::UnsupportedDataType {
+                    data_type: format!("{dt:?}")
+                },
+                "Only primitive data types can be converted to GenericDataType, found {dt:?}"
+            ),
         }
     }
 }
@@ -129,6 +113,10 @@ impl TryFrom<&Field> for GenericField {
             Some(strategy_str) => Some(strategy_str.parse::<Strategy>()?),
             None => None,
         };
+        let null_default = field.metadata().get(NULL_DEFAULT_KEY).cloned();
+        let extension_name = field.metadata().get(EXTENSION_NAME_KEY).cloned();
+        let extension_metadata = field.metadata().get(EXTENSION_METADATA_KEY).cloned();
+        let layout_hints = field.metadata().get(LAYOUT_HINTS_KEY).cloned();
         let name = field.name().to_owned();
         let nullable = field.is_nullable();
 
@@ -142,6 +130,10 @@ impl TryFrom<&Field> for GenericField {
                 children.push(field.as_ref().try_into()?);
                 GenericDataType::LargeList
             }
+            DataType::FixedSizeList(field, n) => {
+                children.push(field.as_ref().try_into()?);
+                GenericDataType::FixedSizeList(*n)
+            }
             DataType::Struct(fields) => {
                 for field in fields {
                     children.push(field.as_field_ref().try_into()?);
@@ -183,6 +175,10 @@ impl TryFrom<&Field> for GenericField {
             strategy,
             children,
             nullable,
+            null_default,
+            extension_name,
+            extension_metadata,
+            layout_hints,
         };
         field.validate()?;
 
@@ -231,6 +227,17 @@ impl TryFrom<&GenericField> for Field {
                 )
                 .into(),
             ),
+            GenericDataType::FixedSizeList(n) => DataType::FixedSizeList(
+                Box::<Field>::new(
+                    value
+                        .children
+                        .first()
+                        .ok_or_else(|| error!("List must a single child"))?
+                        .try_into()?,
+                )
+                .into(),
+                *n,
+            ),
             GenericDataType::Struct => DataType::Struct(
                 value
                     .children
@@ -303,12 +310,13 @@ impl TryFrom<&GenericField> for Field {
             GenericDataType::Timestamp(GenericTimeUnit::Nanosecond, tz) => {
                 DataType::Timestamp(TimeUnit::Nanosecond, tz.clone().map(|s| s.into()))
             }
+            GenericDataType::Decimal128(precision, scale) => {
+                DataType::Decimal128(*precision, *scale)
+            }
         };
 
         let mut field = Field::new(&value.name, data_type, value.nullable);
-        if let Some(strategy) = value.strategy.as_ref() {
-            field.set_metadata(strategy.clone().into());
-        }
+        field.set_metadata(value.get_metadata());
 
         Ok(field)
     }
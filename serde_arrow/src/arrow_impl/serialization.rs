@@ -3,48 +3,122 @@
 use crate::internal::{
     common::{ArrayMapping, DictionaryIndex, DictionaryValue, MutableBitBuffer},
     conversions::ToBytes,
-    error::{fail, Result},
-    serialization::{interpreter::MutableBuffers, Interpreter},
+    error::{error, fail, Result},
+    instrument::{span, ColumnTimer},
+    metrics::{ColumnMetrics, Instant},
+    schema::GenericField,
+    serialization::{interpreter::MutableBuffers, sort::index_runs, Interpreter},
 };
 
-use crate::_impl::arrow::{
+use super::arrow::{
+    _raw::data::transform::MutableArrayData,
     array::{make_array, Array, ArrayData, ArrayRef, NullArray},
     buffer::{Buffer, ScalarBuffer},
     datatypes::{ArrowNativeType, ArrowPrimitiveType, DataType, Field, Float16Type},
 };
 
-impl Interpreter {
-    /// Build the arrow arrays
-    pub fn build_arrow_arrays(&mut self) -> Result<Vec<ArrayRef>> {
-        let mut res = Vec::new();
-        for mapping in &self.structure.array_mapping {
-            let data = build_array_data(&mut self.buffers, mapping)?;
-            let array = make_array(data);
-            res.push(array);
+/// Build the arrow arrays and clear the buffers of the interpreter
+pub fn build_arrays(interpreter: &mut Interpreter) -> Result<Vec<ArrayRef>> {
+    let data = build_arrays_data(interpreter)?;
+    Ok(data.into_iter().map(make_array).collect())
+}
+
+/// Build the data of the arrow arrays and clear the buffers of the
+/// interpreter
+///
+/// The data shares the buffers moved out of the interpreter, no typed arrays
+/// are constructed.
+pub fn build_arrays_data(interpreter: &mut Interpreter) -> Result<Vec<ArrayData>> {
+    span!(
+        "build_arrays",
+        num_columns = interpreter.structure.array_mapping.len(),
+        num_rows = interpreter.buffers.num_rows,
+    );
+    interpreter.apply_empty_list_policy();
+    interpreter.collect_statistics();
+    let sort_indices = interpreter.sort_indices()?;
+    let start = interpreter.metrics.as_ref().map(|_| Instant::now());
+    let num_rows = interpreter.buffers.num_rows;
+
+    let mut res = Vec::new();
+    let mut columns = Vec::new();
+    for mapping in &interpreter.structure.array_mapping {
+        let timer = ColumnTimer::start(&interpreter.buffers);
+        let size = start.map(|_| interpreter.buffers.estimated_size());
+        res.push(build_array_data(&mut interpreter.buffers, mapping)?);
+        timer.finish(mapping.get_field(), &interpreter.buffers);
+        if let Some(size) = size {
+            columns.push(ColumnMetrics {
+                name: mapping.get_field().name.clone(),
+                bytes: size.saturating_sub(interpreter.buffers.estimated_size()),
+            });
         }
-        self.buffers.clear();
-
-        let max_len = res.iter().map(|a| a.len()).max().unwrap_or_default();
-        for (arr, mapping) in res.iter().zip(&self.structure.array_mapping) {
-            if arr.len() != max_len {
-                fail!("
-                    Unbalanced array lengths: array {name} has length {len}, but expected {max_len}",
-                    name = mapping.get_field().name,
-                    len = arr.len(),
-                );
-            }
+    }
+    interpreter.clear_buffers();
+
+    let max_len = res.iter().map(|a| a.len()).max().unwrap_or_default();
+    for (arr, mapping) in res.iter().zip(&interpreter.structure.array_mapping) {
+        if arr.len() != max_len {
+            fail!(
+                "
+                Unbalanced array lengths: array {name} has length {len}, but expected {max_len}",
+                name = mapping.get_field().name,
+                len = arr.len(),
+            );
         }
+    }
 
-        Ok(res)
+    if let Some(indices) = sort_indices {
+        let runs = index_runs(&indices);
+        res = res
+            .iter()
+            .map(|data| take_array_data(data, &runs))
+            .collect();
     }
 
-    pub fn build_arrow_array(&mut self) -> Result<ArrayRef> {
-        let arrays = self.build_arrow_arrays()?;
-        if arrays.len() != 1 {
-            fail!("Invalid number of result arrays: {}", arrays.len());
+    if interpreter.structure.validate_arrays {
+        for (arr, mapping) in res.iter().zip(&interpreter.structure.array_mapping) {
+            validate_array_data(arr, mapping.get_field())?;
         }
-        Ok(arrays.into_iter().next().unwrap())
     }
+
+    if let (Some(metrics), Some(start)) = (interpreter.metrics.as_mut(), start) {
+        metrics.add_execute_time(start.elapsed());
+        metrics.report(num_rows, columns);
+    }
+
+    Ok(res)
+}
+
+pub fn build_array(interpreter: &mut Interpreter) -> Result<ArrayRef> {
+    let arrays = build_arrays(interpreter)?;
+    if arrays.len() != 1 {
+        fail!("Invalid number of result arrays: {}", arrays.len());
+    }
+    Ok(arrays.into_iter().next().unwrap())
+}
+
+/// Copy the given runs of rows, given as `(start, len)`, into a new array
+fn take_array_data(data: &ArrayData, runs: &[(usize, usize)]) -> ArrayData {
+    let mut res = MutableArrayData::new(vec![data], false, data.len());
+    for &(start, len) in runs {
+        res.extend(0, start, start + len);
+    }
+    res.freeze()
+}
+
+fn validate_array_data(data: &ArrayData, field: &GenericField) -> Result<()> {
+    let expected = Field::try_from(field)?;
+    if data.data_type() != expected.data_type() {
+        fail!(
+            "Invalid array for field {name}: expected data type {expected:?}, found {actual:?}",
+            name = field.name,
+            expected = expected.data_type(),
+            actual = data.data_type(),
+        );
+    }
+    data.validate_full()
+        .map_err(|err| error!("Invalid array for field {name}: {err}", name = field.name))
 }
 
 macro_rules! build_primitive_array_data {
@@ -145,6 +219,12 @@ pub fn build_array_data(buffers: &mut MutableBuffers, mapping: &ArrayMapping) ->
             validity,
             ..
         } => build_primitive_array_data!(buffers, field, i64, u64, *buffer, *validity),
+        M::Decimal128 {
+            field,
+            buffer,
+            validity,
+            ..
+        } => build_primitive_array_data!(buffers, field, i128, u128, *buffer, *validity),
         &M::Utf8 {
             buffer,
             offsets,
@@ -281,6 +361,31 @@ pub fn build_array_data(buffers: &mut MutableBuffers, mapping: &ArrayMapping) ->
 
             Ok(array_data_builder.build()?)
         }
+        M::FixedSizeList {
+            field,
+            item,
+            count,
+            validity,
+            ..
+        } => {
+            let values = build_array_data(buffers, item)?;
+            let len = std::mem::take(&mut buffers.u0[*count]).len();
+
+            let validity = if let Some(validity) = validity {
+                let validity = std::mem::take(&mut buffers.u1[*validity]);
+                Some(Buffer::from(validity.buffer))
+            } else {
+                None
+            };
+
+            let field: Field = field.try_into()?;
+            let array_data_builder = ArrayData::builder(field.data_type().clone())
+                .len(len)
+                .add_child_data(values)
+                .null_bit_buffer(validity);
+
+            Ok(array_data_builder.build()?)
+        }
         M::Union {
             field,
             fields,
@@ -1,4 +1,4 @@
-use crate::_impl::arrow::{datatypes::Field, error::ArrowError};
+use super::arrow::{datatypes::Field, error::ArrowError};
 
 use crate::internal::error::Error;
 
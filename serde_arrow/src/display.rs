@@ -0,0 +1,2 @@
+//! Helpers to display arrays, e.g., for debugging
+pub use crate::internal::display::{pretty, FieldLike};
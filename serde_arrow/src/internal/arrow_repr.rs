@@ -0,0 +1,153 @@
+//! Support for types that control their own Arrow representation
+//!
+//! Values are serialized as two nested newtype structs: the outer one is named
+//! [`ARROW_REPR_MARKER`] and the inner one is named after the data type. Both
+//! are transparent for the serializers and deserializers of `serde_arrow` (and
+//! most other formats), but allow `from_type` to pick up the data type.
+use std::marker::PhantomData;
+
+use serde::{
+    de::{DeserializeOwned, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::internal::error::Result;
+
+/// The name of the newtype struct marking a value with a custom representation
+pub(crate) const ARROW_REPR_MARKER: &str = "SERDE_ARROW:ArrowRepr";
+
+/// A type that fully controls its Arrow representation
+///
+/// Implementing this trait allows to specify both the data type of the column
+/// and how values are converted to and from it, for cases that cannot be
+/// expressed with serde attributes alone. The data type is given in the
+/// format of the `"data_type"` key of
+/// [`SchemaLike::from_value`][crate::schema::SchemaLike::from_value], e.g.,
+/// `"Date64"` or `"I32"`. It must be a primitive type. Values are converted
+/// into their representation `Repr` before serialization, and converted back
+/// after deserialization.
+///
+/// Fields using this trait must be annotated with
+/// `#[serde(with = "serde_arrow::utils::arrow_repr")]`. When tracing the schema
+/// with [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type], the
+/// data type is taken from the trait. Note that `from_type` calls `from_repr`
+/// with the default value of `Repr` and that
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples] only
+/// sees the representation and traces its type.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{schema::SchemaLike, utils::ArrowRepr};
+///
+/// ##[derive(Debug, PartialEq)]
+/// struct Millis(u32);
+///
+/// impl ArrowRepr for Millis {
+///     const DATA_TYPE: &'static str = "Date64";
+///     type Repr = i64;
+///
+///     fn to_repr(&self) -> i64 {
+///         i64::from(self.0)
+///     }
+///
+///     fn from_repr(repr: i64) -> serde_arrow::Result<Self> {
+///         Ok(Millis(u32::try_from(repr)?))
+///     }
+/// }
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "serde_arrow::utils::arrow_repr")]
+///     time: Millis,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(Default::default())?;
+/// assert_eq!(fields[0].data_type(), &DataType::Date64);
+///
+/// let items = vec![Record { time: Millis(13) }];
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// let roundtripped: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(roundtripped, items);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+pub trait ArrowRepr: Sized {
+    /// The data type of the column
+    const DATA_TYPE: &'static str;
+
+    /// The representation of the values
+    type Repr: Serialize + DeserializeOwned;
+
+    /// Convert a value into its representation
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Convert the representation back into the value
+    fn from_repr(repr: Self::Repr) -> Result<Self>;
+}
+
+/// Serialize a type implementing [`ArrowRepr`], for use with `#[serde(with)]`
+pub fn serialize<T: ArrowRepr, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_newtype_struct(
+        ARROW_REPR_MARKER,
+        &WithDataType::<T>(value.to_repr(), PhantomData),
+    )
+}
+
+/// Deserialize a type implementing [`ArrowRepr`], for use with `#[serde(with)]`
+pub fn deserialize<'de, T: ArrowRepr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<T, D::Error> {
+    deserializer.deserialize_newtype_struct(ARROW_REPR_MARKER, MarkerVisitor::<T>(PhantomData))
+}
+
+struct WithDataType<T: ArrowRepr>(T::Repr, PhantomData<T>);
+
+impl<T: ArrowRepr> Serialize for WithDataType<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(T::DATA_TYPE, &self.0)
+    }
+}
+
+struct MarkerVisitor<T>(PhantomData<T>);
+
+impl<'de, T: ArrowRepr> Visitor<'de> for MarkerVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a value with data type {}", T::DATA_TYPE)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<T, D::Error> {
+        deserializer.deserialize_newtype_struct(T::DATA_TYPE, ReprVisitor::<T>(PhantomData))
+    }
+}
+
+struct ReprVisitor<T>(PhantomData<T>);
+
+impl<'de, T: ArrowRepr> Visitor<'de> for ReprVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a value with data type {}", T::DATA_TYPE)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<T, D::Error> {
+        let repr = T::Repr::deserialize(deserializer)?;
+        T::from_repr(repr).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,477 @@
+//! Conversion between `serde_arrow` schemas and Avro schemas
+//!
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    error::{fail, Result},
+    schema::{
+        to_upper_camel_case, GenericDataType, GenericField, GenericTimeUnit, SerdeArrowSchema,
+        Strategy,
+    },
+};
+
+/// An Avro schema
+///
+/// The schema implements `Serialize` and `Deserialize` using the JSON
+/// representation of Avro schemas. Use, e.g., `serde_json` to read or write
+/// it. See [`SerdeArrowSchema::to_avro`] and [`SerdeArrowSchema::from_avro`]
+/// for the supported types.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AvroSchema(AvroType);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum AvroType {
+    /// A primitive type or a reference to a named type
+    Name(String),
+    Union(Vec<AvroType>),
+    Complex(Box<AvroComplexType>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AvroComplexType {
+    #[serde(rename = "type")]
+    ty: AvroType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fields: Option<Vec<AvroField>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    items: Option<AvroType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    values: Option<AvroType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    symbols: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+    #[serde(
+        default,
+        rename = "logicalType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    logical_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    precision: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scale: Option<i8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AvroField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: AvroType,
+}
+
+impl AvroComplexType {
+    fn new(ty: &str) -> Self {
+        Self {
+            ty: AvroType::Name(ty.to_owned()),
+            name: None,
+            namespace: None,
+            fields: None,
+            items: None,
+            values: None,
+            symbols: None,
+            size: None,
+            logical_type: None,
+            precision: None,
+            scale: None,
+        }
+    }
+
+    fn logical(ty: &str, logical_type: &str) -> Self {
+        let mut res = Self::new(ty);
+        res.logical_type = Some(logical_type.to_owned());
+        res
+    }
+}
+
+impl SerdeArrowSchema {
+    /// Convert the schema into an Avro record schema with the given name
+    ///
+    /// The types are mapped as follows:
+    ///
+    /// - Nullable fields: unions of `"null"` and the type
+    /// - `Null`: `"null"`
+    /// - `Bool`: `"boolean"`
+    /// - `I8`, `I16`, `I32`, `U8`, `U16`: `"int"`
+    /// - `I64`, `U32`, `U64`: `"long"` (`U64` values above `i64::MAX` cannot
+    ///   be represented)
+    /// - `F16`, `F32`: `"float"`, `F64`: `"double"`
+    /// - `Utf8`, `LargeUtf8` and dictionaries: `"string"`
+    /// - `Date64` and `Timestamp` with millisecond or microsecond units: `long`
+    ///   with a `timestamp-*` logical type, or `local-timestamp-*` without
+    ///   timezone or with the `NaiveStrAsDate64` strategy
+    /// - `Decimal128`: `bytes` with a `decimal` logical type
+    /// - `List`, `LargeList`, `FixedSizeList`: `array`
+    /// - `Map` with string keys: `map`
+    /// - `Struct`: `record`, named after the field
+    ///
+    /// Other types, e.g., unions, result in an error.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "a", "data_type": "I64", "nullable": true},
+    ///     {"name": "b", "data_type": "LargeList", "children": [
+    ///         {"name": "element", "data_type": "LargeUtf8"},
+    ///     ]},
+    /// ]))?;
+    ///
+    /// let avro = schema.to_avro("Record")?;
+    /// assert_eq!(
+    ///     serde_json::to_value(&avro)?,
+    ///     serde_json::json!({
+    ///         "type": "record",
+    ///         "name": "Record",
+    ///         "fields": [
+    ///             {"name": "a", "type": ["null", "long"]},
+    ///             {"name": "b", "type": {"type": "array", "items": "string"}},
+    ///         ],
+    ///     }),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_avro(&self, name: &str) -> Result<AvroSchema> {
+        let mut names = HashSet::new();
+        let record = build_avro_record(&mut names, name, &self.fields)?;
+        Ok(AvroSchema(AvroType::Complex(Box::new(record))))
+    }
+
+    /// Build a schema from an Avro record schema
+    ///
+    /// The fields of the record become the fields of the schema. The types
+    /// are mapped as follows:
+    ///
+    /// - `"null"`: `Null`, `"boolean"`: `Bool`
+    /// - `"int"`: `I32`, `"long"`: `I64`
+    /// - `"float"`: `F32`, `"double"`: `F64`
+    /// - `"string"` and `enum`: `LargeUtf8`
+    /// - `array`: `LargeList`
+    /// - `map`: `Map` with `LargeUtf8` keys
+    /// - `record`: `Struct`
+    /// - unions of `"null"` and a single other type: the other type, marked
+    ///   as nullable
+    /// - `long` with a `timestamp-millis` / `timestamp-micros` logical type:
+    ///   `Timestamp` with the unit and a `UTC` timezone, for the
+    ///   `local-timestamp-*` logical types without timezone
+    /// - `bytes` or `fixed` with a `decimal` logical type: `Decimal128`
+    ///
+    /// Unknown logical types are ignored, as required by the Avro spec. Other
+    /// types, e.g., `bytes` or other unions, result in an error.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{AvroSchema, SchemaLike, SerdeArrowSchema};
+    ///
+    /// let avro: AvroSchema = serde_json::from_value(serde_json::json!({
+    ///     "type": "record",
+    ///     "name": "Record",
+    ///     "fields": [
+    ///         {"name": "a", "type": ["null", "long"]},
+    ///         {"name": "b", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+    ///     ],
+    /// }))?;
+    ///
+    /// assert_eq!(
+    ///     SerdeArrowSchema::from_avro(&avro)?,
+    ///     SerdeArrowSchema::from_value(&serde_json::json!([
+    ///         {"name": "a", "data_type": "I64", "nullable": true},
+    ///         {"name": "b", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+    ///     ]))?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_avro(schema: &AvroSchema) -> Result<Self> {
+        let mut named = HashMap::new();
+        let field = field_from_avro(&mut named, "$", &schema.0)?;
+        if field.data_type != GenericDataType::Struct || field.nullable {
+            fail!("Avro schemas must be records to be converted into a schema");
+        }
+        Ok(Self {
+            fields: field.children,
+        })
+    }
+}
+
+fn build_avro_record(
+    names: &mut HashSet<String>,
+    name: &str,
+    fields: &[GenericField],
+) -> Result<AvroComplexType> {
+    let name = unique_name(names, name);
+    let mut avro_fields = Vec::new();
+    for field in fields {
+        check_avro_name(&field.name)?;
+        avro_fields.push(AvroField {
+            name: field.name.clone(),
+            ty: to_avro_type(names, field)?,
+        });
+    }
+
+    let mut record = AvroComplexType::new("record");
+    record.name = Some(name);
+    record.fields = Some(avro_fields);
+    Ok(record)
+}
+
+/// Record names must be unique within the schema, append a counter if not
+fn unique_name(names: &mut HashSet<String>, name: &str) -> String {
+    let mut candidate = name.to_owned();
+    let mut idx = 1;
+    while names.contains(&candidate) {
+        candidate = format!("{name}{idx}");
+        idx += 1;
+    }
+    names.insert(candidate.clone());
+    candidate
+}
+
+fn check_avro_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_')
+        .unwrap_or_default();
+    if !valid_start || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        fail!("The field name {name:?} is not a valid Avro name");
+    }
+    Ok(())
+}
+
+fn to_avro_type(names: &mut HashSet<String>, field: &GenericField) -> Result<AvroType> {
+    use {AvroType as A, GenericDataType as D};
+
+    let ty = match &field.data_type {
+        D::Null => return Ok(A::Name(String::from("null"))),
+        D::Bool => A::Name(String::from("boolean")),
+        D::I8 | D::I16 | D::I32 | D::U8 | D::U16 => A::Name(String::from("int")),
+        D::I64 | D::U32 | D::U64 => A::Name(String::from("long")),
+        D::F16 | D::F32 => A::Name(String::from("float")),
+        D::F64 => A::Name(String::from("double")),
+        D::Utf8 | D::LargeUtf8 | D::Dictionary => A::Name(String::from("string")),
+        D::Date64 => {
+            let logical_type = if matches!(field.strategy, Some(Strategy::NaiveStrAsDate64)) {
+                "local-timestamp-millis"
+            } else {
+                "timestamp-millis"
+            };
+            A::Complex(Box::new(AvroComplexType::logical("long", logical_type)))
+        }
+        D::Timestamp(unit, tz) => {
+            let logical_type = match (unit, tz) {
+                (GenericTimeUnit::Millisecond, Some(_)) => "timestamp-millis",
+                (GenericTimeUnit::Millisecond, None) => "local-timestamp-millis",
+                (GenericTimeUnit::Microsecond, Some(_)) => "timestamp-micros",
+                (GenericTimeUnit::Microsecond, None) => "local-timestamp-micros",
+                _ => fail!(
+                    "Cannot convert field {:?} with data type {} to Avro",
+                    field.name,
+                    field.data_type
+                ),
+            };
+            A::Complex(Box::new(AvroComplexType::logical("long", logical_type)))
+        }
+        D::Decimal128(precision, scale) => {
+            let mut ty = AvroComplexType::logical("bytes", "decimal");
+            ty.precision = Some(*precision);
+            ty.scale = Some(*scale);
+            A::Complex(Box::new(ty))
+        }
+        D::List | D::LargeList | D::FixedSizeList(_) => {
+            let Some(item) = field.children.first() else {
+                fail!("List field {:?} without item field", field.name);
+            };
+            let mut ty = AvroComplexType::new("array");
+            ty.items = Some(to_avro_type(names, item)?);
+            A::Complex(Box::new(ty))
+        }
+        D::Map => {
+            let Some([key, value]) = field.children.first().map(|entries| &entries.children[..])
+            else {
+                fail!("Map field {:?} without key and value fields", field.name);
+            };
+            if !matches!(key.data_type, D::Utf8 | D::LargeUtf8) || key.nullable {
+                fail!(
+                    "Cannot convert map field {:?} with non-string keys to Avro",
+                    field.name
+                );
+            }
+            let mut ty = AvroComplexType::new("map");
+            ty.values = Some(to_avro_type(names, value)?);
+            A::Complex(Box::new(ty))
+        }
+        D::Struct => {
+            let name = to_upper_camel_case(&field.name);
+            A::Complex(Box::new(build_avro_record(names, &name, &field.children)?))
+        }
+        D::Union => fail!(
+            "Cannot convert union field {:?} to Avro, only unions with null are supported",
+            field.name
+        ),
+    };
+
+    if field.nullable {
+        Ok(A::Union(vec![A::Name(String::from("null")), ty]))
+    } else {
+        Ok(ty)
+    }
+}
+
+fn field_from_avro(
+    named: &mut HashMap<String, GenericField>,
+    name: &str,
+    ty: &AvroType,
+) -> Result<GenericField> {
+    match ty {
+        AvroType::Name(ty) => field_from_avro_name(named, name, ty),
+        AvroType::Union(variants) => {
+            let is_null = |ty: &AvroType| matches!(ty, AvroType::Name(name) if name == "null");
+            let (nulls, others): (Vec<_>, Vec<_>) = variants.iter().partition(|ty| is_null(ty));
+            match (nulls.len(), others.as_slice()) {
+                (1, [ty]) => {
+                    let mut field = field_from_avro(named, name, ty)?;
+                    field.nullable = true;
+                    Ok(field)
+                }
+                _ => fail!(
+                    "Cannot convert the Avro union of field {name:?}, only unions of null and a single type are supported"
+                ),
+            }
+        }
+        AvroType::Complex(ty) => field_from_avro_complex(named, name, ty),
+    }
+}
+
+fn field_from_avro_name(
+    named: &mut HashMap<String, GenericField>,
+    name: &str,
+    ty: &str,
+) -> Result<GenericField> {
+    let data_type = match ty {
+        "null" => return Ok(GenericField::new(name, GenericDataType::Null, true)),
+        "boolean" => GenericDataType::Bool,
+        "int" => GenericDataType::I32,
+        "long" => GenericDataType::I64,
+        "float" => GenericDataType::F32,
+        "double" => GenericDataType::F64,
+        "string" => GenericDataType::LargeUtf8,
+        "bytes" => fail!("Cannot convert the Avro type bytes of field {name:?}"),
+        ty => {
+            let Some(field) = named.get(ty) else {
+                fail!("Unknown Avro type {ty:?} of field {name:?}");
+            };
+            let mut field = field.clone();
+            field.name = name.to_owned();
+            return Ok(field);
+        }
+    };
+    Ok(GenericField::new(name, data_type, false))
+}
+
+fn field_from_avro_complex(
+    named: &mut HashMap<String, GenericField>,
+    name: &str,
+    ty: &AvroComplexType,
+) -> Result<GenericField> {
+    let base = match &ty.ty {
+        AvroType::Name(base) => base.as_str(),
+        // the type is given as a nested type, e.g., `{"type": {"type": "int"}}`
+        nested => return field_from_avro(named, name, nested),
+    };
+
+    match (base, ty.logical_type.as_deref()) {
+        ("long", Some(logical_type)) if logical_type.contains("timestamp-") => {
+            let (unit, tz) = match logical_type {
+                "timestamp-millis" => (GenericTimeUnit::Millisecond, Some(String::from("UTC"))),
+                "timestamp-micros" => (GenericTimeUnit::Microsecond, Some(String::from("UTC"))),
+                "local-timestamp-millis" => (GenericTimeUnit::Millisecond, None),
+                "local-timestamp-micros" => (GenericTimeUnit::Microsecond, None),
+                _ => return field_from_avro_name(named, name, base),
+            };
+            return Ok(GenericField::new(
+                name,
+                GenericDataType::Timestamp(unit, tz),
+                false,
+            ));
+        }
+        ("bytes" | "fixed", Some("decimal")) => {
+            let Some(precision) = ty.precision else {
+                fail!("Avro decimal field {name:?} without precision");
+            };
+            let field = GenericField::new(
+                name,
+                GenericDataType::Decimal128(precision, ty.scale.unwrap_or_default()),
+                false,
+            );
+            field.validate_decimal128()?;
+            return Ok(field);
+        }
+        _ => {}
+    }
+
+    match base {
+        "record" => {
+            let Some(fields) = ty.fields.as_ref() else {
+                fail!("Avro record of field {name:?} without fields");
+            };
+            let mut field = GenericField::new(name, GenericDataType::Struct, false);
+            for child in fields {
+                field
+                    .children
+                    .push(field_from_avro(named, &child.name, &child.ty)?);
+            }
+            register_named(named, ty, &field);
+            Ok(field)
+        }
+        "enum" => {
+            let field = GenericField::new(name, GenericDataType::LargeUtf8, false);
+            register_named(named, ty, &field);
+            Ok(field)
+        }
+        "array" => {
+            let Some(items) = ty.items.as_ref() else {
+                fail!("Avro array of field {name:?} without items");
+            };
+            Ok(GenericField::new(name, GenericDataType::LargeList, false)
+                .with_child(field_from_avro(named, "element", items)?))
+        }
+        "map" => {
+            let Some(values) = ty.values.as_ref() else {
+                fail!("Avro map of field {name:?} without values");
+            };
+            let entries = GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(field_from_avro(named, "value", values)?);
+            Ok(GenericField::new(name, GenericDataType::Map, false).with_child(entries))
+        }
+        "fixed" => fail!("Cannot convert the Avro type fixed of field {name:?}"),
+        base => field_from_avro_name(named, name, base),
+    }
+}
+
+/// Register named types under their name and full name to resolve references
+fn register_named(
+    named: &mut HashMap<String, GenericField>,
+    ty: &AvroComplexType,
+    field: &GenericField,
+) {
+    let Some(name) = ty.name.as_ref() else {
+        return;
+    };
+    if let Some(namespace) = ty.namespace.as_ref() {
+        named.insert(format!("{namespace}.{name}"), field.clone());
+    }
+    named.insert(name.clone(), field.clone());
+}
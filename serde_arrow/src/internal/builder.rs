@@ -0,0 +1,221 @@
+use std::marker::PhantomData;
+
+use serde::Serialize;
+
+use crate::internal::{
+    error::Result,
+    generic,
+    options::SerializationOptions,
+    schema::{GenericField, Sealed},
+    serialization::{statistics::ColumnStatistics, Interpreter},
+};
+
+/// A sealed trait for the fields of the supported Arrow implementations that
+/// can be used with [`GenericBuilder`]
+///
+/// The following types implement [`BuilderField`]:
+///
+#[cfg_attr(
+    has_arrow,
+    doc = "- [`arrow::datatypes::Field`][crate::_impl::arrow::datatypes::Field] building `arrow::array::ArrayRef`"
+)]
+#[cfg_attr(
+    has_arrow2,
+    doc = "- [`arrow2::datatypes::Field`][crate::_impl::arrow2::datatypes::Field] building `Box<dyn arrow2::array::Array>`"
+)]
+///
+pub trait BuilderField: Sealed + Sized {
+    /// The type of the arrays built for fields of this type
+    type Array;
+
+    #[doc(hidden)]
+    fn to_generic_field(&self) -> Result<GenericField>;
+
+    #[doc(hidden)]
+    fn from_generic_field(field: &GenericField) -> Result<Self>;
+
+    #[doc(hidden)]
+    fn build_arrays(interpreter: &mut Interpreter) -> Result<Vec<Self::Array>>;
+}
+
+/// Build arrays record by record for any supported Arrow implementation
+///
+/// The builder is generic over the field type, which selects the Arrow
+/// implementation: for arrow fields it builds arrow arrays, for arrow2 fields
+/// it builds arrow2 arrays. This way, conversion logic can be written once and
+/// the Arrow implementation can be chosen by the caller, e.g., via features.
+/// The methods behave as the ones of [`ArrowBuilder`][crate::ArrowBuilder]
+/// and [`Arrow2Builder`][crate::Arrow2Builder].
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::{schema::BuilderField, GenericBuilder};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// // the conversion logic does not depend on the Arrow implementation
+/// fn convert<F: BuilderField>(fields: &[F], items: &[Record]) -> serde_arrow::Result<Vec<F::Array>> {
+///     let mut builder = GenericBuilder::new(fields)?;
+///     builder.extend(items)?;
+///     builder.build_arrays()
+/// }
+///
+/// let fields = vec![
+///     Field::new("a", DataType::Float32, true),
+///     Field::new("b", DataType::UInt64, false),
+/// ];
+/// let arrays = convert(&fields, &[Record { a: Some(1.0), b: 2 }])?;
+///
+/// assert_eq!(arrays.len(), 2);
+/// assert_eq!(arrays[0].len(), 1);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+pub struct GenericBuilder<F: BuilderField> {
+    builder: generic::GenericBuilder,
+    field: PhantomData<fn() -> F>,
+}
+
+impl<F: BuilderField> std::fmt::Debug for GenericBuilder<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.builder.0.fmt_progress("GenericBuilder", f)
+    }
+}
+
+impl<F: BuilderField> GenericBuilder<F> {
+    fn from_builder(builder: generic::GenericBuilder) -> Self {
+        Self {
+            builder,
+            field: PhantomData,
+        }
+    }
+
+    /// Build a new builder for the given fields
+    ///
+    /// This method may fail when unsupported data types are encountered in the
+    /// given fields.
+    ///
+    pub fn new(fields: &[F]) -> Result<Self> {
+        Self::with_options(fields, SerializationOptions::default())
+    }
+
+    /// Build a new builder for the given fields with custom serialization
+    /// options
+    ///
+    pub fn with_options(fields: &[F], options: SerializationOptions) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(F::to_generic_field)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_builder(
+            generic::GenericBuilder::new_for_arrays_with_options(&fields, &options)?,
+        ))
+    }
+
+    /// Add a single record to the arrays
+    ///
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.builder.push(item)
+    }
+
+    /// Add multiple records to the arrays
+    ///
+    pub fn extend<T: Serialize + ?Sized>(&mut self, items: &T) -> Result<()> {
+        self.builder.extend(items)
+    }
+
+    /// Add the records of a slice to the arrays, see
+    /// [`ArrowBuilder::extend_from_slice`][crate::ArrowBuilder::extend_from_slice]
+    ///
+    pub fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        self.builder.extend_from_slice(items)
+    }
+
+    /// The approximate size in bytes of the arrays built from the rows pushed
+    /// so far, see
+    /// [`ArrowBuilder::estimated_size`][crate::ArrowBuilder::estimated_size]
+    ///
+    pub fn estimated_size(&self) -> usize {
+        self.builder.estimated_size()
+    }
+
+    /// Invoke `callback` once the [estimated size][GenericBuilder::estimated_size]
+    /// exceeds `budget` bytes, see
+    /// [`ArrowBuilder::on_size_exceeded`][crate::ArrowBuilder::on_size_exceeded]
+    ///
+    pub fn on_size_exceeded<C>(&mut self, budget: usize, callback: C)
+    where
+        C: FnMut(usize) + Send + Sync + 'static,
+    {
+        self.builder.on_size_exceeded(budget, callback)
+    }
+
+    /// Build the arrays from the rows pushed to far.
+    ///
+    /// This operation will reset the underlying buffers and start a new batch.
+    ///
+    pub fn build_arrays(&mut self) -> Result<Vec<F::Array>> {
+        F::build_arrays(&mut self.builder.0)
+    }
+
+    /// The statistics of the columns built by the last call to
+    /// [`build_arrays`][GenericBuilder::build_arrays]
+    ///
+    pub fn column_statistics(&self) -> &[ColumnStatistics] {
+        &self.builder.0.statistics
+    }
+
+    /// The fields of the arrays built by this builder
+    ///
+    pub fn fields(&self) -> Result<Vec<F>> {
+        self.builder
+            .fields()
+            .iter()
+            .map(F::from_generic_field)
+            .collect()
+    }
+
+    /// Rename the field `old` to `new` without losing the rows pushed so far
+    ///
+    pub fn rename_field(&mut self, old: &str, new: &str) -> Result<()> {
+        self.builder.rename_field(old, new)
+    }
+
+    /// Reorder the fields without losing the rows pushed so far
+    ///
+    pub fn with_field_order(&mut self, names: &[&str]) -> Result<()> {
+        self.builder.reorder_fields(names)
+    }
+
+    /// Split the builder into one builder per column, see
+    /// [`ArrowBuilder::into_columns`][crate::ArrowBuilder::into_columns]
+    ///
+    pub fn into_columns(self) -> Result<Vec<Self>> {
+        Ok(self
+            .builder
+            .into_columns()?
+            .into_iter()
+            .map(Self::from_builder)
+            .collect())
+    }
+
+    /// Combine builders into a single builder with the columns of all of them,
+    /// see [`ArrowBuilder::from_columns`][crate::ArrowBuilder::from_columns]
+    ///
+    pub fn from_columns(columns: Vec<Self>) -> Result<Self> {
+        let columns = columns.into_iter().map(|column| column.builder).collect();
+        Ok(Self::from_builder(generic::GenericBuilder::from_columns(
+            columns,
+        )?))
+    }
+}
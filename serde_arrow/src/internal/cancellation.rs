@@ -0,0 +1,90 @@
+//! Cooperative cancellation of long running conversions
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::internal::error::{fail, ErrorKind, Result};
+
+/// A token to cancel conversions from another thread
+///
+/// The token is passed to the conversion via
+/// [`SerializationOptions::cancellation`][crate::utils::SerializationOptions::cancellation]
+/// or
+/// [`DeserializationOptions::cancellation`][crate::utils::DeserializationOptions::cancellation].
+/// Clones share their state, i.e., cancelling any clone cancels all
+/// conversions using the token. The token is checked before each top-level
+/// row. Once cancelled, the conversion fails with
+/// [`ErrorKind::Cancelled`][crate::ErrorKind::Cancelled] that reports the
+/// number of rows processed before.
+///
+/// Builders stay consistent after cancellation: the rows pushed before are
+/// kept and can still be built into arrays. Any further push fails as long as
+/// the token is cancelled.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde_arrow::{
+///     utils::{CancellationToken, Item, SerializationOptions},
+///     ArrowBuilder, ErrorKind,
+/// };
+///
+/// let token = CancellationToken::new();
+/// let options = SerializationOptions::default().cancellation(token.clone());
+///
+/// let fields = vec![Field::new("item", DataType::UInt32, false)];
+/// let mut builder = ArrowBuilder::with_options(&fields, options)?;
+/// builder.push(&Item(1_u32))?;
+///
+/// // e.g., called from another thread
+/// token.cancel();
+///
+/// let err = builder.push(&Item(2_u32)).unwrap_err();
+/// assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 1 });
+///
+/// let arrays = builder.build_arrays()?;
+/// assert_eq!(arrays[0].len(), 1);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Construct a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all conversions using this token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether the token was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fail with a cancellation error, if the token was cancelled
+    pub(crate) fn check(&self, rows: usize) -> Result<()> {
+        if self.is_cancelled() {
+            fail!(
+                kind = ErrorKind::Cancelled { rows },
+                "The conversion was cancelled after {rows} rows"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl std::cmp::PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
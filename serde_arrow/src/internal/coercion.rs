@@ -0,0 +1,45 @@
+//! Reports of the coercions applied by forgiving deserializers
+
+/// A coercion applied to a column to match the target type
+///
+/// Returned by [`from_record_batch_auto`][crate::from_record_batch_auto] next
+/// to the deserialized items. The data types are formatted as Arrow data types,
+/// e.g., `"Int32"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Coercion {
+    /// The values of the column were cast losslessly to the traced type of
+    /// the field, e.g., from `Int32` to `Int64`
+    Widened {
+        /// The name of the column
+        column: String,
+        /// The data type of the column
+        from: String,
+        /// The data type of the field
+        to: String,
+    },
+    /// The column is missing and its nullable field was filled with nulls
+    MissingAsNull {
+        /// The name of the missing column
+        column: String,
+    },
+    /// The column is not declared by the target type and was ignored
+    Ignored {
+        /// The name of the ignored column
+        column: String,
+    },
+}
+
+impl std::fmt::Display for Coercion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Widened { column, from, to } => {
+                write!(f, "widened column {column:?} from {from} to {to}")
+            }
+            Self::MissingAsNull { column } => {
+                write!(f, "filled missing column {column:?} with nulls")
+            }
+            Self::Ignored { column } => write!(f, "ignored column {column:?}"),
+        }
+    }
+}
@@ -45,11 +45,30 @@ macro_rules! define_array_mapping {
                 }
             }
 
+            pub fn get_field_mut(&mut self) -> &mut GenericField {
+                match self {
+                    $(  ArrayMapping::$variant { field, .. } => field, )*
+                }
+            }
+
             pub fn get_validity(&self) -> Option<usize> {
                 match self {
                     $(  ArrayMapping::$variant { validity, .. } => *validity, )*
                 }
             }
+
+            /// Replace the field and drop the validity
+            pub fn without_validity(self, new_field: GenericField) -> Self {
+                match self {
+                    $(
+                        ArrayMapping::$variant { $($field,)* .. } => ArrayMapping::$variant {
+                            field: new_field,
+                            validity: None,
+                            $($field,)*
+                        },
+                    )*
+                }
+            }
         }
     };
 }
@@ -106,6 +125,9 @@ define_array_mapping!(
     Date64 {
         buffer: usize,
     },
+    Decimal128 {
+        buffer: usize,
+    },
     List {
         item: Box<ArrayMapping>,
         offsets: usize,
@@ -118,6 +140,11 @@ define_array_mapping!(
         item: Box<ArrayMapping>,
         offsets: usize,
     },
+    FixedSizeList {
+        item: Box<ArrayMapping>,
+        n: usize,
+        count: usize,
+    },
     Struct {
         fields: Vec<ArrayMapping>,
     },
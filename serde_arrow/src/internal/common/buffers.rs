@@ -46,7 +46,11 @@ impl<T: BufferExtract + ?Sized> BufferExtract for Arc<T> {
 }
 
 /// Readonly buffers
-#[derive(Default)]
+///
+/// The buffers only store slices borrowed from the source arrays. Extracting
+/// the buffers of an array does not copy its data, and the memory overhead is
+/// one slice per arrow buffer, independent of the number of rows.
+#[derive(Default, Clone)]
 pub struct Buffers<'a> {
     pub u0: Vec<usize>,
     pub u1: Vec<BitBuffer<'a>>,
@@ -54,6 +58,7 @@ pub struct Buffers<'a> {
     pub u16: Vec<&'a [u16]>,
     pub u32: Vec<&'a [u32]>,
     pub u64: Vec<&'a [u64]>,
+    pub u128: Vec<&'a [u128]>,
 }
 
 impl<'a> Buffers<'a> {
@@ -90,6 +95,11 @@ impl<'a> Buffers<'a> {
         self.u64.push(val);
         self.u64.len() - 1
     }
+
+    pub fn push_u128(&mut self, val: &'a [u128]) -> usize {
+        self.u128.push(val);
+        self.u128.len() - 1
+    }
 }
 
 impl<'a> Buffers<'a> {
@@ -108,6 +118,10 @@ impl<'a> Buffers<'a> {
     pub fn push_u64_cast<T: NoUninit>(&mut self, val: &'a [T]) -> Result<usize> {
         Ok(self.push_u64(bytemuck::try_cast_slice::<T, u64>(val)?))
     }
+
+    pub fn push_u128_cast<T: NoUninit>(&mut self, val: &'a [T]) -> Result<usize> {
+        Ok(self.push_u128(bytemuck::try_cast_slice::<T, u128>(val)?))
+    }
 }
 
 impl<'a> Buffers<'a> {
@@ -142,6 +156,10 @@ impl<'a> Buffers<'a> {
     pub fn get_i64(&self, idx: usize) -> &'a [i64] {
         bytemuck::cast_slice(self.u64[idx])
     }
+
+    pub fn get_i128(&self, idx: usize) -> &'a [i128] {
+        bytemuck::cast_slice(self.u128[idx])
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -223,19 +241,39 @@ impl MutableCountBuffer {
 }
 
 pub trait Offset: std::ops::Add<Self, Output = Self> + Clone + Default {
+    /// A description of the offset type used in error messages
+    const DESCRIPTION: &'static str;
+    /// The data types to use, if the offsets overflow
+    const MITIGATION: &'static str;
+
     fn try_form_usize(val: usize) -> Result<Self>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
 }
 
 impl Offset for i32 {
+    const DESCRIPTION: &'static str = "32 bit offsets";
+    const MITIGATION: &'static str = "Consider using LargeUtf8 / LargeList instead of Utf8 / List";
+
     fn try_form_usize(val: usize) -> Result<Self> {
         Ok(i32::try_from(val)?)
     }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        i32::checked_add(*self, *other)
+    }
 }
 
 impl Offset for i64 {
+    const DESCRIPTION: &'static str = "64 bit offsets";
+    const MITIGATION: &'static str = "Consider splitting the data into multiple batches";
+
     fn try_form_usize(val: usize) -> Result<Self> {
         Ok(i64::try_from(val)?)
     }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        i64::checked_add(*self, *other)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -255,14 +293,13 @@ impl<O: Offset> std::default::Default for MutableOffsetBuffer<O> {
 
 impl<O: Offset> MutableOffsetBuffer<O> {
     /// The number of items pushed (one less than the number of offsets)
-    #[allow(unused)]
     pub fn len(&self) -> usize {
         self.offsets.len() - 1
     }
 
     // push a new item with the given number of children
     pub fn push(&mut self, num_children: usize) -> Result<()> {
-        self.current_items = self.current_items.clone() + O::try_form_usize(num_children)?;
+        self.add_current_items(num_children)?;
         self.offsets.push(self.current_items.clone());
 
         Ok(())
@@ -273,7 +310,24 @@ impl<O: Offset> MutableOffsetBuffer<O> {
     }
 
     pub fn inc_current_items(&mut self) -> Result<()> {
-        self.current_items = self.current_items.clone() + O::try_form_usize(1)?;
+        self.add_current_items(1)
+    }
+
+    /// Add to the current items, failing instead of producing invalid offsets
+    /// if the offset type overflows
+    fn add_current_items(&mut self, num_children: usize) -> Result<()> {
+        let next = O::try_form_usize(num_children)
+            .ok()
+            .and_then(|num_children| self.current_items.checked_add(&num_children));
+        let Some(next) = next else {
+            fail!(
+                "Offset overflow in item {item}: the total length exceeds the maximum supported by {description}. {mitigation}",
+                item = self.len(),
+                description = O::DESCRIPTION,
+                mitigation = O::MITIGATION,
+            );
+        };
+        self.current_items = next;
         Ok(())
     }
 
@@ -281,3 +335,42 @@ impl<O: Offset> MutableOffsetBuffer<O> {
         *self = Self::default();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::MutableOffsetBuffer;
+
+    #[test]
+    fn offset_overflow_push() {
+        let mut offsets = MutableOffsetBuffer::<i32>::default();
+        offsets.push(i32::MAX as usize).unwrap();
+
+        let err = offsets.push(1).unwrap_err().to_string();
+        assert!(err.contains("Offset overflow in item 1"), "{err}");
+        assert!(err.contains("LargeUtf8 / LargeList"), "{err}");
+
+        // the offsets are unchanged after a failed push
+        assert_eq!(offsets.offsets, vec![0, i32::MAX]);
+    }
+
+    #[test]
+    fn offset_overflow_inc_current_items() {
+        let mut offsets = MutableOffsetBuffer::<i32>::default();
+        offsets.push(i32::MAX as usize - 1).unwrap();
+        offsets.inc_current_items().unwrap();
+
+        let err = offsets.inc_current_items().unwrap_err().to_string();
+        assert!(err.contains("Offset overflow in item 1"), "{err}");
+    }
+
+    #[test]
+    fn large_offsets_do_not_overflow() {
+        let mut offsets = MutableOffsetBuffer::<i64>::default();
+        offsets.push(i32::MAX as usize).unwrap();
+        offsets.push(1).unwrap();
+        assert_eq!(
+            offsets.offsets,
+            vec![0, i32::MAX as i64, i32::MAX as i64 + 1]
+        );
+    }
+}
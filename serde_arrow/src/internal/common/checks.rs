@@ -1,8 +1,67 @@
 use crate::internal::{
     common::BitBuffer,
-    error::{fail, Result},
+    error::{fail, ErrorKind, Result},
+    schema::{GenericDataType, GenericField},
 };
 
+/// Check that an array can be read with the given field
+///
+/// `array_field` is the field converted from the data type of the array. The
+/// check compares the physical layout only: the timezones of timestamps and
+/// the precision and scale of decimals are not compared. Children are checked
+/// when their arrays are extracted. Catching mismatches upfront results in
+/// better error messages than failing to interpret the buffers of the array.
+pub fn check_array_data_type(
+    field: &GenericField,
+    array_field: Result<GenericField>,
+    array_data_type: &dyn std::fmt::Debug,
+) -> Result<()> {
+    let array_field = match array_field {
+        Ok(array_field) => array_field,
+        Err(err) if matches!(err.kind(), ErrorKind::UnsupportedDataType { .. }) => fail!(
+            kind = ErrorKind::UnsupportedDataType {
+                data_type: format!("{array_data_type:?}"),
+            },
+            concat!(
+                "The array of field {name:?} has the data type {array_data_type:?}, which is not ",
+                "supported by serde_arrow with the enabled arrow version. The array may have been ",
+                "created with a different arrow version or may need to be cast to a supported ",
+                "data type first",
+            ),
+            name = field.name,
+            array_data_type = array_data_type,
+        ),
+        // other errors, e.g., invalid unions, are reported during extraction
+        Err(_) => return Ok(()),
+    };
+
+    let same_layout = match (&field.data_type, &array_field.data_type) {
+        (GenericDataType::Timestamp(unit, _), GenericDataType::Timestamp(array_unit, _)) => {
+            unit == array_unit
+        }
+        (GenericDataType::Decimal128(_, _), GenericDataType::Decimal128(_, _)) => true,
+        (data_type, array_data_type) => data_type == array_data_type,
+    };
+    if !same_layout {
+        fail!(
+            kind = ErrorKind::SchemaMismatch {
+                expected: field.data_type.to_string(),
+                found: array_field.data_type.to_string(),
+                path: field.name.clone(),
+            },
+            concat!(
+                "The array of field {name:?} has the data type {array_data_type:?}, but the ",
+                "field has the data type {data_type}. The fields must match the arrays, e.g., ",
+                "use the schema of the record batch",
+            ),
+            name = field.name,
+            array_data_type = array_data_type,
+            data_type = field.data_type,
+        );
+    }
+    Ok(())
+}
+
 /// Check that the list layout given in terms of validity and offsets is
 /// supported by serde_arrow
 ///
@@ -0,0 +1,120 @@
+use crate::internal::error::{fail, Result};
+
+/// The parts of a decimal string, e.g., `-12.50` is split into
+/// `(true, "12", "50")`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DecimalParts<'a> {
+    pub negative: bool,
+    pub integer: &'a str,
+    pub fraction: &'a str,
+}
+
+impl<'a> DecimalParts<'a> {
+    /// Split a string of the form `[+-]digits[.digits]` into its parts
+    ///
+    /// Leading zeros of the integer part and trailing zeros of the fraction
+    /// are removed, as they do not contribute to the precision.
+    pub fn parse(s: &'a str) -> Option<Self> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        Some(Self {
+            negative,
+            integer: integer.trim_start_matches('0'),
+            fraction: fraction.trim_end_matches('0'),
+        })
+    }
+}
+
+/// Parse a decimal string into its integer representation with the given
+/// scale, e.g., `"1.23"` with scale 3 is parsed as `1230`
+pub fn parse_decimal(s: &str, precision: u8, scale: i8) -> Result<i128> {
+    let Some(parts) = DecimalParts::parse(s) else {
+        fail!("Cannot parse {s:?} as a decimal number");
+    };
+    let scale = usize::try_from(scale)?;
+
+    if parts.fraction.len() > scale {
+        fail!("Cannot store {s:?} as Decimal128({precision}, {scale}) without loss of precision");
+    }
+    if parts.integer.len() + scale > usize::from(precision) {
+        fail!("Cannot store {s:?} as Decimal128({precision}, {scale}): too many digits");
+    }
+
+    let mut res: i128 = 0;
+    for digit in parts.integer.bytes().chain(parts.fraction.bytes()) {
+        res = res * 10 + i128::from(digit - b'0');
+    }
+    for _ in parts.fraction.len()..scale {
+        res *= 10;
+    }
+
+    Ok(if parts.negative { -res } else { res })
+}
+
+/// Format the integer representation of a decimal with the given scale, e.g.,
+/// `1230` with scale 3 is formatted as `"1.230"`
+pub fn format_decimal(val: i128, scale: i8) -> String {
+    let sign = if val < 0 { "-" } else { "" };
+    let digits = val.unsigned_abs().to_string();
+
+    if scale <= 0 {
+        let zeros = "0".repeat(scale.unsigned_abs().into());
+        return format!("{sign}{digits}{zeros}");
+    }
+
+    let scale = usize::from(scale.unsigned_abs());
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_decimal, parse_decimal, DecimalParts};
+
+    #[test]
+    fn parts() {
+        let parts = DecimalParts::parse("-0012.5000").unwrap();
+        assert!(parts.negative);
+        assert_eq!(parts.integer, "12");
+        assert_eq!(parts.fraction, "5");
+
+        assert_eq!(DecimalParts::parse(""), None);
+        assert_eq!(DecimalParts::parse("."), None);
+        assert_eq!(DecimalParts::parse("1e3"), None);
+        assert_eq!(DecimalParts::parse("1.2.3"), None);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_decimal("1.23", 5, 3).unwrap(), 1230);
+        assert_eq!(parse_decimal("-1.23", 5, 2).unwrap(), -123);
+        assert_eq!(parse_decimal("42", 2, 0).unwrap(), 42);
+        assert_eq!(parse_decimal(".5", 1, 1).unwrap(), 5);
+
+        assert!(parse_decimal("1.234", 5, 2).is_err());
+        assert!(parse_decimal("123.4", 3, 1).is_err());
+        assert!(parse_decimal("foo", 3, 1).is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_decimal(1230, 3), "1.230");
+        assert_eq!(format_decimal(-5, 2), "-0.05");
+        assert_eq!(format_decimal(42, 0), "42");
+        assert_eq!(format_decimal(42, -2), "4200");
+    }
+}
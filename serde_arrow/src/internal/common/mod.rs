@@ -2,12 +2,14 @@
 mod array_mapping;
 mod buffers;
 mod checks;
+mod decimal;
 
 pub use array_mapping::{ArrayMapping, DictionaryIndex, DictionaryValue};
 pub use buffers::{
     BitBuffer, BufferExtract, Buffers, MutableBitBuffer, MutableCountBuffer, MutableOffsetBuffer,
 };
-pub use checks::check_supported_list_layout;
+pub use checks::{check_array_data_type, check_supported_list_layout};
+pub use decimal::{format_decimal, parse_decimal, DecimalParts};
 
 macro_rules! define_bytecode {
     (
@@ -1,6 +1,6 @@
 use half::f16;
 
-use crate::Error;
+use crate::{internal::options::Overflow, Error};
 
 pub trait ToBytes: Sized {
     type Bytes;
@@ -113,6 +113,18 @@ impl ToBytes for i64 {
     }
 }
 
+impl ToBytes for i128 {
+    type Bytes = u128;
+
+    fn to_bytes(self) -> Self::Bytes {
+        Self::Bytes::from_ne_bytes(self.to_ne_bytes())
+    }
+
+    fn from_bytes(val: Self::Bytes) -> Self {
+        Self::from_ne_bytes(val.to_ne_bytes())
+    }
+}
+
 impl ToBytes for f32 {
     type Bytes = u32;
 
@@ -230,3 +242,96 @@ impl ToBytes for WrappedF16 {
         self.0.to_bits()
     }
 }
+
+/// Convert numbers between different types, following the overflow policy
+///
+/// Returns `None` if the value cannot be represented in the target type.
+pub trait ConvertNumber<T>: Sized {
+    fn convert(val: T, overflow: Overflow) -> Option<Self>;
+}
+
+macro_rules! impl_convert_int {
+    ($($dst:ty),*) => {
+        $(impl_convert_int!(@impl $dst, u8, u16, u32, u64, i8, i16, i32, i64);)*
+    };
+    (@impl $dst:ty, $($src:ty),*) => {
+        $(
+            impl ConvertNumber<$src> for $dst {
+                #[allow(clippy::unnecessary_cast)]
+                fn convert(val: $src, overflow: Overflow) -> Option<Self> {
+                    match overflow {
+                        Overflow::Error => <$dst>::try_from(val).ok(),
+                        Overflow::Saturate => {
+                            let val = (val as i128).clamp(<$dst>::MIN as i128, <$dst>::MAX as i128);
+                            Some(val as $dst)
+                        }
+                        Overflow::Wrap => Some(val as $dst),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_convert_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl<T> ConvertNumber<T> for WrappedF32
+where
+    WrappedF32: From<T>,
+{
+    fn convert(val: T, _: Overflow) -> Option<Self> {
+        Some(Self::from(val))
+    }
+}
+
+impl<T> ConvertNumber<T> for WrappedF64
+where
+    WrappedF64: From<T>,
+{
+    fn convert(val: T, _: Overflow) -> Option<Self> {
+        Some(Self::from(val))
+    }
+}
+
+impl ConvertNumber<f32> for WrappedF16 {
+    fn convert(val: f32, _: Overflow) -> Option<Self> {
+        Self::try_from(val).ok()
+    }
+}
+
+impl ConvertNumber<f64> for WrappedF16 {
+    fn convert(val: f64, _: Overflow) -> Option<Self> {
+        Self::try_from(val).ok()
+    }
+}
+
+/// Check whether a number is finite, integers are always finite
+pub trait IsFinite {
+    fn is_finite_number(&self) -> bool;
+}
+
+macro_rules! impl_is_finite_int {
+    ($($ty:ty),*) => {
+        $(
+            impl IsFinite for $ty {
+                fn is_finite_number(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_is_finite_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl IsFinite for f32 {
+    fn is_finite_number(&self) -> bool {
+        self.is_finite()
+    }
+}
+
+impl IsFinite for f64 {
+    fn is_finite_number(&self) -> bool {
+        self.is_finite()
+    }
+}
@@ -0,0 +1,142 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::internal::{error::Result, schema::GenericDataType, value::Value};
+
+/// A registry of conversions for newtype structs, keyed by their serde name
+///
+/// Converters allow to customize how third-party types are handled, when they
+/// cannot be annotated with serde attributes. During serialization, any value
+/// serialized as a newtype struct with a registered name (e.g., `struct
+/// Meters(f64)` is serialized with the name `"Meters"`) is first converted
+/// into a [`Value`], passed to the registered function and the result is
+/// written instead. With
+/// [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type], fields
+/// with a registered name are traced with the registered data type, whereas
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples]
+/// traces the converted values.
+///
+/// Converters are passed via
+/// [`SerializationOptions::converters`][crate::utils::SerializationOptions::converters]
+/// and [`TracingOptions::converters`][crate::schema::TracingOptions::converters].
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::{Converters, SerializationOptions},
+///     ArrowBuilder, Value,
+/// };
+///
+/// // a third-party type that is serialized as a string
+/// ##[derive(Serialize, Deserialize)]
+/// struct Seconds(String);
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     duration: Seconds,
+/// }
+///
+/// let converters = Converters::new().register("Seconds", "I64", |value| match value {
+///     Value::Str(s) => Ok(Value::I64(s.trim_end_matches('s').parse()?)),
+///     value => Ok(value),
+/// })?;
+///
+/// let fields = Vec::<Field>::from_type::<Record>(
+///     TracingOptions::default().converters(converters.clone()),
+/// )?;
+/// assert_eq!(fields[0].data_type(), &DataType::Int64);
+///
+/// let mut builder = ArrowBuilder::with_options(
+///     &fields,
+///     SerializationOptions::default().converters(converters),
+/// )?;
+/// builder.push(&Record { duration: Seconds(String::from("30s")) })?;
+/// let arrays = builder.build_arrays()?;
+/// assert_eq!(arrays[0].len(), 1);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Clone, Default)]
+pub struct Converters {
+    converters: BTreeMap<String, Converter>,
+}
+
+/// A single registered conversion
+#[derive(Clone)]
+pub struct Converter {
+    pub data_type: GenericDataType,
+    pub func: Arc<dyn Fn(Value) -> Result<Value> + Send + Sync>,
+}
+
+impl Converters {
+    /// Construct an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a conversion for newtype structs with the given name
+    ///
+    /// The data type is given in the format of the `"data_type"` key of
+    /// [`SchemaLike::from_value`][crate::schema::SchemaLike::from_value] and
+    /// must be a primitive type. A previous conversion for the same name is
+    /// replaced.
+    pub fn register<F>(mut self, name: &str, data_type: &str, func: F) -> Result<Self>
+    where
+        F: Fn(Value) -> Result<Value> + Send + Sync + 'static,
+    {
+        self.converters.insert(
+            name.to_owned(),
+            Converter {
+                data_type: data_type.parse()?,
+                func: Arc::new(func),
+            },
+        );
+        Ok(self)
+    }
+
+    /// Check whether no conversions are registered
+    pub fn is_empty(&self) -> bool {
+        self.converters.is_empty()
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&Converter> {
+        self.converters.get(name)
+    }
+}
+
+impl Converter {
+    pub fn convert(&self, value: Value) -> Result<Value> {
+        (self.func)(value)
+    }
+}
+
+impl std::fmt::Debug for Converters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.converters
+                    .iter()
+                    .map(|(name, converter)| (name, &converter.data_type)),
+            )
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for Converters {
+    fn eq(&self, other: &Self) -> bool {
+        self.converters.len() == other.converters.len()
+            && self.converters.iter().zip(other.converters.iter()).all(
+                |((name, converter), (other_name, other_converter))| {
+                    name == other_name
+                        && converter.data_type == other_converter.data_type
+                        && Arc::ptr_eq(&converter.func, &other_converter.func)
+                },
+            )
+    }
+}
@@ -1,33 +1,48 @@
+mod parallel;
+
 use std::collections::HashMap;
 
 use crate::{
     internal::{
         error::{error, fail, Result},
         event::Event,
+        instrument::span,
+        options::{EmptyListPolicy, TimezoneConversion},
+        schema::{GenericDataType, GenericTimeUnit},
         source::EventSource,
     },
     schema::Strategy,
 };
 
 use super::{
-    common::{define_bytecode, ArrayMapping, Buffers, DictionaryIndex, DictionaryValue},
+    common::{
+        define_bytecode, format_decimal, ArrayMapping, Buffers, DictionaryIndex, DictionaryValue,
+    },
     config::CONFIGURATION,
 };
 
 use half::f16;
 
+pub use parallel::{with_array_source, ArraySource};
+
 const UNSET_INSTR: usize = usize::MAX;
 const NEXT_INSTR: usize = usize::MAX - 1;
 
 #[derive(Debug, Clone)]
 pub struct CompilationOptions {
     pub wrap_with_struct: bool,
+    pub timezone_conversion: TimezoneConversion,
+    pub field_timezone_conversions: HashMap<String, TimezoneConversion>,
+    pub empty_lists: EmptyListPolicy,
 }
 
 impl std::default::Default for CompilationOptions {
     fn default() -> Self {
         Self {
             wrap_with_struct: true,
+            timezone_conversion: TimezoneConversion::None,
+            field_timezone_conversions: HashMap::new(),
+            empty_lists: EmptyListPolicy::Preserve,
         }
     }
 }
@@ -37,6 +52,21 @@ impl CompilationOptions {
         self.wrap_with_struct = val;
         self
     }
+
+    pub fn timezone_conversion(mut self, val: TimezoneConversion) -> Self {
+        self.timezone_conversion = val;
+        self
+    }
+
+    pub fn field_timezone_conversions(mut self, val: HashMap<String, TimezoneConversion>) -> Self {
+        self.field_timezone_conversions = val;
+        self
+    }
+
+    pub fn empty_lists(mut self, val: EmptyListPolicy) -> Self {
+        self.empty_lists = val;
+        self
+    }
 }
 
 pub fn compile_deserialization<'a>(
@@ -45,12 +75,44 @@ pub fn compile_deserialization<'a>(
     buffers: Buffers<'a>,
     options: CompilationOptions,
 ) -> Result<Interpreter<'a>> {
+    compile(num_items, arrays, buffers, options, Some(|name| name))
+}
+
+/// Compile the deserialization without borrowing from the array mappings
+///
+/// The field names are copied into the program and emitted as owned strings.
+/// Therefore, the interpreter can outlive the mappings, e.g., to keep it
+/// around and [seek][Interpreter::seek] to individual rows.
+pub fn compile_detached_deserialization<'a>(
+    num_items: usize,
+    arrays: &[ArrayMapping],
+    buffers: Buffers<'a>,
+    options: CompilationOptions,
+) -> Result<Interpreter<'a>> {
+    compile(num_items, arrays, buffers, options, None)
+}
+
+fn compile<'a, 'm>(
+    num_items: usize,
+    arrays: &'m [ArrayMapping],
+    buffers: Buffers<'a>,
+    options: CompilationOptions,
+    borrow_name: Option<fn(&'m str) -> &'a str>,
+) -> Result<Interpreter<'a>> {
+    span!(
+        "compile_deserialization",
+        num_items,
+        num_arrays = arrays.len()
+    );
     let mut compiler = Compiler {
         num_items,
         arrays,
         buffers,
         options,
-        num_positions: 0,
+        borrow_name,
+        position_sources: Vec::new(),
+        outer_count: 0,
+        path: Vec::new(),
         program: Vec::new(),
     };
     compiler.compile()?;
@@ -63,16 +125,24 @@ pub fn compile_deserialization<'a>(
     Ok(compiler.into_program())
 }
 
-struct Compiler<'a> {
+struct Compiler<'a, 'm> {
     num_items: usize,
-    arrays: &'a [ArrayMapping],
+    arrays: &'m [ArrayMapping],
     buffers: Buffers<'a>,
     options: CompilationOptions,
-    num_positions: usize,
+    /// Borrow the field names for the lifetime of the buffers, if `None` the
+    /// names are copied into the program
+    borrow_name: Option<fn(&'m str) -> &'a str>,
+    /// How to compute each position counter when seeking to a row
+    position_sources: Vec<PositionSource>,
+    /// The u0 buffer with the number of items of the outer sequence
+    outer_count: usize,
+    /// The names of the fields currently being compiled
+    path: Vec<&'m str>,
     program: Vec<Bytecode>,
 }
 
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
     fn compile(&mut self) -> Result<()> {
         self.compile_fields()?;
         self.fix_redirects()?;
@@ -87,9 +157,10 @@ impl<'a> Compiler<'a> {
         self.push_instr(EmitOuterStartSequence { next: 1 });
 
         let outer_sequence_count = self.buffers.push_u0(self.num_items);
+        self.outer_count = outer_sequence_count;
         let outer_sequence_item_pos = self.program.len();
 
-        let outer_sequence_position = self.new_position();
+        let outer_sequence_position = self.new_position(PositionSource::Row);
         self.push_instr(EmitOuterItem {
             next: NEXT_INSTR,
             if_end: UNSET_INSTR,
@@ -105,15 +176,11 @@ impl<'a> Compiler<'a> {
         let mut child_positions = Vec::new();
         for array in self.arrays {
             if self.options.wrap_with_struct {
-                let field = array.get_field();
-                let name_buffer = self.buffers.push_u8(field.name.as_bytes());
-                self.push_instr(EmitConstantString {
-                    next: NEXT_INSTR,
-                    buffer: name_buffer,
-                });
+                self.push_name(&array.get_field().name);
             }
 
-            self.compile_field(array, &mut child_positions)?;
+            let source = PositionSource::Parent(outer_sequence_position);
+            self.compile_field(array, source, &mut child_positions)?;
         }
         // The top-level struct cannot be null
         drop(child_positions);
@@ -147,46 +214,120 @@ impl<'a> Compiler<'a> {
     ///
     fn compile_field(
         &mut self,
-        array: &'a ArrayMapping,
+        array: &'m ArrayMapping,
+        source: PositionSource,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
-        let position = self.new_position();
+        let position = self.new_position(source);
+
+        let null_default = match array.get_validity() {
+            Some(_) => array.get_field().get_null_default_event()?,
+            None => None,
+        };
 
         let option_instr;
         if let Some(validity) = array.get_validity() {
             option_instr = Some(self.program.len());
 
-            self.push_instr(EmitOptionPrimitive {
-                next: NEXT_INSTR,
-                position,
-                validity,
-                positions_to_increment: Vec::new(),
-                if_none: usize::MAX,
-            });
+            if null_default.is_some() {
+                self.push_instr(EmitOptionDefault {
+                    next: NEXT_INSTR,
+                    position,
+                    validity,
+                    positions_to_increment: Vec::new(),
+                    if_none: usize::MAX,
+                });
+            } else if let Some(offsets) = self.get_policy_list_offsets(array) {
+                self.push_instr(EmitOptionList {
+                    next: NEXT_INSTR,
+                    position,
+                    validity,
+                    offsets,
+                    policy: self.options.empty_lists,
+                    positions_to_increment: Vec::new(),
+                    if_none: usize::MAX,
+                });
+            } else {
+                self.push_instr(EmitOptionPrimitive {
+                    next: NEXT_INSTR,
+                    position,
+                    validity,
+                    positions_to_increment: Vec::new(),
+                    if_none: usize::MAX,
+                });
+            }
         } else {
             option_instr = None
         };
 
         let mut inner_child_positions = vec![position];
-        self.compile_field_inner(array, position, &mut inner_child_positions)?;
+        self.path.push(&array.get_field().name);
+        let res = self.compile_field_inner(array, position, &mut inner_child_positions);
+        self.path.pop();
+        res?;
 
         child_positions.extend(inner_child_positions.iter().copied());
 
-        if let Some(option_instr) = option_instr {
-            let if_none = self.program.len();
-            let Some(Bytecode::EmitOptionPrimitive(instr)) = self.program.get_mut(option_instr)
-            else {
-                unreachable!()
+        let Some(option_instr) = option_instr else {
+            return Ok(());
+        };
+
+        let if_none = if let Some(value) = null_default {
+            let redirect_instr = self.push_instr(Redirect { next: UNSET_INSTR });
+            let if_none = self.push_instr(EmitNullDefault {
+                next: NEXT_INSTR,
+                value,
+            });
+
+            let instr_after_field = self.program.len();
+            let Some(Bytecode::Redirect(instr)) = self.program.get_mut(redirect_instr) else {
+                fail!("internal error: did not find redirect");
+            };
+            instr.next = instr_after_field;
+
+            if_none
+        } else if self.options.empty_lists == EmptyListPolicy::NullAsEmpty
+            && self.get_policy_list_offsets(array).is_some()
+        {
+            // nulls are emitted as a start sequence followed by this end sequence
+            let redirect_instr = self.push_instr(Redirect { next: UNSET_INSTR });
+            let if_none = self.push_instr(EmitNullDefault {
+                next: NEXT_INSTR,
+                value: Event::EndSequence,
+            });
+
+            let instr_after_field = self.program.len();
+            let Some(Bytecode::Redirect(instr)) = self.program.get_mut(redirect_instr) else {
+                fail!("internal error: did not find redirect");
             };
-            instr.if_none = if_none;
-            instr.positions_to_increment = inner_child_positions;
+            instr.next = instr_after_field;
+
+            if_none
+        } else {
+            self.program.len()
+        };
+
+        match self.program.get_mut(option_instr) {
+            Some(Bytecode::EmitOptionPrimitive(instr)) => {
+                instr.if_none = if_none;
+                instr.positions_to_increment = inner_child_positions;
+            }
+            Some(Bytecode::EmitOptionDefault(instr)) => {
+                instr.if_none = if_none;
+                instr.positions_to_increment = inner_child_positions;
+            }
+            Some(Bytecode::EmitOptionList(instr)) => {
+                instr.if_none = if_none;
+                instr.positions_to_increment = inner_child_positions;
+            }
+            _ => unreachable!(),
         }
         Ok(())
     }
 
     fn compile_field_inner(
         &mut self,
-        array: &'a ArrayMapping,
+        array: &'m ArrayMapping,
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -254,21 +395,29 @@ impl<'a> Compiler<'a> {
                 buffer,
                 position,
             }),
-            &M::Utf8 {
-                buffer, offsets, ..
-            } => self.push_instr(EmitStr32 {
-                next: NEXT_INSTR,
+            M::Utf8 {
+                field,
                 buffer,
                 offsets,
+                ..
+            } => self.push_instr(EmitStr32 {
+                next: NEXT_INSTR,
+                buffer: *buffer,
+                offsets: *offsets,
                 position,
+                as_number: matches!(field.strategy, Some(Strategy::NumberAsStr)),
             }),
-            &M::LargeUtf8 {
-                buffer, offsets, ..
-            } => self.push_instr(EmitStr64 {
-                next: NEXT_INSTR,
+            M::LargeUtf8 {
+                field,
                 buffer,
                 offsets,
+                ..
+            } => self.push_instr(EmitStr64 {
+                next: NEXT_INSTR,
+                buffer: *buffer,
+                offsets: *offsets,
                 position,
+                as_number: matches!(field.strategy, Some(Strategy::NumberAsStr)),
             }),
             &M::Dictionary {
                 dictionary,
@@ -280,32 +429,81 @@ impl<'a> Compiler<'a> {
                 value: dictionary,
                 index: indices,
             }),
-            M::Date64 { field, buffer, .. } => match field.strategy.as_ref() {
-                Some(Strategy::NaiveStrAsDate64) => self.push_instr(EmitDate64NaiveStr {
-                    next: NEXT_INSTR,
-                    buffer: *buffer,
-                    position,
-                }),
-                Some(Strategy::UtcStrAsDate64) => self.push_instr(EmitDate64UtcStr {
-                    next: NEXT_INSTR,
-                    buffer: *buffer,
-                    position,
-                }),
-                None => self.push_instr(EmitI64 {
+            M::Date64 { field, buffer, .. } => {
+                // whether to emit timezone-aware strings, `None` for integers
+                let conversion = match self
+                    .options
+                    .field_timezone_conversions
+                    .get(&self.path.join("."))
+                {
+                    Some(conversion) => *conversion,
+                    None => self.options.timezone_conversion,
+                };
+                let utc = match field.strategy.as_ref() {
+                    Some(Strategy::NaiveStrAsDate64) => {
+                        Some(conversion == TimezoneConversion::AssumeUtc)
+                    }
+                    Some(Strategy::UtcStrAsDate64) => {
+                        Some(conversion != TimezoneConversion::DropOffset)
+                    }
+                    None => None,
+                    Some(strategy) => {
+                        fail!("compilation of date64 with strategy {strategy} is not yet supported")
+                    }
+                };
+                let unit = match field.data_type {
+                    GenericDataType::Timestamp(unit, _) => unit,
+                    _ => GenericTimeUnit::Millisecond,
+                };
+                match utc {
+                    None => self.push_instr(EmitI64 {
+                        next: NEXT_INSTR,
+                        buffer: *buffer,
+                        position,
+                    }),
+                    Some(true) => self.push_instr(EmitDate64UtcStr {
+                        next: NEXT_INSTR,
+                        buffer: *buffer,
+                        position,
+                        unit,
+                    }),
+                    Some(false) => self.push_instr(EmitDate64NaiveStr {
+                        next: NEXT_INSTR,
+                        buffer: *buffer,
+                        position,
+                        unit,
+                    }),
+                }
+            }
+            &M::Decimal128 {
+                ref field, buffer, ..
+            } => {
+                let GenericDataType::Decimal128(_, scale) = field.data_type else {
+                    fail!("invalid data type for decimal array: {}", field.data_type);
+                };
+                self.push_instr(EmitDecimal128Str {
                     next: NEXT_INSTR,
-                    buffer: *buffer,
+                    buffer,
                     position,
-                }),
-                Some(strategy) => {
-                    fail!("compilation of date64 with strategy {strategy} is not yet supported")
-                }
-            },
+                    scale,
+                })
+            }
             M::List { item, offsets, .. } => self
-                .compile_list(item, position, *offsets, false)
+                .compile_list(item, position, ListOffsets::I32(*offsets))
                 .map(|_| 0)?,
             M::LargeList { item, offsets, .. } => self
-                .compile_list(item, position, *offsets, true)
+                .compile_list(item, position, ListOffsets::I64(*offsets))
                 .map(|_| 0)?,
+            M::FixedSizeList { item, n, .. } => {
+                let item_positions =
+                    self.compile_list(item, position, ListOffsets::FixedSize(*n))?;
+
+                // null lists contain n items, that need to be skipped
+                for _ in 0..*n {
+                    child_positions.extend(item_positions.iter().copied());
+                }
+                0
+            }
             M::Struct { field, fields, .. } => match field.strategy.as_ref() {
                 None => self
                     .compile_struct(fields, position, child_positions)
@@ -336,7 +534,6 @@ impl<'a> Compiler<'a> {
                 let Some(values_field) = entries_fields.get(1) else {
                     fail!("cannot extract values field")
                 };
-                // TODO: check that keys and values are truly non-nullable
                 self.compile_map(key_field, values_field, position, *offsets)
                     .map(|_| 0)?
             }
@@ -348,23 +545,80 @@ impl<'a> Compiler<'a> {
     }
 }
 
+/// The offsets of the items of a list array
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListOffsets {
+    /// i32 offsets stored in the buffer with the given index
+    I32(usize),
+    /// i64 offsets stored in the buffer with the given index
+    I64(usize),
+    /// Offsets implied by the given number of items of each list
+    FixedSize(usize),
+}
+
+/// How to compute a position counter at the start of a row
+#[derive(Debug, Clone, Copy)]
+enum PositionSource {
+    /// The index of the row
+    Row,
+    /// The value of the parent position counter
+    Parent(usize),
+    /// The number of items of the lists before the parent position
+    ListItem(usize, ListOffsets),
+    /// The number of values of the variant before the parent position
+    UnionVariant {
+        parent: usize,
+        types: usize,
+        variant: usize,
+    },
+    /// Set by the instruction using it, before it is read
+    Unused,
+}
+
+/// The name of a union variant
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariantName {
+    /// The name is stored in the u8 buffer with the given index
+    Buffer(usize),
+    /// The name is stored in the program
+    Owned(String),
+}
+
+impl ListOffsets {
+    fn get(&self, buffers: &Buffers<'_>, idx: usize) -> Result<usize> {
+        match *self {
+            Self::I32(offsets) => Ok(buffers
+                .get_i32(offsets)
+                .get(idx)
+                .copied()
+                .ok_or_else(|| error!("Cannot get offset"))?
+                .try_into()?),
+            Self::I64(offsets) => Ok(buffers
+                .get_i64(offsets)
+                .get(idx)
+                .copied()
+                .ok_or_else(|| error!("Cannot get offset"))?
+                .try_into()?),
+            Self::FixedSize(n) => Ok(idx * n),
+        }
+    }
+}
+
 /// List support
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
     fn compile_list(
         &mut self,
-        item: &'a ArrayMapping,
+        item: &'m ArrayMapping,
         position: usize,
-        offsets: usize,
-        is_large: bool,
-    ) -> Result<()> {
-        let inner_position = self.new_position();
+        offsets: ListOffsets,
+    ) -> Result<Vec<usize>> {
+        let inner_position = self.new_position(PositionSource::Unused);
         let emit_start_instr = self.push_instr(EmitStartSequence {
             next: NEXT_INSTR,
             if_end: UNSET_INSTR,
             position,
             inner_position,
             offsets,
-            is_large,
         });
 
         let if_item_instr = self.program.len() + 1;
@@ -374,12 +628,11 @@ impl<'a> Compiler<'a> {
             position,
             inner_position,
             offsets,
-            is_large,
         });
 
         let mut child_positions = Vec::new();
-        self.compile_field(item, &mut child_positions)?;
-        drop(child_positions);
+        let source = PositionSource::ListItem(position, offsets);
+        self.compile_field(item, source, &mut child_positions)?;
 
         let if_end_instr = self.program.len() + 1;
         self.push_instr(EmitEndSequence {
@@ -388,7 +641,6 @@ impl<'a> Compiler<'a> {
             position,
             inner_position,
             offsets,
-            is_large,
         });
 
         if let Some(Bytecode::EmitItemSequence(instr)) = self.program.get_mut(emit_item_instr) {
@@ -402,29 +654,23 @@ impl<'a> Compiler<'a> {
             fail!("invalid state during compilation");
         }
 
-        Ok(())
+        Ok(child_positions)
     }
 }
 
 /// Struct support
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
     fn compile_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &'m [ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
         self.push_instr(EmitStartStruct { next: NEXT_INSTR });
 
         for array in arrays {
-            let field = array.get_field();
-            let name_buffer = self.buffers.push_u8(field.name.as_bytes());
-            self.push_instr(EmitConstantString {
-                next: NEXT_INSTR,
-                buffer: name_buffer,
-            });
-
-            self.compile_field(array, child_positions)?;
+            self.push_name(&array.get_field().name);
+            self.compile_field(array, PositionSource::Parent(position), child_positions)?;
         }
 
         self.push_instr(EmitEndStruct {
@@ -436,7 +682,7 @@ impl<'a> Compiler<'a> {
 
     fn compile_tuple_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &'m [ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -444,7 +690,7 @@ impl<'a> Compiler<'a> {
 
         for array in arrays {
             self.push_instr(EmitItemTuple { next: NEXT_INSTR });
-            self.compile_field(array, child_positions)?;
+            self.compile_field(array, PositionSource::Parent(position), child_positions)?;
         }
 
         self.push_instr(EmitEndTuple {
@@ -456,20 +702,15 @@ impl<'a> Compiler<'a> {
 
     fn compile_map_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &'m [ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
         self.push_instr(EmitStartMapStruct { next: NEXT_INSTR });
 
         for array in arrays {
-            let field = array.get_field();
-            let name_buffer = self.buffers.push_u8(field.name.as_bytes());
-            self.push_instr(EmitConstantString {
-                next: NEXT_INSTR,
-                buffer: name_buffer,
-            });
-            self.compile_field(array, child_positions)?;
+            self.push_name(&array.get_field().name);
+            self.compile_field(array, PositionSource::Parent(position), child_positions)?;
         }
 
         self.push_instr(EmitEndMapStruct {
@@ -481,15 +722,15 @@ impl<'a> Compiler<'a> {
 }
 
 /// Map support
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
     fn compile_map(
         &mut self,
-        key_field: &'a ArrayMapping,
-        value_field: &'a ArrayMapping,
+        key_field: &'m ArrayMapping,
+        value_field: &'m ArrayMapping,
         position: usize,
         offsets: usize,
     ) -> Result<()> {
-        let inner_position = self.new_position();
+        let inner_position = self.new_position(PositionSource::Unused);
 
         let emit_start_instr = self.push_instr(EmitStartMap {
             next: NEXT_INSTR,
@@ -510,11 +751,10 @@ impl<'a> Compiler<'a> {
 
         let mut dummy_positions = Vec::new();
 
-        let keys_position = self.new_position();
-        self.compile_field_inner(key_field, keys_position, &mut dummy_positions)?;
-
-        let values_position = self.new_position();
-        self.compile_field_inner(value_field, values_position, &mut dummy_positions)?;
+        // NOTE: compile keys and values as fields to handle nullable entries
+        let source = PositionSource::ListItem(position, ListOffsets::I32(offsets));
+        self.compile_field(key_field, source, &mut dummy_positions)?;
+        self.compile_field(value_field, source, &mut dummy_positions)?;
 
         // null maps entries with non-empty segments are not supported
         drop(dummy_positions);
@@ -544,17 +784,22 @@ impl<'a> Compiler<'a> {
 }
 
 /// Union support
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
     fn compile_union(
         &mut self,
-        fields: &'a [ArrayMapping],
+        fields: &'m [ArrayMapping],
         position: usize,
         types: usize,
     ) -> Result<()> {
         let mut field_names = Vec::new();
         for field in fields {
-            let idx = self.buffers.push_u8(field.get_field().name.as_bytes());
-            field_names.push(idx);
+            let name = field.get_field().name.as_str();
+            field_names.push(match self.borrow_name {
+                Some(borrow_name) => {
+                    VariantName::Buffer(self.buffers.push_u8(borrow_name(name).as_bytes()))
+                }
+                None => VariantName::Owned(name.to_owned()),
+            });
         }
         let dispatch_instr = self.push_instr(UnionDispatch {
             next: UNSET_INSTR,
@@ -567,11 +812,16 @@ impl<'a> Compiler<'a> {
         let mut field_instr = Vec::new();
         let mut redirect_instrs = Vec::new();
 
-        for field in fields {
+        for (variant, field) in fields.iter().enumerate() {
             field_instr.push(self.program.len());
 
             let mut dummy_positions = Vec::new();
-            self.compile_field(field, &mut dummy_positions)?;
+            let source = PositionSource::UnionVariant {
+                parent: position,
+                types,
+                variant,
+            };
+            self.compile_field(field, source, &mut dummy_positions)?;
             // unions in nullable structs are currently not supported
             drop(dummy_positions);
 
@@ -628,7 +878,19 @@ impl<'a> Compiler<'a> {
 }
 
 /// Utility functions
-impl<'a> Compiler<'a> {
+impl<'a, 'm> Compiler<'a, 'm> {
+    /// The offsets of nullable lists affected by the empty list policy
+    fn get_policy_list_offsets(&self, array: &ArrayMapping) -> Option<ListOffsets> {
+        if self.options.empty_lists == EmptyListPolicy::Preserve {
+            return None;
+        }
+        match array {
+            ArrayMapping::List { offsets, .. } => Some(ListOffsets::I32(*offsets)),
+            ArrayMapping::LargeList { offsets, .. } => Some(ListOffsets::I64(*offsets)),
+            _ => None,
+        }
+    }
+
     fn push_instr<I: Into<Bytecode>>(&mut self, instr: I) -> usize {
         let instr_idx = self.program.len();
 
@@ -643,16 +905,35 @@ impl<'a> Compiler<'a> {
         instr_idx
     }
 
-    fn new_position(&mut self) -> usize {
-        self.num_positions += 1;
-        self.num_positions - 1
+    /// Emit the name of a field, either borrowed from the buffers or owned
+    fn push_name(&mut self, name: &'m str) -> usize {
+        match self.borrow_name {
+            Some(borrow_name) => {
+                let buffer = self.buffers.push_u8(borrow_name(name).as_bytes());
+                self.push_instr(EmitConstantString {
+                    next: NEXT_INSTR,
+                    buffer,
+                })
+            }
+            None => self.push_instr(EmitOwnedString {
+                next: NEXT_INSTR,
+                value: name.to_owned(),
+            }),
+        }
+    }
+
+    fn new_position(&mut self, source: PositionSource) -> usize {
+        self.position_sources.push(source);
+        self.position_sources.len() - 1
     }
 
     fn into_program(self) -> Interpreter<'a> {
         Interpreter {
             current_instr: 0,
             program: self.program,
-            positions: vec![0; self.num_positions],
+            positions: vec![0; self.position_sources.len()],
+            position_sources: self.position_sources,
+            outer_count: self.outer_count,
             buffers: self.buffers,
         }
     }
@@ -686,10 +967,8 @@ define_bytecode!{
         if_end: usize,
         /// the position inside the overall items
         inner_position: usize,
-        /// the buffer that contains the offsets
-        offsets: usize,
-        /// whether to use i64 offsets (`true`) or i32 offsets (`false )`
-        is_large: bool,
+        /// the offsets of the lists
+        offsets: ListOffsets,
     },
     /// Handle the end-of-sequence / item case
     EmitEndSequence {
@@ -699,10 +978,8 @@ define_bytecode!{
         inner_position: usize,
         /// the instruction to jump to if the list is not yet at its end
         if_item: usize,
-        /// the buffer that contains the offsets
-        offsets: usize,
-        /// whether to use i64 offsets (`true`) or i32 offsets (`false )`
-        is_large: bool,
+        /// the offsets of the lists
+        offsets: ListOffsets,
     },
     EmitItemSequence {
         /// the position inside the offsets array
@@ -711,10 +988,8 @@ define_bytecode!{
         inner_position: usize,
         /// the instruction to jump to if the list is at its end
         if_end: usize,
-        /// the buffer that contains the number of offsets in this sequence
-        offsets: usize,
-        /// whether to use i64 offsets (`true`) or i32 offsets (`false )`
-        is_large: bool,
+        /// the offsets of the lists
+        offsets: ListOffsets,
     },
     EmitStartMap {
         /// the position inside the offsets array
@@ -765,6 +1040,11 @@ define_bytecode!{
     EmitConstantString{
         buffer: usize,
     },
+    /// Emit a string stored in the program, used for the field names of
+    /// detached programs
+    EmitOwnedString {
+        value: String,
+    },
     EndOfProgram {},
     /// Emit nullability information for a primitive type
     /// 
@@ -779,6 +1059,44 @@ define_bytecode!{
         /// The indices of the position counters to increment if none
         positions_to_increment: Vec<usize>,
     },
+    /// Emit nullability information for a primitive type with a null default
+    ///
+    /// Like `EmitOptionPrimitive`, but nulls are emitted as `Event::Default`
+    /// followed by the default value.
+    EmitOptionDefault {
+        /// The index of the position counter for the validity
+        position: usize,
+        /// The index of the u1 buffer containing the validity
+        validity: usize,
+        /// The instruction to jump to, if the validity is false
+        if_none: usize,
+        /// The indices of the position counters to increment if none
+        positions_to_increment: Vec<usize>,
+    },
+    /// Emit nullability information for a list with a non-default empty list
+    /// policy
+    ///
+    /// Like `EmitOptionPrimitive`, but empty lists may be emitted as nulls and
+    /// nulls may be emitted as `Event::StartSequence` followed by the
+    /// `Event::EndSequence` at `if_none`.
+    EmitOptionList {
+        /// The index of the position counter for the validity
+        position: usize,
+        /// The index of the u1 buffer containing the validity
+        validity: usize,
+        /// The offsets of the lists
+        offsets: ListOffsets,
+        /// How to treat null and empty lists
+        policy: EmptyListPolicy,
+        /// The instruction to jump to, if the list is emitted as null or empty
+        if_none: usize,
+        /// The indices of the position counters to increment if none
+        positions_to_increment: Vec<usize>,
+    },
+    /// Emit the default value used in place of a null
+    EmitNullDefault {
+        value: Event<'static>,
+    },
     EmitNull {},
     EmitBool {
         position: usize,
@@ -832,19 +1150,30 @@ define_bytecode!{
         position: usize,
         buffer: usize,
         offsets: usize,
+        /// If true, parse the string into a number
+        as_number: bool,
     },
     EmitStr64 {
         position: usize,
         buffer: usize,
         offsets: usize,
+        /// If true, parse the string into a number
+        as_number: bool,
     },
     EmitDate64NaiveStr {
         position: usize,
         buffer: usize,
+        unit: GenericTimeUnit,
     },
     EmitDate64UtcStr {
         position: usize,
         buffer: usize,
+        unit: GenericTimeUnit,
+    },
+    EmitDecimal128Str {
+        position: usize,
+        buffer: usize,
+        scale: i8,
     },
     EmitDictionaryStr {
         position: usize,
@@ -855,7 +1184,7 @@ define_bytecode!{
         position: usize,
         types: usize,
         field_instr: Vec<usize>,
-        field_names: Vec<usize>,
+        field_names: Vec<VariantName>,
     },
 }
 
@@ -1106,21 +1435,10 @@ impl Instruction for EmitStartSequence {
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
-        let start: usize = if self.is_large {
-            buffers
-                .get_i64(self.offsets)
-                .get(outer_pos)
-                .copied()
-                .ok_or_else(|| error!("attempting to to get non existing list"))?
-                .try_into()?
-        } else {
-            buffers
-                .get_i32(self.offsets)
-                .get(outer_pos)
-                .copied()
-                .ok_or_else(|| error!("attempting to to get non existing list"))?
-                .try_into()?
-        };
+        let start = self
+            .offsets
+            .get(buffers, outer_pos)
+            .map_err(|_| error!("attempting to to get non existing list"))?;
 
         positions[self.inner_position] = start;
 
@@ -1141,21 +1459,7 @@ impl Instruction for EmitItemSequence {
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
-        let end: usize = if self.is_large {
-            buffers
-                .get_i64(self.offsets)
-                .get(outer_pos + 1)
-                .copied()
-                .ok_or_else(|| error!("Cannot get offset"))?
-                .try_into()?
-        } else {
-            buffers
-                .get_i32(self.offsets)
-                .get(outer_pos + 1)
-                .copied()
-                .ok_or_else(|| error!("Cannot get offset"))?
-                .try_into()?
-        };
+        let end = self.offsets.get(buffers, outer_pos + 1)?;
 
         let inner_pos = positions[self.inner_position];
         if inner_pos >= end {
@@ -1182,21 +1486,7 @@ impl Instruction for EmitEndSequence {
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
-        let end: usize = if self.is_large {
-            buffers
-                .get_i64(self.offsets)
-                .get(outer_pos + 1)
-                .copied()
-                .ok_or_else(|| error!("Cannot get offset"))?
-                .try_into()?
-        } else {
-            buffers
-                .get_i32(self.offsets)
-                .get(outer_pos + 1)
-                .copied()
-                .ok_or_else(|| error!("Cannot get offset"))?
-                .try_into()?
-        };
+        let end = self.offsets.get(buffers, outer_pos + 1)?;
 
         let inner_pos = positions[self.inner_position];
         if inner_pos >= end {
@@ -1319,6 +1609,21 @@ impl Instruction for EmitConstantString {
     }
 }
 
+impl Instruction for EmitOwnedString {
+    fn emit<'a>(
+        &self,
+        _positions: &mut [usize],
+        _buffers: &Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        Ok((self.next, Some(Event::OwnedStr(self.value.clone()))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
 impl Instruction for EmitOptionPrimitive {
     fn emit<'a>(
         &self,
@@ -1343,6 +1648,79 @@ impl Instruction for EmitOptionPrimitive {
     }
 }
 
+impl Instruction for EmitOptionList {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let pos = positions[self.position];
+        let valid = buffers.u1[self.validity].is_set(pos);
+
+        let event = match self.policy {
+            EmptyListPolicy::NullAsEmpty if !valid => Event::StartSequence,
+            EmptyListPolicy::EmptyAsNull
+                if valid
+                    && self.offsets.get(buffers, pos)? == self.offsets.get(buffers, pos + 1)? =>
+            {
+                Event::Null
+            }
+            _ if valid => return Ok((self.next, Some(Event::Some))),
+            _ => Event::Null,
+        };
+
+        for idx in &self.positions_to_increment {
+            positions[*idx] += 1;
+        }
+        Ok((self.if_none, Some(event)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_none = get_target_update(redirects, self.if_none);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitOptionDefault {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let pos = positions[self.position];
+        if buffers.u1[self.validity].is_set(pos) {
+            Ok((self.next, Some(Event::Some)))
+        } else {
+            for idx in &self.positions_to_increment {
+                positions[*idx] += 1;
+            }
+            Ok((self.if_none, Some(Event::Default)))
+        }
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_none = get_target_update(redirects, self.if_none);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitNullDefault {
+    fn emit<'a>(
+        &self,
+        _positions: &mut [usize],
+        _buffers: &Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        Ok((self.next, Some(self.value.clone())))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
 impl Instruction for UnionDispatch {
     fn emit<'a>(
         &self,
@@ -1353,12 +1731,15 @@ impl Instruction for UnionDispatch {
         positions[self.position] += 1;
 
         let ty: usize = buffers.get_i8(self.types)[pos].try_into()?;
-        let name = buffers.get_u8(self.field_names[ty]);
-        let name = std::str::from_utf8(name)?;
-
         let next = self.field_instr[ty];
 
-        Ok((next, Some(Event::Variant(name, ty))))
+        let ev = match &self.field_names[ty] {
+            &VariantName::Buffer(buffer) => {
+                Event::Variant(std::str::from_utf8(buffers.get_u8(buffer))?, ty)
+            }
+            VariantName::Owned(name) => Event::OwnedVariant(name.clone(), ty),
+        };
+        Ok((next, Some(ev)))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1610,6 +1991,22 @@ impl Instruction for EmitF64 {
     }
 }
 
+/// Parse a string written with the `NumberAsStr` strategy
+///
+/// Integers are parsed as `i64` or, if they exceed its range, as `u64`. All
+/// other values are parsed as `f64`.
+fn parse_number_str(s: &str) -> Result<Event<'static>> {
+    if let Ok(val) = s.parse::<i64>() {
+        Ok(Event::I64(val))
+    } else if let Ok(val) = s.parse::<u64>() {
+        Ok(Event::U64(val))
+    } else if let Ok(val) = s.parse::<f64>() {
+        Ok(Event::F64(val))
+    } else {
+        fail!("Cannot parse {s:?} as a number")
+    }
+}
+
 impl Instruction for EmitStr32 {
     fn emit<'a>(
         &self,
@@ -1622,7 +2019,11 @@ impl Instruction for EmitStr32 {
         let start = usize::try_from(buffers.get_i32(self.offsets)[pos])?;
         let end = usize::try_from(buffers.get_i32(self.offsets)[pos + 1])?;
         let s = std::str::from_utf8(&buffers.u8[self.buffer][start..end])?;
-        Ok((self.next, Some(Event::Str(s))))
+        if self.as_number {
+            Ok((self.next, Some(parse_number_str(s)?)))
+        } else {
+            Ok((self.next, Some(Event::Str(s))))
+        }
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1643,7 +2044,11 @@ impl Instruction for EmitStr64 {
         let start = usize::try_from(buffers.get_i64(self.offsets)[pos])?;
         let end = usize::try_from(buffers.get_i64(self.offsets)[pos + 1])?;
         let s = std::str::from_utf8(&buffers.u8[self.buffer][start..end])?;
-        Ok((self.next, Some(Event::Str(s))))
+        if self.as_number {
+            Ok((self.next, Some(parse_number_str(s)?)))
+        } else {
+            Ok((self.next, Some(Event::Str(s))))
+        }
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1658,18 +2063,12 @@ impl Instruction for EmitDate64NaiveStr {
         positions: &mut [usize],
         buffers: &Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        use chrono::NaiveDateTime;
-
         let val =
             i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
         positions[self.position] += 1;
 
-        let Some(val) = NaiveDateTime::from_timestamp_opt(val / 1000, (val % 1000) as u32 * 100_000) else {
-            fail!("Unsupported timestamp value: {val}");
-        };
-
-        // NOTE: chrono documents that Debug, not Display, can be parsed
-        Ok((self.next, Some(format!("{:?}", val).into())))
+        // the value is formatted once the requested type is known
+        Ok((self.next, Some(Event::Timestamp(val, self.unit, false))))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1684,18 +2083,30 @@ impl Instruction for EmitDate64UtcStr {
         positions: &mut [usize],
         buffers: &Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        use chrono::{TimeZone, Utc};
-
         let val =
             i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
         positions[self.position] += 1;
 
-        let Some(val) = Utc.timestamp_opt(val / 1000, (val % 1000) as u32 * 100_000).earliest() else {
-            fail!("Unsupported timestamp value: {val}");
-        };
+        // the value is formatted once the requested type is known
+        Ok((self.next, Some(Event::Timestamp(val, self.unit, true))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDecimal128Str {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val = buffers.get_i128(self.buffer)[positions[self.position]];
+        positions[self.position] += 1;
 
-        // NOTE: chrono documents that Debug, not Display, can be parsed
-        Ok((self.next, Some(format!("{:?}", val).into())))
+        Ok((self.next, Some(format_decimal(val, self.scale).into())))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1752,9 +2163,50 @@ pub struct Interpreter<'a> {
     current_instr: usize,
     program: Vec<Bytecode>,
     positions: Vec<usize>,
+    position_sources: Vec<PositionSource>,
+    outer_count: usize,
     buffers: Buffers<'a>,
 }
 
+impl<'a> Interpreter<'a> {
+    /// Restart the program to emit a sequence containing only the given row
+    ///
+    /// The position counters are derived from the row: list items and map
+    /// entries from the offsets, union variants by counting the preceding
+    /// values of the same variant.
+    pub fn seek(&mut self, row: usize) -> Result<()> {
+        for idx in 0..self.positions.len() {
+            self.positions[idx] = match self.position_sources[idx] {
+                PositionSource::Row => row,
+                PositionSource::Parent(parent) => self.positions[parent],
+                PositionSource::ListItem(parent, offsets) => {
+                    let start = offsets.get(&self.buffers, self.positions[parent])?;
+                    let first = offsets.get(&self.buffers, 0)?;
+                    start - first
+                }
+                PositionSource::UnionVariant {
+                    parent,
+                    types,
+                    variant,
+                } => {
+                    let Some(types) = self.buffers.get_i8(types).get(..self.positions[parent])
+                    else {
+                        fail!("Cannot get the union types before row {row}");
+                    };
+                    types
+                        .iter()
+                        .filter(|&&ty| usize::try_from(ty).ok() == Some(variant))
+                        .count()
+                }
+                PositionSource::Unused => 0,
+            };
+        }
+        self.buffers.u0[self.outer_count] = row + 1;
+        self.current_instr = 0;
+        Ok(())
+    }
+}
+
 impl<'a> EventSource<'a> for Interpreter<'a> {
     fn next(&mut self) -> Result<Option<Event<'a>>> {
         let (next_instr, ev) =
@@ -1764,6 +2216,14 @@ impl<'a> EventSource<'a> for Interpreter<'a> {
     }
 }
 
+/// Borrow the interpreter as an event source, e.g., to deserialize the rows
+/// after [seeking][Interpreter::seek]
+impl<'a: 'b, 'b> EventSource<'b> for &'b mut Interpreter<'a> {
+    fn next(&mut self) -> Result<Option<Event<'b>>> {
+        Interpreter::next(self)
+    }
+}
+
 fn get_target_update(redirects: &HashMap<usize, usize>, instr: usize) -> usize {
     redirects.get(&instr).copied().unwrap_or(instr)
 }
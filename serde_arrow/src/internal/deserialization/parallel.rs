@@ -0,0 +1,336 @@
+//! Decode the columns of arrays on multiple threads
+//!
+use std::{cell::Cell, collections::VecDeque, panic::AssertUnwindSafe, rc::Rc, sync::mpsc};
+
+use crate::internal::{
+    common::{ArrayMapping, Buffers},
+    error::{error, fail, Result},
+    event::Event,
+    instrument::{self, Timer},
+    source::EventSource,
+};
+
+use super::{compile_deserialization, CompilationOptions, Interpreter};
+
+/// The events of arrays, either decoded row by row or decoded column by
+/// column in parallel (see [`with_array_source`])
+pub struct ArraySource<'a> {
+    kind: ArraySourceKind<'a>,
+    /// The nesting level after the last event
+    depth: usize,
+    /// The number of rows started so far, shared with [`with_array_source`]
+    num_rows: Rc<Cell<usize>>,
+}
+
+enum ArraySourceKind<'a> {
+    Interpreter(Interpreter<'a>),
+    Columns(ZippedColumns<'a>),
+}
+
+impl<'a> ArraySource<'a> {
+    fn new(kind: ArraySourceKind<'a>, num_rows: Rc<Cell<usize>>) -> Self {
+        Self {
+            kind,
+            depth: 0,
+            num_rows,
+        }
+    }
+}
+
+impl<'a> EventSource<'a> for ArraySource<'a> {
+    fn next(&mut self) -> Result<Option<Event<'a>>> {
+        let ev = match &mut self.kind {
+            ArraySourceKind::Interpreter(interpreter) => interpreter.next()?,
+            ArraySourceKind::Columns(columns) => columns.next()?,
+        };
+        match &ev {
+            // the items of the outer sequence are the rows
+            Some(Event::Item) if self.depth == 1 => self.num_rows.set(self.num_rows.get() + 1),
+            Some(ev) if ev.is_start() => self.depth += 1,
+            Some(ev) if ev.is_end() => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+        Ok(ev)
+    }
+}
+
+/// The number of rows decoded per column at once, bounds the number of
+/// buffered events
+const CHUNK_ROWS: usize = 4096;
+
+/// Compile the deserialization of the arrays and pass the events to `func`
+///
+/// Returns the result of `func` together with the number of rows it consumed,
+/// which may be smaller than `num_items` if `func` stops early.
+///
+/// With `column_threads` of two or more and multiple arrays, each array is
+/// decoded by its own program. The columns are distributed over
+/// `column_threads` worker threads, started once for the call. The rows are
+/// decoded in chunks of [`CHUNK_ROWS`] rows, with the columns of each chunk
+/// decoded in parallel by the workers. The decoded columns are then zipped
+/// into rows, emitting the same events as a single program for all arrays.
+pub fn with_array_source<'a, R>(
+    num_items: usize,
+    arrays: &'a [ArrayMapping],
+    buffers: Buffers<'a>,
+    options: CompilationOptions,
+    column_threads: usize,
+    func: impl FnOnce(ArraySource<'_>) -> Result<R>,
+) -> Result<(R, usize)> {
+    let num_rows = Rc::new(Cell::new(0));
+    if column_threads < 2 || arrays.len() < 2 || !options.wrap_with_struct {
+        let interpreter = compile_deserialization(num_items, arrays, buffers, options)?;
+        let kind = ArraySourceKind::Interpreter(interpreter);
+        let res = func(ArraySource::new(kind, num_rows.clone()))?;
+        return Ok((res, num_rows.get()));
+    }
+
+    let options = options.wrap_with_struct(false);
+    let mut columns = Vec::new();
+    let mut groups = (0..column_threads.min(arrays.len()))
+        .map(|_| Vec::new())
+        .collect::<Vec<_>>();
+    let num_groups = groups.len();
+    for (idx, array) in arrays.iter().enumerate() {
+        let decoder = ColumnDecoder::new(num_items, array, buffers.clone(), options.clone())?;
+        columns.push(DecodedColumn {
+            name: decoder.name,
+            events: VecDeque::new(),
+            row_lens: Vec::new(),
+        });
+        groups[idx % num_groups].push((idx, decoder));
+    }
+
+    let context = instrument::Context::current();
+    let context = &context;
+    std::thread::scope(|scope| {
+        let (result_sender, results) = mpsc::channel();
+        let mut workers = Vec::new();
+        for mut group in groups {
+            let (sender, requests) = mpsc::channel::<usize>();
+            let result_sender = result_sender.clone();
+            scope.spawn(move || {
+                // the requests end, once the zipped columns are dropped
+                while let Ok(num_rows) = requests.recv() {
+                    let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        context.in_scope(|| decode_group(&mut group, num_rows))
+                    }))
+                    .unwrap_or_else(|_| Err(error!("Panic while decoding columns in parallel")));
+                    let failed = res.is_err();
+                    if result_sender.send(res).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+            workers.push(sender);
+        }
+
+        let kind = ArraySourceKind::Columns(ZippedColumns {
+            columns,
+            workers,
+            results,
+            num_items,
+            state: ZipState::Start,
+            row: 0,
+            chunk_start: 0,
+            chunk_end: 0,
+            column: 0,
+            remaining: 0,
+        });
+        let res = func(ArraySource::new(kind, num_rows.clone()))?;
+        Ok((res, num_rows.get()))
+    })
+}
+
+/// The decoded rows of the given columns, keyed by the index of the column
+type DecodedRows<'a> = Vec<(usize, VecDeque<Event<'a>>, Vec<usize>)>;
+
+fn decode_group<'a>(
+    group: &mut [(usize, ColumnDecoder<'a>)],
+    num_rows: usize,
+) -> Result<DecodedRows<'a>> {
+    let mut res = Vec::new();
+    for (idx, column) in group {
+        let (events, row_lens) = column.decode_rows(num_rows)?;
+        res.push((*idx, events, row_lens));
+    }
+    Ok(res)
+}
+
+/// The decoder of a single column, split into rows
+pub struct ColumnDecoder<'a> {
+    name: &'a str,
+    interpreter: Interpreter<'a>,
+}
+
+impl<'a> ColumnDecoder<'a> {
+    fn new(
+        num_items: usize,
+        array: &'a ArrayMapping,
+        buffers: Buffers<'a>,
+        options: CompilationOptions,
+    ) -> Result<Self> {
+        let name = array.get_field().name.as_str();
+        let mut interpreter =
+            compile_deserialization(num_items, std::slice::from_ref(array), buffers, options)?;
+
+        let Some(Event::StartSequence) = interpreter.next()? else {
+            fail!("Invalid events while decoding column {name:?}: expected a sequence");
+        };
+
+        Ok(Self { name, interpreter })
+    }
+
+    /// Decode the events of the next `num_rows` rows, together with the
+    /// number of events of each row
+    fn decode_rows(&mut self, num_rows: usize) -> Result<(VecDeque<Event<'a>>, Vec<usize>)> {
+        let name = self.name;
+        let timer = Timer::start();
+        let mut events = VecDeque::new();
+        let mut row_lens = Vec::with_capacity(num_rows);
+
+        for _ in 0..num_rows {
+            match self.interpreter.next()? {
+                Some(Event::Item) => {}
+                ev => fail!("Invalid events while decoding column {name:?}: unexpected {ev:?}"),
+            }
+
+            let row_start = events.len();
+            let mut depth = 0_usize;
+            loop {
+                let Some(ev) = self.interpreter.next()? else {
+                    fail!("Unexpected end of events while decoding column {name:?}");
+                };
+                if ev.is_start() {
+                    depth += 1;
+                } else if ev.is_end() {
+                    depth = depth.saturating_sub(1);
+                }
+                let pending_value = ev.is_marker() || matches!(ev, Event::Default);
+                events.push_back(ev);
+
+                if depth == 0 && !pending_value {
+                    break;
+                }
+            }
+            row_lens.push(events.len() - row_start);
+        }
+        instrument::decoded_column(name, events.len(), timer);
+
+        Ok((events, row_lens))
+    }
+}
+
+/// The rows of the current chunk of a column
+pub struct DecodedColumn<'a> {
+    name: &'a str,
+    /// The events of the current chunk not yet emitted
+    events: VecDeque<Event<'a>>,
+    /// The number of events of each row of the current chunk
+    row_lens: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ZipState {
+    Start,
+    Item,
+    StartStruct,
+    Name,
+    Value,
+    Done,
+}
+
+/// Emit the rows of decoded columns as structs with one field per column
+pub struct ZippedColumns<'a> {
+    columns: Vec<DecodedColumn<'a>>,
+    /// The channels to request the next chunk from each worker
+    workers: Vec<mpsc::Sender<usize>>,
+    /// The decoded chunks sent by the workers
+    results: mpsc::Receiver<Result<DecodedRows<'a>>>,
+    num_items: usize,
+    state: ZipState,
+    row: usize,
+    /// The rows of the currently decoded chunk
+    chunk_start: usize,
+    chunk_end: usize,
+    column: usize,
+    /// The number of events of the current value not yet emitted
+    remaining: usize,
+}
+
+impl<'a> ZippedColumns<'a> {
+    /// Decode the next chunk of rows of all columns in parallel
+    fn decode_chunk(&mut self) -> Result<()> {
+        let num_rows = CHUNK_ROWS.min(self.num_items - self.row);
+
+        for worker in &self.workers {
+            if worker.send(num_rows).is_err() {
+                fail!("Panic while decoding columns in parallel");
+            }
+        }
+        for _ in 0..self.workers.len() {
+            let Ok(res) = self.results.recv() else {
+                fail!("Panic while decoding columns in parallel");
+            };
+            for (idx, events, row_lens) in res? {
+                let column = &mut self.columns[idx];
+                column.events = events;
+                column.row_lens = row_lens;
+            }
+        }
+
+        self.chunk_start = self.row;
+        self.chunk_end = self.row + num_rows;
+        Ok(())
+    }
+}
+
+impl<'a> EventSource<'a> for ZippedColumns<'a> {
+    fn next(&mut self) -> Result<Option<Event<'a>>> {
+        let ev = match self.state {
+            ZipState::Start => {
+                self.state = ZipState::Item;
+                Event::StartSequence
+            }
+            ZipState::Item if self.row >= self.num_items => {
+                self.state = ZipState::Done;
+                Event::EndSequence
+            }
+            ZipState::Item => {
+                if self.row >= self.chunk_end {
+                    self.decode_chunk()?;
+                }
+                self.state = ZipState::StartStruct;
+                Event::Item
+            }
+            ZipState::StartStruct => {
+                self.column = 0;
+                self.state = ZipState::Name;
+                Event::StartStruct
+            }
+            ZipState::Name => {
+                let Some(column) = self.columns.get(self.column) else {
+                    self.row += 1;
+                    self.state = ZipState::Item;
+                    return Ok(Some(Event::EndStruct));
+                };
+                self.remaining = column.row_lens[self.row - self.chunk_start];
+                self.state = ZipState::Value;
+                Event::Str(column.name)
+            }
+            ZipState::Value => {
+                let Some(ev) = self.columns[self.column].events.pop_front() else {
+                    fail!("Unexpected end of events in column {}", self.column);
+                };
+                self.remaining -= 1;
+                if self.remaining == 0 {
+                    self.column += 1;
+                    self.state = ZipState::Name;
+                }
+                ev
+            }
+            ZipState::Done => return Ok(None),
+        };
+        Ok(Some(ev))
+    }
+}
@@ -0,0 +1,276 @@
+use crate::internal::{
+    error::{fail, Result},
+    schema::{GenericDataType, GenericField, GenericTimeUnit, Sealed},
+    value::Value,
+};
+
+/// A sealed trait for the fields of the supported Arrow implementations
+///
+/// The following types implement [`FieldLike`]:
+///
+#[cfg_attr(
+    has_arrow,
+    doc = "- [`arrow::datatypes::Field`][crate::_impl::arrow::datatypes::Field] with arrays implementing `AsRef<dyn arrow::array::Array>`"
+)]
+#[cfg_attr(
+    has_arrow2,
+    doc = "- [`arrow2::datatypes::Field`][crate::_impl::arrow2::datatypes::Field] with arrays implementing `AsRef<dyn arrow2::array::Array>`"
+)]
+///
+pub trait FieldLike<A>: Sealed + Sized {
+    #[doc(hidden)]
+    fn pretty(fields: &[Self], arrays: &[A], limit: usize) -> Result<String>;
+}
+
+/// Format arrays as a human-readable table
+///
+/// At most `limit` rows are included and only these rows are deserialized.
+/// Values are rendered using the schema: Date64 and Timestamp fields are
+/// rendered as datetimes, structs as `{name: value, ..}`, lists as `[value,
+/// ..]` and maps as `{key: value, ..}`. Nulls are rendered as `null`. Line
+/// breaks are escaped as `\n` and `\r`.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = vec![
+///     Field::new("a", DataType::Float32, true),
+///     Field::new("b", DataType::UInt64, false),
+/// ];
+/// let arrays = serde_arrow::to_arrow(&fields, &[
+///     Record { a: Some(1.0), b: 2 },
+///     Record { a: None, b: 4 },
+/// ])?;
+///
+/// assert_eq!(
+///     serde_arrow::display::pretty(&fields, &arrays, 10)?,
+///     concat!(
+///         "+------+---+\n",
+///         "| a    | b |\n",
+///         "+------+---+\n",
+///         "| 1    | 2 |\n",
+///         "| null | 4 |\n",
+///         "+------+---+\n",
+///     ),
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+pub fn pretty<F: FieldLike<A>, A>(fields: &[F], arrays: &[A], limit: usize) -> Result<String> {
+    F::pretty(fields, arrays, limit)
+}
+
+/// Format the deserialized rows as a table, `rows` must be structs with the
+/// given fields
+///
+/// `rows` contains only the displayed rows, `num_rows` is the total number of
+/// rows of the arrays.
+pub fn format_table(fields: &[GenericField], rows: &[Value], num_rows: usize) -> Result<String> {
+    let header = fields
+        .iter()
+        .map(|field| escape_cell(&field.name))
+        .collect::<Vec<_>>();
+
+    let mut cells = Vec::new();
+    for row in rows {
+        let Value::Struct(values) = row else {
+            fail!("Cannot format row {row:?}: expected a struct");
+        };
+
+        let mut row_cells = Vec::new();
+        for field in fields {
+            let value = find_field(values, &field.name).unwrap_or(&Value::Null);
+            let mut cell = String::new();
+            format_value(&mut cell, field, value)?;
+            row_cells.push(escape_cell(&cell));
+        }
+        cells.push(row_cells);
+    }
+
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            cells
+                .iter()
+                .map(|row| row[idx].chars().count())
+                .chain(std::iter::once(name.chars().count()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let mut res = String::new();
+    write_separator(&mut res, &widths);
+    write_row(&mut res, &widths, &header);
+    write_separator(&mut res, &widths);
+    for row in &cells {
+        write_row(&mut res, &widths, row);
+    }
+    write_separator(&mut res, &widths);
+
+    if num_rows > rows.len() {
+        res.push_str(&format!(
+            "... {} more rows ({num_rows} in total)\n",
+            num_rows - rows.len(),
+        ));
+    }
+    Ok(res)
+}
+
+/// Escape line breaks, that would otherwise break the table layout
+fn escape_cell(cell: &str) -> String {
+    cell.replace('\r', "\\r").replace('\n', "\\n")
+}
+
+fn write_separator(res: &mut String, widths: &[usize]) {
+    res.push('+');
+    for width in widths {
+        res.push_str(&"-".repeat(width + 2));
+        res.push('+');
+    }
+    res.push('\n');
+}
+
+fn write_row(res: &mut String, widths: &[usize], cells: &[String]) {
+    res.push('|');
+    for (cell, width) in cells.iter().zip(widths) {
+        let padding = width - cell.chars().count();
+        res.push(' ');
+        res.push_str(cell);
+        res.push_str(&" ".repeat(padding + 1));
+        res.push('|');
+    }
+    res.push('\n');
+}
+
+fn find_field<'a>(values: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+    values
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+}
+
+fn format_value(res: &mut String, field: &GenericField, value: &Value) -> Result<()> {
+    use std::fmt::Write;
+
+    match value {
+        Value::Null => res.push_str("null"),
+        Value::Bool(val) => write!(res, "{val}")?,
+        Value::I8(val) => write!(res, "{val}")?,
+        Value::I16(val) => write!(res, "{val}")?,
+        Value::I32(val) => write!(res, "{val}")?,
+        &Value::I64(val) => format_i64(res, field, val)?,
+        Value::U8(val) => write!(res, "{val}")?,
+        Value::U16(val) => write!(res, "{val}")?,
+        Value::U32(val) => write!(res, "{val}")?,
+        Value::U64(val) => write!(res, "{val}")?,
+        Value::F32(val) => write!(res, "{val}")?,
+        Value::F64(val) => write!(res, "{val}")?,
+        Value::Str(val) => res.push_str(val),
+        Value::List(items) => {
+            let Some(item_field) = field.children.first() else {
+                fail!("Cannot format list values of field {:?}", field.name);
+            };
+            res.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx != 0 {
+                    res.push_str(", ");
+                }
+                format_value(res, item_field, item)?;
+            }
+            res.push(']');
+        }
+        Value::Struct(values) => {
+            res.push('{');
+            for (idx, (key, value)) in values.iter().enumerate() {
+                if idx != 0 {
+                    res.push_str(", ");
+                }
+                res.push_str(key);
+                res.push_str(": ");
+                match field.data_type {
+                    // maps with string keys are deserialized as structs
+                    GenericDataType::Map => format_value(res, map_field(field, 1)?, value)?,
+                    _ => match field.children.iter().find(|child| &child.name == key) {
+                        Some(child) => format_value(res, child, value)?,
+                        None => fail!("Cannot format unknown field {key:?} of {:?}", field.name),
+                    },
+                }
+            }
+            res.push('}');
+        }
+        Value::Map(entries) => {
+            res.push('{');
+            for (idx, (key, value)) in entries.iter().enumerate() {
+                if idx != 0 {
+                    res.push_str(", ");
+                }
+                format_value(res, map_field(field, 0)?, key)?;
+                res.push_str(": ");
+                format_value(res, map_field(field, 1)?, value)?;
+            }
+            res.push('}');
+        }
+    }
+    Ok(())
+}
+
+/// Get the key (`idx == 0`) or value (`idx == 1`) field of a map
+fn map_field(field: &GenericField, idx: usize) -> Result<&GenericField> {
+    let Some(entries) = field.children.first() else {
+        fail!("Map field {:?} without entries", field.name);
+    };
+    let Some(field) = entries.children.get(idx) else {
+        fail!("Map field {:?} with invalid entries", field.name);
+    };
+    Ok(field)
+}
+
+/// Format integers, rendering timestamps without strategy as datetimes
+fn format_i64(res: &mut String, field: &GenericField, val: i64) -> Result<()> {
+    use std::fmt::Write;
+
+    let (per_second, utc) = match (&field.data_type, &field.strategy) {
+        (GenericDataType::Date64, None) => (1_000, false),
+        (GenericDataType::Timestamp(unit, tz), _) => {
+            let per_second = match unit {
+                GenericTimeUnit::Second => 1,
+                GenericTimeUnit::Millisecond => 1_000,
+                GenericTimeUnit::Microsecond => 1_000_000,
+                GenericTimeUnit::Nanosecond => 1_000_000_000,
+            };
+            (per_second, tz.is_some())
+        }
+        _ => {
+            write!(res, "{val}")?;
+            return Ok(());
+        }
+    };
+
+    let secs = val.div_euclid(per_second);
+    let nanos = val.rem_euclid(per_second) * (1_000_000_000 / per_second);
+    let Some(datetime) = chrono::NaiveDateTime::from_timestamp_opt(secs, nanos as u32) else {
+        fail!("Unsupported timestamp value: {val}");
+    };
+
+    // NOTE: use the same format as the deserialized strings
+    if utc {
+        write!(res, "{datetime:?}Z")?;
+    } else {
+        write!(res, "{datetime:?}")?;
+    }
+    Ok(())
+}
@@ -9,8 +9,22 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Common errors during `serde_arrow`'s usage
 ///
-/// At the moment only a generic string error is supported, but it is planned to
-/// offer concrete types to match against.
+/// Each error carries a human readable message and an [`ErrorKind`] that can be
+/// used to branch on the cause of the error programmatically:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() {
+/// # use serde_arrow::{ErrorKind, _impl::arrow::datatypes::{DataType, Field}};
+/// let fields = vec![Field::new("a", DataType::UInt8, false)];
+/// let items = serde_json::json!([{"a": 1}, {"a": null}]);
+/// let err = serde_arrow::to_arrow(&fields, &items).unwrap_err();
+///
+/// assert!(matches!(err.kind(), ErrorKind::NullInNonNullable { row: 1, .. }));
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
 ///
 /// The error carries a backtrace if `RUST_BACKTRACE=1`, see [`std::backtrace`]
 /// for details. This backtrace is included when printing the error. If the
@@ -22,13 +36,44 @@ pub enum Error {
     Custom(CustomError),
 }
 
+/// The kind of an [`Error`]
+///
+/// Fields that cannot be determined at the point the error is raised are left
+/// empty, e.g., the `path` of errors raised outside of a compiled program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A data type that is not supported by `serde_arrow`
+    UnsupportedDataType { data_type: String },
+    /// The data does not match the schema, e.g., a string written to an
+    /// integer field. `path` is the dotted path of the affected field.
+    SchemaMismatch {
+        expected: String,
+        found: String,
+        path: String,
+    },
+    /// A null value was encountered for a non-nullable field. `path` is the
+    /// dotted path of the affected field.
+    NullInNonNullable { path: String, row: usize },
+    /// A union variant that is not declared by the target enum, and the enum
+    /// has no `#[serde(other)]` fallback. `variant` is the name of the union
+    /// field.
+    UnknownVariant { variant: String },
+    /// A field of the target struct without a matching column. The message
+    /// suggests columns with similar names, if any.
+    MissingField { field: String },
+    /// The conversion was cancelled via a
+    /// [`CancellationToken`][crate::utils::CancellationToken]. `rows` is the
+    /// number of rows processed before, for builders the number of rows of
+    /// the current batch.
+    Cancelled { rows: usize },
+    /// Any other error, see the message for details
+    Custom,
+}
+
 impl Error {
     pub fn custom(message: String) -> Self {
-        Self::Custom(CustomError {
-            message,
-            backtrace: Backtrace::capture(),
-            cause: None,
-        })
+        Self::with_kind(ErrorKind::Custom, message)
     }
 
     pub fn custom_from<E: std::error::Error + Send + Sync + 'static>(
@@ -36,14 +81,51 @@ impl Error {
         cause: E,
     ) -> Self {
         Self::Custom(CustomError {
+            kind: Box::new(ErrorKind::Custom),
             message,
             backtrace: Backtrace::capture(),
             cause: Some(Box::new(cause)),
         })
     }
+
+    pub(crate) fn with_kind(kind: ErrorKind, message: String) -> Self {
+        Self::Custom(CustomError {
+            kind: Box::new(kind),
+            message,
+            backtrace: Backtrace::capture(),
+            cause: None,
+        })
+    }
+
+    /// Set the path of the error kind, if it is not yet known
+    pub(crate) fn with_path(mut self, field_path: &str) -> Self {
+        let Self::Custom(err) = &mut self;
+        match err.kind.as_mut() {
+            ErrorKind::SchemaMismatch { path, .. } | ErrorKind::NullInNonNullable { path, .. }
+                if path.is_empty() =>
+            {
+                *path = field_path.to_owned();
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Replace the message, while keeping the kind, backtrace and cause
+    pub(crate) fn with_message(mut self, message: String) -> Self {
+        let Self::Custom(err) = &mut self;
+        err.message = message;
+        self
+    }
 }
 
 impl Error {
+    pub fn kind(&self) -> &ErrorKind {
+        match self {
+            Self::Custom(err) => &err.kind,
+        }
+    }
+
     pub fn message(&self) -> &str {
         match self {
             Self::Custom(err) => &err.message,
@@ -58,6 +140,7 @@ impl Error {
 }
 
 pub struct CustomError {
+    kind: Box<ErrorKind>,
     message: String,
     backtrace: Backtrace,
     cause: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
@@ -82,6 +165,39 @@ impl std::fmt::Display for Error {
     }
 }
 
+/// The error of a single row that failed to deserialize
+///
+/// Returned by [`from_arrow_collect_errors`][crate::from_arrow_collect_errors]
+/// and [`from_arrow2_collect_errors`][crate::from_arrow2_collect_errors] and
+/// yielded by [`RowIter`][crate::RowIter].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RowError {
+    /// The index of the row
+    pub row: usize,
+    /// The dotted path of the field that failed to deserialize, empty if the
+    /// error is not associated with a field
+    pub path: String,
+    /// The underlying error
+    pub error: Error,
+}
+
+impl std::fmt::Display for RowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Row {row}", row = self.row)?;
+        if !self.path.is_empty() {
+            write!(f, ", field {path:?}", path = self.path)?;
+        }
+        write!(f, ": {error}", error = self.error)
+    }
+}
+
+impl std::error::Error for RowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 struct BacktraceDisplay<'a>(&'a Backtrace);
 
 impl<'a> std::fmt::Display for BacktraceDisplay<'a> {
@@ -121,9 +237,21 @@ impl serde::de::Error for Error {
     {
         Self::custom(format!("serde::de::Error: {}", msg))
     }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::with_kind(
+            ErrorKind::MissingField {
+                field: field.to_owned(),
+            },
+            format!("serde::de::Error: missing field `{field}`"),
+        )
+    }
 }
 
 macro_rules! error {
+    (kind = $kind:expr, $($tt:tt)*) => {
+        $crate::internal::error::Error::with_kind($kind, format!($($tt)*))
+    };
     ($($tt:tt)*) => {
         $crate::internal::error::Error::custom(format!($($tt)*))
     };
@@ -151,6 +279,12 @@ impl From<std::num::TryFromIntError> for Error {
     }
 }
 
+impl From<std::num::ParseIntError> for Error {
+    fn from(err: std::num::ParseIntError) -> Error {
+        Self::custom_from(format!("ParseIntError: {err}"), err)
+    }
+}
+
 impl From<std::fmt::Error> for Error {
     fn from(err: std::fmt::Error) -> Self {
         Self::custom_from(format!("std::fmt::Error: {err}"), err)
@@ -175,6 +309,22 @@ impl From<bytemuck::PodCastError> for Error {
     }
 }
 
+#[cfg(feature = "pyo3")]
+impl From<pyo3::PyErr> for Error {
+    fn from(err: pyo3::PyErr) -> Self {
+        Self::custom_from(format!("pyo3::PyErr: {err}"), err)
+    }
+}
+
+/// Raise `serde_arrow` errors as Python `RuntimeError`s (*requires the `pyo3`
+/// feature*)
+#[cfg(feature = "pyo3")]
+impl From<Error> for pyo3::PyErr {
+    fn from(err: Error) -> Self {
+        pyo3::exceptions::PyRuntimeError::new_err(err.to_string())
+    }
+}
+
 pub type PanicOnError<T> = std::result::Result<T, PanicOnErrorError>;
 
 /// An error type for testing, that panics once an error is converted
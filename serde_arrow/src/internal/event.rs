@@ -1,7 +1,10 @@
 //! The underlying data format used to interact with serde
 //!
 
-use crate::internal::error::{fail, Error, Result};
+use crate::internal::{
+    error::{fail, Error, Result},
+    schema::GenericTimeUnit,
+};
 
 /// The events used to interact with serde
 ///
@@ -43,6 +46,10 @@ pub enum Event<'a> {
     /// The owned variant of `Str`
     OwnedStr(String),
     /// Push the default of the current type
+    ///
+    /// When deserializing, it marks a null value that is followed by the
+    /// default configured for the field (see
+    /// [`NULL_DEFAULT_KEY`][crate::schema::NULL_DEFAULT_KEY])
     Default,
     /// Denote the variant of a union
     Variant(&'a str, usize),
@@ -59,6 +66,13 @@ pub enum Event<'a> {
     U64(u64),
     F32(f32),
     F64(f64),
+    /// A timestamp in the given unit since the epoch, emitted for fields with
+    /// a date time strategy
+    ///
+    /// It is deserialized as the raw value into integers and as a date time
+    /// string otherwise. The flag is `true` for timezone-aware values
+    /// formatted with a `Z` suffix.
+    Timestamp(i64, GenericTimeUnit, bool),
 }
 
 impl<'a> std::fmt::Display for Event<'a> {
@@ -87,6 +101,7 @@ impl<'a> std::fmt::Display for Event<'a> {
             Event::U64(v) => write!(f, "U64({v})"),
             Event::F32(v) => write!(f, "F32({v})"),
             Event::F64(v) => write!(f, "F64({v})"),
+            Event::Timestamp(v, unit, utc) => write!(f, "Timestamp({v}, {unit}, {utc})"),
             Event::Variant(n, i) => write!(f, "Variant({n:?}, {i})"),
             Event::OwnedVariant(n, i) => write!(f, "OwnedVariant({n:?}, {i})"),
             Event::Str(v) => write!(f, "Str({v:?})"),
@@ -142,6 +157,9 @@ impl<'this, 'other> std::cmp::PartialEq<Event<'other>> for Event<'this> {
             U64(s) => matches!(other, U64(o) if s == o),
             F32(s) => matches!(other, F32(o) if s == o),
             F64(s) => matches!(other, F64(o) if s == o),
+            Timestamp(s, sn, su) => {
+                matches!(other, Timestamp(o, on, ou) if s == o && sn == on && su == ou)
+            }
         }
     }
 }
@@ -183,6 +201,7 @@ impl<'a> Event<'a> {
             &Event::U64(v) => Event::U64(v),
             &Event::F32(v) => Event::F32(v),
             &Event::F64(v) => Event::F64(v),
+            &Event::Timestamp(v, unit, utc) => Event::Timestamp(v, unit, utc),
             Event::Null => Event::Null,
         }
     }
@@ -217,6 +236,7 @@ impl<'a> Event<'a> {
             &Event::U64(v) => Event::U64(v),
             &Event::F32(v) => Event::F32(v),
             &Event::F64(v) => Event::F64(v),
+            &Event::Timestamp(v, unit, utc) => Event::Timestamp(v, unit, utc),
             Event::Null => Event::Null,
         }
     }
@@ -274,6 +294,7 @@ impl<'a> Event<'a> {
                 | Event::U64(_)
                 | Event::F32(_)
                 | Event::F64(_)
+                | Event::Timestamp(_, _, _)
         )
     }
 
@@ -290,6 +311,38 @@ impl<'a> Event<'a> {
     pub fn is_marker(&self) -> bool {
         matches!(self, Event::Item | Event::Some | Event::Variant(_, _))
     }
+
+    /// Replace timestamps by their date time strings, keep all other events
+    pub fn format_timestamp(self) -> Result<Self> {
+        use chrono::{NaiveDateTime, TimeZone, Utc};
+
+        let Event::Timestamp(val, unit, utc) = self else {
+            return Ok(self);
+        };
+
+        let per_second = match unit {
+            GenericTimeUnit::Second => 1,
+            GenericTimeUnit::Millisecond => 1_000,
+            GenericTimeUnit::Microsecond => 1_000_000,
+            GenericTimeUnit::Nanosecond => 1_000_000_000,
+        };
+        let secs = val.div_euclid(per_second);
+        let nsecs = (val.rem_euclid(per_second) * (1_000_000_000 / per_second)) as u32;
+
+        // NOTE: chrono documents that Debug, not Display, can be parsed
+        let formatted = if utc {
+            let Some(val) = Utc.timestamp_opt(secs, nsecs).earliest() else {
+                fail!("Unsupported timestamp value: {val}");
+            };
+            format!("{val:?}")
+        } else {
+            let Some(val) = NaiveDateTime::from_timestamp_opt(secs, nsecs) else {
+                fail!("Unsupported timestamp value: {val}");
+            };
+            format!("{val:?}")
+        };
+        Ok(Event::OwnedStr(formatted))
+    }
 }
 
 macro_rules! event_implement_simple_from {
@@ -360,4 +413,13 @@ event_implement_try_from_from_event!(u64, U8, U16, U32, U64, I8, I16, I32, I64,
 
 event_implement_try_from_from_event!(f64, F32, F64);
 
-event_implement_try_from_from_event!(String, Str, OwnedStr);
+impl<'a> TryFrom<Event<'a>> for String {
+    type Error = Error;
+    fn try_from(val: Event<'_>) -> Result<String> {
+        match val.format_timestamp()? {
+            Event::Str(val) => Ok(val.to_owned()),
+            Event::OwnedStr(val) => Ok(val),
+            event => fail!("invalid conversion from {} to String", event),
+        }
+    }
+}
@@ -1,12 +1,24 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
 use serde::{ser::SerializeSeq, Deserialize, Serialize};
 
 use crate::internal::{
     common::{BufferExtract, Buffers},
+    converters::Converter,
     deserialization,
-    error::{Error, Result},
-    schema::GenericField,
-    serialization,
-    sink::{serialize_into_sink, EventSerializer, EventSink},
+    error::{fail, Error, Result},
+    event::Event,
+    instrument::span,
+    markers::TypeMarker,
+    metrics::{Instant, MetricsRecorder},
+    options::{FailedItems, SerializationOptions},
+    progress::Progress,
+    schema::{GenericDataType, GenericField},
+    serialization::{self, flat_records::FlatRecords, interpreter::SizeBudget},
+    sink::{macros, serialize_into_sink, EventSerializer, EventSink},
     source::deserialize_from_source,
 };
 
@@ -23,17 +35,75 @@ impl GenericBuilder {
         Ok(Self(interpreter))
     }
 
+    pub fn new_for_struct_array(field: GenericField) -> Result<Self> {
+        if !matches!(field.data_type, GenericDataType::Struct) {
+            fail!(
+                "Cannot serialize field {:?} with data type {} as a struct array",
+                field.name,
+                field.data_type
+            );
+        }
+        Self::new_for_array(field)
+    }
+
     pub fn new_for_arrays(fields: &[GenericField]) -> Result<Self> {
+        Self::new_for_arrays_with_options(fields, &SerializationOptions::default())
+    }
+
+    pub fn new_for_arrays_with_options(
+        fields: &[GenericField],
+        options: &SerializationOptions,
+    ) -> Result<Self> {
+        let start = options.metrics.as_ref().map(|_| Instant::now());
         let program = serialization::compile_serialization(
             fields,
-            serialization::CompilationOptions::default(),
+            serialization::CompilationOptions::default()
+                .overflow(options.overflow)
+                .non_finite(options.non_finite)
+                .null_policy(options.null_policy)
+                .timestamp_formats(
+                    options.timestamp_formats.clone(),
+                    options.field_timestamp_formats.clone(),
+                )
+                .validate_arrays(options.validate_arrays)
+                .coerce_bools(options.coerce_bools)
+                .human_readable(options.human_readable)
+                .converters(options.converters.clone())
+                .column_statistics(options.column_statistics)
+                .sort_by(options.sort_by.clone())
+                .empty_lists(options.empty_lists),
         )?;
-        let interpreter = serialization::Interpreter::new(program);
+        let mut interpreter = serialization::Interpreter::new(program);
+        if let (Some(callback), Some(start)) = (&options.metrics, start) {
+            interpreter.metrics = Some(MetricsRecorder::new(callback.clone(), start.elapsed()));
+        }
+        interpreter.cancellation = options.cancellation.clone();
+        interpreter.progress = options.progress.clone().map(Progress::new);
 
         Ok(Self(interpreter))
     }
 
     pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.measure(|this| {
+            this.push_item(item)?;
+            this.0.check_size_budget();
+            Ok(())
+        })
+    }
+
+    /// Add the elapsed time of `func` to the metrics, if they are recorded
+    fn measure(&mut self, func: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        let Some(start) = self.0.metrics.as_ref().map(|_| Instant::now()) else {
+            return func(self);
+        };
+        let res = func(self);
+        if let Some(metrics) = self.0.metrics.as_mut() {
+            metrics.add_execute_time(start.elapsed());
+        }
+        res
+    }
+
+    fn push_item<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
         self.0.accept_start_sequence()?;
         self.0.accept_item()?;
         item.serialize(EventSerializer(&mut self.0))?;
@@ -42,7 +112,424 @@ impl GenericBuilder {
     }
 
     pub fn extend<T: Serialize + ?Sized>(&mut self, items: &T) -> Result<()> {
-        serialize_into_sink(&mut self.0, items)
+        span!("serialize");
+        self.measure(|this| {
+            serialize_into_sink(&mut this.0, items)?;
+            this.0.check_size_budget();
+            Ok(())
+        })
+    }
+
+    pub fn extend_from_slice<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        span!("serialize", num_items = items.len());
+        self.measure(|this| this.extend_from_slice_impl(items))
+    }
+
+    fn extend_from_slice_impl<T: Serialize>(&mut self, items: &[T]) -> Result<()> {
+        let mut flat_records = FlatRecords::new(&self.0);
+        for item in items {
+            self.0.check_cancelled()?;
+            if let Some(records) = flat_records.as_mut() {
+                if records.try_push(&mut self.0.buffers, item) {
+                    if let Some(progress) = self.0.progress.as_mut() {
+                        progress.advance();
+                    }
+                    continue;
+                }
+                // the layout is determined by the type, do not retry
+                flat_records = None;
+            }
+            self.push_item(item)?;
+        }
+        self.0.check_size_budget();
+        Ok(())
+    }
+
+    pub fn estimated_size(&self) -> usize {
+        self.0.buffers.estimated_size()
+    }
+
+    pub fn on_size_exceeded<F>(&mut self, budget: usize, callback: F)
+    where
+        F: FnMut(usize) + Send + Sync + 'static,
+    {
+        self.0.size_budget = Some(SizeBudget {
+            budget,
+            callback: Box::new(callback),
+            exceeded: false,
+        });
+    }
+
+    pub fn fields(&self) -> Vec<GenericField> {
+        self.0
+            .structure
+            .array_mapping
+            .iter()
+            .map(|mapping| mapping.get_field().clone())
+            .collect()
+    }
+
+    pub fn rename_field(&mut self, old: &str, new: &str) -> Result<()> {
+        let mappings = &mut self.0.structure.array_mapping;
+        if old != new && mappings.iter().any(|m| m.get_field().name == new) {
+            fail!("Cannot rename field {old:?} to {new:?}: the field already exists");
+        }
+        let Some(mapping) = mappings.iter_mut().find(|m| m.get_field().name == old) else {
+            fail!("Cannot rename field {old:?}: the field does not exist");
+        };
+        if old != new {
+            mapping.get_field_mut().name = new.to_owned();
+            self.0.structure.fields_remapped = true;
+        }
+        Ok(())
+    }
+
+    pub fn reorder_fields(&mut self, names: &[&str]) -> Result<()> {
+        let mappings = &mut self.0.structure.array_mapping;
+        if names.len() != mappings.len() {
+            fail!(
+                "Cannot reorder {} fields into {} fields, each field must be given exactly once",
+                mappings.len(),
+                names.len(),
+            );
+        }
+
+        let mut order = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(idx) = mappings.iter().position(|m| m.get_field().name == *name) else {
+                fail!("Cannot reorder fields: the field {name:?} does not exist");
+            };
+            if order.contains(&idx) {
+                fail!("Cannot reorder fields: duplicate field {name:?}");
+            }
+            order.push(idx);
+        }
+
+        if order.iter().enumerate().any(|(pos, idx)| pos != *idx) {
+            self.0.structure.fields_remapped = true;
+        }
+
+        let mut old_mappings = std::mem::take(mappings)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        let mut old_field_buffers = std::mem::take(&mut self.0.structure.field_buffers)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        for idx in order {
+            mappings.extend(old_mappings[idx].take());
+            self.0
+                .structure
+                .field_buffers
+                .extend(old_field_buffers[idx].take());
+        }
+        Ok(())
+    }
+
+    pub fn into_columns(mut self) -> Result<Vec<Self>> {
+        // sorting a single column would break the alignment of the rows
+        let options = self.0.options.clone().sort_by(Vec::new());
+        let mut columns = Vec::new();
+        for (idx, field) in self.fields().iter().enumerate() {
+            let program =
+                serialization::compile_serialization(std::slice::from_ref(field), options.clone())?;
+            let mut column = serialization::Interpreter::new(program);
+            column.swap_field_buffers(0, &mut self.0, idx)?;
+            column.buffers.num_rows = self.0.buffers.num_rows;
+            columns.push(Self(column));
+        }
+        Ok(columns)
+    }
+
+    pub fn from_columns(mut columns: Vec<Self>) -> Result<Self> {
+        let Some(first) = columns.first() else {
+            fail!("Cannot combine an empty list of columns");
+        };
+        let num_rows = first.0.buffers.num_rows;
+        if let Some(column) = columns.iter().find(|c| c.0.buffers.num_rows != num_rows) {
+            fail!(
+                "Cannot combine columns with different numbers of rows ({} and {})",
+                num_rows,
+                column.0.buffers.num_rows,
+            );
+        }
+
+        let fields = columns
+            .iter()
+            .flat_map(|column| column.fields())
+            .collect::<Vec<_>>();
+        let program = serialization::compile_serialization(&fields, first.0.options.clone())?;
+        let mut res = serialization::Interpreter::new(program);
+
+        let mut idx = 0;
+        for column in &mut columns {
+            for column_idx in 0..column.0.structure.array_mapping.len() {
+                res.swap_field_buffers(idx, &mut column.0, column_idx)?;
+                idx += 1;
+            }
+        }
+        res.buffers.num_rows = num_rows;
+        Ok(Self(res))
+    }
+
+    pub fn extend_filtered<T, E, I>(&mut self, items: I, failed_items: FailedItems) -> Result<()>
+    where
+        T: Serialize,
+        I: IntoIterator<Item = std::result::Result<T, E>>,
+    {
+        for item in items {
+            match (item, failed_items) {
+                (Ok(item), _) => self.push(&item)?,
+                (Err(_), FailedItems::Skip) => {}
+                (Err(_), FailedItems::Null) => self.push(&None::<T>)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Route rows into one builder per key
+///
+/// The builders of the partitions are created on first use, all with the same
+/// fields and options. The partitions are kept in order of their first row.
+pub struct GenericPartitionedBuilder<K> {
+    fields: Vec<GenericField>,
+    options: SerializationOptions,
+    index: HashMap<K, usize>,
+    partitions: Vec<(K, GenericBuilder)>,
+}
+
+impl<K: Hash + Eq + Clone> GenericPartitionedBuilder<K> {
+    pub fn new(fields: Vec<GenericField>, options: SerializationOptions) -> Result<Self> {
+        // fail early for unsupported fields, not on the first row
+        GenericBuilder::new_for_arrays_with_options(&fields, &options)?;
+        Ok(Self {
+            fields,
+            options,
+            index: HashMap::new(),
+            partitions: Vec::new(),
+        })
+    }
+
+    pub fn push<T: Serialize + ?Sized>(&mut self, key: K, item: &T) -> Result<()> {
+        if let Some(&idx) = self.index.get(&key) {
+            return self.partitions[idx].1.push(item);
+        }
+
+        let mut builder = GenericBuilder::new_for_arrays_with_options(&self.fields, &self.options)?;
+        builder.push(item)?;
+        self.index.insert(key.clone(), self.partitions.len());
+        self.partitions.push((key, builder));
+        Ok(())
+    }
+
+    pub fn num_partitions(&self) -> usize {
+        self.partitions.len()
+    }
+
+    /// Build the arrays of all partitions and remove them
+    ///
+    /// The partitions are built in order and appended to `built`, which is
+    /// returned if all partitions could be built. If building a partition
+    /// fails, the partition is discarded and the error is returned. The
+    /// partitions built before stay in `built` and the partitions after it are
+    /// kept, so that they are returned by the next call.
+    pub fn finish_with<A>(
+        &mut self,
+        built: &mut Vec<(K, A)>,
+        mut build: impl FnMut(&mut GenericBuilder) -> Result<A>,
+    ) -> Result<Vec<(K, A)>> {
+        let mut res = Ok(());
+        let mut num_removed = 0;
+        for (key, builder) in &mut self.partitions {
+            num_removed += 1;
+            match build(builder) {
+                Ok(arrays) => built.push((key.clone(), arrays)),
+                Err(err) => {
+                    res = Err(err);
+                    break;
+                }
+            }
+        }
+
+        self.partitions.drain(..num_removed);
+        self.index = self
+            .partitions
+            .iter()
+            .enumerate()
+            .map(|(idx, (key, _))| (key.clone(), idx))
+            .collect();
+
+        res?;
+        Ok(std::mem::take(built))
+    }
+}
+
+/// Route enum values into one builder per variant
+///
+/// Each variant has its own fields. The content of a variant, e.g., the record
+/// of a newtype variant or the fields of a struct variant, is pushed as a
+/// single row into the builder of the variant.
+pub struct GenericVariantBuilder {
+    index: HashMap<String, usize>,
+    variants: Vec<(String, GenericBuilder)>,
+    human_readable: bool,
+}
+
+impl GenericVariantBuilder {
+    pub fn new(
+        variants: Vec<(String, Vec<GenericField>)>,
+        options: SerializationOptions,
+    ) -> Result<Self> {
+        let mut index = HashMap::new();
+        let mut builders = Vec::new();
+        for (name, fields) in variants {
+            if index.contains_key(&name) {
+                fail!("Duplicate variant {name:?}");
+            }
+            let builder = GenericBuilder::new_for_arrays_with_options(&fields, &options)?;
+            index.insert(name.clone(), builders.len());
+            builders.push((name, builder));
+        }
+        Ok(Self {
+            index,
+            variants: builders,
+            human_readable: options.human_readable,
+        })
+    }
+
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        let mut router = VariantRouter {
+            index: &self.index,
+            variants: &mut self.variants,
+            current: None,
+            human_readable: self.human_readable,
+        };
+        item.serialize(EventSerializer(&mut router))?;
+
+        let Some(idx) = router.current else {
+            fail!("Cannot push a value without variant into a variant builder");
+        };
+        let builder = &mut self.variants[idx].1;
+        builder.0.accept_end_sequence()?;
+        builder.0.finish()?;
+        builder.0.check_size_budget();
+        Ok(())
+    }
+
+    /// Build the arrays of all variants and clear their builders
+    ///
+    /// The arrays are inserted into `built`, which is returned if all variants
+    /// could be built. Variants already contained in `built` are skipped. If
+    /// building a variant fails, its values are discarded and the error is
+    /// returned. The variants built before stay in `built` and the variants
+    /// after it keep their values, so that they are returned by the next call.
+    pub fn finish_with<A>(
+        &mut self,
+        built: &mut HashMap<String, A>,
+        mut build: impl FnMut(&mut GenericBuilder) -> Result<A>,
+    ) -> Result<HashMap<String, A>> {
+        for (name, builder) in &mut self.variants {
+            if built.contains_key(name) {
+                continue;
+            }
+            match build(builder) {
+                Ok(arrays) => {
+                    built.insert(name.clone(), arrays);
+                }
+                Err(err) => {
+                    builder.0.clear_buffers();
+                    return Err(err);
+                }
+            }
+        }
+        Ok(std::mem::take(built))
+    }
+}
+
+/// Select the builder with the first variant event and forward all other
+/// events to it
+struct VariantRouter<'a> {
+    index: &'a HashMap<String, usize>,
+    variants: &'a mut [(String, GenericBuilder)],
+    current: Option<usize>,
+    human_readable: bool,
+}
+
+impl<'a> VariantRouter<'a> {
+    fn select(&mut self, name: &str) -> Result<()> {
+        let Some(&idx) = self.index.get(name) else {
+            fail!("Unknown variant {name:?}");
+        };
+        let builder = &mut self.variants[idx].1;
+        builder.0.accept_start_sequence()?;
+        builder.0.accept_item()?;
+        self.current = Some(idx);
+        Ok(())
+    }
+
+    fn target(&mut self, ev: &Event<'_>) -> Result<&mut serialization::Interpreter> {
+        let Some(idx) = self.current else {
+            fail!("Expected an enum value for the variant builder, found {ev}");
+        };
+        Ok(&mut self.variants[idx].1 .0)
+    }
+}
+
+impl<'a> EventSink for VariantRouter<'a> {
+    fn accept(&mut self, event: Event<'_>) -> Result<()> {
+        match (self.current, event) {
+            (None, Event::Variant(name, _)) => self.select(name),
+            (None, Event::OwnedVariant(name, _)) => self.select(&name),
+            (_, ev) => {
+                let target = self.target(&ev)?;
+                target.accept(ev)
+            }
+        }
+    }
+
+    macros::accept_start!((this, ev, val, next) {
+        next(this.target(&ev)?, val)
+    });
+    macros::accept_end!((this, ev, val, next) {
+        next(this.target(&ev)?, val)
+    });
+    macros::accept_value!((this, ev, val, next) {
+        next(this.target(&ev)?, val)
+    });
+    macros::accept_marker!((this, ev, val, next) {
+        if let (None, Event::Variant(name, _)) = (this.current, &ev) {
+            return this.select(name);
+        }
+        next(this.target(&ev)?, val)
+    });
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        let idx = self.current?;
+        self.variants[idx].1 .0.get_converter(name)
+    }
+
+    fn accept_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        match self.current {
+            Some(idx) => self.variants[idx].1 .0.accept_type_marker(marker),
+            None => Ok(()),
+        }
+    }
+
+    fn expects_map(&self) -> bool {
+        match self.current {
+            Some(idx) => self.variants[idx].1 .0.expects_map(),
+            None => false,
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
     }
 }
 
@@ -68,6 +555,109 @@ where
     deserialize_from_source(interpreter)
 }
 
+/// Deserialize the rows of a struct array, null rows are passed as `None`
+pub fn deserialize_from_struct_array<'de, T, F, A>(field: &'de F, array: &'de A) -> Result<T>
+where
+    T: Deserialize<'de>,
+    F: 'static,
+    GenericField: TryFrom<&'de F, Error = Error>,
+    A: BufferExtract + ?Sized,
+{
+    let generic_field = GenericField::try_from(field)?;
+    if !matches!(generic_field.data_type, GenericDataType::Struct) {
+        fail!(
+            "Cannot deserialize field {:?} with data type {} as a struct array",
+            generic_field.name,
+            generic_field.data_type
+        );
+    }
+    deserialize_from_array(field, array)
+}
+
+/// Reorder arrays to match the column order of the `target` fields
+///
+/// The arrays are matched to the target fields by the names of `fields`. Each
+/// target field must have a column with the same name and data type, columns
+/// not included in the target are dropped. This function is helpful when
+/// downstream writers require a fixed column order.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::utils::reorder_columns;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u8,
+///     b: String,
+/// }
+///
+/// let fields = vec![
+///     Field::new("a", DataType::UInt8, false),
+///     Field::new("b", DataType::LargeUtf8, false),
+/// ];
+/// let arrays = serde_arrow::to_arrow(&fields, &[Record { a: 1, b: String::from("x") }])?;
+///
+/// let target = vec![
+///     Field::new("b", DataType::LargeUtf8, false),
+///     Field::new("a", DataType::UInt8, false),
+/// ];
+/// let arrays = reorder_columns(&fields, arrays, &target)?;
+/// assert_eq!(arrays[0].data_type(), &DataType::LargeUtf8);
+/// assert_eq!(arrays[1].data_type(), &DataType::UInt8);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+pub fn reorder_columns<F, A>(fields: &[F], arrays: Vec<A>, target: &[F]) -> Result<Vec<A>>
+where
+    for<'a> GenericField: TryFrom<&'a F, Error = Error>,
+{
+    if fields.len() != arrays.len() {
+        fail!(
+            "Cannot reorder {} arrays with {} fields",
+            arrays.len(),
+            fields.len()
+        );
+    }
+
+    let fields = fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    let mut arrays = arrays.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut res = Vec::new();
+    for target_field in target {
+        let target_field = GenericField::try_from(target_field)?;
+        let Some(idx) = fields
+            .iter()
+            .position(|field| field.name == target_field.name)
+        else {
+            fail!("Missing column {:?}", target_field.name);
+        };
+        if fields[idx].data_type != target_field.data_type
+            || fields[idx].children != target_field.children
+        {
+            fail!(
+                "Column {:?} has data type {}, expected {}",
+                target_field.name,
+                fields[idx].data_type,
+                target_field.data_type,
+            );
+        }
+        let Some(array) = arrays[idx].take() else {
+            fail!("Duplicate column {:?} in target", target_field.name);
+        };
+        res.push(array);
+    }
+    Ok(res)
+}
+
 /// A wrapper around a sequence of items
 ///
 /// When serialized or deserialized, it behaves as if each item was wrapped in a
@@ -203,3 +793,182 @@ impl<'a, T: Serialize> Serialize for Items<&'a [T]> {
         seq.end()
     }
 }
+
+/// A wrapper around a sequence of key-value pairs
+///
+/// When serialized or deserialized, it behaves as if each pair was wrapped in a
+/// struct with the attributes `"key"` and `"value"`. It supports vectors,
+/// slices and arrays of tuples as well as `HashMap` and `BTreeMap`. For
+/// `HashMap` the order of the rows is unspecified.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// # use std::collections::BTreeMap;
+/// # use serde_arrow::utils::Pairs;
+/// #
+/// assert_eq!(
+///     serde_json::to_string(&Pairs([("a", 13), ("b", 21)]))?,
+///     r#"[{"key":"a","value":13},{"key":"b","value":21}]"#,
+/// );
+///
+/// let Pairs(pairs): Pairs<BTreeMap<String, u32>> = serde_json::from_str(r#"[
+///     {"key": "a", "value": 21},
+///     {"key": "b", "value": 42}
+/// ]"#)?;
+/// assert_eq!(pairs[&String::from("b")], 42);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Pairs<T>(
+    /// The wrapped object
+    pub T,
+);
+
+#[derive(Serialize)]
+struct SerializePair<'a, K, V> {
+    key: &'a K,
+    value: &'a V,
+}
+
+#[derive(Deserialize)]
+struct DeserializePair<K, V> {
+    key: K,
+    value: V,
+}
+
+fn serialize_pairs<'a, S, K, V, I>(
+    serializer: S,
+    len: usize,
+    pairs: I,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    K: Serialize + 'a,
+    V: Serialize + 'a,
+    I: Iterator<Item = (&'a K, &'a V)>,
+{
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    for (key, value) in pairs {
+        seq.serialize_element(&SerializePair { key, value })?;
+    }
+    seq.end()
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<&[(K, V)]> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serialize_pairs(serializer, self.0.len(), self.0.iter().map(|(k, v)| (k, v)))
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<Vec<(K, V)>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(self.0.as_slice()).serialize(serializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<&Vec<(K, V)>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(self.0.as_slice()).serialize(serializer)
+    }
+}
+
+impl<const N: usize, K: Serialize, V: Serialize> Serialize for Pairs<[(K, V); N]> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(self.0.as_slice()).serialize(serializer)
+    }
+}
+
+impl<const N: usize, K: Serialize, V: Serialize> Serialize for Pairs<&[(K, V); N]> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(self.0.as_slice()).serialize(serializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<HashMap<K, V>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(&self.0).serialize(serializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<&HashMap<K, V>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serialize_pairs(serializer, self.0.len(), self.0.iter())
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<BTreeMap<K, V>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        Pairs(&self.0).serialize(serializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Pairs<&BTreeMap<K, V>> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serialize_pairs(serializer, self.0.len(), self.0.iter())
+    }
+}
+
+impl<'de, K: Deserialize<'de>, V: Deserialize<'de>> Deserialize<'de> for Pairs<Vec<(K, V)>> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let pairs = Vec::<DeserializePair<K, V>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|pair| (pair.key, pair.value))
+            .collect();
+        Ok(Pairs(pairs))
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Pairs<HashMap<K, V>>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let Pairs(pairs) = Pairs::<Vec<(K, V)>>::deserialize(deserializer)?;
+        Ok(Pairs(pairs.into_iter().collect()))
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Pairs<BTreeMap<K, V>>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let Pairs(pairs) = Pairs::<Vec<(K, V)>>::deserialize(deserializer)?;
+        Ok(Pairs(pairs.into_iter().collect()))
+    }
+}
@@ -0,0 +1,115 @@
+//! Optional instrumentation with the `tracing` crate
+//!
+//! With the `tracing` feature, compilation, serialization and deserialization
+//! are wrapped in debug spans and the columns are reported as debug events
+//! with their timing and size. Without the feature, the helpers compile to
+//! nothing.
+use crate::internal::{schema::GenericField, serialization::interpreter::MutableBuffers};
+
+/// Enter a debug span until the end of the current scope
+///
+/// The fields are only evaluated with the `tracing` feature.
+macro_rules! span {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::debug_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+pub(crate) use span;
+
+/// Measure the elapsed time of an operation
+pub struct Timer {
+    #[cfg(feature = "tracing")]
+    start: crate::internal::metrics::Instant,
+}
+
+impl Timer {
+    pub fn start() -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            start: crate::internal::metrics::Instant::now(),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    fn elapsed_us(&self) -> u64 {
+        self.start.elapsed().as_micros() as u64
+    }
+}
+
+/// Measure the time and size of building a single column
+pub struct ColumnTimer {
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    timer: Timer,
+    #[cfg(feature = "tracing")]
+    size: usize,
+}
+
+impl ColumnTimer {
+    /// Start the timer before the buffers of the column are moved out
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub fn start(buffers: &MutableBuffers) -> Self {
+        Self {
+            timer: Timer::start(),
+            #[cfg(feature = "tracing")]
+            size: buffers.estimated_size(),
+        }
+    }
+
+    /// Report the column, its size is the size of the buffers moved out since
+    /// the timer was started
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub fn finish(self, field: &GenericField, buffers: &MutableBuffers) {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!(
+            column = field.name.as_str(),
+            data_type = %field.data_type,
+            elapsed_us = self.timer.elapsed_us(),
+            bytes = self.size.saturating_sub(buffers.estimated_size()) as u64,
+            "built column",
+        );
+    }
+}
+
+/// Report a chunk of a column decoded on its own, e.g., when decoding in
+/// parallel
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub fn decoded_column(name: &str, num_events: usize, timer: Timer) {
+    #[cfg(feature = "tracing")]
+    ::tracing::debug!(
+        column = name,
+        num_events = num_events as u64,
+        elapsed_us = timer.elapsed_us(),
+        "decoded column",
+    );
+}
+
+/// The tracing context of the current thread, to be entered on worker
+/// threads, so that their events are reported to the same subscriber and
+/// span
+pub struct Context {
+    #[cfg(feature = "tracing")]
+    dispatch: ::tracing::Dispatch,
+    #[cfg(feature = "tracing")]
+    span: ::tracing::Span,
+}
+
+impl Context {
+    pub fn current() -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            dispatch: ::tracing::dispatcher::get_default(|dispatch| dispatch.clone()),
+            #[cfg(feature = "tracing")]
+            span: ::tracing::Span::current(),
+        }
+    }
+
+    pub fn in_scope<R>(&self, func: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "tracing")]
+        return ::tracing::dispatcher::with_default(&self.dispatch, || self.span.in_scope(func));
+
+        #[cfg(not(feature = "tracing"))]
+        func()
+    }
+}
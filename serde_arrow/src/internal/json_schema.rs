@@ -0,0 +1,237 @@
+//! Export `serde_arrow` schemas as JSON Schema documents
+//!
+use crate::internal::{
+    error::{fail, Result},
+    schema::{GenericDataType, GenericField, SerdeArrowSchema, Strategy},
+    value::Value,
+};
+
+/// The JSON Schema dialect of the exported documents
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl SerdeArrowSchema {
+    /// Describe the records of this schema as a JSON Schema document
+    ///
+    /// The document describes the JSON representation of the Rust values,
+    /// e.g., as written by `serde_json`. It follows the 2020-12 draft and can
+    /// be serialized with any serde format. The types are mapped as follows:
+    ///
+    /// - Nullable fields: a type union with `"null"`, nullable fields are not
+    ///   required
+    /// - Integers: `integer` with the bounds of the integer type
+    /// - Floats and numbers stored as strings: `number`
+    /// - Strings, dictionaries and decimals: `string`
+    /// - Dates and timestamps: `string` with the `date-time` format for the
+    ///   `UtcStrAsDate64` strategy, `string` for the `NaiveStrAsDate64`
+    ///   strategy, and `integer` otherwise
+    /// - Lists: `array`, with a fixed length for fixed size lists
+    /// - Structs: `object` with the fields as properties, tuples as
+    ///   `array` with `prefixItems`
+    /// - Maps: `object` with the values as `additionalProperties`
+    /// - Unions: `oneOf` the externally tagged variants
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "a", "data_type": "I64"},
+    ///     {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(schema.to_json_schema()?)?,
+    ///     serde_json::json!({
+    ///         "$schema": "https://json-schema.org/draft/2020-12/schema",
+    ///         "type": "object",
+    ///         "properties": {
+    ///             "a": {
+    ///                 "type": "integer",
+    ///                 "minimum": i64::MIN,
+    ///                 "maximum": i64::MAX,
+    ///             },
+    ///             "b": {"type": ["string", "null"]},
+    ///         },
+    ///         "required": ["a"],
+    ///     }),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_json_schema(&self) -> Result<Value> {
+        let mut document = vec![(
+            String::from("$schema"),
+            Value::Str(String::from(JSON_SCHEMA_DIALECT)),
+        )];
+        document.extend(object_schema(&self.fields)?);
+        Ok(Value::Struct(document))
+    }
+}
+
+fn entry(key: &str, value: Value) -> (String, Value) {
+    (key.to_owned(), value)
+}
+
+fn str(value: &str) -> Value {
+    Value::Str(value.to_owned())
+}
+
+fn object_schema(fields: &[GenericField]) -> Result<Vec<(String, Value)>> {
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+    for field in fields {
+        properties.push((field.name.clone(), field_schema(field)?));
+        if !field.nullable {
+            required.push(Value::Str(field.name.clone()));
+        }
+    }
+    Ok(vec![
+        entry("type", str("object")),
+        entry("properties", Value::Struct(properties)),
+        entry("required", Value::List(required)),
+    ])
+}
+
+fn integer_schema(min: i64, max: u64) -> Vec<(String, Value)> {
+    vec![
+        entry("type", str("integer")),
+        entry("minimum", Value::I64(min)),
+        entry("maximum", Value::U64(max)),
+    ]
+}
+
+fn field_schema(field: &GenericField) -> Result<Value> {
+    let schema = type_schema(field)?;
+    if field.nullable && !matches!(field.data_type, GenericDataType::Null) {
+        Ok(Value::Struct(make_nullable(schema)))
+    } else {
+        Ok(Value::Struct(schema))
+    }
+}
+
+/// The schema of the field's type, ignoring its nullability
+fn type_schema(field: &GenericField) -> Result<Vec<(String, Value)>> {
+    use GenericDataType as D;
+
+    let schema = match &field.data_type {
+        D::Null => vec![entry("type", str("null"))],
+        D::Bool => vec![entry("type", str("boolean"))],
+        D::I8 => integer_schema(i8::MIN.into(), i8::MAX as u64),
+        D::I16 => integer_schema(i16::MIN.into(), i16::MAX as u64),
+        D::I32 => integer_schema(i32::MIN.into(), i32::MAX as u64),
+        D::I64 => integer_schema(i64::MIN, i64::MAX as u64),
+        D::U8 => integer_schema(0, u8::MAX.into()),
+        D::U16 => integer_schema(0, u16::MAX.into()),
+        D::U32 => integer_schema(0, u32::MAX.into()),
+        D::U64 => integer_schema(0, u64::MAX),
+        D::F16 | D::F32 | D::F64 => vec![entry("type", str("number"))],
+        D::Utf8 | D::LargeUtf8 => match field.strategy.as_ref() {
+            Some(Strategy::NumberAsStr) => vec![entry("type", str("number"))],
+            _ => vec![entry("type", str("string"))],
+        },
+        D::Decimal128(_, _) => vec![entry("type", str("string"))],
+        D::Date64 | D::Timestamp(_, _) => match field.strategy.as_ref() {
+            Some(Strategy::UtcStrAsDate64) => vec![
+                entry("type", str("string")),
+                entry("format", str("date-time")),
+            ],
+            Some(Strategy::NaiveStrAsDate64) => vec![entry("type", str("string"))],
+            _ => vec![entry("type", str("integer"))],
+        },
+        D::List | D::LargeList | D::FixedSizeList(_) => {
+            let Some(item) = field.children.first() else {
+                fail!("List field {:?} without item field", field.name);
+            };
+            let mut res = vec![
+                entry("type", str("array")),
+                entry("items", field_schema(item)?),
+            ];
+            if let D::FixedSizeList(n) = &field.data_type {
+                res.push(entry("minItems", Value::I32(*n)));
+                res.push(entry("maxItems", Value::I32(*n)));
+            }
+            res
+        }
+        D::Map => {
+            // JSON object keys are always strings, e.g., serde_json writes
+            // integer keys as strings
+            let Some([_, value]) = field.children.first().map(|entries| &entries.children[..])
+            else {
+                fail!("Map field {:?} without key and value fields", field.name);
+            };
+            vec![
+                entry("type", str("object")),
+                entry("additionalProperties", field_schema(value)?),
+            ]
+        }
+        D::Dictionary => {
+            let Some(values) = field.children.get(1) else {
+                fail!("Dictionary field {:?} without value field", field.name);
+            };
+            type_schema(values)?
+        }
+        D::Struct => match field.strategy.as_ref() {
+            Some(Strategy::TupleAsStruct) => {
+                let items = field
+                    .children
+                    .iter()
+                    .map(field_schema)
+                    .collect::<Result<Vec<_>>>()?;
+                let len = Value::U64(items.len() as u64);
+                vec![
+                    entry("type", str("array")),
+                    entry("prefixItems", Value::List(items)),
+                    entry("minItems", len.clone()),
+                    entry("maxItems", len),
+                ]
+            }
+            _ => object_schema(&field.children)?,
+        },
+        D::Union => {
+            let mut variants = Vec::new();
+            for variant in &field.children {
+                if matches!(variant.data_type, D::Null) {
+                    variants.push(Value::Struct(vec![entry(
+                        "const",
+                        Value::Str(variant.name.clone()),
+                    )]));
+                } else {
+                    variants.push(Value::Struct(vec![
+                        entry("type", str("object")),
+                        entry(
+                            "properties",
+                            Value::Struct(vec![(variant.name.clone(), field_schema(variant)?)]),
+                        ),
+                        entry(
+                            "required",
+                            Value::List(vec![Value::Str(variant.name.clone())]),
+                        ),
+                        entry("additionalProperties", Value::Bool(false)),
+                    ]));
+                }
+            }
+            vec![entry("oneOf", Value::List(variants))]
+        }
+    };
+    Ok(schema)
+}
+
+/// Allow nulls, either by extending the type or with an `anyOf`
+fn make_nullable(mut schema: Vec<(String, Value)>) -> Vec<(String, Value)> {
+    for (key, value) in &mut schema {
+        if key != "type" {
+            continue;
+        }
+        if let Value::Str(ty) = value {
+            *value = Value::List(vec![Value::Str(std::mem::take(ty)), str("null")]);
+            return schema;
+        }
+    }
+    vec![entry(
+        "anyOf",
+        Value::List(vec![
+            Value::Struct(schema),
+            Value::Struct(vec![entry("type", str("null"))]),
+        ]),
+    )]
+}
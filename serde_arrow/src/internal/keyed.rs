@@ -0,0 +1,503 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use serde::{
+    ser::{Error, Impossible, SerializeMap, SerializeSeq, SerializeStruct},
+    Serialize, Serializer,
+};
+
+/// A wrapper around a map of records that stores the keys in an extra column
+///
+/// When serialized, each entry of the map behaves as if the record was
+/// extended by a field containing the key. The name of the key column defaults
+/// to `"key"` and can be configured with [`with_key`][Keyed::with_key]. The
+/// records must serialize as structs or maps. `HashMap` and `BTreeMap` are
+/// supported, for `HashMap` the order of the rows is unspecified. Records
+/// with a field of the same name as the key column are rejected.
+///
+/// As the name of the key column is only known at runtime, the fields must be
+/// traced from samples. The rows are serialized as maps, therefore the traced
+/// columns are sorted by name. To read the rows back, deserialize into a
+/// struct that contains the key field and the record as a `#[serde(flatten)]`
+/// field.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// # use std::collections::BTreeMap;
+/// # use serde::Serialize;
+/// # use serde_arrow::utils::Keyed;
+/// #
+/// #[derive(Serialize)]
+/// struct Record {
+///     value: u32,
+/// }
+///
+/// let mut records = BTreeMap::new();
+/// records.insert("a", Record { value: 13 });
+/// records.insert("b", Record { value: 21 });
+///
+/// assert_eq!(
+///     serde_json::to_string(&Keyed::new(&records).with_key("id"))?,
+///     r#"[{"id":"a","value":13},{"id":"b","value":21}]"#,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct Keyed<T> {
+    /// The wrapped map of records
+    pub records: T,
+    /// The name of the key column
+    pub key: String,
+}
+
+impl<T> Keyed<T> {
+    /// Wrap a map of records, storing the keys in a column named `"key"`
+    pub fn new(records: T) -> Self {
+        Self {
+            records,
+            key: String::from("key"),
+        }
+    }
+
+    /// Set the name of the key column
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+}
+
+fn serialize_keyed<'a, S, K, V, I>(
+    serializer: S,
+    name: &str,
+    len: usize,
+    records: I,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize + 'a,
+    V: Serialize + 'a,
+    I: Iterator<Item = (&'a K, &'a V)>,
+{
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    for (key, record) in records {
+        seq.serialize_element(&KeyedRecord { name, key, record })?;
+    }
+    seq.end()
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Keyed<HashMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyed(
+            serializer,
+            &self.key,
+            self.records.len(),
+            self.records.iter(),
+        )
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Keyed<&HashMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyed(
+            serializer,
+            &self.key,
+            self.records.len(),
+            self.records.iter(),
+        )
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Keyed<BTreeMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyed(
+            serializer,
+            &self.key,
+            self.records.len(),
+            self.records.iter(),
+        )
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Keyed<&BTreeMap<K, V>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyed(
+            serializer,
+            &self.key,
+            self.records.len(),
+            self.records.iter(),
+        )
+    }
+}
+
+struct KeyedRecord<'a, K, V> {
+    name: &'a str,
+    key: &'a K,
+    record: &'a V,
+}
+
+impl<'a, K: Serialize, V: Serialize> Serialize for KeyedRecord<'a, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let is_human_readable = serializer.is_human_readable();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(self.name, self.key)?;
+        self.record.serialize(FlatRecordSerializer {
+            map: &mut map,
+            name: self.name,
+            is_human_readable,
+        })?;
+        map.end()
+    }
+}
+
+/// Serialize the fields of a struct or map as entries of an outer map
+struct FlatRecordSerializer<'a, M> {
+    map: &'a mut M,
+    /// The name of the key column
+    name: &'a str,
+    /// The value reported by the serializer of the outer map
+    is_human_readable: bool,
+}
+
+impl<'a, M: SerializeMap> FlatRecordSerializer<'a, M> {
+    fn check_field(&self, field: &str) -> Result<(), M::Error> {
+        if field == self.name {
+            return Err(M::Error::custom(format!(
+                "Keyed records must not contain a field named {field:?}, as it is used for the key column"
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn unsupported<E: Error>(ty: &str) -> E {
+    E::custom(format!(
+        "Keyed records must serialize as structs or maps, found {ty}"
+    ))
+}
+
+impl<'a, M: SerializeMap> Serializer for FlatRecordSerializer<'a, M> {
+    type Ok = ();
+    type Error = M::Error;
+
+    type SerializeSeq = Impossible<(), M::Error>;
+    type SerializeTuple = Impossible<(), M::Error>;
+    type SerializeTupleStruct = Impossible<(), M::Error>;
+    type SerializeTupleVariant = Impossible<(), M::Error>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), M::Error>;
+
+    fn is_human_readable(&self) -> bool {
+        self.is_human_readable
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<(), M::Error> {
+        Err(unsupported("bool"))
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), M::Error> {
+        Err(unsupported("i8"))
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), M::Error> {
+        Err(unsupported("i16"))
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), M::Error> {
+        Err(unsupported("i32"))
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), M::Error> {
+        Err(unsupported("i64"))
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), M::Error> {
+        Err(unsupported("u8"))
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), M::Error> {
+        Err(unsupported("u16"))
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), M::Error> {
+        Err(unsupported("u32"))
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), M::Error> {
+        Err(unsupported("u64"))
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<(), M::Error> {
+        Err(unsupported("f32"))
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), M::Error> {
+        Err(unsupported("f64"))
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), M::Error> {
+        Err(unsupported("char"))
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), M::Error> {
+        Err(unsupported("str"))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), M::Error> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), M::Error> {
+        Err(unsupported("none"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), M::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), M::Error> {
+        Err(unsupported("unit"))
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), M::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), M::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, M::Error> {
+        Err(unsupported("sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, M::Error> {
+        Err(unsupported("tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, M::Error> {
+        Err(unsupported("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, M::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, M::Error> {
+        Err(unsupported("enum"))
+    }
+}
+
+impl<'a, M: SerializeMap> SerializeMap for FlatRecordSerializer<'a, M> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), M::Error> {
+        if let Ok(key) = key.serialize(FieldNameSerializer) {
+            self.check_field(&key)?;
+        }
+        self.map.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), M::Error> {
+        self.map.serialize_value(value)
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+impl<'a, M: SerializeMap> SerializeStruct for FlatRecordSerializer<'a, M> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        self.check_field(key)?;
+        self.map.serialize_entry(key, value)
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+/// Extract the name of a field from map keys that serialize as strings
+struct FieldNameSerializer;
+
+macro_rules! not_a_field_name {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<String, fmt::Error> {
+                Err(fmt::Error)
+            }
+        )*
+    };
+}
+
+impl Serializer for FieldNameSerializer {
+    type Ok = String;
+    type Error = fmt::Error;
+
+    type SerializeSeq = Impossible<String, fmt::Error>;
+    type SerializeTuple = Impossible<String, fmt::Error>;
+    type SerializeTupleStruct = Impossible<String, fmt::Error>;
+    type SerializeTupleVariant = Impossible<String, fmt::Error>;
+    type SerializeMap = Impossible<String, fmt::Error>;
+    type SerializeStruct = Impossible<String, fmt::Error>;
+    type SerializeStructVariant = Impossible<String, fmt::Error>;
+
+    fn serialize_str(self, val: &str) -> Result<String, fmt::Error> {
+        Ok(val.to_owned())
+    }
+
+    fn serialize_char(self, val: char) -> Result<String, fmt::Error> {
+        Ok(val.to_string())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, fmt::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, fmt::Error> {
+        value.serialize(self)
+    }
+
+    not_a_field_name!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    );
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<String, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, fmt::Error> {
+        Err(fmt::Error)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, fmt::Error> {
+        Err(fmt::Error)
+    }
+}
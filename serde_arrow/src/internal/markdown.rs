@@ -0,0 +1,104 @@
+//! Render `serde_arrow` schemas as Markdown tables
+//!
+use crate::internal::schema::{
+    GenericField, SerdeArrowSchema, EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, LAYOUT_HINTS_KEY,
+    NULL_DEFAULT_KEY,
+};
+
+impl SerdeArrowSchema {
+    /// Render the fields as a Markdown table, e.g., for data catalogs
+    ///
+    /// The table contains one row per field with its name, data type,
+    /// nullability, strategy, and metadata. Nested fields are listed after
+    /// their parent with the path of their names joined by `.`, e.g., the
+    /// items of a list `tags` as `tags.element`. Pipes in the cells are
+    /// escaped, so that arbitrary field names and metadata can be rendered.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "a", "data_type": "I64", "null_default": "0"},
+    ///     {"name": "b", "data_type": "Date64", "strategy": "UtcStrAsDate64", "nullable": true},
+    ///     {"name": "c", "data_type": "LargeList", "children": [
+    ///         {"name": "element", "data_type": "LargeUtf8"},
+    ///     ]},
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     schema.to_markdown_table(),
+    ///     concat!(
+    ///         "| Field | Type | Nullable | Strategy | Metadata |\n",
+    ///         "|---|---|---|---|---|\n",
+    ///         "| `a` | `I64` | no |  | `SERDE_ARROW:null_default`: `0` |\n",
+    ///         "| `b` | `Date64` | yes | `UtcStrAsDate64` |  |\n",
+    ///         "| `c` | `LargeList` | no |  |  |\n",
+    ///         "| `c.element` | `LargeUtf8` | no |  |  |\n",
+    ///     ),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_markdown_table(&self) -> String {
+        let mut table = String::from("| Field | Type | Nullable | Strategy | Metadata |\n");
+        table.push_str("|---|---|---|---|---|\n");
+        for field in &self.fields {
+            push_rows(&mut table, "", field);
+        }
+        table
+    }
+}
+
+fn push_rows(table: &mut String, prefix: &str, field: &GenericField) {
+    let path = format!("{prefix}{name}", name = field.name);
+    let strategy = match &field.strategy {
+        Some(strategy) => code(&strategy.to_string()),
+        None => String::new(),
+    };
+
+    let cells = [
+        code(&path),
+        code(&field.data_type.to_string()),
+        String::from(if field.nullable { "yes" } else { "no" }),
+        strategy,
+        metadata(field),
+    ];
+    table.push('|');
+    for cell in cells {
+        table.push(' ');
+        table.push_str(&cell);
+        table.push_str(" |");
+    }
+    table.push('\n');
+
+    let prefix = format!("{path}.");
+    for child in &field.children {
+        push_rows(table, &prefix, child);
+    }
+}
+
+/// The metadata besides the strategy, in the keys used for Arrow fields
+fn metadata(field: &GenericField) -> String {
+    let entries = [
+        (NULL_DEFAULT_KEY, &field.null_default),
+        (EXTENSION_NAME_KEY, &field.extension_name),
+        (EXTENSION_METADATA_KEY, &field.extension_metadata),
+        (LAYOUT_HINTS_KEY, &field.layout_hints),
+    ];
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| Some(format!("{}: {}", code(key), code(value.as_ref()?))))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format the text as a code span that can be used inside table cells
+fn code(text: &str) -> String {
+    let text = text.replace('|', "\\|").replace(['\n', '\r'], " ");
+    if text.contains('`') {
+        format!("`` {text} ``")
+    } else {
+        format!("`{text}`")
+    }
+}
@@ -0,0 +1,292 @@
+//! Wrapper types that fix the Arrow representation of their values
+//!
+//! The wrappers serialize their values as newtype structs with a name known to
+//! `serde_arrow`. The newtype structs are transparent for the serializers and
+//! deserializers of `serde_arrow` (and most other formats), but allow both
+//! `from_type` and `from_samples` to assign the data type and strategy without
+//! inspecting the values.
+use std::marker::PhantomData;
+
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::internal::schema::{GenericDataType, GenericTimeUnit, Strategy, BFLOAT16};
+
+const UTC_TIMESTAMP_MILLIS_MARKER: &str = "SERDE_ARROW:UtcTimestampMillis";
+const NAIVE_TIMESTAMP_MILLIS_MARKER: &str = "SERDE_ARROW:NaiveTimestampMillis";
+const DICTIONARY_MARKER: &str = "SERDE_ARROW:Dictionary";
+const BFLOAT16_MARKER: &str = "SERDE_ARROW:BFloat16";
+
+/// The representation requested by a marker newtype struct
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeMarker {
+    UtcTimestampMillis,
+    NaiveTimestampMillis,
+    Dictionary,
+    BFloat16,
+}
+
+impl TypeMarker {
+    /// The marker of a newtype struct with the given name, if any
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            UTC_TIMESTAMP_MILLIS_MARKER => Some(Self::UtcTimestampMillis),
+            NAIVE_TIMESTAMP_MILLIS_MARKER => Some(Self::NaiveTimestampMillis),
+            DICTIONARY_MARKER => Some(Self::Dictionary),
+            BFLOAT16_MARKER => Some(Self::BFloat16),
+            _ => None,
+        }
+    }
+
+    /// The data type of the traced values
+    ///
+    /// Dictionaries are traced as strings and only encoded once the field is
+    /// built.
+    pub fn data_type(self) -> GenericDataType {
+        match self {
+            Self::UtcTimestampMillis => {
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(String::from("UTC")))
+            }
+            Self::NaiveTimestampMillis => {
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None)
+            }
+            Self::Dictionary => GenericDataType::LargeUtf8,
+            Self::BFloat16 => GenericDataType::U16,
+        }
+    }
+
+    /// A serialized value of the marked types, used when tracing types
+    ///
+    /// `bf16` values are serialized as their bits and do not use the example
+    /// value.
+    pub fn example_value(self) -> &'static str {
+        match self {
+            Self::UtcTimestampMillis => "1970-01-01T00:00:00Z",
+            Self::NaiveTimestampMillis => "1970-01-01T00:00:00",
+            Self::Dictionary | Self::BFloat16 => "",
+        }
+    }
+
+    pub fn strategy(self) -> Option<Strategy> {
+        match self {
+            Self::UtcTimestampMillis => Some(Strategy::UtcStrAsDate64),
+            Self::NaiveTimestampMillis => Some(Strategy::NaiveStrAsDate64),
+            Self::Dictionary | Self::BFloat16 => None,
+        }
+    }
+
+    /// The extension type attached to the traced field, if any
+    pub fn extension_name(self) -> Option<&'static str> {
+        match self {
+            Self::BFloat16 => Some(BFLOAT16),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TypeMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UtcTimestampMillis => write!(f, "TimestampMillis<DateTime<Utc>>"),
+            Self::NaiveTimestampMillis => write!(f, "TimestampMillis<NaiveDateTime>"),
+            Self::Dictionary => write!(f, "AsDictionary"),
+            Self::BFloat16 => write!(f, "BFloat16"),
+        }
+    }
+}
+
+/// Datetime types that can be wrapped in [`TimestampMillis`]
+///
+/// Implemented for chrono's `DateTime<Utc>` and `NaiveDateTime`.
+pub trait TimestampLike {
+    #[doc(hidden)]
+    const MARKER: &'static str;
+}
+
+impl TimestampLike for chrono::DateTime<chrono::Utc> {
+    const MARKER: &'static str = UTC_TIMESTAMP_MILLIS_MARKER;
+}
+
+impl TimestampLike for chrono::NaiveDateTime {
+    const MARKER: &'static str = NAIVE_TIMESTAMP_MILLIS_MARKER;
+}
+
+/// Store a chrono datetime as a timestamp with millisecond precision
+///
+/// `DateTime<Utc>` values are stored as `Timestamp(Millisecond, Some("UTC"))`
+/// with the `UtcStrAsDate64` strategy, `NaiveDateTime` values as
+/// `Timestamp(Millisecond, None)` with the `NaiveStrAsDate64` strategy. Both
+/// [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type] and
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples] pick
+/// up the data type from the wrapper, without the need to guess dates from
+/// the serialized strings. The wrapper requires chrono's `serde` feature.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field, TimeUnit};
+/// use chrono::{DateTime, Utc};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{schema::SchemaLike, utils::TimestampMillis};
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     time: TimestampMillis<DateTime<Utc>>,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(Default::default())?;
+/// assert_eq!(
+///     fields[0].data_type(),
+///     &DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+/// );
+///
+/// let items = vec![Record {
+///     time: TimestampMillis("2020-12-24T08:30:00Z".parse::<DateTime<Utc>>()?),
+/// }];
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// let roundtripped: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(roundtripped, items);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimestampMillis<T>(pub T);
+
+impl<T: TimestampLike + Serialize> Serialize for TimestampMillis<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(T::MARKER, &self.0)
+    }
+}
+
+impl<'de, T: TimestampLike + Deserialize<'de>> Deserialize<'de> for TimestampMillis<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value =
+            deserializer.deserialize_newtype_struct(T::MARKER, MarkerVisitor(PhantomData))?;
+        Ok(Self(value))
+    }
+}
+
+/// Store strings dictionary encoded
+///
+/// The values are stored in a `Dictionary` field with `U32` keys and
+/// `LargeUtf8` values, independent of the
+/// [`string_dictionary_encoding`][crate::schema::TracingOptions::string_dictionary_encoding]
+/// option. The wrapped type must serialize as a string.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{schema::{SchemaLike, TracingOptions}, utils::AsDictionary};
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     label: AsDictionary<String>,
+/// }
+///
+/// let items = vec![
+///     Record { label: AsDictionary(String::from("foo")) },
+///     Record { label: AsDictionary(String::from("bar")) },
+/// ];
+/// let fields = Vec::<Field>::from_samples(&items, TracingOptions::default())?;
+/// assert_eq!(
+///     fields[0].data_type(),
+///     &DataType::Dictionary(Box::new(DataType::UInt32), Box::new(DataType::LargeUtf8)),
+/// );
+///
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// let roundtripped: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(roundtripped, items);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsDictionary<T>(pub T);
+
+impl<T: Serialize> Serialize for AsDictionary<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(DICTIONARY_MARKER, &self.0)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for AsDictionary<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = deserializer
+            .deserialize_newtype_struct(DICTIONARY_MARKER, MarkerVisitor(PhantomData))?;
+        Ok(Self(value))
+    }
+}
+
+/// Store `bf16` values losslessly as their bits in `UInt16` fields
+///
+/// The fields are tagged with the `serde_arrow.bfloat16` extension type.
+/// Both [`SchemaLike::from_type`][crate::schema::SchemaLike::from_type] and
+/// [`SchemaLike::from_samples`][crate::schema::SchemaLike::from_samples]
+/// attach the extension to the traced fields.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use half::bf16;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{schema::SchemaLike, utils::BFloat16};
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     weight: BFloat16,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(Default::default())?;
+/// assert_eq!(fields[0].data_type(), &DataType::UInt16);
+/// assert_eq!(
+///     fields[0].metadata().get("ARROW:extension:name").map(String::as_str),
+///     Some("serde_arrow.bfloat16"),
+/// );
+///
+/// let items = vec![Record { weight: BFloat16(bf16::from_f32(0.15625)) }];
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// let roundtripped: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(roundtripped, items);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct BFloat16(pub half::bf16);
+
+impl Serialize for BFloat16 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(BFLOAT16_MARKER, &self.0.to_bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for BFloat16 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits: u16 =
+            deserializer.deserialize_newtype_struct(BFLOAT16_MARKER, MarkerVisitor(PhantomData))?;
+        Ok(Self(half::bf16::from_bits(bits)))
+    }
+}
+
+struct MarkerVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for MarkerVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a newtype struct")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,162 @@
+//! Metrics of conversions reported to user callbacks
+//!
+//! The metrics are only measured, if a callback is configured. In particular,
+//! no clock is read otherwise.
+//!
+//! On `wasm32-unknown-unknown` no clock is available and all durations are
+//! reported as zero.
+use std::{sync::Arc, time::Duration};
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use std::time::Instant;
+
+/// A stand-in for [`std::time::Instant`], which panics on
+/// `wasm32-unknown-unknown`. All measured durations are zero.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[derive(Debug, Clone, Copy)]
+pub struct Instant;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl Instant {
+    pub fn now() -> Self {
+        Self
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// The metrics of a single conversion, as reported to the callback set with
+/// [`SerializationOptions::on_metrics`][crate::utils::SerializationOptions::on_metrics]
+/// or
+/// [`DeserializationOptions::on_metrics`][crate::utils::DeserializationOptions::on_metrics]
+///
+/// For serialization, the metrics are reported once per built batch, i.e.,
+/// each time the arrays of a builder are built. For deserialization, the
+/// metrics are reported once the items were deserialized successfully.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct ConversionMetrics {
+    /// The number of rows serialized into the batch or deserialized from the
+    /// arrays
+    pub num_rows: usize,
+    /// The top-level columns built, in the order of the fields. Only
+    /// populated during serialization.
+    pub columns: Vec<ColumnMetrics>,
+    /// The time spent compiling the fields into the program used to convert
+    /// the items. For builders, this is the time spent when constructing the
+    /// builder and it is reported with each batch. Always zero on
+    /// `wasm32-unknown-unknown`, as no clock is available.
+    pub compile_time: Duration,
+    /// The time spent converting the items. For builders, this is the time
+    /// spent pushing items since the last batch and building the arrays.
+    /// Always zero on `wasm32-unknown-unknown`.
+    pub execute_time: Duration,
+}
+
+/// The metrics of a single top-level column
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct ColumnMetrics {
+    /// The name of the column
+    pub name: String,
+    /// The estimated number of bytes of the buffers of the built array
+    pub bytes: usize,
+}
+
+impl ConversionMetrics {
+    /// The total number of bytes of all columns
+    pub fn total_bytes(&self) -> usize {
+        self.columns.iter().map(|column| column.bytes).sum()
+    }
+}
+
+/// A callback invoked with the metrics of each conversion
+#[derive(Clone)]
+pub struct MetricsCallback(Arc<dyn Fn(&ConversionMetrics) + Send + Sync>);
+
+impl MetricsCallback {
+    pub fn new<F: Fn(&ConversionMetrics) + Send + Sync + 'static>(func: F) -> Self {
+        Self(Arc::new(func))
+    }
+
+    pub fn call(&self, metrics: &ConversionMetrics) {
+        (self.0)(metrics)
+    }
+}
+
+impl std::fmt::Debug for MetricsCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MetricsCallback")
+    }
+}
+
+impl std::cmp::PartialEq for MetricsCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The metrics of the current batch of a builder
+pub struct MetricsRecorder {
+    callback: MetricsCallback,
+    compile_time: Duration,
+    execute_time: Duration,
+}
+
+impl MetricsRecorder {
+    pub fn new(callback: MetricsCallback, compile_time: Duration) -> Self {
+        Self {
+            callback,
+            compile_time,
+            execute_time: Duration::ZERO,
+        }
+    }
+
+    pub fn add_execute_time(&mut self, elapsed: Duration) {
+        self.execute_time += elapsed;
+    }
+
+    /// Report the metrics of the batch and start a new batch
+    pub fn report(&mut self, num_rows: usize, columns: Vec<ColumnMetrics>) {
+        let metrics = ConversionMetrics {
+            num_rows,
+            columns,
+            compile_time: self.compile_time,
+            execute_time: std::mem::take(&mut self.execute_time),
+        };
+        self.callback.call(&metrics);
+    }
+}
+
+/// The metrics of a single deserialization, only measured if a callback is
+/// given
+pub struct DeserializationMetrics<'a>(Option<(&'a MetricsCallback, Instant, Duration)>);
+
+impl<'a> DeserializationMetrics<'a> {
+    /// Start measuring before the arrays are prepared
+    pub fn start(callback: Option<&'a MetricsCallback>) -> Self {
+        Self(callback.map(|callback| (callback, Instant::now(), Duration::ZERO)))
+    }
+
+    /// Finish measuring the compile time, once the arrays are prepared
+    pub fn compiled(&mut self) {
+        if let Some((_, start, compile_time)) = self.0.as_mut() {
+            *compile_time = start.elapsed();
+            *start = Instant::now();
+        }
+    }
+
+    /// Report the metrics once the items were deserialized
+    pub fn report(self, num_rows: usize) {
+        if let Some((callback, start, compile_time)) = self.0 {
+            callback.call(&ConversionMetrics {
+                num_rows,
+                columns: Vec::new(),
+                compile_time,
+                execute_time: start.elapsed(),
+            });
+        }
+    }
+}
@@ -1,12 +1,33 @@
+pub mod arrow_repr;
+pub mod avro;
+pub mod builder;
+pub mod cancellation;
+pub mod coercion;
 pub mod common;
 pub mod config;
 pub mod conversions;
+pub mod converters;
 pub mod deserialization;
+pub mod display;
 pub mod error;
 pub mod event;
 pub mod generic;
+pub mod instrument;
+pub mod json_schema;
+pub mod keyed;
+pub mod markdown;
+pub mod markers;
+pub mod metrics;
+pub mod options;
+pub mod progress;
+#[cfg(feature = "prost")]
+pub mod protobuf;
+pub mod rows_view;
 pub mod schema;
+pub mod schema_macro;
 pub mod serialization;
+pub mod shape;
 pub mod sink;
 pub mod source;
 pub mod tracing;
+pub mod value;
@@ -0,0 +1,862 @@
+use std::collections::HashMap;
+
+use crate::internal::{
+    cancellation::CancellationToken,
+    converters::Converters,
+    metrics::{ConversionMetrics, MetricsCallback},
+    progress::ProgressCallback,
+    schema::GenericField,
+};
+
+/// Configure how values are serialized into arrays
+///
+/// The options can be passed to [`to_arrow_with_options`][crate::to_arrow_with_options],
+/// [`to_arrow2_with_options`][crate::to_arrow2_with_options],
+/// [`ArrowBuilder::with_options`][crate::ArrowBuilder::with_options] or
+/// [`Arrow2Builder::with_options`][crate::Arrow2Builder::with_options].
+///
+/// Example:
+///
+/// ```rust
+/// # use serde_arrow::utils::{Overflow, SerializationOptions};
+/// let options = SerializationOptions::default().overflow(Overflow::Saturate);
+/// ```
+///
+/// The defaults are:
+///
+/// ```rust
+/// # use serde_arrow::utils::{
+/// #     EmptyListPolicy, NonFinite, NullPolicy, Overflow, SerializationOptions,
+/// # };
+/// # use std::collections::HashMap;
+/// assert_eq!(
+///     SerializationOptions::default(),
+///     SerializationOptions::new()
+///         .overflow(Overflow::Error)
+///         .non_finite(NonFinite::Serialize)
+///         .null_policy(NullPolicy::Error)
+///         .timestamp_formats(vec![])
+///         .validate_arrays(false)
+///         .coerce_bools(false)
+///         .human_readable(true)
+///         .sort_by(vec![])
+///         .empty_lists(EmptyListPolicy::Preserve),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SerializationOptions {
+    /// How to handle integers that cannot be represented by the integer type
+    /// of the field, e.g., `300_u32` written to an `UInt8` field. See
+    /// [`Overflow`] for the available policies.
+    pub overflow: Overflow,
+    /// How to handle non-finite floats (NaN, positive and negative infinity).
+    /// See [`NonFinite`] for the available policies.
+    pub non_finite: NonFinite,
+    /// How to handle nulls in non-nullable fields. See [`NullPolicy`] for the
+    /// available policies.
+    pub null_policy: NullPolicy,
+    /// The formats tried in order when parsing strings into `Date64` or
+    /// `Timestamp` fields. If empty, strings are parsed with chrono's
+    /// `FromStr` implementations (RFC 3339 like strings). See
+    /// [`TimestampFormat`] for the available formats.
+    pub timestamp_formats: Vec<TimestampFormat>,
+    /// Per field overrides of [`timestamp_formats`](#structfield.timestamp_formats),
+    /// keyed by the path of the field with components separated by `.`, e.g.,
+    /// `"parent.child"`
+    pub field_timestamp_formats: HashMap<String, Vec<TimestampFormat>>,
+    /// If `true`, check the built arrays before returning them, so that
+    /// invalid arrays are reported by the builder and not by downstream
+    /// consumers. The data types of the arrays are compared against the
+    /// fields. For arrow, the arrays are additionally checked with
+    /// `ArrayData::validate_full` (offsets, UTF-8 data, dictionary keys,
+    /// nested arrays). The checks require a full pass over the data.
+    pub validate_arrays: bool,
+    /// If `true`, accept the integers `0` / `1` and the strings `"true"` /
+    /// `"false"` (ignoring case) for `Bool` fields, e.g., when the data stems
+    /// from a loosely typed export. Other values result in an error.
+    pub coerce_bools: bool,
+    /// The value reported by `Serializer::is_human_readable()`. Types such as
+    /// `uuid::Uuid`, `chrono` datetimes or `std::net::IpAddr` change their
+    /// representation depending on it, e.g., IP addresses are serialized as
+    /// strings if `true` (the default) and as bytes otherwise. The schema
+    /// must be traced with the same setting, see
+    /// [`TracingOptions::human_readable`][crate::schema::TracingOptions#structfield.human_readable].
+    pub human_readable: bool,
+    /// Conversions applied to newtype structs with the registered names. See
+    /// [`Converters`] for details.
+    pub converters: Converters,
+    /// If `true`, collect the statistics of the top-level columns (number of
+    /// nulls, minimum, maximum, number of distinct values) when building the
+    /// arrays. The statistics of the last batch are available via
+    /// `column_statistics()` of the builders. See
+    /// [`ColumnStatistics`][crate::utils::ColumnStatistics] for details.
+    pub column_statistics: bool,
+    /// The top-level columns to sort the rows by when building the arrays,
+    /// later columns break ties of earlier ones. The sort is stable and nulls
+    /// are placed first. Only boolean, primitive and string columns are
+    /// supported. The columns of builders split with `into_columns` are not
+    /// sorted. The sort order is computed by `serde_arrow` itself, not with the
+    /// sort kernels of arrow or arrow2.
+    pub sort_by: Vec<(String, SortOrder)>,
+    /// How to write `None`, missing fields and empty sequences into nullable
+    /// list fields. See [`EmptyListPolicy`] for the available policies.
+    pub empty_lists: EmptyListPolicy,
+    /// The callback invoked with the metrics of each built batch, set with
+    /// [`on_metrics`](#method.on_metrics)
+    pub metrics: Option<MetricsCallback>,
+    /// A token to cancel the serialization before the next row, see
+    /// [`CancellationToken`]
+    pub cancellation: Option<CancellationToken>,
+    /// The callback invoked with the number of rows pushed into the builder
+    /// so far, set with [`on_progress`](#method.on_progress)
+    pub progress: Option<ProgressCallback>,
+}
+
+impl Default for SerializationOptions {
+    fn default() -> Self {
+        Self {
+            overflow: Overflow::default(),
+            non_finite: NonFinite::default(),
+            null_policy: NullPolicy::default(),
+            timestamp_formats: Vec::new(),
+            field_timestamp_formats: HashMap::new(),
+            validate_arrays: false,
+            coerce_bools: false,
+            human_readable: true,
+            converters: Converters::default(),
+            column_statistics: false,
+            sort_by: Vec::new(),
+            empty_lists: EmptyListPolicy::default(),
+            metrics: None,
+            cancellation: None,
+            progress: None,
+        }
+    }
+}
+
+impl SerializationOptions {
+    /// Construct the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set [`overflow`](#structfield.overflow)
+    pub fn overflow(mut self, value: Overflow) -> Self {
+        self.overflow = value;
+        self
+    }
+
+    /// Set [`non_finite`](#structfield.non_finite)
+    pub fn non_finite(mut self, value: NonFinite) -> Self {
+        self.non_finite = value;
+        self
+    }
+
+    /// Set [`null_policy`](#structfield.null_policy)
+    pub fn null_policy(mut self, value: NullPolicy) -> Self {
+        self.null_policy = value;
+        self
+    }
+
+    /// Set [`timestamp_formats`](#structfield.timestamp_formats)
+    ///
+    /// ```rust
+    /// # use serde_arrow::utils::{SerializationOptions, TimestampFormat};
+    /// let options = SerializationOptions::default().timestamp_formats(vec![
+    ///     TimestampFormat::Rfc3339,
+    ///     TimestampFormat::Rfc2822,
+    ///     TimestampFormat::Custom(String::from("%Y-%m-%d %H:%M:%S")),
+    ///     TimestampFormat::EpochMillis,
+    /// ]);
+    /// ```
+    pub fn timestamp_formats(mut self, value: Vec<TimestampFormat>) -> Self {
+        self.timestamp_formats = value;
+        self
+    }
+
+    /// Set the timestamp formats of a single field, see
+    /// [`field_timestamp_formats`](#structfield.field_timestamp_formats)
+    pub fn field_timestamp_formats(mut self, path: &str, value: Vec<TimestampFormat>) -> Self {
+        self.field_timestamp_formats.insert(path.to_owned(), value);
+        self
+    }
+
+    /// Set [`validate_arrays`](#structfield.validate_arrays)
+    pub fn validate_arrays(mut self, value: bool) -> Self {
+        self.validate_arrays = value;
+        self
+    }
+
+    /// Set [`coerce_bools`](#structfield.coerce_bools)
+    pub fn coerce_bools(mut self, value: bool) -> Self {
+        self.coerce_bools = value;
+        self
+    }
+
+    /// Set [`human_readable`](#structfield.human_readable)
+    pub fn human_readable(mut self, value: bool) -> Self {
+        self.human_readable = value;
+        self
+    }
+
+    /// Set [`converters`](#structfield.converters)
+    pub fn converters(mut self, value: Converters) -> Self {
+        self.converters = value;
+        self
+    }
+
+    /// Set [`column_statistics`](#structfield.column_statistics)
+    pub fn column_statistics(mut self, value: bool) -> Self {
+        self.column_statistics = value;
+        self
+    }
+
+    /// Set [`sort_by`](#structfield.sort_by)
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::{
+    ///     array::{Array, PrimitiveArray},
+    ///     datatypes::{DataType, Field, Int64Type},
+    /// };
+    /// use serde::Serialize;
+    /// use serde_arrow::{
+    ///     utils::{SerializationOptions, SortOrder},
+    ///     ArrowBuilder,
+    /// };
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     time: i64,
+    /// }
+    ///
+    /// let mut builder = ArrowBuilder::with_options(
+    ///     &[Field::new("time", DataType::Int64, false)],
+    ///     SerializationOptions::default().sort_by(vec![(String::from("time"), SortOrder::Ascending)]),
+    /// )?;
+    /// builder.extend(&[Record { time: 3 }, Record { time: 1 }, Record { time: 2 }])?;
+    /// let arrays = builder.build_arrays()?;
+    ///
+    /// let time = arrays[0]
+    ///     .as_any()
+    ///     .downcast_ref::<PrimitiveArray<Int64Type>>()
+    ///     .unwrap();
+    /// assert_eq!(time.values().to_vec(), vec![1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn sort_by(mut self, value: Vec<(String, SortOrder)>) -> Self {
+        self.sort_by = value;
+        self
+    }
+
+    /// Set [`empty_lists`](#structfield.empty_lists)
+    pub fn empty_lists(mut self, value: EmptyListPolicy) -> Self {
+        self.empty_lists = value;
+        self
+    }
+
+    /// Report the metrics of each built batch to the given callback, see
+    /// [`ConversionMetrics`]
+    ///
+    /// The metrics contain the number of rows, the estimated bytes of each
+    /// column, the time spent constructing the builder, and the time spent
+    /// pushing items and building the arrays. The callback is invoked each
+    /// time the arrays are built successfully.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde::Serialize;
+    /// use serde_arrow::{utils::SerializationOptions, ArrowBuilder};
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     a: u32,
+    /// }
+    ///
+    /// let reported = Arc::new(Mutex::new(Vec::new()));
+    /// let options = SerializationOptions::default().on_metrics({
+    ///     let reported = Arc::clone(&reported);
+    ///     move |metrics| reported.lock().unwrap().push(metrics.clone())
+    /// });
+    ///
+    /// let fields = vec![Field::new("a", DataType::UInt32, false)];
+    /// let mut builder = ArrowBuilder::with_options(&fields, options)?;
+    /// builder.extend(&[Record { a: 1 }, Record { a: 2 }])?;
+    /// builder.build_arrays()?;
+    ///
+    /// let reported = reported.lock().unwrap();
+    /// assert_eq!(reported[0].num_rows, 2);
+    /// assert_eq!(reported[0].columns[0].name, "a");
+    /// assert_eq!(reported[0].columns[0].bytes, 8);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn on_metrics<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ConversionMetrics) + Send + Sync + 'static,
+    {
+        self.metrics = Some(MetricsCallback::new(callback));
+        self
+    }
+
+    /// Set [`cancellation`](#structfield.cancellation)
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Invoke the callback every `every` rows with the number of rows
+    /// pushed so far, e.g., to render a progress bar
+    ///
+    /// The rows are counted once they are started and across all batches of
+    /// the builder. An `every` of `0` is treated as `1`.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use arrow::datatypes::{DataType, Field};
+    /// use serde_arrow::{
+    ///     utils::{Items, SerializationOptions},
+    ///     ArrowBuilder,
+    /// };
+    ///
+    /// let reported = Arc::new(Mutex::new(Vec::new()));
+    /// let options = SerializationOptions::default().on_progress(2, {
+    ///     let reported = Arc::clone(&reported);
+    ///     move |rows| reported.lock().unwrap().push(rows)
+    /// });
+    ///
+    /// let fields = vec![Field::new("item", DataType::UInt32, false)];
+    /// let mut builder = ArrowBuilder::with_options(&fields, options)?;
+    /// builder.extend(&Items(&[1_u32, 2, 3, 4, 5]))?;
+    /// assert_eq!(*reported.lock().unwrap(), [2, 4]);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn on_progress<F>(mut self, every: usize, callback: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.progress = Some(ProgressCallback::new(every, callback));
+        self
+    }
+}
+
+/// How to treat null and empty lists
+///
+/// For `Option<Vec<T>>` fields, `None`, a missing field and `Some(vec![])` are
+/// easily conflated. This policy fixes their representation. It is used by
+/// both [`SerializationOptions`] and [`DeserializationOptions`] and applies to
+/// nullable `List` and `LargeList` fields at any nesting level. Non-nullable
+/// lists are not affected.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     utils::{DeserializationOptions, EmptyListPolicy, SerializationOptions},
+///     ArrowBuilder,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Record {
+///     tags: Option<Vec<u8>>,
+/// }
+///
+/// let fields = vec![Field::new(
+///     "tags",
+///     DataType::LargeList(Field::new("element", DataType::UInt8, false).into()),
+///     true,
+/// )];
+/// let items = [Record { tags: None }, Record { tags: Some(vec![]) }];
+///
+/// let options = SerializationOptions::default().empty_lists(EmptyListPolicy::EmptyAsNull);
+/// let mut builder = ArrowBuilder::with_options(&fields, options)?;
+/// builder.extend(&items)?;
+/// let arrays = builder.build_arrays()?;
+/// assert_eq!(arrays[0].null_count(), 2);
+///
+/// let options = DeserializationOptions::default().empty_lists(EmptyListPolicy::NullAsEmpty);
+/// let items: Vec<Record> = serde_arrow::from_arrow_with_options(&fields, &arrays, &options)?;
+/// assert_eq!(items, [Record { tags: Some(vec![]) }, Record { tags: Some(vec![]) }]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum EmptyListPolicy {
+    /// Keep nulls and empty lists apart (the default): `None` and missing
+    /// fields are written as nulls, empty sequences as empty lists. Nulls are
+    /// read as `None`, empty lists as `Some(vec![])`.
+    #[default]
+    Preserve,
+    /// Treat nulls as empty lists: `None` and missing fields are written as
+    /// empty lists. Null lists are read as empty sequences.
+    NullAsEmpty,
+    /// Treat empty lists as nulls: empty sequences are written as nulls.
+    /// Empty lists are read as `None`.
+    EmptyAsNull,
+}
+
+/// The order of a sort column, see
+/// [`SerializationOptions::sort_by`](SerializationOptions#structfield.sort_by)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Smaller values first (the default)
+    #[default]
+    Ascending,
+    /// Larger values first
+    Descending,
+}
+
+/// How to handle integers that are out of range for the target type
+///
+/// The policy is used by both [`SerializationOptions`] and
+/// [`DeserializationOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Fail with an error that includes the offending value and, during
+    /// serialization, the row (the default)
+    #[default]
+    Error,
+    /// Clamp the value to the closest value representable by the target type,
+    /// e.g., `300_u32` is converted to `255_u8` and `-1_i32` to `0_u32`
+    Saturate,
+    /// Keep the lowest bits of the value as in an `as` cast, e.g., `300_u32`
+    /// is converted to `44_u8` and `-1_i32` to `u32::MAX`
+    Wrap,
+}
+
+/// How to handle non-finite floats (NaN, positive and negative infinity)
+///
+/// Some downstream consumers, e.g., certain query engines, cannot handle
+/// non-finite values. This policy allows to filter them during serialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum NonFinite {
+    /// Write the values as-is (the default)
+    #[default]
+    Serialize,
+    /// Write non-finite values as nulls. Non-finite values in non-nullable
+    /// fields result in an error.
+    Null,
+    /// Fail with an error that includes the offending value and row
+    Error,
+}
+
+/// How to handle nulls in non-nullable fields
+///
+/// The policy applies to null values and to missing struct fields. Fields with
+/// data type `Null` and unions are not affected, as are dictionaries for
+/// [`NullPolicy::Default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum NullPolicy {
+    /// Fail with an error (the default)
+    #[default]
+    Error,
+    /// Write the default value of the type instead, e.g., `0`, `""`, `false`
+    /// or an empty list
+    Default,
+    /// Treat all fields as nullable. The arrays are built as if all fields,
+    /// including nested fields, were declared nullable. The promoted fields
+    /// are returned by the `fields()` method of the builders and are used by
+    /// the IPC writers. The fields passed to the builder are not modified.
+    Nullable,
+}
+
+/// How to handle items that failed before serialization, e.g., the `Err` items
+/// passed to [`to_arrow_filtered`][crate::to_arrow_filtered]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum FailedItems {
+    /// Do not write a row for failed items (the default)
+    #[default]
+    Skip,
+    /// Write a row with all fields set to null. Non-nullable fields result in
+    /// an error.
+    Null,
+}
+
+/// A format to parse timestamps from strings
+///
+/// Formats with timezone information (RFC 3339, RFC 2822 and custom formats
+/// with an offset) are converted to UTC. Formats without timezone information
+/// are interpreted as UTC for fields with a timezone and as local time for
+/// naive fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimestampFormat {
+    /// RFC 3339, e.g., `"2015-09-18T23:56:04Z"`
+    Rfc3339,
+    /// RFC 2822, e.g., `"Fri, 18 Sep 2015 23:56:04 +0000"`
+    Rfc2822,
+    /// A [chrono format string](https://docs.rs/chrono/latest/chrono/format/strftime/index.html),
+    /// e.g., `"%Y-%m-%d %H:%M:%S"`
+    Custom(String),
+    /// The seconds since the unix epoch as digits, e.g., `"1442620564"`
+    EpochSeconds,
+    /// The milliseconds since the unix epoch as digits, e.g., `"1442620564000"`
+    EpochMillis,
+}
+
+impl std::fmt::Display for TimestampFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rfc3339 => write!(f, "RFC 3339"),
+            Self::Rfc2822 => write!(f, "RFC 2822"),
+            Self::Custom(format) => write!(f, "{format:?}"),
+            Self::EpochSeconds => write!(f, "epoch seconds"),
+            Self::EpochMillis => write!(f, "epoch milliseconds"),
+        }
+    }
+}
+
+/// Configure how arrays are deserialized into Rust objects
+///
+/// The options can be passed to
+/// [`from_arrow_with_options`][crate::from_arrow_with_options] or
+/// [`from_arrow2_with_options`][crate::from_arrow2_with_options].
+///
+/// The defaults are:
+///
+/// ```rust
+/// # use serde_arrow::utils::{
+/// #     DeserializationOptions, EmptyListPolicy, Overflow, TimezoneConversion, UnknownColumns,
+/// # };
+/// assert_eq!(
+///     DeserializationOptions::default(),
+///     DeserializationOptions::new()
+///         .unknown_columns(UnknownColumns::Ignore)
+///         .strict(false)
+///         .timezone_conversion(TimezoneConversion::None)
+///         .overflow(Overflow::Error)
+///         .human_readable(true)
+///         .column_threads(0)
+///         .empty_lists(EmptyListPolicy::Preserve),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DeserializationOptions {
+    /// How to handle columns (and nested struct fields) that are not declared
+    /// by the target struct. See [`UnknownColumns`] for the available
+    /// policies.
+    pub unknown_columns: UnknownColumns,
+    /// If `true`, require that the Arrow type of each primitive value matches
+    /// the requested Rust type exactly, e.g., an `Int32` column can only be
+    /// deserialized into an `i32`
+    ///
+    /// If `false` (the default), the following conversions are performed:
+    ///
+    /// - integers (and booleans) into any integer type, following the
+    ///   [`overflow`](#structfield.overflow) policy if the value is out of
+    ///   range
+    /// - `Float32` into `f64` and `Float64` into `f32`
+    ///
+    /// Values deserialized via `deserialize_any`, e.g., `serde_json::Value`,
+    /// always use the Arrow type.
+    pub strict: bool,
+    /// How to convert timestamps between their timezone-naive and
+    /// timezone-aware representations. See [`TimezoneConversion`] for
+    /// details.
+    pub timezone_conversion: TimezoneConversion,
+    /// Per field overrides of
+    /// [`timezone_conversion`](#structfield.timezone_conversion), keyed by the
+    /// path of the field with components separated by `.`, e.g.,
+    /// `"parent.child"`
+    pub field_timezone_conversions: HashMap<String, TimezoneConversion>,
+    /// How to handle integers that are out of range for the requested Rust
+    /// type, e.g., an `Int64` value deserialized into a `u64`. See
+    /// [`Overflow`] for the available policies.
+    pub overflow: Overflow,
+    /// Rename top-level columns before deserializing them, keyed by the
+    /// column name with the name of the target field as the value, e.g., to
+    /// populate a struct field `userId` from a column named `user_id`.
+    /// Columns without an entry keep their name.
+    pub rename: HashMap<String, String>,
+    /// The value reported by `Deserializer::is_human_readable()`. It should
+    /// match the setting used to build the arrays, see
+    /// [`SerializationOptions::human_readable`]. The default is `true`.
+    pub human_readable: bool,
+    /// The number of threads used to decode the top-level columns
+    ///
+    /// With two or more threads, the columns are decoded in parallel and
+    /// zipped into rows before the items are deserialized. This speeds up
+    /// wide schemas. The worker threads are started once per call and decode
+    /// the rows in chunks of 4096 rows, i.e., only the decoded values of a
+    /// single chunk are kept in memory. The default, `0`, as well as `1`
+    /// decode the rows on the calling thread.
+    ///
+    /// The option applies whenever arrays are deserialized with these
+    /// options, e.g., by
+    /// [`from_arrow_with_options`][crate::from_arrow_with_options],
+    /// [`from_arrow2_with_options`][crate::from_arrow2_with_options] or
+    /// `Arrow2StreamDecoder::with_options`.
+    pub column_threads: usize,
+    /// How to read null and empty lists of nullable list fields. See
+    /// [`EmptyListPolicy`] for the available policies.
+    pub empty_lists: EmptyListPolicy,
+    /// The callback invoked with the metrics of each deserialization, set
+    /// with [`on_metrics`](#method.on_metrics)
+    pub metrics: Option<MetricsCallback>,
+    /// A token to cancel the deserialization before the next row, see
+    /// [`CancellationToken`]
+    pub cancellation: Option<CancellationToken>,
+    /// The callback invoked with the number of rows deserialized so far, set
+    /// with [`on_progress`](#method.on_progress)
+    pub progress: Option<ProgressCallback>,
+}
+
+impl Default for DeserializationOptions {
+    fn default() -> Self {
+        Self {
+            unknown_columns: UnknownColumns::default(),
+            strict: false,
+            timezone_conversion: TimezoneConversion::default(),
+            field_timezone_conversions: HashMap::new(),
+            overflow: Overflow::default(),
+            rename: HashMap::new(),
+            human_readable: true,
+            column_threads: 0,
+            empty_lists: EmptyListPolicy::default(),
+            metrics: None,
+            cancellation: None,
+            progress: None,
+        }
+    }
+}
+
+impl DeserializationOptions {
+    /// Construct the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set [`unknown_columns`](#structfield.unknown_columns)
+    pub fn unknown_columns(mut self, value: UnknownColumns) -> Self {
+        self.unknown_columns = value;
+        self
+    }
+
+    /// Set [`strict`](#structfield.strict)
+    pub fn strict(mut self, value: bool) -> Self {
+        self.strict = value;
+        self
+    }
+
+    /// Set [`timezone_conversion`](#structfield.timezone_conversion)
+    pub fn timezone_conversion(mut self, value: TimezoneConversion) -> Self {
+        self.timezone_conversion = value;
+        self
+    }
+
+    /// Set the timezone conversion of a single field, see
+    /// [`field_timezone_conversions`](#structfield.field_timezone_conversions)
+    pub fn field_timezone_conversion(mut self, path: &str, value: TimezoneConversion) -> Self {
+        self.field_timezone_conversions
+            .insert(path.to_owned(), value);
+        self
+    }
+
+    /// Set [`overflow`](#structfield.overflow)
+    pub fn overflow(mut self, value: Overflow) -> Self {
+        self.overflow = value;
+        self
+    }
+
+    /// Set [`rename`](#structfield.rename)
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use std::collections::HashMap;
+    ///
+    /// use arrow::datatypes::Field;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{schema::SchemaLike, utils::DeserializationOptions};
+    ///
+    /// ##[derive(Serialize, Deserialize)]
+    /// struct Written {
+    ///     user_id: u64,
+    /// }
+    ///
+    /// ##[derive(Debug, PartialEq, Deserialize)]
+    /// ##[allow(non_snake_case)]
+    /// struct Read {
+    ///     userId: u64,
+    /// }
+    ///
+    /// let fields = Vec::<Field>::from_type::<Written>(Default::default())?;
+    /// let arrays = serde_arrow::to_arrow(&fields, &[Written { user_id: 42 }])?;
+    ///
+    /// let options = DeserializationOptions::default().rename(HashMap::from([(
+    ///     String::from("user_id"),
+    ///     String::from("userId"),
+    /// )]));
+    /// let items: Vec<Read> = serde_arrow::from_arrow_with_options(&fields, &arrays, &options)?;
+    /// assert_eq!(items, vec![Read { userId: 42 }]);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn rename(mut self, value: HashMap<String, String>) -> Self {
+        self.rename = value;
+        self
+    }
+
+    /// Set [`human_readable`](#structfield.human_readable)
+    pub fn human_readable(mut self, value: bool) -> Self {
+        self.human_readable = value;
+        self
+    }
+
+    /// Set [`column_threads`](#structfield.column_threads)
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::Field;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{schema::SchemaLike, utils::DeserializationOptions};
+    ///
+    /// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Record {
+    ///     a: u32,
+    ///     b: Option<String>,
+    ///     c: Vec<f64>,
+    /// }
+    ///
+    /// let items = vec![
+    ///     Record { a: 1, b: Some(String::from("x")), c: vec![1.0] },
+    ///     Record { a: 2, b: None, c: vec![] },
+    /// ];
+    /// let fields = Vec::<Field>::from_type::<Record>(Default::default())?;
+    /// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+    ///
+    /// let options = DeserializationOptions::default().column_threads(4);
+    /// let actual: Vec<Record> = serde_arrow::from_arrow_with_options(&fields, &arrays, &options)?;
+    /// assert_eq!(actual, items);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn column_threads(mut self, value: usize) -> Self {
+        self.column_threads = value;
+        self
+    }
+
+    /// Set [`empty_lists`](#structfield.empty_lists)
+    pub fn empty_lists(mut self, value: EmptyListPolicy) -> Self {
+        self.empty_lists = value;
+        self
+    }
+
+    /// Report the metrics of each deserialization to the given callback, see
+    /// [`ConversionMetrics`]
+    ///
+    /// The metrics contain the number of rows, the time spent preparing the
+    /// arrays, and the time spent deserializing the items. The callback is
+    /// only invoked if the items were deserialized successfully.
+    pub fn on_metrics<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ConversionMetrics) + Send + Sync + 'static,
+    {
+        self.metrics = Some(MetricsCallback::new(callback));
+        self
+    }
+
+    /// Set [`cancellation`](#structfield.cancellation)
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Invoke the callback every `every` rows with the number of rows
+    /// deserialized so far, e.g., to render a progress bar
+    ///
+    /// The rows are counted once they are started. An `every` of `0` is
+    /// treated as `1`.
+    pub fn on_progress<F>(mut self, every: usize, callback: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.progress = Some(ProgressCallback::new(every, callback));
+        self
+    }
+
+    /// Apply [`rename`](#structfield.rename) to the top-level fields
+    pub(crate) fn rename_fields(&self, fields: &mut [GenericField]) {
+        for field in fields {
+            if let Some(name) = self.rename.get(&field.name) {
+                field.name = name.clone();
+            }
+        }
+    }
+}
+
+/// How to convert timestamps between timezone-naive and timezone-aware
+/// representations during deserialization
+///
+/// Timestamps with the strategies `NaiveStrAsDate64` or `UtcStrAsDate64` are
+/// deserialized as strings. Timezone-naive timestamps (without offset) can
+/// only be deserialized into timezone-naive types, e.g.,
+/// `chrono::NaiveDateTime`, and timezone-aware timestamps (in UTC) only into
+/// timezone-aware types, e.g., `chrono::DateTime<Utc>`. This option allows to
+/// convert between the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TimezoneConversion {
+    /// Deserialize timestamps in their stored representation (the default)
+    #[default]
+    None,
+    /// Interpret timezone-naive timestamps as UTC, e.g., to deserialize them
+    /// into `chrono::DateTime<Utc>`
+    AssumeUtc,
+    /// Drop the offset of timezone-aware timestamps, e.g., to deserialize them
+    /// into `chrono::NaiveDateTime`
+    DropOffset,
+}
+
+/// How to handle columns that are not declared by the target struct
+///
+/// The check is performed for types that are deserialized as structs, e.g.,
+/// types with `#[derive(Deserialize)]`. Maps and structs with flattened fields
+/// (`#[serde(flatten)]`) accept any column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum UnknownColumns {
+    /// Skip unknown columns (the default)
+    #[default]
+    Ignore,
+    /// Fail with an error naming the unknown column
+    Error,
+}
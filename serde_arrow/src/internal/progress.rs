@@ -0,0 +1,65 @@
+//! Progress reports of long running conversions
+use std::sync::Arc;
+
+/// A callback invoked every `every` rows with the number of rows converted so
+/// far
+///
+/// Set via
+/// [`SerializationOptions::on_progress`][crate::utils::SerializationOptions::on_progress]
+/// or
+/// [`DeserializationOptions::on_progress`][crate::utils::DeserializationOptions::on_progress].
+#[derive(Clone)]
+pub struct ProgressCallback {
+    every: usize,
+    func: Arc<dyn Fn(usize) + Send + Sync>,
+}
+
+impl ProgressCallback {
+    pub fn new<F: Fn(usize) + Send + Sync + 'static>(every: usize, func: F) -> Self {
+        Self {
+            every: every.max(1),
+            func: Arc::new(func),
+        }
+    }
+}
+
+impl std::fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressCallback")
+            .field("every", &self.every)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::cmp::PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        self.every == other.every && Arc::ptr_eq(&self.func, &other.func)
+    }
+}
+
+/// The rows counted for a progress callback
+pub struct Progress {
+    callback: ProgressCallback,
+    rows: usize,
+    next_report: usize,
+}
+
+impl Progress {
+    pub fn new(callback: ProgressCallback) -> Self {
+        let next_report = callback.every;
+        Self {
+            callback,
+            rows: 0,
+            next_report,
+        }
+    }
+
+    /// Count a row, invoking the callback every `every` rows
+    pub fn advance(&mut self) {
+        self.rows += 1;
+        if self.rows == self.next_report {
+            self.next_report += self.callback.every;
+            (self.callback.func)(self.rows);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Build `serde_arrow` schemas from protobuf descriptors
+//!
+use std::collections::HashMap;
+
+use prost_types::{
+    field_descriptor_proto::{Label, Type},
+    DescriptorProto, FieldDescriptorProto, FileDescriptorSet,
+};
+
+use crate::internal::{
+    error::{fail, Result},
+    schema::{to_upper_camel_case, GenericDataType, GenericField, SerdeArrowSchema},
+};
+
+impl SerdeArrowSchema {
+    /// Build a schema for a protobuf message (*requires the `prost` feature*)
+    ///
+    /// The schema describes the Rust types generated by `prost` for the
+    /// message, if they implement serde's traits (e.g., via derives added with
+    /// `type_attribute`). `message` is the full name of the message, e.g.,
+    /// `"my.package.Message"`. All referenced messages must be contained in
+    /// `files`. The types are mapped as follows:
+    ///
+    /// - `double`: `F64`, `float`: `F32`, `bool`: `Bool`, `string`: `LargeUtf8`
+    /// - `int32`, `sint32`, `sfixed32`: `I32`, `uint32`, `fixed32`: `U32`
+    /// - `int64`, `sint64`, `sfixed64`: `I64`, `uint64`, `fixed64`: `U64`
+    /// - enums: `I32`, as `prost` stores enums as `i32` values
+    /// - messages: nullable `Struct` fields, maps: `Map`
+    /// - repeated fields: `LargeList`
+    /// - optional fields: nullable fields
+    /// - oneofs: nullable `Union` fields named after the oneof, placed at the
+    ///   position of its first member. Note, that nullable unions are not yet
+    ///   supported in serialization
+    ///
+    /// Bytes, groups and recursive messages result in an error.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use prost_types::{
+    ///     field_descriptor_proto::{Label, Type},
+    ///     DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+    /// };
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// // message Point { double x = 1; repeated string tags = 2; }
+    /// let field = |name: &str, ty: Type, label: Label| FieldDescriptorProto {
+    ///     name: Some(name.to_owned()),
+    ///     r#type: Some(ty.into()),
+    ///     label: Some(label.into()),
+    ///     ..Default::default()
+    /// };
+    /// let files = FileDescriptorSet {
+    ///     file: vec![FileDescriptorProto {
+    ///         package: Some(String::from("example")),
+    ///         syntax: Some(String::from("proto3")),
+    ///         message_type: vec![DescriptorProto {
+    ///             name: Some(String::from("Point")),
+    ///             field: vec![
+    ///                 field("x", Type::Double, Label::Optional),
+    ///                 field("tags", Type::String, Label::Repeated),
+    ///             ],
+    ///             ..Default::default()
+    ///         }],
+    ///         ..Default::default()
+    ///     }],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     SerdeArrowSchema::from_protobuf(&files, "example.Point")?,
+    ///     SerdeArrowSchema::from_value(&serde_json::json!([
+    ///         {"name": "x", "data_type": "F64"},
+    ///         {"name": "tags", "data_type": "LargeList", "children": [
+    ///             {"name": "element", "data_type": "LargeUtf8"},
+    ///         ]},
+    ///     ]))?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_protobuf(files: &FileDescriptorSet, message: &str) -> Result<Self> {
+        let messages = collect_messages(files);
+        let name = format!(".{}", message.trim_start_matches('.'));
+        let Some((name, message)) = messages.get_key_value(&name) else {
+            fail!("Unknown protobuf message {name:?}");
+        };
+
+        let mut stack = vec![name.as_str()];
+        let fields = message_fields(&messages, &mut stack, message)?;
+        Ok(Self { fields })
+    }
+}
+
+/// A message together with the syntax of the file defining it
+struct Message<'a> {
+    descriptor: &'a DescriptorProto,
+    proto3: bool,
+}
+
+/// Collect all messages by their fully qualified name, e.g., `.pkg.Outer.Inner`
+fn collect_messages(files: &FileDescriptorSet) -> HashMap<String, Message<'_>> {
+    fn collect<'a>(
+        res: &mut HashMap<String, Message<'a>>,
+        prefix: &str,
+        proto3: bool,
+        descriptors: &'a [DescriptorProto],
+    ) {
+        for descriptor in descriptors {
+            let name = format!("{prefix}.{}", descriptor.name());
+            collect(res, &name, proto3, &descriptor.nested_type);
+            res.insert(name, Message { descriptor, proto3 });
+        }
+    }
+
+    let mut res = HashMap::new();
+    for file in &files.file {
+        let prefix = if file.package().is_empty() {
+            String::new()
+        } else {
+            format!(".{}", file.package())
+        };
+        collect(
+            &mut res,
+            &prefix,
+            file.syntax() == "proto3",
+            &file.message_type,
+        );
+    }
+    res
+}
+
+fn message_fields<'a>(
+    messages: &'a HashMap<String, Message<'a>>,
+    stack: &mut Vec<&'a str>,
+    message: &Message<'a>,
+) -> Result<Vec<GenericField>> {
+    let descriptor = message.descriptor;
+    let mut fields = Vec::new();
+    // the position of the union field of each oneof in `fields`
+    let mut oneofs = HashMap::<i32, usize>::new();
+
+    for field in &descriptor.field {
+        let oneof_index = match field.oneof_index {
+            // proto3 optional fields are wrapped in synthetic oneofs
+            Some(idx) if !field.proto3_optional() => idx,
+            _ => {
+                fields.push(build_field(messages, stack, message, field)?);
+                continue;
+            }
+        };
+
+        let mut variant = build_field(messages, stack, message, field)?;
+        variant.name = to_upper_camel_case(field.name());
+        variant.nullable = false;
+
+        if let Some(&pos) = oneofs.get(&oneof_index) {
+            fields[pos].children.push(variant);
+        } else {
+            let Some(oneof) = usize::try_from(oneof_index)
+                .ok()
+                .and_then(|idx| descriptor.oneof_decl.get(idx))
+            else {
+                fail!(
+                    "Invalid oneof index {oneof_index} of field {:?}",
+                    field.name()
+                );
+            };
+            oneofs.insert(oneof_index, fields.len());
+            fields.push(
+                GenericField::new(oneof.name(), GenericDataType::Union, true).with_child(variant),
+            );
+        }
+    }
+    Ok(fields)
+}
+
+fn build_field<'a>(
+    messages: &'a HashMap<String, Message<'a>>,
+    stack: &mut Vec<&'a str>,
+    message: &Message<'a>,
+    field: &FieldDescriptorProto,
+) -> Result<GenericField> {
+    let name = field.name();
+    let data_type = match field.r#type() {
+        Type::Double => GenericDataType::F64,
+        Type::Float => GenericDataType::F32,
+        Type::Int64 | Type::Sint64 | Type::Sfixed64 => GenericDataType::I64,
+        Type::Uint64 | Type::Fixed64 => GenericDataType::U64,
+        Type::Int32 | Type::Sint32 | Type::Sfixed32 | Type::Enum => GenericDataType::I32,
+        Type::Uint32 | Type::Fixed32 => GenericDataType::U32,
+        Type::Bool => GenericDataType::Bool,
+        Type::String => GenericDataType::LargeUtf8,
+        Type::Message => return build_message_field(messages, stack, field),
+        Type::Bytes | Type::Group => fail!(
+            "Cannot convert protobuf field {name:?} of type {}",
+            field.r#type().as_str_name()
+        ),
+    };
+
+    if field.label() == Label::Repeated {
+        return Ok(repeated(
+            name,
+            GenericField::new("element", data_type, false),
+        ));
+    }
+    let nullable = field.proto3_optional() || (!message.proto3 && field.label() == Label::Optional);
+    Ok(GenericField::new(name, data_type, nullable))
+}
+
+fn build_message_field<'a>(
+    messages: &'a HashMap<String, Message<'a>>,
+    stack: &mut Vec<&'a str>,
+    field: &FieldDescriptorProto,
+) -> Result<GenericField> {
+    let name = field.name();
+    let Some((type_name, message)) = messages.get_key_value(field.type_name()) else {
+        fail!(
+            "Unknown protobuf message {:?} of field {name:?}",
+            field.type_name()
+        );
+    };
+    if stack.contains(&type_name.as_str()) {
+        fail!("Cannot convert the recursive protobuf message {type_name:?} of field {name:?}");
+    }
+
+    stack.push(type_name);
+    let children = message_fields(messages, stack, message);
+    stack.pop();
+    let children = children?;
+
+    let is_map_entry = message
+        .descriptor
+        .options
+        .as_ref()
+        .map(|options| options.map_entry())
+        .unwrap_or_default();
+    if is_map_entry {
+        let mut entries = GenericField::new("entries", GenericDataType::Struct, false);
+        // prost stores map values directly, i.e., message values are not optional
+        for (child, name) in children.into_iter().zip(["key", "value"]) {
+            entries.children.push(GenericField {
+                name: name.to_owned(),
+                nullable: false,
+                ..child
+            });
+        }
+        return Ok(GenericField::new(name, GenericDataType::Map, false).with_child(entries));
+    }
+
+    let mut inner = GenericField::new(name, GenericDataType::Struct, true);
+    inner.children = children;
+    if field.label() == Label::Repeated {
+        inner.name = String::from("element");
+        inner.nullable = false;
+        return Ok(repeated(name, inner));
+    }
+    Ok(inner)
+}
+
+fn repeated(name: &str, item: GenericField) -> GenericField {
+    GenericField::new(name, GenericDataType::LargeList, false).with_child(item)
+}
@@ -0,0 +1,84 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use serde::de::DeserializeOwned;
+
+use crate::internal::{
+    deserialization::Interpreter,
+    error::{fail, Result},
+    options::DeserializationOptions,
+    source::deserialize_from_source_with_options,
+};
+
+/// A view over the rows of arrays, that deserializes each row on demand
+///
+/// The deserialization is compiled once when the view is created. Each call
+/// to [`get`][RowsView::get] moves the compiled program to the requested row
+/// and deserializes only this row. Therefore, accessing a few rows of a large
+/// batch does not process the remaining rows. To deserialize all rows, the
+/// bulk functions, e.g., [`from_arrow`][crate::from_arrow], are faster.
+///
+/// See [`from_arrow_view`][crate::from_arrow_view] and
+/// [`from_arrow2_view`][crate::from_arrow2_view].
+pub struct RowsView<'a, T> {
+    len: usize,
+    interpreter: RefCell<Interpreter<'a>>,
+    options: DeserializationOptions,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> RowsView<'a, T> {
+    pub(crate) fn new(
+        len: usize,
+        interpreter: Interpreter<'a>,
+        options: DeserializationOptions,
+    ) -> Self {
+        Self {
+            len,
+            interpreter: RefCell::new(interpreter),
+            options,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The number of rows
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the view does not contain any rows
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, T: DeserializeOwned> RowsView<'a, T> {
+    /// Deserialize the row with the given index
+    pub fn get(&self, idx: usize) -> Result<T> {
+        if idx >= self.len {
+            fail!(
+                "Cannot get row {idx} of a view with {len} rows",
+                len = self.len
+            );
+        }
+
+        let mut interpreter = self.interpreter.borrow_mut();
+        interpreter.seek(idx)?;
+        let mut items: Vec<T> =
+            deserialize_from_source_with_options(&mut *interpreter, &self.options)?;
+        let Some(item) = items.pop() else {
+            fail!("Could not deserialize row {idx}");
+        };
+        Ok(item)
+    }
+
+    /// Deserialize the rows in order
+    pub fn iter(&self) -> Box<dyn Iterator<Item = Result<T>> + '_> {
+        Box::new((0..self.len).map(|idx| self.get(idx)))
+    }
+}
+
+impl<'a, T> std::fmt::Debug for RowsView<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowsView").field("len", &self.len).finish()
+    }
+}
@@ -1,21 +1,67 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     str::FromStr,
 };
 
 use crate::internal::{
     error::{fail, Error, Result},
+    event::Event,
     tracing::{Tracer, TracingMode, TracingOptions},
 };
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(has_arrow)]
+use crate::_impl::arrow::datatypes::Field as ArrowField;
+
 /// The metadata key under which to store the strategy
 ///
 /// See the [module][crate::schema] for details.
 ///
 pub const STRATEGY_KEY: &str = "SERDE_ARROW:strategy";
 
+/// The metadata key under which to store the value used for nulls
+///
+/// When a null value of a field with this metadata entry is deserialized into
+/// a non-`Option` type, the value is used instead. It is parsed according to
+/// the data type of the field, e.g., `"0"` for integers or `"unknown"` for
+/// strings. Only fields with a boolean, numeric, or string type support null
+/// defaults.
+///
+pub const NULL_DEFAULT_KEY: &str = "SERDE_ARROW:null_default";
+
+/// The metadata key under which to store layout hints gathered during tracing
+///
+/// The hints are only informative, e.g., for Parquet writers to choose the
+/// encoding of a column. They do not affect serialization or deserialization.
+/// The value is a comma separated list of the entries
+///
+/// - `distinct=<n>`: the number of distinct non-null values observed, only
+///   included for up to 1024 distinct values
+/// - `low_cardinality`: on average each observed value is repeated at least
+///   ten times, i.e., the column is suitable for dictionary encoding
+/// - `sorted`: the observed non-null values are in ascending order, i.e., the
+///   column is suitable for delta encoding
+///
+/// See [`TracingOptions::layout_hints`][crate::schema::TracingOptions#structfield.layout_hints].
+pub const LAYOUT_HINTS_KEY: &str = "SERDE_ARROW:layout_hints";
+
+/// The metadata key under which Arrow stores the name of extension types
+pub(crate) const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The metadata key under which Arrow stores the parameters of extension types
+pub(crate) const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// The name of the canonical fixed shape tensor extension type
+pub(crate) const FIXED_SHAPE_TENSOR: &str = "arrow.fixed_shape_tensor";
+
+/// The name of the extension type of `bf16` values stored as their bits in
+/// `UInt16` fields
+pub(crate) const BFLOAT16: &str = "serde_arrow.bfloat16";
+
+/// The maximum precision of `Decimal128` fields
+pub(crate) const MAX_DECIMAL128_PRECISION: u8 = 38;
+
 pub trait Sealed {}
 
 /// A sealed trait to add support for constructing schema-like objects
@@ -101,8 +147,15 @@ pub trait SchemaLike: Sized + Sealed {
     /// - unsigned integers: `"U8"`, `"U16"`, `"U32"`, `"U64"`
     /// - floats: `"F16"`, `"F32"`, `"F64"`
     /// - strings: `"Utf8"`, `"LargeUtf8"`
+    /// - decimals: `"Decimal128(precision, scale)"`, e.g., `"Decimal128(10, 2)"`
     /// - lists: `"List"`, `"LargeList"`. `"children"` must contain a single
     ///   field named `"element"` that describes the element types
+    /// - fixed size lists: `"FixedSizeList(n)"`, e.g., `"FixedSizeList(3)"`.
+    ///   `"children"` must contain a single field that describes the element
+    ///   types. They can be deserialized into sequences, e.g., `Vec<T>` or
+    ///   `smallvec::SmallVec`, and into arrays `[T; n]`, tuples or
+    ///   `nalgebra::SVector<T, n>` (with nalgebra's `serde-serialize-no-std`
+    ///   feature) of matching length
     /// - structs: `"Struct"`. `"children"` must contain the child fields
     /// - maps: `"Map"`. `"children"` must contain two fields, named `"key"` and
     ///   `"value"` that encode the key and value types
@@ -280,6 +333,157 @@ impl SerdeArrowSchema {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Render the schema as an indented tree
+    ///
+    /// Each field is shown with its data type, its nullability, its strategy
+    /// and its null default. Nested fields are indented below their parent.
+    /// The same representation is used by the `Display` implementation.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "a", "data_type": "U8", "nullable": true},
+    ///     {
+    ///         "name": "b",
+    ///         "data_type": "Struct",
+    ///         "children": [
+    ///             {"name": "c", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+    ///             {"name": "d", "data_type": "Utf8"},
+    ///         ],
+    ///     },
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     schema.to_tree_string(),
+    ///     concat!(
+    ///         "a: U8 (nullable)\n",
+    ///         "b: Struct\n",
+    ///         "├── c: Date64 (strategy: UtcStrAsDate64)\n",
+    ///         "└── d: Utf8\n",
+    ///     ),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_tree_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// The Rust types the columns of this schema deserialize into
+    ///
+    /// For each top-level field the name and the Rust type is returned. The
+    /// types are given as Rust source code, e.g., to help writing structs
+    /// matching existing arrays or to generate them. Nullable fields are
+    /// wrapped in an `Option`, unless they have a null default. Structs and
+    /// unions have no predefined Rust type, they are given a name derived from
+    /// the field name that needs to be defined by the user.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "id", "data_type": "I64", "nullable": true},
+    ///     {"name": "tags", "data_type": "LargeList", "children": [
+    ///         {"name": "element", "data_type": "LargeUtf8"},
+    ///     ]},
+    ///     {"name": "scores", "data_type": "Map", "children": [
+    ///         {"name": "entries", "data_type": "Struct", "children": [
+    ///             {"name": "key", "data_type": "Utf8"},
+    ///             {"name": "value", "data_type": "F64"},
+    ///         ]},
+    ///     ]},
+    ///     {"name": "last_point", "data_type": "Struct", "children": [
+    ///         {"name": "x", "data_type": "F32"},
+    ///         {"name": "y", "data_type": "F32"},
+    ///     ]},
+    /// ]))?;
+    ///
+    /// assert_eq!(
+    ///     schema.to_rust_types()?,
+    ///     vec![
+    ///         (String::from("id"), String::from("Option<i64>")),
+    ///         (String::from("tags"), String::from("Vec<String>")),
+    ///         (String::from("scores"), String::from("HashMap<String, f64>")),
+    ///         (String::from("last_point"), String::from("LastPoint")),
+    ///     ],
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// To inspect existing arrays, build the schema from their fields first,
+    /// e.g., with [`SchemaLike::from_value`] or the `TryFrom` implementations
+    /// for the fields of the enabled Arrow implementations.
+    pub fn to_rust_types(&self) -> Result<Vec<(String, String)>> {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), field.to_rust_type(&field.name)?)))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for SerdeArrowSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for field in &self.fields {
+            writeln!(f, "{}", FieldSummary(field))?;
+            fmt_children(f, field, "")?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_children(
+    f: &mut std::fmt::Formatter<'_>,
+    field: &GenericField,
+    prefix: &str,
+) -> std::fmt::Result {
+    for (idx, child) in field.children.iter().enumerate() {
+        let is_last = idx + 1 == field.children.len();
+        let (connector, indent) = if is_last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        writeln!(f, "{prefix}{connector}{}", FieldSummary(child))?;
+        fmt_children(f, child, &format!("{prefix}{indent}"))?;
+    }
+    Ok(())
+}
+
+/// Format a single field as `name: data_type (annotations)`
+struct FieldSummary<'a>(&'a GenericField);
+
+impl<'a> std::fmt::Display for FieldSummary<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let field = self.0;
+        write!(f, "{}: {}", field.name, field.data_type)?;
+
+        let mut annotations = Vec::new();
+        if field.nullable {
+            annotations.push(String::from("nullable"));
+        }
+        if let Some(strategy) = field.strategy.as_ref() {
+            annotations.push(format!("strategy: {strategy}"));
+        }
+        if let Some(null_default) = field.null_default.as_ref() {
+            annotations.push(format!("null default: {null_default:?}"));
+        }
+        if let Some(extension_name) = field.extension_name.as_ref() {
+            annotations.push(format!("extension: {extension_name}"));
+        }
+        if let Some(layout_hints) = field.layout_hints.as_ref() {
+            annotations.push(format!("layout hints: {layout_hints}"));
+        }
+
+        if !annotations.is_empty() {
+            write!(f, " ({})", annotations.join(", "))?;
+        }
+        Ok(())
+    }
 }
 
 impl Sealed for SerdeArrowSchema {}
@@ -310,6 +514,43 @@ impl SchemaLike for SerdeArrowSchema {
     }
 }
 
+/// Support for arrow types (*requires one of the `arrow-*` features*)
+///
+/// The methods use the highest enabled arrow version. For other versions use
+/// the `TryFrom` implementations between [`SerdeArrowSchema`] and the fields.
+#[cfg(has_arrow)]
+impl SerdeArrowSchema {
+    /// Build a new Schema object from fields
+    pub fn from_arrow_fields(fields: &[ArrowField]) -> Result<Self> {
+        Self::try_from(fields)
+    }
+
+    /// This method is deprecated. Use
+    /// [`to_arrow_fields`][SerdeArrowSchema::to_arrow_fields] instead:
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// # use serde_arrow::schema::{SerdeArrowSchema, SchemaLike, TracingOptions};
+    /// # #[derive(serde::Deserialize)]
+    /// # struct Item { a: u32 }
+    /// # let schema = SerdeArrowSchema::from_type::<Item>(TracingOptions::default()).unwrap();
+    /// # let fields =
+    /// schema.to_arrow_fields()?
+    /// # ;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[deprecated = "The method `get_arrow_fields` is deprecated. Use `to_arrow_fields` instead"]
+    pub fn get_arrow_fields(&self) -> Result<Vec<ArrowField>> {
+        self.to_arrow_fields()
+    }
+
+    /// Build a vec of fields from a Schema object
+    pub fn to_arrow_fields(&self) -> Result<Vec<ArrowField>> {
+        self.fields.iter().map(ArrowField::try_from).collect()
+    }
+}
+
 /// Strategies for handling types without direct match between arrow and serde
 ///
 /// For the correct strategy both the field type and the field metadata must be
@@ -328,19 +569,28 @@ pub enum Strategy {
     ///
     /// This strategy makes sense for chrono's `DateTime<Utc>` types without
     /// additional configuration. As they are serialized as strings.
+    ///
+    /// When deserializing into integers, e.g., `i64` or `u64`, the raw value
+    /// of the column is returned without formatting it as a string. Its unit
+    /// is given by the data type of the field.
     UtcStrAsDate64,
     /// Serialize Rust strings containing datetimes without timezone as Arrow
     /// Date64
     ///
     /// This strategy makes sense for chrono's `NaiveDateTime` types without
-    /// additional configuration. As they are serialized as strings.
+    /// additional configuration. As they are serialized as strings. As for
+    /// [`UtcStrAsDate64`][Strategy::UtcStrAsDate64], integers are deserialized
+    /// from the raw value of the column.
     ///
     NaiveStrAsDate64,
     /// Serialize Rust tuples as Arrow structs with numeric field names starting
     /// at `"0"`
     ///
     /// This strategy is most-likely the most optimal one, as Rust tuples can
-    /// contain different types, whereas Arrow sequences must be of uniform type
+    /// contain different types, whereas Arrow sequences must be of uniform type.
+    /// The fields are matched by position, therefore the numeric names can be
+    /// replaced by arbitrary names, e.g., with
+    /// [`TracingOptions::tuple_field_names`][crate::schema::TracingOptions::tuple_field_names].
     ///
     TupleAsStruct,
     /// Serialize Rust maps as Arrow structs
@@ -365,6 +615,24 @@ pub enum Strategy {
     /// serialization or deserialization of such a field is attempted, it will
     /// result in an error.
     UnknownVariant,
+    /// Coerce numbers between integer and float types during serialization
+    ///
+    /// This strategy applies only to fields with numeric data types. For
+    /// integer fields, floats with integral values are accepted (e.g., `3.0`
+    /// is written as `3`), whereas floats with fractional parts result in an
+    /// error. For float fields, integers are accepted and converted with a
+    /// possible loss of precision. This strategy may be helpful when ingesting
+    /// data formats that do not distinguish between integers and floats, e.g.,
+    /// JSON.
+    NumericCoercion,
+    /// Serialize numbers as strings into Utf8 or LargeUtf8 fields
+    ///
+    /// This strategy applies only to fields with data type Utf8 or LargeUtf8.
+    /// During serialization, integers and floats are written as strings, with
+    /// floats always containing a decimal point or an exponent. During
+    /// deserialization, the strings are parsed back into numbers. This strategy may be helpful when targeting consumers
+    /// that would lose precision for 64 bit integers, e.g., JavaScript.
+    NumberAsStr,
 }
 
 impl std::fmt::Display for Strategy {
@@ -376,6 +644,8 @@ impl std::fmt::Display for Strategy {
             Self::TupleAsStruct => write!(f, "TupleAsStruct"),
             Self::MapAsStruct => write!(f, "MapAsStruct"),
             Self::UnknownVariant => write!(f, "UnknownVariant"),
+            Self::NumericCoercion => write!(f, "NumericCoercion"),
+            Self::NumberAsStr => write!(f, "NumberAsStr"),
         }
     }
 }
@@ -391,6 +661,8 @@ impl FromStr for Strategy {
             "TupleAsStruct" => Ok(Self::TupleAsStruct),
             "MapAsStruct" => Ok(Self::MapAsStruct),
             "UnknownVariant" => Ok(Self::UnknownVariant),
+            "NumericCoercion" => Ok(Self::NumericCoercion),
+            "NumberAsStr" => Ok(Self::NumberAsStr),
             _ => fail!("Unknown strategy {s}"),
         }
     }
@@ -412,7 +684,248 @@ impl From<Strategy> for HashMap<String, String> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+/// The canonical `arrow.fixed_shape_tensor` extension type
+///
+/// Fields with this extension store a tensor of the given shape per row as a
+/// `FixedSizeList` in row-major order. The size of the list must be equal to
+/// the product of the shape. `serde_arrow` serializes sequences and arrays,
+/// e.g., `[f32; 6]` or `Vec<f32>`, into these fields and deserializes them
+/// back as flat sequences. As with strategies, the extension is attached to
+/// the metadata of the field:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use std::sync::Arc;
+///
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::FixedShapeTensor;
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     matrix: [f32; 6],
+/// }
+///
+/// let element = Arc::new(Field::new("element", DataType::Float32, false));
+/// let fields = vec![
+///     Field::new("matrix", DataType::FixedSizeList(element, 6), false)
+///         .with_metadata(FixedShapeTensor::new(&[2, 3]).into()),
+/// ];
+///
+/// let records = vec![Record { matrix: [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] }];
+/// let arrays = serde_arrow::to_arrow(&fields, &records)?;
+///
+/// let records: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(records[0].matrix, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FixedShapeTensor {
+    /// The shape of the tensors
+    pub shape: Vec<usize>,
+}
+
+impl FixedShapeTensor {
+    /// Construct the extension for tensors of the given shape
+    pub fn new(shape: &[usize]) -> Self {
+        Self {
+            shape: shape.to_vec(),
+        }
+    }
+
+    fn to_metadata(&self) -> [(String, String); 2] {
+        let shape = self
+            .shape
+            .iter()
+            .map(|dim| dim.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        [
+            (
+                EXTENSION_NAME_KEY.to_string(),
+                FIXED_SHAPE_TENSOR.to_string(),
+            ),
+            (
+                EXTENSION_METADATA_KEY.to_string(),
+                format!("{{\"shape\":[{shape}]}}"),
+            ),
+        ]
+    }
+}
+
+impl From<FixedShapeTensor> for BTreeMap<String, String> {
+    fn from(value: FixedShapeTensor) -> Self {
+        value.to_metadata().into_iter().collect()
+    }
+}
+
+impl From<FixedShapeTensor> for HashMap<String, String> {
+    fn from(value: FixedShapeTensor) -> Self {
+        value.to_metadata().into_iter().collect()
+    }
+}
+
+/// Extract the shape from the metadata of a fixed shape tensor, e.g.,
+/// `{"shape":[2,3]}`
+///
+/// The metadata is a JSON object, other keys (`dim_names`, `permutation`) are
+/// skipped.
+fn parse_tensor_shape(metadata: &str) -> Result<Vec<usize>> {
+    let mut parser = TensorMetadataParser {
+        input: metadata.as_bytes(),
+        pos: 0,
+    };
+    match parser.parse() {
+        Some(Some(shape)) => Ok(shape),
+        Some(None) => fail!("Missing shape in fixed shape tensor metadata {metadata:?}"),
+        None => fail!("Invalid fixed shape tensor metadata {metadata:?}: expected a JSON object with a shape of non-negative integers"),
+    }
+}
+
+/// A minimal JSON parser for the metadata of fixed shape tensors
+struct TensorMetadataParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> TensorMetadataParser<'a> {
+    /// Parse the metadata, `None` if invalid and `Some(None)` without shape
+    fn parse(&mut self) -> Option<Option<Vec<usize>>> {
+        let mut shape = None;
+        self.expect(b'{')?;
+        if !self.consume(b'}') {
+            loop {
+                let key = self.parse_string()?;
+                self.expect(b':')?;
+                if key == "shape" {
+                    shape = Some(self.parse_shape()?);
+                } else {
+                    self.skip_value()?;
+                }
+                if self.consume(b'}') {
+                    break;
+                }
+                self.expect(b',')?;
+            }
+        }
+        self.skip_whitespace();
+        if self.pos != self.input.len() {
+            return None;
+        }
+        Some(shape)
+    }
+
+    fn parse_shape(&mut self) -> Option<Vec<usize>> {
+        let mut shape = Vec::new();
+        self.expect(b'[')?;
+        if self.consume(b']') {
+            return Some(shape);
+        }
+        loop {
+            self.skip_whitespace();
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            let digits = std::str::from_utf8(&self.input[start..self.pos]).ok()?;
+            shape.push(digits.parse().ok()?);
+
+            if self.consume(b']') {
+                return Some(shape);
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    /// Parse a string, escaped characters are kept as is
+    fn parse_string(&mut self) -> Option<&'a str> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        loop {
+            match self.peek()? {
+                b'"' => break,
+                b'\\' => self.pos += 2,
+                _ => self.pos += 1,
+            }
+        }
+        let res = std::str::from_utf8(self.input.get(start..self.pos)?).ok()?;
+        self.pos += 1;
+        Some(res)
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'"' => {
+                self.parse_string()?;
+            }
+            open @ (b'[' | b'{') => {
+                let close = if open == b'[' { b']' } else { b'}' };
+                self.pos += 1;
+                if self.consume(close) {
+                    return Some(());
+                }
+                loop {
+                    if open == b'{' {
+                        self.parse_string()?;
+                        self.expect(b':')?;
+                    }
+                    self.skip_value()?;
+                    if self.consume(close) {
+                        break;
+                    }
+                    self.expect(b',')?;
+                }
+            }
+            _ => {
+                // numbers and literals
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'+' | b'.'))
+                {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return None;
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        self.consume(c).then_some(())
+    }
+
+    fn consume(&mut self, c: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
 pub enum GenericTimeUnit {
     Second,
     Millisecond,
@@ -457,6 +970,8 @@ pub enum GenericDataType {
     Map,
     Dictionary,
     Timestamp(GenericTimeUnit, Option<String>),
+    Decimal128(u8, i8),
+    FixedSizeList(i32),
 }
 
 impl std::fmt::Display for GenericDataType {
@@ -492,6 +1007,8 @@ impl std::fmt::Display for GenericDataType {
                     write!(f, "Timestamp({unit}, None)")
                 }
             }
+            Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale})"),
+            FixedSizeList(n) => write!(f, "FixedSizeList({n})"),
         }
     }
 }
@@ -574,6 +1091,22 @@ impl std::str::FromStr for GenericDataType {
             };
 
             Ok(GenericDataType::Timestamp(unit, Some(s.to_string())))
+        } else if let Some(s) = s.strip_prefix("Decimal128(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("expected closing parenthesis, found: {s:?}");
+            };
+            let Some((precision, scale)) = s.split_once(',') else {
+                fail!("expected precision and scale, found: {s:?}");
+            };
+            let precision = precision.trim().parse::<u8>()?;
+            let scale = scale.trim().parse::<i8>()?;
+
+            Ok(GenericDataType::Decimal128(precision, scale))
+        } else if let Some(s) = s.strip_prefix("FixedSizeList(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("expected closing parenthesis, found: {s:?}");
+            };
+            Ok(GenericDataType::FixedSizeList(s.trim().parse::<i32>()?))
         } else {
             fail!("cannot parse data type")
         }
@@ -610,6 +1143,22 @@ pub struct GenericField {
     #[serde(skip_serializing_if = "is_false")]
     pub nullable: bool,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub null_default: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_name: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_metadata: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout_hints: Option<String>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<GenericField>,
@@ -627,6 +1176,10 @@ impl GenericField {
             nullable,
             children: Vec::new(),
             strategy: None,
+            null_default: None,
+            extension_name: None,
+            extension_metadata: None,
+            layout_hints: None,
         }
     }
 
@@ -635,22 +1188,24 @@ impl GenericField {
     }
 
     pub fn validate(&self) -> Result<()> {
+        self.validate_null_default()?;
+        self.validate_extension()?;
         match self.data_type {
             GenericDataType::Null => self.validate_null(),
             GenericDataType::Bool => self.validate_primitive(),
-            GenericDataType::U8 => self.validate_primitive(),
-            GenericDataType::U16 => self.validate_primitive(),
-            GenericDataType::U32 => self.validate_primitive(),
-            GenericDataType::U64 => self.validate_primitive(),
-            GenericDataType::I8 => self.validate_primitive(),
-            GenericDataType::I16 => self.validate_primitive(),
-            GenericDataType::I32 => self.validate_primitive(),
-            GenericDataType::I64 => self.validate_primitive(),
-            GenericDataType::F16 => self.validate_primitive(),
-            GenericDataType::F32 => self.validate_primitive(),
-            GenericDataType::F64 => self.validate_primitive(),
-            GenericDataType::Utf8 => self.validate_primitive(),
-            GenericDataType::LargeUtf8 => self.validate_primitive(),
+            GenericDataType::U8 => self.validate_number(),
+            GenericDataType::U16 => self.validate_number(),
+            GenericDataType::U32 => self.validate_number(),
+            GenericDataType::U64 => self.validate_number(),
+            GenericDataType::I8 => self.validate_number(),
+            GenericDataType::I16 => self.validate_number(),
+            GenericDataType::I32 => self.validate_number(),
+            GenericDataType::I64 => self.validate_number(),
+            GenericDataType::F16 => self.validate_number(),
+            GenericDataType::F32 => self.validate_number(),
+            GenericDataType::F64 => self.validate_number(),
+            GenericDataType::Utf8 => self.validate_utf8(),
+            GenericDataType::LargeUtf8 => self.validate_utf8(),
             GenericDataType::Date64 => self.validate_date64(),
             GenericDataType::Struct => self.validate_struct(),
             GenericDataType::Map => self.validate_map(),
@@ -659,6 +1214,8 @@ impl GenericField {
             GenericDataType::Union => self.validate_union(),
             GenericDataType::Dictionary => self.validate_dictionary(),
             GenericDataType::Timestamp(_, _) => self.validate_timestamp(),
+            GenericDataType::Decimal128(_, _) => self.validate_decimal128(),
+            GenericDataType::FixedSizeList(_) => self.validate_fixed_size_list(),
         }
     }
 
@@ -695,9 +1252,245 @@ impl GenericField {
         self.strategy = strategy;
         self
     }
+
+    pub fn with_null_default(mut self, value: &str) -> Self {
+        self.null_default = Some(value.to_owned());
+        self
+    }
+
+    /// Build the metadata storing the strategy, the null default, the
+    /// extension type and the layout hints
+    pub(crate) fn get_metadata<M: Default + Extend<(String, String)>>(&self) -> M {
+        let mut res = M::default();
+        if let Some(strategy) = self.strategy.as_ref() {
+            res.extend([(STRATEGY_KEY.to_string(), strategy.to_string())]);
+        }
+        if let Some(null_default) = self.null_default.as_ref() {
+            res.extend([(NULL_DEFAULT_KEY.to_string(), null_default.clone())]);
+        }
+        if let Some(extension_name) = self.extension_name.as_ref() {
+            res.extend([(EXTENSION_NAME_KEY.to_string(), extension_name.clone())]);
+        }
+        if let Some(extension_metadata) = self.extension_metadata.as_ref() {
+            res.extend([(
+                EXTENSION_METADATA_KEY.to_string(),
+                extension_metadata.clone(),
+            )]);
+        }
+        if let Some(layout_hints) = self.layout_hints.as_ref() {
+            res.extend([(LAYOUT_HINTS_KEY.to_string(), layout_hints.clone())]);
+        }
+        res
+    }
+
+    /// Parse the null default into the event emitted in place of nulls
+    pub(crate) fn get_null_default_event(&self) -> Result<Option<Event<'static>>> {
+        use GenericDataType as D;
+
+        let Some(value) = self.null_default.as_deref() else {
+            return Ok(None);
+        };
+        let event = match &self.data_type {
+            D::Bool => Event::Bool(parse_null_default(value, &self.data_type)?),
+            D::I8 => Event::I8(parse_null_default(value, &self.data_type)?),
+            D::I16 => Event::I16(parse_null_default(value, &self.data_type)?),
+            D::I32 => Event::I32(parse_null_default(value, &self.data_type)?),
+            D::I64 => Event::I64(parse_null_default(value, &self.data_type)?),
+            D::U8 => Event::U8(parse_null_default(value, &self.data_type)?),
+            D::U16 => Event::U16(parse_null_default(value, &self.data_type)?),
+            D::U32 => Event::U32(parse_null_default(value, &self.data_type)?),
+            D::U64 => Event::U64(parse_null_default(value, &self.data_type)?),
+            D::F16 | D::F32 => Event::F32(parse_null_default(value, &self.data_type)?),
+            D::F64 => Event::F64(parse_null_default(value, &self.data_type)?),
+            D::Utf8 | D::LargeUtf8 | D::Dictionary => Event::OwnedStr(value.to_owned()),
+            dt => fail!("Null defaults are not supported for fields of type {dt}"),
+        };
+        Ok(Some(event))
+    }
+}
+
+impl GenericField {
+    /// The Rust type this field deserializes into
+    ///
+    /// `name` is used to derive the names of structs and unions. For the
+    /// children of lists, maps and dictionaries, the name of the parent is
+    /// used, as their own names (e.g., `"element"`) carry no meaning.
+    pub(crate) fn to_rust_type(&self, name: &str) -> Result<String> {
+        use GenericDataType as D;
+
+        let ty = match &self.data_type {
+            D::Null => return Ok(String::from("()")),
+            D::Bool => String::from("bool"),
+            D::I8 => String::from("i8"),
+            D::I16 => String::from("i16"),
+            D::I32 => String::from("i32"),
+            D::I64 => String::from("i64"),
+            D::U8 => String::from("u8"),
+            D::U16 => String::from("u16"),
+            D::U32 => String::from("u32"),
+            D::U64 => String::from("u64"),
+            D::F16 | D::F32 => String::from("f32"),
+            D::F64 => String::from("f64"),
+            D::Utf8 | D::LargeUtf8 | D::Decimal128(_, _) => String::from("String"),
+            D::Date64 | D::Timestamp(_, _) => match self.strategy.as_ref() {
+                Some(Strategy::UtcStrAsDate64) => String::from("chrono::DateTime<chrono::Utc>"),
+                Some(Strategy::NaiveStrAsDate64) => String::from("chrono::NaiveDateTime"),
+                _ => String::from("i64"),
+            },
+            D::List | D::LargeList | D::FixedSizeList(_) => {
+                let Some(item) = self.children.first() else {
+                    fail!("List field {:?} without item field", self.name);
+                };
+                format!("Vec<{}>", item.to_rust_type(name)?)
+            }
+            D::Map => {
+                let Some([key, value]) = self.children.first().map(|entries| &entries.children[..])
+                else {
+                    fail!("Map field {:?} without key and value fields", self.name);
+                };
+                format!(
+                    "HashMap<{}, {}>",
+                    key.to_rust_type(&format!("{name}_key"))?,
+                    value.to_rust_type(&format!("{name}_value"))?,
+                )
+            }
+            D::Dictionary => {
+                let Some(values) = self.children.get(1) else {
+                    fail!("Dictionary field {:?} without value field", self.name);
+                };
+                values.to_rust_type(name)?
+            }
+            D::Struct => match self.strategy.as_ref() {
+                Some(Strategy::TupleAsStruct) => {
+                    let items = self
+                        .children
+                        .iter()
+                        .map(|child| child.to_rust_type(&format!("{name}_{}", child.name)))
+                        .collect::<Result<Vec<_>>>()?;
+                    if items.len() == 1 {
+                        format!("({},)", items[0])
+                    } else {
+                        format!("({})", items.join(", "))
+                    }
+                }
+                Some(Strategy::MapAsStruct) => {
+                    let values = self
+                        .children
+                        .iter()
+                        .map(|child| child.to_rust_type(&format!("{name}_value")))
+                        .collect::<Result<HashSet<_>>>()?;
+                    // maps require values of a uniform type
+                    if values.len() == 1 {
+                        let value = values.into_iter().next().unwrap();
+                        format!("HashMap<String, {value}>")
+                    } else {
+                        to_upper_camel_case(name)
+                    }
+                }
+                _ => to_upper_camel_case(name),
+            },
+            D::Union => to_upper_camel_case(name),
+        };
+
+        if self.nullable && self.null_default.is_none() {
+            Ok(format!("Option<{ty}>"))
+        } else {
+            Ok(ty)
+        }
+    }
+}
+
+/// Convert a field name, e.g., `last_point`, into a type name, e.g.,
+/// `LastPoint`
+pub(crate) fn to_upper_camel_case(name: &str) -> String {
+    let mut res = String::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            res.extend(first.to_uppercase());
+            res.extend(chars);
+        }
+    }
+    if !res.starts_with(|c: char| c.is_alphabetic()) {
+        res.insert(0, 'T');
+    }
+    res
+}
+
+fn parse_null_default<T: FromStr>(value: &str, data_type: &GenericDataType) -> Result<T> {
+    match value.parse() {
+        Ok(value) => Ok(value),
+        Err(_) => fail!("Cannot parse null default {value:?} as {data_type}"),
+    }
 }
 
 impl GenericField {
+    pub(crate) fn validate_extension(&self) -> Result<()> {
+        match self.extension_name.as_deref() {
+            Some(FIXED_SHAPE_TENSOR) => self.validate_fixed_shape_tensor(),
+            Some(BFLOAT16) => self.validate_bfloat16(),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_bfloat16(&self) -> Result<()> {
+        if self.data_type != GenericDataType::U16 {
+            fail!(
+                "The extension {BFLOAT16} of field {:?} requires a UInt16 field, found {}",
+                self.name,
+                self.data_type,
+            );
+        }
+        Ok(())
+    }
+
+    fn validate_fixed_shape_tensor(&self) -> Result<()> {
+        let GenericDataType::FixedSizeList(n) = self.data_type else {
+            fail!(
+                "The extension {FIXED_SHAPE_TENSOR} of field {:?} requires a FixedSizeList, found {}",
+                self.name,
+                self.data_type,
+            );
+        };
+        let Some(metadata) = self.extension_metadata.as_deref() else {
+            fail!(
+                "The extension {FIXED_SHAPE_TENSOR} of field {:?} requires metadata with the shape",
+                self.name
+            );
+        };
+        let shape = parse_tensor_shape(metadata)?;
+        let Some(size) = shape
+            .iter()
+            .try_fold(1_usize, |size, dim| size.checked_mul(*dim))
+        else {
+            fail!(
+                "The shape {shape:?} of field {:?} has too many elements",
+                self.name
+            );
+        };
+        if usize::try_from(n).ok() != Some(size) {
+            fail!(
+                "The shape {shape:?} of field {:?} has {size} elements, but the list has size {n}",
+                self.name
+            );
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_null_default(&self) -> Result<()> {
+        if self.null_default.is_none() {
+            return Ok(());
+        }
+        if !self.nullable {
+            fail!(
+                "Null default of field {:?} requires the field to be nullable",
+                self.name
+            );
+        }
+        self.get_null_default_event()?;
+        Ok(())
+    }
+
     pub(crate) fn validate_null(&self) -> Result<()> {
         if !matches!(
             self.strategy,
@@ -728,6 +1521,34 @@ impl GenericField {
         Ok(())
     }
 
+    pub(crate) fn validate_number(&self) -> Result<()> {
+        if !matches!(self.strategy, None | Some(Strategy::NumericCoercion)) {
+            fail!(
+                "invalid strategy for {}: {}",
+                self.data_type,
+                self.strategy.as_ref().unwrap()
+            );
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_utf8(&self) -> Result<()> {
+        if !matches!(self.strategy, None | Some(Strategy::NumberAsStr)) {
+            fail!(
+                "invalid strategy for {}: {}",
+                self.data_type,
+                self.strategy.as_ref().unwrap()
+            );
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
     pub(crate) fn validate_date64(&self) -> Result<()> {
         if !matches!(
             self.strategy,
@@ -741,11 +1562,37 @@ impl GenericField {
         Ok(())
     }
 
+    pub(crate) fn validate_decimal128(&self) -> Result<()> {
+        let GenericDataType::Decimal128(precision, scale) = self.data_type else {
+            fail!("expected a Decimal128 field, found: {}", self.data_type);
+        };
+        if !(1..=MAX_DECIMAL128_PRECISION).contains(&precision) {
+            fail!(
+                "invalid precision for {}: expected a value between 1 and {}",
+                self.data_type,
+                MAX_DECIMAL128_PRECISION,
+            );
+        }
+        if scale < 0 || scale as u8 > precision {
+            fail!(
+                "invalid scale for {}: expected a value between 0 and the precision",
+                self.data_type
+            );
+        }
+        if let Some(strategy) = self.strategy.as_ref() {
+            fail!("invalid strategy for {}: {}", self.data_type, strategy);
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
     pub(crate) fn validate_timestamp(&self) -> Result<()> {
         match &self.strategy {
             None => Ok(()),
             Some(strategy @ Strategy::UtcStrAsDate64) => {
-                if !matches!(&self.data_type, GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(tz)) if tz == "UTC")
+                if !matches!(&self.data_type, GenericDataType::Timestamp(_, Some(tz)) if tz == "UTC")
                 {
                     fail!(
                         "invalid strategy for timestamp field {}: {}",
@@ -756,10 +1603,7 @@ impl GenericField {
                 Ok(())
             }
             Some(strategy @ Strategy::NaiveStrAsDate64) => {
-                if !matches!(
-                    &self.data_type,
-                    GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None)
-                ) {
+                if !matches!(&self.data_type, GenericDataType::Timestamp(_, None)) {
                     fail!(
                         "invalid strategy for timestamp field {}: {}",
                         self.data_type,
@@ -788,7 +1632,11 @@ impl GenericField {
             );
         }
 
+        let mut names = HashSet::new();
         for child in &self.children {
+            if !names.insert(child.name.as_str()) {
+                fail!("Duplicate field \"{}.{}\" in struct", self.name, child.name);
+            }
             child.validate()?;
         }
 
@@ -843,6 +1691,19 @@ impl GenericField {
         Ok(())
     }
 
+    pub(crate) fn validate_fixed_size_list(&self) -> Result<()> {
+        let GenericDataType::FixedSizeList(n) = self.data_type else {
+            fail!("expected a FixedSizeList field, found: {}", self.data_type);
+        };
+        if n < 0 {
+            fail!(
+                "invalid size for {}: expected a non-negative value",
+                self.data_type
+            );
+        }
+        self.validate_list()
+    }
+
     pub(crate) fn validate_union(&self) -> Result<()> {
         if self.strategy.is_some() {
             fail!(
@@ -987,6 +1848,38 @@ mod test_schema_serialization {
         assert_eq!(round_tripped, schema);
     }
 
+    #[test]
+    fn tree_string() {
+        use super::Strategy;
+
+        let schema = SerdeArrowSchema::new()
+            .with_field(
+                GenericField::new("a", GenericDataType::LargeList, true).with_child(
+                    GenericField::new("element", GenericDataType::Struct, false)
+                        .with_child(
+                            GenericField::new("b", GenericDataType::I64, true)
+                                .with_null_default("0"),
+                        )
+                        .with_child(
+                            GenericField::new("c", GenericDataType::Date64, false)
+                                .with_strategy(Strategy::NaiveStrAsDate64),
+                        ),
+                ),
+            )
+            .with_field(GenericField::new("d", GenericDataType::Bool, false));
+
+        assert_eq!(
+            schema.to_string(),
+            concat!(
+                "a: LargeList (nullable)\n",
+                "└── element: Struct\n",
+                "    ├── b: I64 (nullable, null default: \"0\")\n",
+                "    └── c: Date64 (strategy: NaiveStrAsDate64)\n",
+                "d: Bool\n",
+            ),
+        );
+    }
+
     #[test]
     fn example_without_wrapper() {
         let expected = SerdeArrowSchema::new()
@@ -1101,4 +1994,39 @@ mod test_schema_serialization {
         Null, Bool, I8, I16, I32, I64, U8, U16, U32, U64, F16, F32, F64, Utf8, LargeUtf8, List,
         LargeList, Struct, Dictionary, Union, Map, Date64,
     );
+    #[test]
+    fn decimal128() {
+        let ty = GenericDataType::Decimal128(5, 2);
+
+        let s = serde_json::to_string(&ty).unwrap();
+        assert_eq!(s, "\"Decimal128(5, 2)\"");
+
+        let rt: GenericDataType = serde_json::from_str(&s).unwrap();
+        assert_eq!(ty, rt);
+    }
+
+    #[test]
+    fn fixed_size_list() {
+        let ty = GenericDataType::FixedSizeList(3);
+
+        let s = serde_json::to_string(&ty).unwrap();
+        assert_eq!(s, "\"FixedSizeList(3)\"");
+
+        let rt: GenericDataType = serde_json::from_str(&s).unwrap();
+        assert_eq!(ty, rt);
+    }
+
+    #[test]
+    fn null_default() {
+        let field = GenericField::new("item", GenericDataType::U8, true).with_null_default("0");
+
+        let s = serde_json::to_string(&field).unwrap();
+        assert_eq!(
+            s,
+            r#"{"name":"item","data_type":"U8","nullable":true,"null_default":"0"}"#
+        );
+
+        let rt: GenericField = serde_json::from_str(&s).unwrap();
+        assert_eq!(field, rt);
+    }
 }
@@ -0,0 +1,272 @@
+//! Support for the [`schema!`][crate::schema!] macro
+//!
+//! The macro expands into calls of the functions of this module. Data types
+//! and time units are resolved as paths into the `types` and `units` modules,
+//! so that unknown names are reported at compile time.
+use crate::internal::{
+    error::Result,
+    schema::{GenericDataType, GenericField, GenericTimeUnit, SerdeArrowSchema},
+};
+
+/// Build a schema from explicitly listed fields
+///
+/// Each field is given as `name: DataType`, nullable fields are marked with a
+/// trailing `?`. Field names that are not valid identifiers can be given as
+/// string literals. The macro evaluates to a
+/// [`Result<SerdeArrowSchema>`][crate::schema::SerdeArrowSchema], the fields
+/// are validated once the macro is evaluated. The supported data types are:
+///
+/// - `Null`, `Bool`, `Utf8`, `LargeUtf8`, `Date64`
+/// - integers and floats, both as `UInt8`, .., `Int64`, `Float16`, ..,
+///   `Float64` and as `U8`, .., `I64`, `F16`, .., `F64`
+/// - `Timestamp(unit)` and `Timestamp(unit, "timezone")` with the units `s`,
+///   `ms`, `us`, `ns`
+/// - `Decimal128(precision, scale)`
+/// - `List(item)`, `LargeList(item)` and `FixedSizeList(n, item)`, the item
+///   field is named `"element"` and can be marked as nullable with `?`
+/// - `Struct { .. }` with the fields given in the same syntax
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+///
+/// let schema = serde_arrow::schema! {
+///     a: Float32?,
+///     b: UInt64,
+///     ts: Timestamp(ms, "UTC"),
+///     tags: LargeList(LargeUtf8?),
+///     point: Struct { x: F64, y: F64 },
+/// }?;
+///
+/// assert_eq!(
+///     schema,
+///     SerdeArrowSchema::from_value(&serde_json::json!([
+///         {"name": "a", "data_type": "F32", "nullable": true},
+///         {"name": "b", "data_type": "U64"},
+///         {"name": "ts", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+///         {"name": "tags", "data_type": "LargeList", "children": [
+///             {"name": "element", "data_type": "LargeUtf8", "nullable": true},
+///         ]},
+///         {"name": "point", "data_type": "Struct", "children": [
+///             {"name": "x", "data_type": "F64"},
+///             {"name": "y", "data_type": "F64"},
+///         ]},
+///     ]))?,
+/// );
+///
+/// let fields: Vec<Field> = schema.try_into()?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! schema {
+    ($($fields:tt)*) => {
+        $crate::_impl::schema_macro::schema($crate::__schema_fields!(@acc [] $($fields)*))
+    };
+}
+
+/// Collect the fields of [`schema!`][crate::schema!]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_fields {
+    (@acc [$($acc:expr,)*]) => {
+        ::std::vec![$($acc,)*]
+    };
+    (@acc [$($acc:expr,)*] $name:ident : $($rest:tt)*) => {
+        $crate::__schema_fields!(@field [$($acc,)*] ::std::stringify!($name), [] $($rest)*)
+    };
+    (@acc [$($acc:expr,)*] $name:literal : $($rest:tt)*) => {
+        $crate::__schema_fields!(@field [$($acc,)*] $name, [] $($rest)*)
+    };
+    // collect the tokens of the data type up to the next comma
+    (@field [$($acc:expr,)*] $name:expr, [$($ty:tt)*] , $($rest:tt)*) => {
+        $crate::__schema_fields!(
+            @acc [$($acc,)* $crate::__schema_field!($name, $($ty)*),] $($rest)*
+        )
+    };
+    (@field [$($acc:expr,)*] $name:expr, [$($ty:tt)*]) => {
+        $crate::__schema_fields!(@acc [$($acc,)* $crate::__schema_field!($name, $($ty)*),])
+    };
+    (@field [$($acc:expr,)*] $name:expr, [$($ty:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__schema_fields!(@field [$($acc,)*] $name, [$($ty)* $next] $($rest)*)
+    };
+}
+
+/// Build a single field of [`schema!`][crate::schema!]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_field {
+    // NOTE: the data types must be matched first, as `$name:expr` cannot
+    // backtrack
+    (@type Timestamp($unit:ident)) => {
+        $crate::_impl::schema_macro::timestamp($crate::_impl::schema_macro::units::$unit, None)
+    };
+    (@type Timestamp($unit:ident, $tz:expr)) => {
+        $crate::_impl::schema_macro::timestamp(
+            $crate::_impl::schema_macro::units::$unit,
+            Some($tz),
+        )
+    };
+    (@type Decimal128($precision:expr, $scale:expr)) => {
+        $crate::_impl::schema_macro::decimal128($precision, $scale)
+    };
+    (@type List($($item:tt)*)) => {
+        $crate::_impl::schema_macro::list($crate::__schema_field!("element", $($item)*))
+    };
+    (@type LargeList($($item:tt)*)) => {
+        $crate::_impl::schema_macro::large_list($crate::__schema_field!("element", $($item)*))
+    };
+    (@type FixedSizeList($n:expr, $($item:tt)*)) => {
+        $crate::_impl::schema_macro::fixed_size_list(
+            $n,
+            $crate::__schema_field!("element", $($item)*),
+        )
+    };
+    (@type Struct { $($fields:tt)* }) => {
+        $crate::_impl::schema_macro::r#struct($crate::__schema_fields!(@acc [] $($fields)*))
+    };
+    (@type $ty:ident) => {
+        $crate::_impl::schema_macro::primitive($crate::_impl::schema_macro::types::$ty())
+    };
+    ($name:expr, $ty:ident ?) => {
+        $crate::_impl::schema_macro::field($name, true, $crate::__schema_field!(@type $ty))
+    };
+    ($name:expr, $ty:ident $args:tt ?) => {
+        $crate::_impl::schema_macro::field($name, true, $crate::__schema_field!(@type $ty $args))
+    };
+    ($name:expr, $ty:ident) => {
+        $crate::_impl::schema_macro::field($name, false, $crate::__schema_field!(@type $ty))
+    };
+    ($name:expr, $ty:ident $args:tt) => {
+        $crate::_impl::schema_macro::field($name, false, $crate::__schema_field!(@type $ty $args))
+    };
+}
+
+/// The fields without name and nullability, as produced by the data types of
+/// the macro
+pub struct DataTypeSpec {
+    data_type: GenericDataType,
+    children: Vec<GenericField>,
+}
+
+pub fn schema(fields: Vec<GenericField>) -> Result<SerdeArrowSchema> {
+    for field in &fields {
+        field.validate()?;
+    }
+    Ok(SerdeArrowSchema { fields })
+}
+
+pub fn field(name: &str, nullable: bool, spec: DataTypeSpec) -> GenericField {
+    let mut field = GenericField::new(name, spec.data_type, nullable);
+    field.children = spec.children;
+    field
+}
+
+pub fn primitive(data_type: GenericDataType) -> DataTypeSpec {
+    DataTypeSpec {
+        data_type,
+        children: Vec::new(),
+    }
+}
+
+pub fn timestamp(unit: GenericTimeUnit, timezone: Option<&str>) -> DataTypeSpec {
+    primitive(GenericDataType::Timestamp(
+        unit,
+        timezone.map(str::to_owned),
+    ))
+}
+
+pub fn decimal128(precision: u8, scale: i8) -> DataTypeSpec {
+    primitive(GenericDataType::Decimal128(precision, scale))
+}
+
+pub fn list(item: GenericField) -> DataTypeSpec {
+    DataTypeSpec {
+        data_type: GenericDataType::List,
+        children: vec![item],
+    }
+}
+
+pub fn large_list(item: GenericField) -> DataTypeSpec {
+    DataTypeSpec {
+        data_type: GenericDataType::LargeList,
+        children: vec![item],
+    }
+}
+
+pub fn fixed_size_list(n: i32, item: GenericField) -> DataTypeSpec {
+    DataTypeSpec {
+        data_type: GenericDataType::FixedSizeList(n),
+        children: vec![item],
+    }
+}
+
+pub fn r#struct(fields: Vec<GenericField>) -> DataTypeSpec {
+    DataTypeSpec {
+        data_type: GenericDataType::Struct,
+        children: fields,
+    }
+}
+
+/// The primitive data types, under both the Arrow and the `serde_arrow` names
+#[allow(non_snake_case)]
+pub mod types {
+    use crate::internal::schema::GenericDataType;
+
+    macro_rules! define_types {
+        ($($name:ident => $variant:ident),* $(,)?) => {
+            $(
+                pub fn $name() -> GenericDataType {
+                    GenericDataType::$variant
+                }
+            )*
+        };
+    }
+
+    define_types!(
+        Null => Null,
+        Bool => Bool,
+        Boolean => Bool,
+        Utf8 => Utf8,
+        LargeUtf8 => LargeUtf8,
+        Date64 => Date64,
+        I8 => I8,
+        I16 => I16,
+        I32 => I32,
+        I64 => I64,
+        Int8 => I8,
+        Int16 => I16,
+        Int32 => I32,
+        Int64 => I64,
+        U8 => U8,
+        U16 => U16,
+        U32 => U32,
+        U64 => U64,
+        UInt8 => U8,
+        UInt16 => U16,
+        UInt32 => U32,
+        UInt64 => U64,
+        F16 => F16,
+        F32 => F32,
+        F64 => F64,
+        Float16 => F16,
+        Float32 => F32,
+        Float64 => F64,
+    );
+}
+
+/// The time units of timestamps
+#[allow(non_upper_case_globals)]
+pub mod units {
+    use crate::internal::schema::GenericTimeUnit;
+
+    pub const s: GenericTimeUnit = GenericTimeUnit::Second;
+    pub const ms: GenericTimeUnit = GenericTimeUnit::Millisecond;
+    pub const us: GenericTimeUnit = GenericTimeUnit::Microsecond;
+    pub const ns: GenericTimeUnit = GenericTimeUnit::Nanosecond;
+}
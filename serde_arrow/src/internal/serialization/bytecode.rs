@@ -1,4 +1,7 @@
-use crate::internal::common::{define_bytecode, DictionaryIndex, DictionaryValue};
+use crate::internal::{
+    common::{define_bytecode, DictionaryIndex, DictionaryValue},
+    schema::GenericTimeUnit,
+};
 
 #[rustfmt::skip]
 define_bytecode!(
@@ -52,11 +55,86 @@ define_bytecode!(
     PushBool {
         idx: usize,
     },
+    /// Push a nullable primitive value, handling `Some` and `Null` events
+    /// directly, without a separate option marker
+    PushOptionalBool {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalU8 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalU16 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalU32 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalU64 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalI8 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalI16 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalI32 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalI64 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalF16 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalF32 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    PushOptionalF64 {
+        self_pos: usize,
+        idx: usize,
+        validity: usize,
+    },
+    /// `formats` is an index into `Structure::timestamp_formats`, `unit` the
+    /// unit of the stored values
     PushDate64FromNaiveStr {
         idx: usize,
+        formats: usize,
+        unit: GenericTimeUnit,
     },
+    /// `formats` is an index into `Structure::timestamp_formats`, `unit` the
+    /// unit of the stored values
     PushDate64FromUtcStr {
         idx: usize,
+        formats: usize,
+        unit: GenericTimeUnit,
+    },
+    PushDecimal128 {
+        idx: usize,
+        precision: u8,
+        scale: i8,
     },
     PushUtf8 {
         buffer: usize,
@@ -73,6 +151,13 @@ define_bytecode!(
     OuterSequenceEnd {
         list_idx: usize,
     },
+    /// Handle optional records, e.g., `Option<Record>` items, and write a row
+    /// with all fields set to null for `None`
+    OuterRecordOption {
+        self_pos: usize,
+        /// The index of the struct definition of the record
+        struct_idx: usize,
+    },
     LargeListItem {
         list_idx: usize,
         offsets: usize,
@@ -89,6 +174,24 @@ define_bytecode!(
         list_idx: usize,
         offsets: usize,
     },
+    FixedSizeListStart {
+        /// The buffer counting the items of the current list
+        items: usize,
+    },
+    FixedSizeListItem {
+        list_idx: usize,
+        /// The number of items each list must contain
+        n: usize,
+        items: usize,
+        /// The buffer counting the lists
+        count: usize,
+    },
+    FixedSizeListEnd {
+        list_idx: usize,
+        n: usize,
+        items: usize,
+        count: usize,
+    },
     StructItem {
         struct_idx: usize,
         seen: usize,
@@ -138,6 +241,18 @@ define_bytecode!(
         union_idx: usize,
         type_idx: usize,
     },
+    /// Convert floats with integral values to integers (`to_float == false`) or
+    /// integers to floats (`to_float == true`) and forward all events to the
+    /// next instruction
+    CoerceNumber {
+        to_float: bool,
+    },
+    /// Convert the integers `0` / `1` and the strings `"true"` / `"false"` to
+    /// bools and forward all events to the next instruction
+    CoerceBool {},
+    /// Convert numbers to their string representation and forward all events
+    /// to the next instruction
+    NumberToStr {},
     PushDictionary {
         values: DictionaryValue,
         indices: DictionaryIndex,
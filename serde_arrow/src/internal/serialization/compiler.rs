@@ -1,22 +1,32 @@
+use std::collections::HashMap;
+
 use crate::internal::{
     common::{ArrayMapping, DictionaryIndex, DictionaryValue},
     config::CONFIGURATION,
+    converters::Converters,
     error::Result,
     error::{error, fail},
+    instrument::span,
+    options::{EmptyListPolicy, NonFinite, NullPolicy, Overflow, SortOrder, TimestampFormat},
     schema::{GenericDataType, GenericField, GenericTimeUnit, Strategy},
 };
 
 use super::{
     bit_set::BitSet,
     bytecode::{
-        Bytecode, LargeListEnd, LargeListItem, LargeListStart, ListEnd, ListItem, ListStart,
-        MapEnd, MapItem, MapStart, OptionMarker, OuterSequenceEnd, OuterSequenceItem,
-        OuterSequenceStart, Panic, ProgramEnd, PushBool, PushDate64FromNaiveStr,
-        PushDate64FromUtcStr, PushDictionary, PushF16, PushF32, PushF64, PushI16, PushI32, PushI64,
-        PushI8, PushLargeUtf8, PushNull, PushU16, PushU32, PushU64, PushU8, PushUtf8, StructEnd,
+        Bytecode, CoerceBool, CoerceNumber, FixedSizeListEnd, FixedSizeListItem,
+        FixedSizeListStart, LargeListEnd, LargeListItem, LargeListStart, ListEnd, ListItem,
+        ListStart, MapEnd, MapItem, MapStart, NumberToStr, OptionMarker, OuterRecordOption,
+        OuterSequenceEnd, OuterSequenceItem, OuterSequenceStart, Panic, ProgramEnd, PushBool,
+        PushDate64FromNaiveStr, PushDate64FromUtcStr, PushDecimal128, PushDictionary, PushF16,
+        PushF32, PushF64, PushI16, PushI32, PushI64, PushI8, PushLargeUtf8, PushNull,
+        PushOptionalBool, PushOptionalF16, PushOptionalF32, PushOptionalF64, PushOptionalI16,
+        PushOptionalI32, PushOptionalI64, PushOptionalI8, PushOptionalU16, PushOptionalU32,
+        PushOptionalU64, PushOptionalU8, PushU16, PushU32, PushU64, PushU8, PushUtf8, StructEnd,
         StructField, StructItem, StructStart, StructUnknownField, TupleStructEnd, TupleStructItem,
         TupleStructStart, UnionEnd, Variant,
     },
+    sort::check_sort_columns,
     structure::{
         FieldDefinition, ListDefinition, MapDefinition, NullDefinition, StructDefinition,
         UnionDefinition,
@@ -29,6 +39,7 @@ pub fn compile_serialization(
     fields: &[GenericField],
     options: CompilationOptions,
 ) -> Result<Program> {
+    span!("compile_serialization", num_fields = fields.len());
     let mut program = Program::new(options);
     program.compile(fields)?;
 
@@ -45,12 +56,36 @@ pub fn compile_serialization(
 #[derive(Debug, Clone)]
 pub struct CompilationOptions {
     pub wrap_with_struct: bool,
+    pub overflow: Overflow,
+    pub non_finite: NonFinite,
+    pub null_policy: NullPolicy,
+    pub timestamp_formats: Vec<TimestampFormat>,
+    pub field_timestamp_formats: HashMap<String, Vec<TimestampFormat>>,
+    pub validate_arrays: bool,
+    pub coerce_bools: bool,
+    pub human_readable: bool,
+    pub converters: Converters,
+    pub column_statistics: bool,
+    pub sort_by: Vec<(String, SortOrder)>,
+    pub empty_lists: EmptyListPolicy,
 }
 
 impl std::default::Default for CompilationOptions {
     fn default() -> Self {
         Self {
             wrap_with_struct: true,
+            overflow: Overflow::default(),
+            non_finite: NonFinite::default(),
+            null_policy: NullPolicy::default(),
+            timestamp_formats: Vec::new(),
+            field_timestamp_formats: HashMap::new(),
+            validate_arrays: false,
+            coerce_bools: false,
+            human_readable: true,
+            converters: Converters::default(),
+            column_statistics: false,
+            sort_by: Vec::new(),
+            empty_lists: EmptyListPolicy::default(),
         }
     }
 }
@@ -60,6 +95,66 @@ impl CompilationOptions {
         self.wrap_with_struct = value;
         self
     }
+
+    pub fn overflow(mut self, value: Overflow) -> Self {
+        self.overflow = value;
+        self
+    }
+
+    pub fn non_finite(mut self, value: NonFinite) -> Self {
+        self.non_finite = value;
+        self
+    }
+
+    pub fn null_policy(mut self, value: NullPolicy) -> Self {
+        self.null_policy = value;
+        self
+    }
+
+    pub fn timestamp_formats(
+        mut self,
+        formats: Vec<TimestampFormat>,
+        field_formats: HashMap<String, Vec<TimestampFormat>>,
+    ) -> Self {
+        self.timestamp_formats = formats;
+        self.field_timestamp_formats = field_formats;
+        self
+    }
+
+    pub fn validate_arrays(mut self, value: bool) -> Self {
+        self.validate_arrays = value;
+        self
+    }
+
+    pub fn coerce_bools(mut self, value: bool) -> Self {
+        self.coerce_bools = value;
+        self
+    }
+
+    pub fn human_readable(mut self, value: bool) -> Self {
+        self.human_readable = value;
+        self
+    }
+
+    pub fn column_statistics(mut self, value: bool) -> Self {
+        self.column_statistics = value;
+        self
+    }
+
+    pub fn sort_by(mut self, value: Vec<(String, SortOrder)>) -> Self {
+        self.sort_by = value;
+        self
+    }
+
+    pub fn empty_lists(mut self, value: EmptyListPolicy) -> Self {
+        self.empty_lists = value;
+        self
+    }
+
+    pub fn converters(mut self, value: Converters) -> Self {
+        self.converters = value;
+        self
+    }
 }
 
 trait Counter {
@@ -79,6 +174,8 @@ pub struct Program {
     pub(crate) options: CompilationOptions,
     pub(crate) structure: Structure,
     pub(crate) buffers: BufferCounts,
+    /// The names of the fields currently being compiled
+    pub(crate) path: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -87,11 +184,37 @@ pub struct Structure {
     pub program: Vec<Bytecode>,
     pub lists: Vec<ListDefinition>,
     pub large_lists: Vec<ListDefinition>,
+    pub fixed_size_lists: Vec<ListDefinition>,
     pub maps: Vec<MapDefinition>,
     pub structs: Vec<StructDefinition>,
     pub unions: Vec<UnionDefinition>,
     pub nulls: Vec<NullDefinition>,
     pub array_mapping: Vec<ArrayMapping>,
+    /// The dotted path of the field each instruction belongs to
+    pub paths: Vec<String>,
+    /// How to handle out of range integers
+    pub overflow: Overflow,
+    /// How to handle non-finite floats
+    pub non_finite: NonFinite,
+    /// The timestamp formats referenced by the instructions parsing strings
+    /// into timestamps. The first entry are the global formats.
+    pub timestamp_formats: Vec<Vec<TimestampFormat>>,
+    /// Whether to validate the arrays after building them
+    pub validate_arrays: bool,
+    /// The conversions applied to newtype structs
+    pub converters: Converters,
+    /// Whether to collect statistics of the arrays before building them
+    pub column_statistics: bool,
+    /// The columns to sort the rows by when building the arrays
+    pub sort_by: Vec<(String, SortOrder)>,
+    /// How to write null and empty lists
+    pub empty_lists: EmptyListPolicy,
+    /// The buffers of each top-level field, given as the buffer counts before
+    /// and after compiling it
+    pub field_buffers: Vec<(BufferCounts, BufferCounts)>,
+    /// Whether the top-level fields were renamed or reordered after
+    /// compilation, i.e., the array mapping no longer follows the serde keys
+    pub fields_remapped: bool,
 }
 
 /// See [MutableBuffers][super::interpreter::MutableBuffers] for details
@@ -109,6 +232,8 @@ pub struct BufferCounts {
     pub(crate) num_u32: usize,
     /// number of 64-bit buffers (u64, i64, f64)
     pub(crate) num_u64: usize,
+    /// number of 128-bit buffers (decimal128)
+    pub(crate) num_u128: usize,
     /// number of offsets encoded with 32 bits
     pub(crate) num_u32_offsets: usize,
     /// number of offsets encoded with 64 bits
@@ -122,18 +247,35 @@ pub struct BufferCounts {
 impl Program {
     pub fn new(options: CompilationOptions) -> Self {
         Self {
+            structure: Structure {
+                overflow: options.overflow,
+                non_finite: options.non_finite,
+                timestamp_formats: vec![options.timestamp_formats.clone()],
+                validate_arrays: options.validate_arrays,
+                converters: options.converters.clone(),
+                column_statistics: options.column_statistics,
+                sort_by: options.sort_by.clone(),
+                empty_lists: options.empty_lists,
+                ..Structure::default()
+            },
             options,
-            structure: Structure::default(),
             buffers: BufferCounts::default(),
+            path: Vec::new(),
         }
     }
 }
 
 impl Program {
     fn compile(&mut self, fields: &[GenericField]) -> Result<()> {
-        self.compile_outer_structure(fields)?;
+        if self.options.null_policy == NullPolicy::Nullable {
+            let fields = fields.iter().map(to_nullable).collect::<Vec<_>>();
+            self.compile_outer_structure(&fields)?;
+        } else {
+            self.compile_outer_structure(fields)?;
+        }
         self.update_jumps()?;
         self.validate()?;
+        check_sort_columns(&self.structure.array_mapping, &self.structure.sort_by)?;
 
         Ok(())
     }
@@ -142,6 +284,7 @@ impl Program {
 impl Program {
     fn push_instr<I: Into<Bytecode>>(&mut self, instr: I) {
         self.structure.program.push(instr.into());
+        self.structure.paths.push(self.path.join("."));
     }
 }
 
@@ -160,6 +303,12 @@ impl Program {
         self.structure.large_lists[0].item = self.structure.program.len();
 
         if self.options.wrap_with_struct {
+            let self_pos = self.structure.program.len();
+            self.push_instr(OuterRecordOption {
+                next: self_pos + 1,
+                self_pos,
+                struct_idx: self.structure.structs.len(),
+            });
             self.structure.array_mapping = self.compile_struct_impl(fields, None)?;
         } else {
             let (f, _) = self.compile_field(&fields[0])?;
@@ -248,6 +397,15 @@ impl Program {
                 if field_idx >= BitSet::MAX {
                     fail!("Structs can contain at most {} fields", BitSet::MAX);
                 }
+                if self.structure.structs[struct_idx]
+                    .fields
+                    .contains_key(&field.name)
+                {
+                    fail!(
+                        "Duplicate field {path:?}",
+                        path = join_path(&self.path, &field.name),
+                    );
+                }
                 if is_map {
                     self.push_instr(StructItem {
                         next: UNSET_INSTR,
@@ -392,6 +550,63 @@ impl Program {
         })
     }
 
+    fn compile_fixed_size_list(
+        &mut self,
+        field: &GenericField,
+        n: i32,
+        validity: Option<usize>,
+    ) -> Result<ArrayMapping> {
+        if field.nullable != validity.is_some() {
+            fail!("inconsistent arguments");
+        }
+
+        let Some(item) = field.children.first() else {
+            fail!("invalid list: no child");
+        };
+        let n = usize::try_from(n)?;
+
+        let list_idx = self.structure.fixed_size_lists.len();
+        let items = self.buffers.num_u0.next_value();
+        let count = self.buffers.num_u0.next_value();
+
+        self.structure
+            .fixed_size_lists
+            .push(ListDefinition::default());
+        self.structure.fixed_size_lists[list_idx].offset = count;
+
+        self.push_instr(FixedSizeListStart {
+            next: UNSET_INSTR,
+            items,
+        });
+        self.push_instr(FixedSizeListItem {
+            next: UNSET_INSTR,
+            list_idx,
+            n,
+            items,
+            count,
+        });
+        self.structure.fixed_size_lists[list_idx].item = self.structure.program.len();
+
+        let (field_mapping, _) = self.compile_field(item)?;
+
+        self.push_instr(FixedSizeListEnd {
+            next: UNSET_INSTR,
+            list_idx,
+            n,
+            items,
+            count,
+        });
+        self.structure.fixed_size_lists[list_idx].r#return = self.structure.program.len();
+
+        Ok(ArrayMapping::FixedSizeList {
+            field: field.clone(),
+            item: Box::new(field_mapping),
+            n,
+            count,
+            validity,
+        })
+    }
+
     fn compile_union(
         &mut self,
         field: &GenericField,
@@ -476,6 +691,60 @@ impl Program {
     /// definition index
     ///
     fn compile_field(&mut self, field: &GenericField) -> Result<(ArrayMapping, Option<usize>)> {
+        let top_level_start = self.path.is_empty().then(|| self.buffers.clone());
+        self.path.push(field.name.clone());
+        let res = if self.options.null_policy == NullPolicy::Default
+            && !field.nullable
+            && can_be_nullable(field)
+            && !matches!(field.data_type, GenericDataType::Dictionary)
+        {
+            // compile the field as nullable, nulls are written as default
+            // values and the validity is ignored
+            let mut nullable_field = field.clone();
+            nullable_field.nullable = true;
+            self.compile_field_impl(&nullable_field)
+                .map(|(mapping, null_definition)| {
+                    (mapping.without_validity(field.clone()), null_definition)
+                })
+        } else {
+            self.compile_field_impl(field)
+        };
+        self.path.pop();
+
+        if let Some(start) = top_level_start {
+            let end = self.buffers.clone();
+            self.structure.field_buffers.push((start, end));
+        }
+        res
+    }
+
+    fn compile_field_impl(
+        &mut self,
+        field: &GenericField,
+    ) -> Result<(ArrayMapping, Option<usize>)> {
+        // NOTE: coerced bools require a separate instruction and are compiled
+        // with an option marker
+        let coerce_bool = self.options.coerce_bools && field.data_type == GenericDataType::Bool;
+        if is_optional_primitive(field) && !coerce_bool {
+            return self.compile_optional_primitive(field);
+        }
+        if field.nullable
+            && self.options.non_finite == NonFinite::Null
+            && matches!(
+                field.data_type,
+                GenericDataType::F16 | GenericDataType::F32 | GenericDataType::F64
+            )
+        {
+            fail!(
+                "The non-finite policy `Null` is not supported for float fields with strategy {}",
+                field
+                    .strategy
+                    .as_ref()
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+            );
+        }
+
         let mut option_marker_pos = None;
         let validity = if self.requires_null_check(field) {
             let validity = self.buffers.num_u1.next_value();
@@ -522,6 +791,139 @@ impl Program {
         // not require additional null checks
         field.nullable && !matches!(field.data_type, GenericDataType::Null)
     }
+
+    /// Compile a nullable primitive into a single instruction that handles
+    /// both the validity and the value, without an option marker
+    ///
+    /// Numeric coercions are applied by a preceding `CoerceNumber`
+    /// instruction. It forwards all events, therefore `Some` markers return to
+    /// the coercion and the non-finite policy is applied to coerced values.
+    ///
+    fn compile_optional_primitive(
+        &mut self,
+        field: &GenericField,
+    ) -> Result<(ArrayMapping, Option<usize>)> {
+        use GenericDataType as D;
+
+        let self_pos = self.structure.program.len();
+        if matches!(field.strategy, Some(Strategy::NumericCoercion)) {
+            field.validate_number()?;
+            self.push_instr(CoerceNumber {
+                next: UNSET_INSTR,
+                to_float: matches!(field.data_type, D::F16 | D::F32 | D::F64),
+            });
+        }
+        let validity = self.buffers.num_u1.next_value();
+
+        macro_rules! compile {
+            ($num:ident, $instr:ident, $mapping:ident) => {{
+                let idx = self.buffers.$num.next_value();
+                self.push_instr($instr {
+                    next: UNSET_INSTR,
+                    self_pos,
+                    idx,
+                    validity,
+                });
+                ArrayMapping::$mapping {
+                    field: field.clone(),
+                    buffer: idx,
+                    validity: Some(validity),
+                }
+            }};
+        }
+
+        let array_mapping = match &field.data_type {
+            D::Bool => compile!(num_u1, PushOptionalBool, Bool),
+            D::U8 => compile!(num_u8, PushOptionalU8, U8),
+            D::U16 => compile!(num_u16, PushOptionalU16, U16),
+            D::U32 => compile!(num_u32, PushOptionalU32, U32),
+            D::U64 => compile!(num_u64, PushOptionalU64, U64),
+            D::I8 => compile!(num_u8, PushOptionalI8, I8),
+            D::I16 => compile!(num_u16, PushOptionalI16, I16),
+            D::I32 => compile!(num_u32, PushOptionalI32, I32),
+            D::I64 => compile!(num_u64, PushOptionalI64, I64),
+            D::F16 => compile!(num_u16, PushOptionalF16, F16),
+            D::F32 => compile!(num_u32, PushOptionalF32, F32),
+            D::F64 => compile!(num_u64, PushOptionalF64, F64),
+            dt => fail!("cannot compile {dt} as an optional primitive"),
+        };
+
+        // the null definition is still required for missing struct fields
+        let null_definition = self.structure.nulls.len();
+        let mut definition = NullDefinition::default();
+        definition.update_from_array_mapping(&array_mapping)?;
+        definition.sort_indices();
+        self.structure.nulls.push(definition);
+
+        Ok((array_mapping, Some(null_definition)))
+    }
+}
+
+/// Whether the field can be compiled as nullable field
+fn can_be_nullable(field: &GenericField) -> bool {
+    use GenericDataType as D;
+
+    match field.data_type {
+        D::Null | D::Union => false,
+        D::Struct => !field.children.is_empty(),
+        _ => true,
+    }
+}
+
+/// Mark the field and its children as nullable, if supported
+///
+/// Map entries and keys, as well as the children of dictionaries, are kept
+/// unchanged.
+fn to_nullable(field: &GenericField) -> GenericField {
+    use GenericDataType as D;
+
+    let mut field = field.clone();
+    field.nullable = field.nullable || can_be_nullable(&field);
+    match field.data_type {
+        D::Dictionary => {}
+        D::Map => {
+            for entries in &mut field.children {
+                for (idx, child) in entries.children.iter_mut().enumerate() {
+                    if idx != 0 {
+                        *child = to_nullable(child);
+                    }
+                }
+            }
+        }
+        _ => field.children = field.children.iter().map(to_nullable).collect(),
+    }
+    field
+}
+
+fn join_path(path: &[String], name: &str) -> String {
+    let mut res = path.join(".");
+    if !res.is_empty() {
+        res.push('.');
+    }
+    res.push_str(name);
+    res
+}
+
+fn is_optional_primitive(field: &GenericField) -> bool {
+    use GenericDataType as D;
+
+    field.nullable
+        && matches!(field.strategy, None | Some(Strategy::NumericCoercion))
+        && matches!(
+            field.data_type,
+            D::Bool
+                | D::U8
+                | D::U16
+                | D::U32
+                | D::U64
+                | D::I8
+                | D::I16
+                | D::I32
+                | D::I64
+                | D::F16
+                | D::F32
+                | D::F64
+        )
 }
 
 macro_rules! compile_primtive {
@@ -542,6 +944,49 @@ macro_rules! compile_primtive {
 }
 
 impl Program {
+    fn compile_date64_from_str(
+        &mut self,
+        field: &GenericField,
+        validity: Option<usize>,
+        utc: bool,
+        unit: GenericTimeUnit,
+    ) -> Result<ArrayMapping> {
+        let formats = match self
+            .options
+            .field_timestamp_formats
+            .get(&self.path.join("."))
+        {
+            Some(formats) => {
+                self.structure.timestamp_formats.push(formats.clone());
+                self.structure.timestamp_formats.len() - 1
+            }
+            None => 0,
+        };
+
+        let idx = self.buffers.num_u64.next_value();
+        if utc {
+            self.push_instr(PushDate64FromUtcStr {
+                next: UNSET_INSTR,
+                idx,
+                formats,
+                unit,
+            });
+        } else {
+            self.push_instr(PushDate64FromNaiveStr {
+                next: UNSET_INSTR,
+                idx,
+                formats,
+                unit,
+            });
+        }
+
+        Ok(ArrayMapping::Date64 {
+            field: field.clone(),
+            buffer: idx,
+            validity,
+        })
+    }
+
     fn compile_field_inner(
         &mut self,
         field: &GenericField,
@@ -549,6 +994,23 @@ impl Program {
     ) -> Result<ArrayMapping> {
         use GenericDataType as D;
 
+        if matches!(field.strategy, Some(Strategy::NumericCoercion)) {
+            field.validate_number()?;
+            self.push_instr(CoerceNumber {
+                next: UNSET_INSTR,
+                to_float: matches!(field.data_type, D::F16 | D::F32 | D::F64),
+            });
+        }
+
+        if self.options.coerce_bools && matches!(field.data_type, D::Bool) {
+            self.push_instr(CoerceBool { next: UNSET_INSTR });
+        }
+
+        if matches!(field.strategy, Some(Strategy::NumberAsStr)) {
+            field.validate_utf8()?;
+            self.push_instr(NumberToStr { next: UNSET_INSTR });
+        }
+
         match &field.data_type {
             D::Null => compile_primtive!(self, field, validity, num_u0, PushNull, Null),
             D::Bool => compile_primtive!(self, field, validity, num_u1, PushBool, Bool),
@@ -596,51 +1058,48 @@ impl Program {
                 })
             }
             D::Date64 => match field.strategy.as_ref() {
-                Some(Strategy::NaiveStrAsDate64) => compile_primtive!(
-                    self,
+                Some(Strategy::NaiveStrAsDate64) => self.compile_date64_from_str(
                     field,
                     validity,
-                    num_u64,
-                    PushDate64FromNaiveStr,
-                    Date64
+                    false,
+                    GenericTimeUnit::Millisecond,
+                ),
+                Some(Strategy::UtcStrAsDate64) => self.compile_date64_from_str(
+                    field,
+                    validity,
+                    true,
+                    GenericTimeUnit::Millisecond,
                 ),
-                Some(Strategy::UtcStrAsDate64) => {
-                    compile_primtive!(self, field, validity, num_u64, PushDate64FromUtcStr, Date64)
-                }
                 None => compile_primtive!(self, field, validity, num_u64, PushI64, Date64),
                 Some(strategy) => fail!("Cannot compile Date64 with strategy {strategy}"),
             },
+            &D::Decimal128(precision, scale) => {
+                field.validate_decimal128()?;
+                let idx = self.buffers.num_u128.next_value();
+                self.push_instr(PushDecimal128 {
+                    next: UNSET_INSTR,
+                    idx,
+                    precision,
+                    scale,
+                });
+                Ok(ArrayMapping::Decimal128 {
+                    field: field.clone(),
+                    buffer: idx,
+                    validity,
+                })
+            }
             D::Dictionary => self.compile_dictionary(field, validity),
             D::Struct => self.compile_struct(field, validity),
             D::List => self.compile_list(field, validity),
             D::LargeList => self.compile_large_list(field, validity),
+            &D::FixedSizeList(n) => self.compile_fixed_size_list(field, n, validity),
             D::Union => self.compile_union(field, validity),
             D::Map => self.compile_map(field, validity),
-            D::Timestamp(unit, tz) => {
-                if !matches!(unit, GenericTimeUnit::Millisecond) {
-                    fail!("Only timestamps with millisecond unit are supported");
-                }
-
-                match tz.as_deref() {
-                    None => compile_primtive!(
-                        self,
-                        field,
-                        validity,
-                        num_u64,
-                        PushDate64FromNaiveStr,
-                        Date64
-                    ),
-                    Some("UTC") => compile_primtive!(
-                        self,
-                        field,
-                        validity,
-                        num_u64,
-                        PushDate64FromUtcStr,
-                        Date64
-                    ),
-                    Some(tz) => fail!("Timezone {tz} is not supported"),
-                }
-            }
+            &D::Timestamp(unit, ref tz) => match tz.as_deref() {
+                None => self.compile_date64_from_str(field, validity, false, unit),
+                Some("UTC") => self.compile_date64_from_str(field, validity, true, unit),
+                Some(tz) => fail!("Timezone {tz} is not supported"),
+            },
         }
     }
 }
@@ -792,6 +1251,10 @@ impl Program {
             l.r#return = follow(l.r#return, &self.structure.program);
         }
 
+        for l in &mut self.structure.fixed_size_lists {
+            l.r#return = follow(l.r#return, &self.structure.program);
+        }
+
         // TODO: handle unions, ...
 
         Ok(())
@@ -802,6 +1265,7 @@ impl Program {
     fn validate(&self) -> Result<()> {
         self.validate_lists("list", &self.structure.lists)?;
         self.validate_lists("large list", &self.structure.large_lists)?;
+        self.validate_lists("fixed size list", &self.structure.fixed_size_lists)?;
         self.validate_maps()?;
         self.validate_structs()?;
         self.validate_nulls()?;
@@ -817,6 +1281,7 @@ impl Program {
                 item_instr,
                 Some(Bytecode::ListItem(_))
                     | Some(Bytecode::LargeListItem(_))
+                    | Some(Bytecode::FixedSizeListItem(_))
                     | Some(&Bytecode::OuterSequenceItem(_))
             ) {
                 fail!("invalid {label} definition ({list_idx}): item points to {item_instr:?}");
@@ -827,6 +1292,7 @@ impl Program {
                 before_return_instr,
                 Some(Bytecode::ListEnd(_))
                     | Some(Bytecode::LargeListEnd(_))
+                    | Some(Bytecode::FixedSizeListEnd(_))
                     | Some(Bytecode::OuterSequenceEnd(_))
             ) {
                 fail!("invalid {label} definition ({list_idx}): instr before return is {before_return_instr:?}");
@@ -895,6 +1361,9 @@ impl Program {
             if null.u64.iter().any(|&idx| idx >= self.buffers.num_u64) {
                 fail!("invalid null definition {idx}: u64 out of bounds {null:?}");
             }
+            if null.u128.iter().any(|&idx| idx >= self.buffers.num_u128) {
+                fail!("invalid null definition {idx}: u128 out of bounds {null:?}");
+            }
         }
         Ok(())
     }
@@ -991,6 +1460,9 @@ impl Program {
             I64 { .. } => validate_array_mapping_primitive!(self, path, mapping, I64, num_u64),
             F32 { .. } => validate_array_mapping_primitive!(self, path, mapping, F32, num_u32),
             F64 { .. } => validate_array_mapping_primitive!(self, path, mapping, F64, num_u64),
+            Decimal128 { .. } => {
+                validate_array_mapping_primitive!(self, path, mapping, Decimal128, num_u128)
+            }
             _ => {}
         }
         Ok(())
@@ -0,0 +1,85 @@
+use crate::internal::{
+    common::{ArrayMapping, MutableBitBuffer},
+    options::EmptyListPolicy,
+};
+
+use super::interpreter::MutableBuffers;
+
+/// Apply the policy to the nullable lists of the given arrays and their
+/// children
+///
+/// Null lists and empty lists share the same offsets and only differ in their
+/// validity. Therefore, the policy can be applied by updating the validity
+/// before the arrays are built.
+pub fn apply_empty_list_policy(
+    mappings: &[ArrayMapping],
+    buffers: &mut MutableBuffers,
+    policy: EmptyListPolicy,
+) {
+    if policy == EmptyListPolicy::Preserve {
+        return;
+    }
+    for mapping in mappings {
+        apply_to_mapping(mapping, buffers, policy);
+    }
+}
+
+fn apply_to_mapping(mapping: &ArrayMapping, buffers: &mut MutableBuffers, policy: EmptyListPolicy) {
+    use ArrayMapping as M;
+
+    match mapping {
+        &M::List {
+            ref item,
+            offsets,
+            validity,
+            ..
+        } => {
+            if let Some(validity) = validity {
+                let offsets = buffers.u32_offsets[offsets]
+                    .offsets
+                    .iter()
+                    .map(|&o| o as usize)
+                    .collect::<Vec<_>>();
+                update_validity(&mut buffers.u1[validity], &offsets, policy);
+            }
+            apply_to_mapping(item, buffers, policy);
+        }
+        &M::LargeList {
+            ref item,
+            offsets,
+            validity,
+            ..
+        } => {
+            if let Some(validity) = validity {
+                let offsets = buffers.u64_offsets[offsets]
+                    .offsets
+                    .iter()
+                    .map(|&o| o as usize)
+                    .collect::<Vec<_>>();
+                update_validity(&mut buffers.u1[validity], &offsets, policy);
+            }
+            apply_to_mapping(item, buffers, policy);
+        }
+        M::FixedSizeList { item, .. } => apply_to_mapping(item, buffers, policy),
+        M::Map { entries, .. } => apply_to_mapping(entries, buffers, policy),
+        M::Struct { fields, .. } | M::Union { fields, .. } => {
+            for field in fields {
+                apply_to_mapping(field, buffers, policy);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn update_validity(validity: &mut MutableBitBuffer, offsets: &[usize], policy: EmptyListPolicy) {
+    for (idx, window) in offsets.windows(2).enumerate().take(validity.len) {
+        let bit = 1 << (idx % 8);
+        match policy {
+            EmptyListPolicy::NullAsEmpty => validity.buffer[idx / 8] |= bit,
+            EmptyListPolicy::EmptyAsNull if window[0] == window[1] => {
+                validity.buffer[idx / 8] &= !bit
+            }
+            _ => {}
+        }
+    }
+}
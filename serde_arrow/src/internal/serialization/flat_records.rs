@@ -0,0 +1,494 @@
+//! A fast path to serialize records with only primitive fields
+//!
+//! Records are serialized directly into the buffers of the columns, bypassing
+//! the events and the bytecode interpreter. Each record is first staged and
+//! only written once all of its fields matched the columns. Records that do
+//! not match, e.g., because they contain fields of other types, non-finite
+//! floats with a non-default policy or fields in a different order, are
+//! rejected and must be serialized with the interpreter instead.
+use serde::{
+    ser::{Impossible, SerializeStruct},
+    Serialize, Serializer,
+};
+
+use crate::internal::{
+    common::ArrayMapping,
+    conversions::ToBytes,
+    error::{fail, Error, Result},
+    options::NonFinite,
+};
+
+use super::interpreter::{Interpreter, MutableBuffers};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Staged {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+}
+
+struct Column {
+    name: String,
+    kind: Kind,
+    buffer: usize,
+}
+
+/// The layout of records whose fields map to non-nullable primitive columns
+pub struct FlatRecords {
+    columns: Vec<Column>,
+    allow_non_finite: bool,
+    human_readable: bool,
+    staged: Vec<Staged>,
+}
+
+impl FlatRecords {
+    /// Determine the layout of the columns, `None` if the fast path does not
+    /// apply
+    pub fn new(interpreter: &Interpreter) -> Option<Self> {
+        use ArrayMapping as M;
+
+        // the columns are matched to the serde keys by name and position
+        if interpreter.structure.fields_remapped {
+            return None;
+        }
+
+        let mut columns = Vec::new();
+        for mapping in &interpreter.structure.array_mapping {
+            let field = mapping.get_field();
+            if mapping.get_validity().is_some() || field.strategy.is_some() {
+                return None;
+            }
+            let (kind, buffer) = match *mapping {
+                M::Bool { buffer, .. } => (Kind::Bool, buffer),
+                M::U8 { buffer, .. } => (Kind::U8, buffer),
+                M::U16 { buffer, .. } => (Kind::U16, buffer),
+                M::U32 { buffer, .. } => (Kind::U32, buffer),
+                M::U64 { buffer, .. } => (Kind::U64, buffer),
+                M::I8 { buffer, .. } => (Kind::I8, buffer),
+                M::I16 { buffer, .. } => (Kind::I16, buffer),
+                M::I32 { buffer, .. } => (Kind::I32, buffer),
+                M::I64 { buffer, .. } => (Kind::I64, buffer),
+                M::F32 { buffer, .. } => (Kind::F32, buffer),
+                M::F64 { buffer, .. } => (Kind::F64, buffer),
+                _ => return None,
+            };
+            columns.push(Column {
+                name: field.name.clone(),
+                kind,
+                buffer,
+            });
+        }
+
+        if columns.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            staged: Vec::with_capacity(columns.len()),
+            columns,
+            allow_non_finite: matches!(interpreter.structure.non_finite, NonFinite::Serialize),
+            human_readable: interpreter.options.human_readable,
+        })
+    }
+
+    /// Try to write the item directly into the buffers
+    ///
+    /// Returns `false`, without modifying the buffers, if the item does not
+    /// match the columns.
+    pub fn try_push<T: Serialize + ?Sized>(
+        &mut self,
+        buffers: &mut MutableBuffers,
+        item: &T,
+    ) -> bool {
+        self.staged.clear();
+        if item.serialize(RecordSerializer(self)).is_err() {
+            return false;
+        }
+        if self.staged.len() != self.columns.len() {
+            return false;
+        }
+
+        for (column, value) in self.columns.iter().zip(&self.staged) {
+            match *value {
+                Staged::Bool(val) => buffers.u1[column.buffer].push(val),
+                Staged::U8(val) => buffers.u8[column.buffer].push(val),
+                Staged::U16(val) => buffers.u16[column.buffer].push(val),
+                Staged::U32(val) => buffers.u32[column.buffer].push(val),
+                Staged::U64(val) => buffers.u64[column.buffer].push(val),
+                Staged::F32(val) => buffers.u32[column.buffer].push(val.to_bytes()),
+                Staged::F64(val) => buffers.u64[column.buffer].push(val.to_bytes()),
+            }
+        }
+        buffers.num_rows += 1;
+        true
+    }
+
+    fn stage(&mut self, kind: Kind, value: Staged) -> Result<()> {
+        match self.columns.get(self.staged.len()) {
+            Some(column) if column.kind == kind => {
+                self.staged.push(value);
+                Ok(())
+            }
+            _ => fail!("Value does not match the next column"),
+        }
+    }
+}
+
+struct RecordSerializer<'a>(&'a mut FlatRecords);
+
+impl<'a> Serializer for RecordSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self> {
+        if len != self.0.columns.len() {
+            fail!("Number of fields does not match the columns");
+        }
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.human_readable
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_char(self, _: char) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_str(self, _: &str) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, _: &T) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<()> {
+        fail!("Not a record")
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        fail!("Not a record")
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        fail!("Not a record")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        fail!("Not a record")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        fail!("Not a record")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap> {
+        fail!("Not a record")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        fail!("Not a record")
+    }
+}
+
+impl<'a> SerializeStruct for RecordSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        match self.0.columns.get(self.0.staged.len()) {
+            Some(column) if column.name == key => {}
+            _ => fail!("Field {key:?} does not match the next column"),
+        }
+        value.serialize(ValueSerializer(self.0))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<()> {
+        fail!("Cannot skip field {key:?}")
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+struct ValueSerializer<'a>(&'a mut FlatRecords);
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn is_human_readable(&self) -> bool {
+        self.0.human_readable
+    }
+
+    fn serialize_bool(self, val: bool) -> Result<()> {
+        self.0.stage(Kind::Bool, Staged::Bool(val))
+    }
+
+    fn serialize_i8(self, val: i8) -> Result<()> {
+        self.0.stage(Kind::I8, Staged::U8(val.to_bytes()))
+    }
+
+    fn serialize_i16(self, val: i16) -> Result<()> {
+        self.0.stage(Kind::I16, Staged::U16(val.to_bytes()))
+    }
+
+    fn serialize_i32(self, val: i32) -> Result<()> {
+        self.0.stage(Kind::I32, Staged::U32(val.to_bytes()))
+    }
+
+    fn serialize_i64(self, val: i64) -> Result<()> {
+        self.0.stage(Kind::I64, Staged::U64(val.to_bytes()))
+    }
+
+    fn serialize_u8(self, val: u8) -> Result<()> {
+        self.0.stage(Kind::U8, Staged::U8(val))
+    }
+
+    fn serialize_u16(self, val: u16) -> Result<()> {
+        self.0.stage(Kind::U16, Staged::U16(val))
+    }
+
+    fn serialize_u32(self, val: u32) -> Result<()> {
+        self.0.stage(Kind::U32, Staged::U32(val))
+    }
+
+    fn serialize_u64(self, val: u64) -> Result<()> {
+        self.0.stage(Kind::U64, Staged::U64(val))
+    }
+
+    fn serialize_f32(self, val: f32) -> Result<()> {
+        if !val.is_finite() && !self.0.allow_non_finite {
+            fail!("Non-finite value");
+        }
+        self.0.stage(Kind::F32, Staged::F32(val))
+    }
+
+    fn serialize_f64(self, val: f64) -> Result<()> {
+        if !val.is_finite() && !self.0.allow_non_finite {
+            fail!("Non-finite value");
+        }
+        self.0.stage(Kind::F64, Staged::F64(val))
+    }
+
+    fn serialize_char(self, _: char) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_str(self, _: &str) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, _: &T) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<()> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct> {
+        fail!("Not a primitive")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        fail!("Not a primitive")
+    }
+}
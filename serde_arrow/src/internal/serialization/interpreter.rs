@@ -6,13 +6,23 @@ mod structures;
 use std::collections::HashMap;
 
 use crate::internal::{
-    common::{MutableBitBuffer, MutableCountBuffer, MutableOffsetBuffer},
-    error::{fail, Result},
+    cancellation::CancellationToken,
+    common::{
+        ArrayMapping, DictionaryIndex, DictionaryValue, MutableBitBuffer, MutableCountBuffer,
+        MutableOffsetBuffer,
+    },
+    converters::Converter,
+    error::{fail, ErrorKind, Result},
     event::Event,
+    metrics::MetricsRecorder,
+    progress::Progress,
     serialization::{
         bit_set::BitSet,
         bytecode::{dispatch_bytecode, Bytecode},
-        compiler::{BufferCounts, Program, Structure},
+        compiler::{BufferCounts, CompilationOptions, Program, Structure},
+        empty_lists::apply_empty_list_policy,
+        sort::compute_sort_indices,
+        statistics::{compute_statistics, ColumnStatistics},
     },
     sink::EventSink,
 };
@@ -21,8 +31,33 @@ pub struct Interpreter {
     pub program_counter: usize,
     pub structure: Structure,
     pub buffers: MutableBuffers,
+    /// The statistics of the arrays built last, only collected if enabled
+    pub statistics: Vec<ColumnStatistics>,
+    /// The options used to compile the program
+    pub options: CompilationOptions,
+    /// The byte budget of the buffers, if any
+    pub size_budget: Option<SizeBudget>,
+    /// The metrics of the current batch, only recorded if a callback is set
+    pub metrics: Option<MetricsRecorder>,
+    /// The token checked before each row, if any
+    pub cancellation: Option<CancellationToken>,
+    /// The rows counted for the progress callback, if any
+    pub progress: Option<Progress>,
+}
+
+/// A callback invoked once the estimated size of the buffers exceeds a budget
+pub struct SizeBudget {
+    pub budget: usize,
+    pub callback: Box<dyn FnMut(usize) + Send + Sync>,
+    /// Whether the callback was invoked for the current batch
+    pub exceeded: bool,
 }
 
+/// The storage of all arrays being built
+///
+/// The buffers of all fields, independent of their nesting level, are stored
+/// in flat vectors grouped by their bit width. The compiled program refers to
+/// them by index.
 #[derive(Debug, Clone)]
 pub struct MutableBuffers {
     /// 0 bit buffers
@@ -37,6 +72,8 @@ pub struct MutableBuffers {
     pub u32: Vec<Vec<u32>>,
     /// 64 bit buffers
     pub u64: Vec<Vec<u64>>,
+    /// 128 bit buffers
+    pub u128: Vec<Vec<u128>>,
     /// 32 bit offsets
     pub u32_offsets: Vec<MutableOffsetBuffer<i32>>,
     /// 64 bit offsets
@@ -45,6 +82,8 @@ pub struct MutableBuffers {
     pub seen: Vec<BitSet>,
     /// mappings from strings to indices for dictionaries
     pub dictionaries: Vec<HashMap<String, usize>>,
+    /// the number of rows started since the buffers were last cleared
+    pub num_rows: usize,
 }
 
 impl MutableBuffers {
@@ -56,10 +95,12 @@ impl MutableBuffers {
             u16: vec![Default::default(); counts.num_u16],
             u32: vec![Default::default(); counts.num_u32],
             u64: vec![Default::default(); counts.num_u64],
+            u128: vec![Default::default(); counts.num_u128],
             u32_offsets: vec![Default::default(); counts.num_u32_offsets],
             u64_offsets: vec![Default::default(); counts.num_u64_offsets],
             seen: vec![Default::default(); counts.num_seen],
             dictionaries: vec![Default::default(); counts.num_dictionaries],
+            num_rows: 0,
         }
     }
 
@@ -70,20 +111,187 @@ impl MutableBuffers {
         self.u16.iter_mut().for_each(|b| b.clear());
         self.u32.iter_mut().for_each(|b| b.clear());
         self.u64.iter_mut().for_each(|b| b.clear());
+        self.u128.iter_mut().for_each(|b| b.clear());
         self.u32_offsets.iter_mut().for_each(|b| b.clear());
         self.u64_offsets.iter_mut().for_each(|b| b.clear());
         self.seen.iter_mut().for_each(|b| b.clear());
         self.dictionaries.iter_mut().for_each(|b| b.clear());
+        self.num_rows = 0;
+    }
+
+    /// The approximate size in bytes of the values, offsets and validity
+    /// bits written so far
+    pub fn estimated_size(&self) -> usize {
+        let mut size = 0;
+        size += self.u1.iter().map(|b| b.len().div_ceil(8)).sum::<usize>();
+        size += self.u8.iter().map(Vec::len).sum::<usize>();
+        size += 2 * self.u16.iter().map(Vec::len).sum::<usize>();
+        size += 4 * self.u32.iter().map(Vec::len).sum::<usize>();
+        size += 8 * self.u64.iter().map(Vec::len).sum::<usize>();
+        size += 16 * self.u128.iter().map(Vec::len).sum::<usize>();
+        size += 4 * self
+            .u32_offsets
+            .iter()
+            .map(|b| b.offsets.len())
+            .sum::<usize>();
+        size += 8 * self
+            .u64_offsets
+            .iter()
+            .map(|b| b.offsets.len())
+            .sum::<usize>();
+        size
     }
 }
 
 impl Interpreter {
+    /// Update the validity of null and empty lists according to the policy
+    ///
+    /// Must be called before the statistics are collected and the arrays are
+    /// built from the buffers.
+    pub fn apply_empty_list_policy(&mut self) {
+        apply_empty_list_policy(
+            &self.structure.array_mapping,
+            &mut self.buffers,
+            self.structure.empty_lists,
+        );
+    }
+
+    /// Collect the statistics of the current buffers, if enabled
+    ///
+    /// Must be called before the arrays are built from the buffers.
+    pub fn collect_statistics(&mut self) {
+        if self.structure.column_statistics {
+            self.statistics = compute_statistics(&self.structure.array_mapping, &self.buffers);
+        }
+    }
+
+    /// The order of the rows in the current buffers, if sorting is enabled
+    ///
+    /// Must be called before the arrays are built from the buffers.
+    pub fn sort_indices(&self) -> Result<Option<Vec<usize>>> {
+        if self.structure.sort_by.is_empty() {
+            return Ok(None);
+        }
+        let indices = compute_sort_indices(
+            &self.structure.array_mapping,
+            &self.buffers,
+            &self.structure.sort_by,
+        )?;
+        Ok(Some(indices))
+    }
+
     pub fn new(program: Program) -> Self {
         Self {
             program_counter: 0,
             structure: program.structure,
             buffers: MutableBuffers::from_counts(&program.buffers),
+            statistics: Vec::new(),
+            options: program.options,
+            size_budget: None,
+            metrics: None,
+            cancellation: None,
+            progress: None,
+        }
+    }
+
+    /// Clear the buffers after the arrays were built, starting a new batch
+    pub fn clear_buffers(&mut self) {
+        self.buffers.clear();
+        if let Some(size_budget) = self.size_budget.as_mut() {
+            size_budget.exceeded = false;
+        }
+    }
+
+    /// Fail if the conversion was cancelled, the interpreter is reset to
+    /// accept the next row
+    pub fn check_cancelled(&mut self) -> Result<()> {
+        let Some(token) = self.cancellation.as_ref() else {
+            return Ok(());
+        };
+        if let Err(err) = token.check(self.buffers.num_rows) {
+            self.program_counter = 0;
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Run the hooks before each top-level row: fail if the conversion was
+    /// cancelled and count the row for the progress callback
+    pub fn start_row(&mut self) -> Result<()> {
+        self.check_cancelled()?;
+        if let Some(progress) = self.progress.as_mut() {
+            progress.advance();
+        }
+        Ok(())
+    }
+
+    /// Invoke the size budget callback, if the estimated size exceeds the
+    /// budget for the first time in the current batch
+    pub fn check_size_budget(&mut self) {
+        let Some(size_budget) = self.size_budget.as_mut() else {
+            return;
+        };
+        let size = self.buffers.estimated_size();
+        if size > size_budget.budget && !size_budget.exceeded {
+            size_budget.exceeded = true;
+            (size_budget.callback)(size);
+        }
+    }
+
+    /// Exchange the buffers of the top-level field `idx` with the buffers of
+    /// the top-level field `other_idx` of `other`
+    ///
+    /// Both fields must have been compiled from the same field with the same
+    /// options.
+    pub fn swap_field_buffers(
+        &mut self,
+        idx: usize,
+        other: &mut Interpreter,
+        other_idx: usize,
+    ) -> Result<()> {
+        let Some((start, end)) = self.structure.field_buffers.get(idx) else {
+            fail!("Invalid field index {idx}");
+        };
+        let Some((other_start, other_end)) = other.structure.field_buffers.get(other_idx) else {
+            fail!("Invalid field index {other_idx}");
+        };
+
+        macro_rules! swap {
+            ($($buffer:ident: $count:ident),*) => {
+                $(
+                    if end.$count - start.$count != other_end.$count - other_start.$count {
+                        fail!("Incompatible buffers for field {idx}");
+                    }
+                )*
+                $(
+                    self.buffers.$buffer[start.$count..end.$count].swap_with_slice(
+                        &mut other.buffers.$buffer[other_start.$count..other_end.$count],
+                    );
+                )*
+            };
         }
+        swap!(
+            u0: num_u0,
+            u1: num_u1,
+            u8: num_u8,
+            u16: num_u16,
+            u32: num_u32,
+            u64: num_u64,
+            u128: num_u128,
+            u32_offsets: num_u32_offsets,
+            u64_offsets: num_u64_offsets,
+            seen: num_seen,
+            dictionaries: num_dictionaries
+        );
+        Ok(())
+    }
+}
+
+fn schema_mismatch<I: Instruction + ?Sized>(found: &str) -> ErrorKind {
+    ErrorKind::SchemaMismatch {
+        expected: I::EXPECTED.join(", "),
+        found: found.to_owned(),
+        path: String::new(),
     }
 }
 
@@ -99,6 +307,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("StartSequence"),
             "{name} cannot accept StartSequence, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -111,6 +320,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("EndSequence"),
             "{name} cannot accept EndSequence, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -123,6 +333,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("StartTuple"),
             "{name} cannot accept StartTuple, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -135,6 +346,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("EndTuple"),
             "{name} cannot accept EndTuple, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -147,6 +359,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("StartStructure"),
             "{name} cannot accept StartStructure, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -159,6 +372,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("EndStructure"),
             "{name} cannot accept EndStructure, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -171,6 +385,7 @@ trait Instruction: std::fmt::Debug {
         buffers: &mut MutableBuffers,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("StartMap"),
             "{name} cannot accept StartMap, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -179,6 +394,7 @@ trait Instruction: std::fmt::Debug {
 
     fn accept_end_map(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("EndMap"),
             "{name} cannot accept EndMap, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -187,6 +403,7 @@ trait Instruction: std::fmt::Debug {
 
     fn accept_item(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("Item"),
             "{name} cannot accept Item, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -195,6 +412,7 @@ trait Instruction: std::fmt::Debug {
 
     fn accept_some(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("Some"),
             "{name} cannot accept Some, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -203,6 +421,7 @@ trait Instruction: std::fmt::Debug {
 
     fn accept_default(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("Default"),
             "{name} cannot accept Default, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -216,11 +435,18 @@ trait Instruction: std::fmt::Debug {
         name: &str,
         idx: usize,
     ) -> Result<usize> {
-        fail!("{name} cannot accept Variant({name:?}, {idx}")
+        fail!(
+            kind = schema_mismatch::<Self>("Variant"),
+            "{name} cannot accept Variant({name:?}, {idx}"
+        )
     }
 
     fn accept_null(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
         fail!(
+            kind = ErrorKind::NullInNonNullable {
+                path: String::new(),
+                row: buffers.num_rows.saturating_sub(1),
+            },
             "{name} cannot accept Null, expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -234,6 +460,7 @@ trait Instruction: std::fmt::Debug {
         val: bool,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("Bool"),
             "{name} cannot accept Bool({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -247,6 +474,7 @@ trait Instruction: std::fmt::Debug {
         val: u8,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("U8"),
             "{name} cannot accept U8({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -260,6 +488,7 @@ trait Instruction: std::fmt::Debug {
         val: u16,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("U16"),
             "{name} cannot accept U16({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -273,6 +502,7 @@ trait Instruction: std::fmt::Debug {
         val: u32,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("U32"),
             "{name} cannot accept U32({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -286,6 +516,7 @@ trait Instruction: std::fmt::Debug {
         val: u64,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("U64"),
             "{name} cannot accept U64({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -299,6 +530,7 @@ trait Instruction: std::fmt::Debug {
         val: i8,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("I8"),
             "{name} cannot accept I8({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -312,6 +544,7 @@ trait Instruction: std::fmt::Debug {
         val: i16,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("I16"),
             "{name} cannot accept I16({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -325,6 +558,7 @@ trait Instruction: std::fmt::Debug {
         val: i32,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("I32"),
             "{name} cannot accept I32({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -338,6 +572,7 @@ trait Instruction: std::fmt::Debug {
         val: i64,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("I64"),
             "{name} cannot accept I64({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -351,6 +586,7 @@ trait Instruction: std::fmt::Debug {
         val: f32,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("F32"),
             "{name} cannot accept F32({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -364,6 +600,7 @@ trait Instruction: std::fmt::Debug {
         val: f64,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("F64"),
             "{name} cannot accept F64({val}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -377,6 +614,7 @@ trait Instruction: std::fmt::Debug {
         val: &str,
     ) -> Result<usize> {
         fail!(
+            kind = schema_mismatch::<Self>("Str"),
             "{name} cannot accept Str({val:?}), expected {expected:?}",
             name = Self::NAME,
             expected = Self::EXPECTED
@@ -389,8 +627,8 @@ macro_rules! dispatch_instruction {
         {
             $this.program_counter = dispatch_bytecode!(
                 &$this.structure.program[$this.program_counter],
-                instr => instr.$method(&$this.structure, &mut $this.buffers)?
-            );
+                instr => instr.$method(&$this.structure, &mut $this.buffers)
+            ).map_err(|err| err.with_path(&$this.structure.paths[$this.program_counter]))?;
             Ok(())
         }
     };
@@ -398,8 +636,8 @@ macro_rules! dispatch_instruction {
         {
             $this.program_counter = dispatch_bytecode!(
                 &$this.structure.program[$this.program_counter],
-                instr => instr.$method(&$this.structure, &mut $this.buffers, $($val),*)?
-            );
+                instr => instr.$method(&$this.structure, &mut $this.buffers, $($val),*)
+            ).map_err(|err| err.with_path(&$this.structure.paths[$this.program_counter]))?;
             Ok(())
         }
     };
@@ -437,6 +675,7 @@ impl EventSink for Interpreter {
             OwnedStr(val) => self.accept_str(&val),
             Variant(name, idx) => self.accept_variant(name, idx),
             OwnedVariant(name, idx) => self.accept_variant(&name, idx),
+            ev @ Timestamp(_, _, _) => self.accept(ev.format_timestamp()?),
         }
     }
 
@@ -501,6 +740,14 @@ impl EventSink for Interpreter {
     }
 
     fn accept_item(&mut self) -> Result<()> {
+        if (self.cancellation.is_some() || self.progress.is_some())
+            && matches!(
+                self.structure.program[self.program_counter],
+                Bytecode::OuterSequenceItem(_) | Bytecode::OuterSequenceEnd(_)
+            )
+        {
+            self.start_row()?;
+        }
         dispatch_instruction!(self, accept_item)
     }
 
@@ -553,4 +800,215 @@ impl EventSink for Interpreter {
 
         Ok(())
     }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        self.structure.converters.get(name).cloned()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.options.human_readable
+    }
+
+    fn expects_map(&self) -> bool {
+        let mut pos = self.program_counter;
+        loop {
+            match &self.structure.program[pos] {
+                Bytecode::OptionMarker(instr) => pos = instr.next,
+                Bytecode::MapStart(_) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Summarize the progress of the interpreter for debugging
+///
+/// The output lists the number of rows started, the instruction to be
+/// executed next together with the path of its field, and the length and the
+/// buffered bytes of each field.
+impl std::fmt::Debug for Interpreter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_progress("Interpreter", f)
+    }
+}
+
+impl Interpreter {
+    /// Format the progress as a struct with the given name
+    pub fn fmt_progress(&self, name: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(name)
+            .field("rows", &self.buffers.num_rows)
+            .field("state", &self.state())
+            .field(
+                "fields",
+                &FieldsProgress(&self.structure.array_mapping, &self.buffers),
+            )
+            .finish()
+    }
+
+    /// The name of the next instruction and the path of its field
+    fn state(&self) -> String {
+        fn name<I: Instruction>(_: &I) -> &'static str {
+            I::NAME
+        }
+
+        let Some(instr) = self.structure.program.get(self.program_counter) else {
+            return String::from("<invalid>");
+        };
+        let name = dispatch_bytecode!(instr, instr => name(instr));
+
+        match self.structure.paths.get(self.program_counter) {
+            Some(path) if !path.is_empty() => format!("{name} at {path}"),
+            _ => String::from(name),
+        }
+    }
+}
+
+struct FieldsProgress<'a>(&'a [ArrayMapping], &'a MutableBuffers);
+
+impl<'a> std::fmt::Debug for FieldsProgress<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+        for mapping in self.0 {
+            map.entry(&mapping.get_field().name, &FieldProgress(mapping, self.1));
+        }
+        map.finish()
+    }
+}
+
+struct FieldProgress<'a>(&'a ArrayMapping, &'a MutableBuffers);
+
+impl<'a> std::fmt::Debug for FieldProgress<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let FieldProgress(mapping, buffers) = *self;
+        let mut res = f.debug_struct("Field");
+        res.field("len", &field_len(mapping, buffers))
+            .field("bytes", &field_bytes(mapping, buffers));
+
+        let children = field_children(mapping);
+        if !children.is_empty() {
+            res.field("children", &FieldsProgress(children, buffers));
+        }
+        res.finish()
+    }
+}
+
+fn field_children(mapping: &ArrayMapping) -> &[ArrayMapping] {
+    use ArrayMapping as M;
+    match mapping {
+        M::List { item, .. } | M::LargeList { item, .. } | M::FixedSizeList { item, .. } => {
+            std::slice::from_ref(item)
+        }
+        M::Map { entries, .. } => std::slice::from_ref(entries),
+        M::Struct { fields, .. } | M::Union { fields, .. } => fields,
+        _ => &[],
+    }
+}
+
+/// The number of elements pushed into the array
+pub fn field_len(mapping: &ArrayMapping, buffers: &MutableBuffers) -> usize {
+    use ArrayMapping as M;
+    match mapping {
+        &M::Null { buffer, .. } | &M::FixedSizeList { count: buffer, .. } => {
+            buffers.u0[buffer].len()
+        }
+        &M::Bool { buffer, .. } => buffers.u1[buffer].len(),
+        &M::U8 { buffer, .. } | &M::I8 { buffer, .. } => buffers.u8[buffer].len(),
+        &M::U16 { buffer, .. } | &M::I16 { buffer, .. } | &M::F16 { buffer, .. } => {
+            buffers.u16[buffer].len()
+        }
+        &M::U32 { buffer, .. } | &M::I32 { buffer, .. } | &M::F32 { buffer, .. } => {
+            buffers.u32[buffer].len()
+        }
+        &M::U64 { buffer, .. }
+        | &M::I64 { buffer, .. }
+        | &M::F64 { buffer, .. }
+        | &M::Date64 { buffer, .. } => buffers.u64[buffer].len(),
+        &M::Decimal128 { buffer, .. } => buffers.u128[buffer].len(),
+        &M::Utf8 { offsets, .. } | &M::List { offsets, .. } | &M::Map { offsets, .. } => {
+            buffers.u32_offsets[offsets].len()
+        }
+        &M::LargeUtf8 { offsets, .. } | &M::LargeList { offsets, .. } => {
+            buffers.u64_offsets[offsets].len()
+        }
+        &M::Union { types, .. } => buffers.u8[types].len(),
+        M::Dictionary { indices, .. } => match *indices {
+            DictionaryIndex::U8(idx) | DictionaryIndex::I8(idx) => buffers.u8[idx].len(),
+            DictionaryIndex::U16(idx) | DictionaryIndex::I16(idx) => buffers.u16[idx].len(),
+            DictionaryIndex::U32(idx) | DictionaryIndex::I32(idx) => buffers.u32[idx].len(),
+            DictionaryIndex::U64(idx) | DictionaryIndex::I64(idx) => buffers.u64[idx].len(),
+        },
+        M::Struct {
+            fields, validity, ..
+        } => match (validity, fields.first()) {
+            (Some(validity), _) => buffers.u1[*validity].len(),
+            (None, Some(first)) => field_len(first, buffers),
+            (None, None) => 0,
+        },
+    }
+}
+
+/// The number of bytes buffered for the array, excluding its children
+fn field_bytes(mapping: &ArrayMapping, buffers: &MutableBuffers) -> usize {
+    use std::mem::size_of;
+    use ArrayMapping as M;
+
+    let validity = match mapping.get_validity() {
+        Some(validity) => buffers.u1[validity].buffer.len(),
+        None => 0,
+    };
+    let u32_offsets = |idx: usize| buffers.u32_offsets[idx].offsets.len() * size_of::<i32>();
+    let u64_offsets = |idx: usize| buffers.u64_offsets[idx].offsets.len() * size_of::<i64>();
+
+    let data = match mapping {
+        M::Null { .. } | M::Struct { .. } | M::FixedSizeList { .. } => 0,
+        &M::Bool { buffer, .. } => buffers.u1[buffer].buffer.len(),
+        &M::Utf8 {
+            buffer, offsets, ..
+        } => buffers.u8[buffer].len() + u32_offsets(offsets),
+        &M::LargeUtf8 {
+            buffer, offsets, ..
+        } => buffers.u8[buffer].len() + u64_offsets(offsets),
+        &M::List { offsets, .. } | &M::Map { offsets, .. } => u32_offsets(offsets),
+        &M::LargeList { offsets, .. } => u64_offsets(offsets),
+        &M::Union { types, .. } => buffers.u8[types].len(),
+        M::Dictionary {
+            dictionary,
+            indices,
+            ..
+        } => {
+            let values = match *dictionary {
+                DictionaryValue::Utf8 { buffer, offsets } => {
+                    buffers.u8[buffer].len() + u32_offsets(offsets)
+                }
+                DictionaryValue::LargeUtf8 { buffer, offsets } => {
+                    buffers.u8[buffer].len() + u64_offsets(offsets)
+                }
+            };
+            let indices = match *indices {
+                DictionaryIndex::U8(idx) | DictionaryIndex::I8(idx) => buffers.u8[idx].len(),
+                DictionaryIndex::U16(idx) | DictionaryIndex::I16(idx) => {
+                    buffers.u16[idx].len() * size_of::<u16>()
+                }
+                DictionaryIndex::U32(idx) | DictionaryIndex::I32(idx) => {
+                    buffers.u32[idx].len() * size_of::<u32>()
+                }
+                DictionaryIndex::U64(idx) | DictionaryIndex::I64(idx) => {
+                    buffers.u64[idx].len() * size_of::<u64>()
+                }
+            };
+            values + indices
+        }
+        M::U8 { .. } | M::I8 { .. } => field_len(mapping, buffers),
+        M::U16 { .. } | M::I16 { .. } | M::F16 { .. } => {
+            field_len(mapping, buffers) * size_of::<u16>()
+        }
+        M::U32 { .. } | M::I32 { .. } | M::F32 { .. } => {
+            field_len(mapping, buffers) * size_of::<u32>()
+        }
+        M::U64 { .. } | M::I64 { .. } | M::F64 { .. } | M::Date64 { .. } => {
+            field_len(mapping, buffers) * size_of::<u64>()
+        }
+        M::Decimal128 { .. } => field_len(mapping, buffers) * size_of::<u128>(),
+    };
+    data + validity
 }
@@ -5,7 +5,8 @@ use crate::internal::{
 };
 
 use super::super::bytecode::{
-    dispatch_bytecode, Bytecode, OptionMarker, Panic, ProgramEnd, UnionEnd, Variant,
+    dispatch_bytecode, Bytecode, CoerceBool, CoerceNumber, NumberToStr, OptionMarker, Panic,
+    ProgramEnd, UnionEnd, Variant,
 };
 use super::{Instruction, MutableBuffers};
 
@@ -203,6 +204,208 @@ impl Instruction for OptionMarker {
     option_marker_handle!(accept_str, val: &str);
 }
 
+macro_rules! coerce_number_forward {
+    ($name:ident$(, $($val:ident: $ty:ty),*)?) => {
+        fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers $(, $($val: $ty),*)?) -> Result<usize> {
+            dispatch_bytecode!(&structure.program[self.next], instr => instr.$name(structure, buffers $(, $($val),*)?))
+        }
+    };
+}
+
+macro_rules! coerce_number_int_to_float {
+    ($name:ident, $ty:ty) => {
+        fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+            if self.to_float {
+                dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_f64(structure, buffers, val as f64))
+            } else {
+                dispatch_bytecode!(&structure.program[self.next], instr => instr.$name(structure, buffers, val))
+            }
+        }
+    };
+}
+
+macro_rules! coerce_number_float_to_int {
+    ($name:ident, $ty:ty) => {
+        fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+            if self.to_float {
+                return dispatch_bytecode!(&structure.program[self.next], instr => instr.$name(structure, buffers, val));
+            }
+
+            let val = f64::from(val);
+            if val.fract() != 0.0 || !(-(2.0_f64.powi(63))..2.0_f64.powi(64)).contains(&val) {
+                fail!(
+                    "Cannot convert {val} in row {row} to an integer without loss",
+                    row = buffers.num_rows.saturating_sub(1),
+                );
+            }
+            if val < 0.0 {
+                dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_i64(structure, buffers, val as i64))
+            } else {
+                dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_u64(structure, buffers, val as u64))
+            }
+        }
+    };
+}
+
+/// Coerce numbers between integers and floats
+///
+/// All events are forwarded inline to the next instruction. Numeric events are
+/// converted before forwarding if required.
+///
+impl Instruction for CoerceNumber {
+    const NAME: &'static str = "CoerceNumber";
+    const EXPECTED: &'static [&'static str] = &[
+        "U8", "U16", "U32", "U64", "I8", "I16", "I32", "I64", "F32", "F64",
+    ];
+
+    coerce_number_forward!(accept_start_sequence);
+    coerce_number_forward!(accept_end_sequence);
+    coerce_number_forward!(accept_start_tuple);
+    coerce_number_forward!(accept_end_tuple);
+    coerce_number_forward!(accept_start_struct);
+    coerce_number_forward!(accept_end_struct);
+    coerce_number_forward!(accept_start_map);
+    coerce_number_forward!(accept_end_map);
+    coerce_number_forward!(accept_item);
+    coerce_number_forward!(accept_some);
+    coerce_number_forward!(accept_null);
+    coerce_number_forward!(accept_default);
+    coerce_number_forward!(accept_variant, name: &str, idx: usize);
+    coerce_number_forward!(accept_bool, val: bool);
+    coerce_number_forward!(accept_str, val: &str);
+    coerce_number_int_to_float!(accept_u8, u8);
+    coerce_number_int_to_float!(accept_u16, u16);
+    coerce_number_int_to_float!(accept_u32, u32);
+    coerce_number_int_to_float!(accept_u64, u64);
+    coerce_number_int_to_float!(accept_i8, i8);
+    coerce_number_int_to_float!(accept_i16, i16);
+    coerce_number_int_to_float!(accept_i32, i32);
+    coerce_number_int_to_float!(accept_i64, i64);
+    coerce_number_float_to_int!(accept_f32, f32);
+    coerce_number_float_to_int!(accept_f64, f64);
+}
+
+macro_rules! coerce_bool_from_int {
+    ($name:ident, $ty:ty) => {
+        fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+            let val = match val {
+                0 => false,
+                1 => true,
+                val => fail!(
+                    "Cannot interpret {val} in row {row} as a bool, expected 0 or 1",
+                    row = buffers.num_rows.saturating_sub(1),
+                ),
+            };
+            dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_bool(structure, buffers, val))
+        }
+    };
+}
+
+/// Coerce integers and strings to bools
+///
+/// All events are forwarded inline to the next instruction. The integers `0`
+/// and `1` and the strings `"true"` and `"false"` (ignoring case) are
+/// converted to bools before forwarding.
+///
+impl Instruction for CoerceBool {
+    const NAME: &'static str = "CoerceBool";
+    const EXPECTED: &'static [&'static str] = &[
+        "Bool", "U8", "U16", "U32", "U64", "I8", "I16", "I32", "I64", "Str",
+    ];
+
+    coerce_number_forward!(accept_start_sequence);
+    coerce_number_forward!(accept_end_sequence);
+    coerce_number_forward!(accept_start_tuple);
+    coerce_number_forward!(accept_end_tuple);
+    coerce_number_forward!(accept_start_struct);
+    coerce_number_forward!(accept_end_struct);
+    coerce_number_forward!(accept_start_map);
+    coerce_number_forward!(accept_end_map);
+    coerce_number_forward!(accept_item);
+    coerce_number_forward!(accept_some);
+    coerce_number_forward!(accept_null);
+    coerce_number_forward!(accept_default);
+    coerce_number_forward!(accept_variant, name: &str, idx: usize);
+    coerce_number_forward!(accept_bool, val: bool);
+    coerce_number_forward!(accept_f32, val: f32);
+    coerce_number_forward!(accept_f64, val: f64);
+    coerce_bool_from_int!(accept_u8, u8);
+    coerce_bool_from_int!(accept_u16, u16);
+    coerce_bool_from_int!(accept_u32, u32);
+    coerce_bool_from_int!(accept_u64, u64);
+    coerce_bool_from_int!(accept_i8, i8);
+    coerce_bool_from_int!(accept_i16, i16);
+    coerce_bool_from_int!(accept_i32, i32);
+    coerce_bool_from_int!(accept_i64, i64);
+
+    fn accept_str(
+        &self,
+        structure: &Structure,
+        buffers: &mut MutableBuffers,
+        val: &str,
+    ) -> Result<usize> {
+        let val = if val.eq_ignore_ascii_case("true") {
+            true
+        } else if val.eq_ignore_ascii_case("false") {
+            false
+        } else {
+            fail!(
+                "Cannot interpret {val:?} in row {row} as a bool, expected \"true\" or \"false\"",
+                row = buffers.num_rows.saturating_sub(1),
+            );
+        };
+        dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_bool(structure, buffers, val))
+    }
+}
+
+macro_rules! number_to_str {
+    ($name:ident, $ty:ty, $fmt:literal) => {
+        fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+            dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_str(structure, buffers, &format!($fmt, val)))
+        }
+    };
+}
+
+/// Convert numbers to strings
+///
+/// All events are forwarded inline to the next instruction. Numeric events are
+/// converted to strings before forwarding. Floats are formatted with their
+/// `Debug` implementation to keep integral values distinguishable from
+/// integers (e.g., `3.0` is written as `"3.0"`).
+///
+impl Instruction for NumberToStr {
+    const NAME: &'static str = "NumberToStr";
+    const EXPECTED: &'static [&'static str] = &[
+        "U8", "U16", "U32", "U64", "I8", "I16", "I32", "I64", "F32", "F64", "Str",
+    ];
+
+    coerce_number_forward!(accept_start_sequence);
+    coerce_number_forward!(accept_end_sequence);
+    coerce_number_forward!(accept_start_tuple);
+    coerce_number_forward!(accept_end_tuple);
+    coerce_number_forward!(accept_start_struct);
+    coerce_number_forward!(accept_end_struct);
+    coerce_number_forward!(accept_start_map);
+    coerce_number_forward!(accept_end_map);
+    coerce_number_forward!(accept_item);
+    coerce_number_forward!(accept_some);
+    coerce_number_forward!(accept_null);
+    coerce_number_forward!(accept_default);
+    coerce_number_forward!(accept_variant, name: &str, idx: usize);
+    coerce_number_forward!(accept_bool, val: bool);
+    coerce_number_forward!(accept_str, val: &str);
+    number_to_str!(accept_u8, u8, "{}");
+    number_to_str!(accept_u16, u16, "{}");
+    number_to_str!(accept_u32, u32, "{}");
+    number_to_str!(accept_u64, u64, "{}");
+    number_to_str!(accept_i8, i8, "{}");
+    number_to_str!(accept_i16, i16, "{}");
+    number_to_str!(accept_i32, i32, "{}");
+    number_to_str!(accept_i64, i64, "{}");
+    number_to_str!(accept_f32, f32, "{:?}");
+    number_to_str!(accept_f64, f64, "{:?}");
+}
+
 impl Instruction for Variant {
     const NAME: &'static str = "Variant";
     const EXPECTED: &'static [&'static str] = &["Variant"];
@@ -254,6 +457,7 @@ pub fn apply_null(
     apply_null!(structure, buffers, null_definition, u16);
     apply_null!(structure, buffers, null_definition, u32);
     apply_null!(structure, buffers, null_definition, u64);
+    apply_null!(structure, buffers, null_definition, u128);
 
     for &idx in &structure.nulls[null_definition].u32_offsets {
         buffers.u32_offsets[idx].push_current_items();
@@ -1,14 +1,18 @@
 use crate::internal::{
-    common::{DictionaryIndex, DictionaryValue},
-    conversions::{ToBytes, WrappedF16, WrappedF32, WrappedF64},
-    error::Result,
+    common::{parse_decimal, DictionaryIndex, DictionaryValue},
+    conversions::{ConvertNumber, IsFinite, ToBytes, WrappedF16, WrappedF32, WrappedF64},
+    error::{fail, Result},
+    options::{NonFinite, TimestampFormat},
+    schema::GenericTimeUnit,
     serialization::compiler::Structure,
 };
 
 use super::super::bytecode::{
-    PushBool, PushDate64FromNaiveStr, PushDate64FromUtcStr, PushDictionary, PushF16, PushF32,
-    PushF64, PushI16, PushI32, PushI64, PushI8, PushLargeUtf8, PushNull, PushU16, PushU32, PushU64,
-    PushU8, PushUtf8,
+    PushBool, PushDate64FromNaiveStr, PushDate64FromUtcStr, PushDecimal128, PushDictionary,
+    PushF16, PushF32, PushF64, PushI16, PushI32, PushI64, PushI8, PushLargeUtf8, PushNull,
+    PushOptionalBool, PushOptionalF16, PushOptionalF32, PushOptionalF64, PushOptionalI16,
+    PushOptionalI32, PushOptionalI64, PushOptionalI8, PushOptionalU16, PushOptionalU32,
+    PushOptionalU64, PushOptionalU8, PushU16, PushU32, PushU64, PushU8, PushUtf8,
 };
 use super::{Instruction, MutableBuffers};
 
@@ -54,19 +58,99 @@ impl Instruction for PushLargeUtf8 {
     }
 }
 
+/// Parse a timestamp in the given unit since the epoch trying the formats in
+/// order
+fn parse_timestamp(val: &str, formats: &[TimestampFormat], unit: GenericTimeUnit) -> Result<i64> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+    for format in formats {
+        let timestamp = match format {
+            TimestampFormat::Rfc3339 => DateTime::parse_from_rfc3339(val)
+                .ok()
+                .and_then(|dt| timestamp_in_unit(dt.naive_utc(), unit)),
+            TimestampFormat::Rfc2822 => DateTime::parse_from_rfc2822(val)
+                .ok()
+                .and_then(|dt| timestamp_in_unit(dt.naive_utc(), unit)),
+            TimestampFormat::Custom(format) => DateTime::parse_from_str(val, format)
+                .map(|dt| dt.naive_utc())
+                .or_else(|_| NaiveDateTime::parse_from_str(val, format))
+                .or_else(|_| {
+                    NaiveDate::parse_from_str(val, format)
+                        .map(|date| date.and_time(Default::default()))
+                })
+                .ok()
+                .and_then(|dt| timestamp_in_unit(dt, unit)),
+            TimestampFormat::EpochSeconds => parse_epoch_digits(val)
+                .and_then(|secs| secs.checked_mul(1000))
+                .and_then(|millis| millis_in_unit(millis, unit)),
+            TimestampFormat::EpochMillis => {
+                parse_epoch_digits(val).and_then(|millis| millis_in_unit(millis, unit))
+            }
+        };
+        if let Some(timestamp) = timestamp {
+            return Ok(timestamp);
+        }
+    }
+
+    fail!(
+        "Cannot parse {val:?} as timestamp with any of the formats [{formats}]",
+        formats = formats
+            .iter()
+            .map(|format| format.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+}
+
+fn timestamp_in_unit(dt: chrono::NaiveDateTime, unit: GenericTimeUnit) -> Option<i64> {
+    match unit {
+        GenericTimeUnit::Second => Some(dt.timestamp()),
+        GenericTimeUnit::Millisecond => Some(dt.timestamp_millis()),
+        GenericTimeUnit::Microsecond => Some(dt.timestamp_micros()),
+        GenericTimeUnit::Nanosecond => dt.timestamp_nanos_opt(),
+    }
+}
+
+fn millis_in_unit(millis: i64, unit: GenericTimeUnit) -> Option<i64> {
+    match unit {
+        GenericTimeUnit::Second => Some(millis.div_euclid(1000)),
+        GenericTimeUnit::Millisecond => Some(millis),
+        GenericTimeUnit::Microsecond => millis.checked_mul(1000),
+        GenericTimeUnit::Nanosecond => millis.checked_mul(1_000_000),
+    }
+}
+
+fn parse_epoch_digits(val: &str) -> Option<i64> {
+    let digits = val.strip_prefix('-').unwrap_or(val);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    val.parse().ok()
+}
+
 impl Instruction for PushDate64FromNaiveStr {
     const NAME: &'static str = "PushDate64FromNaiveStr";
     const EXPECTED: &'static [&'static str] = &["Str"];
 
     fn accept_str(
         &self,
-        _structure: &Structure,
+        structure: &Structure,
         buffers: &mut MutableBuffers,
         val: &str,
     ) -> Result<usize> {
         use chrono::NaiveDateTime;
 
-        buffers.u64[self.idx].push(val.parse::<NaiveDateTime>()?.timestamp_millis().to_bytes());
+        let formats = &structure.timestamp_formats[self.formats];
+        let timestamp = if formats.is_empty() {
+            let dt = val.parse::<NaiveDateTime>()?;
+            let Some(timestamp) = timestamp_in_unit(dt, self.unit) else {
+                fail!("Timestamp {val:?} is out of range for unit {}", self.unit);
+            };
+            timestamp
+        } else {
+            parse_timestamp(val, formats, self.unit)?
+        };
+        buffers.u64[self.idx].push(timestamp.to_bytes());
         Ok(self.next)
     }
 }
@@ -77,15 +161,68 @@ impl Instruction for PushDate64FromUtcStr {
 
     fn accept_str(
         &self,
-        _structure: &Structure,
+        structure: &Structure,
         buffers: &mut MutableBuffers,
         val: &str,
     ) -> Result<usize> {
         use chrono::{DateTime, Utc};
 
-        buffers.u64[self.idx].push(val.parse::<DateTime<Utc>>()?.timestamp_millis().to_bytes());
+        let formats = &structure.timestamp_formats[self.formats];
+        let timestamp = if formats.is_empty() {
+            let dt = val.parse::<DateTime<Utc>>()?.naive_utc();
+            let Some(timestamp) = timestamp_in_unit(dt, self.unit) else {
+                fail!("Timestamp {val:?} is out of range for unit {}", self.unit);
+            };
+            timestamp
+        } else {
+            parse_timestamp(val, formats, self.unit)?
+        };
+        buffers.u64[self.idx].push(timestamp.to_bytes());
+        Ok(self.next)
+    }
+}
+
+macro_rules! decimal_from_number {
+    ($($name:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $name(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+                self.accept_str(structure, buffers, &val.to_string())
+            }
+        )*
+    };
+}
+
+/// Push decimals given as strings or numbers, numbers are converted via their
+/// string representation
+impl Instruction for PushDecimal128 {
+    const NAME: &'static str = "PushDecimal128";
+    const EXPECTED: &'static [&'static str] = &[
+        "Str", "U8", "U16", "U32", "U64", "I8", "I16", "I32", "I64", "F32", "F64",
+    ];
+
+    fn accept_str(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+        val: &str,
+    ) -> Result<usize> {
+        let val = parse_decimal(val, self.precision, self.scale)?;
+        buffers.u128[self.idx].push(val.to_bytes());
         Ok(self.next)
     }
+
+    decimal_from_number!(
+        accept_u8(u8),
+        accept_u16(u16),
+        accept_u32(u32),
+        accept_u64(u64),
+        accept_i8(i8),
+        accept_i16(i16),
+        accept_i32(i32),
+        accept_i64(i64),
+        accept_f32(f32),
+        accept_f64(f64),
+    );
 }
 
 impl Instruction for PushDictionary {
@@ -133,10 +270,63 @@ impl Instruction for PushDictionary {
     }
 }
 
+fn convert_number<S, T>(val: S, structure: &Structure, buffers: &MutableBuffers) -> Result<T>
+where
+    S: Copy + std::fmt::Display,
+    T: ConvertNumber<S>,
+{
+    let Some(res) = T::convert(val, structure.overflow) else {
+        fail!(
+            concat!(
+                "Cannot convert {val} ({src}) in row {row} without overflow. ",
+                "Consider setting the `overflow` serialization option to saturate or wrap ",
+                "out of range values.",
+            ),
+            val = val,
+            src = std::any::type_name::<S>(),
+            row = buffers.num_rows.saturating_sub(1),
+        );
+    };
+    Ok(res)
+}
+
+/// Apply the non-finite policy and return whether the value is to be stored as
+/// null
+fn is_null_non_finite<T>(
+    val: T,
+    nullable: bool,
+    structure: &Structure,
+    buffers: &MutableBuffers,
+) -> Result<bool>
+where
+    T: Copy + std::fmt::Display + IsFinite,
+{
+    if val.is_finite_number() {
+        return Ok(false);
+    }
+    match structure.non_finite {
+        NonFinite::Serialize => Ok(false),
+        NonFinite::Null if nullable => Ok(true),
+        NonFinite::Null => fail!(
+            "Cannot store the non-finite value {val} in row {row} as null in a non-nullable field",
+            row = buffers.num_rows.saturating_sub(1),
+        ),
+        NonFinite::Error => fail!(
+            concat!(
+                "Cannot serialize the non-finite value {val} in row {row}. ",
+                "Consider setting the `non_finite` serialization option to serialize ",
+                "or to null non-finite values.",
+            ),
+            val = val,
+            row = buffers.num_rows.saturating_sub(1),
+        ),
+    }
+}
+
 macro_rules! impl_primitive_instruction {
     (
         $(
-            $name:ident($val_type:ty, $builder:ident) {
+            $name:ident / $optional_name:ident($val_type:ty, $builder:ident) {
                 $($func:ident($ty:ty),)*
             },
         )*
@@ -147,9 +337,37 @@ macro_rules! impl_primitive_instruction {
                 const EXPECTED: &'static [&'static str] = &[$(stringify!($ty)),*];
 
                 $(
-                    fn $func(&self, _structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
-                        let val = <$val_type>::try_from(val)?;
+                    fn $func(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+                        is_null_non_finite(val, false, structure, buffers)?;
+                        let val = convert_number::<$ty, $val_type>(val, structure, buffers)?;
+                        buffers.$builder[self.idx].push(ToBytes::to_bytes(val));
+                        Ok(self.next)
+                    }
+                )*
+            }
+
+            impl Instruction for $optional_name {
+                const NAME: &'static str = stringify!($optional_name);
+                const EXPECTED: &'static [&'static str] = &["Some", "Null", $(stringify!($ty)),*];
+
+                fn accept_some(&self, _structure: &Structure, _buffers: &mut MutableBuffers) -> Result<usize> {
+                    Ok(self.self_pos)
+                }
+
+                fn accept_null(&self, _structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+                    buffers.$builder[self.idx].push(Default::default());
+                    buffers.u1[self.validity].push(false);
+                    Ok(self.next)
+                }
+
+                $(
+                    fn $func(&self, structure: &Structure, buffers: &mut MutableBuffers, val: $ty) -> Result<usize> {
+                        if is_null_non_finite(val, true, structure, buffers)? {
+                            return self.accept_null(structure, buffers);
+                        }
+                        let val = convert_number::<$ty, $val_type>(val, structure, buffers)?;
                         buffers.$builder[self.idx].push(ToBytes::to_bytes(val));
+                        buffers.u1[self.validity].push(true);
                         Ok(self.next)
                     }
                 )*
@@ -159,7 +377,7 @@ macro_rules! impl_primitive_instruction {
 }
 
 impl_primitive_instruction!(
-    PushU8(u8, u8) {
+    PushU8 / PushOptionalU8(u8, u8) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -169,7 +387,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushU16(u16, u16) {
+    PushU16 / PushOptionalU16(u16, u16) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -179,7 +397,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushU32(u32, u32) {
+    PushU32 / PushOptionalU32(u32, u32) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -189,7 +407,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushU64(u64, u64) {
+    PushU64 / PushOptionalU64(u64, u64) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -199,7 +417,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushI8(i8, u8) {
+    PushI8 / PushOptionalI8(i8, u8) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -209,7 +427,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushI16(i16, u16) {
+    PushI16 / PushOptionalI16(i16, u16) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -219,7 +437,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushI32(i32, u32) {
+    PushI32 / PushOptionalI32(i32, u32) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -229,7 +447,7 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushI64(i64, u64) {
+    PushI64 / PushOptionalI64(i64, u64) {
         accept_u8(u8),
         accept_u16(u16),
         accept_u32(u32),
@@ -239,15 +457,15 @@ impl_primitive_instruction!(
         accept_i32(i32),
         accept_i64(i64),
     },
-    PushF16(WrappedF16, u16) {
+    PushF16 / PushOptionalF16(WrappedF16, u16) {
         accept_f32(f32),
         accept_f64(f64),
     },
-    PushF32(WrappedF32, u32) {
+    PushF32 / PushOptionalF32(WrappedF32, u32) {
         accept_f32(f32),
         accept_f64(f64),
     },
-    PushF64(WrappedF64, u64) {
+    PushF64 / PushOptionalF64(WrappedF64, u64) {
         accept_f32(f32),
         accept_f64(f64),
         accept_u8(u8),
@@ -275,3 +493,29 @@ impl Instruction for PushBool {
         Ok(self.next)
     }
 }
+
+impl Instruction for PushOptionalBool {
+    const NAME: &'static str = "PushOptionalBool";
+    const EXPECTED: &'static [&'static str] = &["Some", "Null", "Bool"];
+
+    fn accept_some(&self, _structure: &Structure, _buffers: &mut MutableBuffers) -> Result<usize> {
+        Ok(self.self_pos)
+    }
+
+    fn accept_null(&self, _structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        buffers.u1[self.idx].push(false);
+        buffers.u1[self.validity].push(false);
+        Ok(self.next)
+    }
+
+    fn accept_bool(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+        val: bool,
+    ) -> Result<usize> {
+        buffers.u1[self.idx].push(val);
+        buffers.u1[self.validity].push(true);
+        Ok(self.next)
+    }
+}
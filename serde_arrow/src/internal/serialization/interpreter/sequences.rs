@@ -1,10 +1,15 @@
-use crate::internal::{error::Result, serialization::compiler::Structure};
+use crate::internal::{
+    error::{error, fail, Result},
+    serialization::compiler::Structure,
+};
 
 use super::super::bytecode::{
-    LargeListEnd, LargeListItem, LargeListStart, ListEnd, ListItem, ListStart, OuterSequenceEnd,
-    OuterSequenceItem, OuterSequenceStart, TupleStructEnd, TupleStructItem, TupleStructStart,
+    dispatch_bytecode, Bytecode, FixedSizeListEnd, FixedSizeListItem, FixedSizeListStart,
+    LargeListEnd, LargeListItem, LargeListStart, ListEnd, ListItem, ListStart, OuterRecordOption,
+    OuterSequenceEnd, OuterSequenceItem, OuterSequenceStart, TupleStructEnd, TupleStructItem,
+    TupleStructStart,
 };
-use super::{Instruction, MutableBuffers};
+use super::{misc::apply_null, Instruction, MutableBuffers};
 
 impl Instruction for OuterSequenceStart {
     const NAME: &'static str = "OuterSequenceStart";
@@ -39,7 +44,8 @@ impl Instruction for OuterSequenceItem {
         Ok(structure.large_lists[self.list_idx].r#return)
     }
 
-    fn accept_item(&self, _structure: &Structure, _buffers: &mut MutableBuffers) -> Result<usize> {
+    fn accept_item(&self, _structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        buffers.num_rows += 1;
         Ok(self.next)
     }
 
@@ -64,7 +70,8 @@ impl Instruction for OuterSequenceEnd {
         Ok(self.next)
     }
 
-    fn accept_item(&self, structure: &Structure, _buffers: &mut MutableBuffers) -> Result<usize> {
+    fn accept_item(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        buffers.num_rows += 1;
         Ok(structure.large_lists[self.list_idx].item)
     }
 
@@ -77,6 +84,48 @@ impl Instruction for OuterSequenceEnd {
     }
 }
 
+/// Handle optional records (`Some` / `Null` events before the record)
+///
+/// `Some` events are skipped. For `Null` events, the null definitions of all
+/// fields are applied and the record is finished. All other events are
+/// forwarded inline to the start of the record.
+///
+impl Instruction for OuterRecordOption {
+    const NAME: &'static str = "OuterRecordOption";
+    const EXPECTED: &'static [&'static str] = &["Some", "Null", "StartStruct", "StartMap"];
+
+    fn accept_some(&self, _structure: &Structure, _buffers: &mut MutableBuffers) -> Result<usize> {
+        Ok(self.self_pos)
+    }
+
+    fn accept_null(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        let definition = &structure.structs[self.struct_idx];
+        for (name, field_def) in &definition.fields {
+            let null_definition = field_def.null_definition.ok_or_else(|| {
+                error!("Cannot serialize a null record: field {name:?} is not nullable")
+            })?;
+            apply_null(structure, buffers, null_definition)?;
+        }
+        Ok(definition.r#return)
+    }
+
+    fn accept_start_struct(
+        &self,
+        structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_start_struct(structure, buffers))
+    }
+
+    fn accept_start_map(
+        &self,
+        structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        dispatch_bytecode!(&structure.program[self.next], instr => instr.accept_start_map(structure, buffers))
+    }
+}
+
 impl Instruction for LargeListStart {
     const NAME: &'static str = "LargeListStart";
     const EXPECTED: &'static [&'static str] = &["StartSequence", "StartTuple"];
@@ -154,6 +203,100 @@ impl Instruction for LargeListEnd {
     }
 }
 
+/// Finish a fixed size list after checking the number of its items
+fn close_fixed_size_list(
+    buffers: &mut MutableBuffers,
+    n: usize,
+    items: usize,
+    count: usize,
+) -> Result<()> {
+    let len = buffers.u0[items].len();
+    if len != n {
+        fail!("Expected {n} items for fixed size list, found {len}");
+    }
+    buffers.u0[count].push(());
+    Ok(())
+}
+
+impl Instruction for FixedSizeListStart {
+    const NAME: &'static str = "FixedSizeListStart";
+    const EXPECTED: &'static [&'static str] = &["StartSequence", "StartTuple"];
+
+    fn accept_start_sequence(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        buffers.u0[self.items].clear();
+        Ok(self.next)
+    }
+
+    fn accept_start_tuple(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        buffers.u0[self.items].clear();
+        Ok(self.next)
+    }
+}
+
+impl Instruction for FixedSizeListItem {
+    const NAME: &'static str = "FixedSizeListItem";
+    const EXPECTED: &'static [&'static str] = &["EndSequence", "EndTuple", "Item"];
+
+    fn accept_end_sequence(
+        &self,
+        structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        close_fixed_size_list(buffers, self.n, self.items, self.count)?;
+        Ok(structure.fixed_size_lists[self.list_idx].r#return)
+    }
+
+    fn accept_end_tuple(
+        &self,
+        structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        close_fixed_size_list(buffers, self.n, self.items, self.count)?;
+        Ok(structure.fixed_size_lists[self.list_idx].r#return)
+    }
+
+    fn accept_item(&self, _structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        buffers.u0[self.items].push(());
+        Ok(self.next)
+    }
+}
+
+impl Instruction for FixedSizeListEnd {
+    const NAME: &'static str = "FixedSizeListEnd";
+    const EXPECTED: &'static [&'static str] = &["EndSequence", "EndTuple", "Item"];
+
+    fn accept_end_sequence(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        close_fixed_size_list(buffers, self.n, self.items, self.count)?;
+        Ok(self.next)
+    }
+
+    fn accept_end_tuple(
+        &self,
+        _structure: &Structure,
+        buffers: &mut MutableBuffers,
+    ) -> Result<usize> {
+        close_fixed_size_list(buffers, self.n, self.items, self.count)?;
+        Ok(self.next)
+    }
+
+    fn accept_item(&self, structure: &Structure, buffers: &mut MutableBuffers) -> Result<usize> {
+        buffers.u0[self.items].push(());
+        Ok(structure.fixed_size_lists[self.list_idx].item)
+    }
+}
+
 impl Instruction for ListStart {
     const NAME: &'static str = "ListStart";
     const EXPECTED: &'static [&'static str] = &["StartSequence", "StartTuple"];
@@ -189,6 +189,31 @@ impl StructUnknownField {
     }
 }
 
+/// Mark a field as seen and fail if it was already seen in the current struct
+fn mark_seen(
+    structure: &Structure,
+    buffers: &mut MutableBuffers,
+    seen: usize,
+    field_idx: usize,
+    self_pos: usize,
+    name: &str,
+) -> Result<()> {
+    if buffers.seen[seen].contains(field_idx) {
+        let path = &structure.paths[self_pos];
+        fail!(
+            "Duplicate field {path:?} in row {row}",
+            path = if path.is_empty() {
+                name.to_owned()
+            } else {
+                format!("{path}.{name}")
+            },
+            row = buffers.num_rows.saturating_sub(1),
+        );
+    }
+    buffers.seen[seen].insert(field_idx);
+    Ok(())
+}
+
 impl Instruction for StructField {
     const NAME: &'static str = "StructField";
     const EXPECTED: &'static [&'static str] = &["EndStruct", "EndMap", "Str"];
@@ -213,10 +238,24 @@ impl Instruction for StructField {
         val: &str,
     ) -> Result<usize> {
         if self.field_name == val {
-            buffers.seen[self.seen].insert(self.field_idx);
+            mark_seen(
+                structure,
+                buffers,
+                self.seen,
+                self.field_idx,
+                self.self_pos,
+                val,
+            )?;
             Ok(self.next)
         } else if let Some(field_def) = structure.structs[self.struct_idx].fields.get(val) {
-            buffers.seen[self.seen].insert(field_def.index);
+            mark_seen(
+                structure,
+                buffers,
+                self.seen,
+                field_def.index,
+                self.self_pos,
+                val,
+            )?;
             Ok(field_def.jump)
         } else {
             Ok(structure.structs[self.struct_idx].unknown_field)
@@ -254,7 +293,14 @@ impl Instruction for StructEnd {
         val: &str,
     ) -> Result<usize> {
         if let Some(field_def) = structure.structs[self.struct_idx].fields.get(val) {
-            buffers.seen[self.seen].insert(field_def.index);
+            mark_seen(
+                structure,
+                buffers,
+                self.seen,
+                field_def.index,
+                self.self_pos,
+                val,
+            )?;
             Ok(field_def.jump)
         } else {
             Ok(structure.structs[self.struct_idx].unknown_field)
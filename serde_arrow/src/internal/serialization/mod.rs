@@ -1,7 +1,11 @@
 pub mod bit_set;
 pub mod bytecode;
 pub mod compiler;
+pub mod empty_lists;
+pub mod flat_records;
 pub mod interpreter;
+pub mod sort;
+pub mod statistics;
 pub mod structure;
 
 pub use compiler::{compile_serialization, CompilationOptions};
@@ -0,0 +1,217 @@
+//! Sort the rows of the serialization buffers before building the arrays
+//!
+//! The sort indices are computed on the serialization buffers by `serde_arrow`
+//! itself, as the sort kernels of `arrow-ord` are not a dependency and arrow2
+//! does not offer a comparable kernel for all supported versions. The rows are
+//! then reordered with the backends' copy kernels (`MutableArrayData` / arrow2
+//! growables).
+use std::cmp::Ordering;
+
+use half::f16;
+
+use crate::internal::{
+    common::ArrayMapping,
+    conversions::ToBytes,
+    error::{fail, Result},
+    options::SortOrder,
+};
+
+use super::{
+    interpreter::{field_len, MutableBuffers},
+    statistics::get_bit,
+};
+
+type Comparator<'a> = Box<dyn Fn(usize, usize) -> Ordering + 'a>;
+
+/// Check that all sort columns are top-level columns of a supported type
+pub fn check_sort_columns(
+    mappings: &[ArrayMapping],
+    sort_by: &[(String, SortOrder)],
+) -> Result<()> {
+    for (column, _) in sort_by {
+        let mapping = find_column(mappings, column)?;
+        if !is_sortable(mapping) {
+            fail!(
+                "Cannot sort by column {column:?} with data type {}",
+                mapping.get_field().data_type
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The indices of the rows in sorted order
+///
+/// The sort is stable, rows with equal sort keys keep the order in which they
+/// were pushed. Nulls are placed before all other values. Floats are compared
+/// with their total order, i.e., NaNs are placed after all other values.
+pub fn compute_sort_indices(
+    mappings: &[ArrayMapping],
+    buffers: &MutableBuffers,
+    sort_by: &[(String, SortOrder)],
+) -> Result<Vec<usize>> {
+    let mut comparators = Vec::new();
+    for (column, order) in sort_by {
+        let mapping = find_column(mappings, column)?;
+        comparators.push(column_comparator(mapping, buffers, *order)?);
+    }
+
+    let mut indices = (0..buffers.num_rows).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| {
+        for comparator in &comparators {
+            let ordering = comparator(a, b);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    });
+    Ok(indices)
+}
+
+/// Group the indices into runs of consecutive indices, given as `(start, len)`
+///
+/// Copying runs instead of single rows speeds up reordering arrays that are
+/// already partially sorted.
+pub fn index_runs(indices: &[usize]) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for &idx in indices {
+        match runs.last_mut() {
+            Some((start, len)) if *start + *len == idx => *len += 1,
+            _ => runs.push((idx, 1)),
+        }
+    }
+    runs
+}
+
+fn find_column<'a>(mappings: &'a [ArrayMapping], column: &str) -> Result<&'a ArrayMapping> {
+    let Some(mapping) = mappings.iter().find(|m| m.get_field().name == column) else {
+        fail!("Cannot sort by unknown column {column:?}");
+    };
+    Ok(mapping)
+}
+
+fn is_sortable(mapping: &ArrayMapping) -> bool {
+    use ArrayMapping as M;
+    matches!(
+        mapping,
+        M::Bool { .. }
+            | M::U8 { .. }
+            | M::U16 { .. }
+            | M::U32 { .. }
+            | M::U64 { .. }
+            | M::I8 { .. }
+            | M::I16 { .. }
+            | M::I32 { .. }
+            | M::I64 { .. }
+            | M::Date64 { .. }
+            | M::F16 { .. }
+            | M::F32 { .. }
+            | M::F64 { .. }
+            | M::Utf8 { .. }
+            | M::LargeUtf8 { .. }
+    )
+}
+
+fn column_comparator<'a>(
+    mapping: &ArrayMapping,
+    buffers: &'a MutableBuffers,
+    order: SortOrder,
+) -> Result<Comparator<'a>> {
+    use ArrayMapping as M;
+
+    let values: Comparator<'a> = match mapping {
+        &M::Bool { buffer, .. } => {
+            let buffer = &buffers.u1[buffer];
+            Box::new(move |a, b| get_bit(buffer, a).cmp(&get_bit(buffer, b)))
+        }
+        &M::U8 { buffer, .. } => bytes_comparator::<u8>(&buffers.u8[buffer]),
+        &M::U16 { buffer, .. } => bytes_comparator::<u16>(&buffers.u16[buffer]),
+        &M::U32 { buffer, .. } => bytes_comparator::<u32>(&buffers.u32[buffer]),
+        &M::U64 { buffer, .. } => bytes_comparator::<u64>(&buffers.u64[buffer]),
+        &M::I8 { buffer, .. } => bytes_comparator::<i8>(&buffers.u8[buffer]),
+        &M::I16 { buffer, .. } => bytes_comparator::<i16>(&buffers.u16[buffer]),
+        &M::I32 { buffer, .. } => bytes_comparator::<i32>(&buffers.u32[buffer]),
+        &M::I64 { buffer, .. } | &M::Date64 { buffer, .. } => {
+            bytes_comparator::<i64>(&buffers.u64[buffer])
+        }
+        &M::F16 { buffer, .. } => {
+            let values = &buffers.u16[buffer];
+            Box::new(move |a, b| {
+                let a = f16::from_bits(values[a]).to_f32();
+                let b = f16::from_bits(values[b]).to_f32();
+                a.total_cmp(&b)
+            })
+        }
+        &M::F32 { buffer, .. } => {
+            let values = &buffers.u32[buffer];
+            Box::new(move |a, b| f32::from_bits(values[a]).total_cmp(&f32::from_bits(values[b])))
+        }
+        &M::F64 { buffer, .. } => {
+            let values = &buffers.u64[buffer];
+            Box::new(move |a, b| f64::from_bits(values[a]).total_cmp(&f64::from_bits(values[b])))
+        }
+        &M::Utf8 {
+            buffer, offsets, ..
+        } => {
+            let data = &buffers.u8[buffer];
+            let offsets = &buffers.u32_offsets[offsets].offsets;
+            Box::new(move |a, b| {
+                let a = &data[offsets[a] as usize..offsets[a + 1] as usize];
+                let b = &data[offsets[b] as usize..offsets[b + 1] as usize];
+                a.cmp(b)
+            })
+        }
+        &M::LargeUtf8 {
+            buffer, offsets, ..
+        } => {
+            let data = &buffers.u8[buffer];
+            let offsets = &buffers.u64_offsets[offsets].offsets;
+            Box::new(move |a, b| {
+                let a = &data[offsets[a] as usize..offsets[a + 1] as usize];
+                let b = &data[offsets[b] as usize..offsets[b + 1] as usize];
+                a.cmp(b)
+            })
+        }
+        _ => fail!(
+            "Cannot sort by column {:?} with data type {}",
+            mapping.get_field().name,
+            mapping.get_field().data_type
+        ),
+    };
+
+    if field_len(mapping, buffers) != buffers.num_rows {
+        fail!(
+            "Cannot sort by column {:?}: it has {} rows, expected {}",
+            mapping.get_field().name,
+            field_len(mapping, buffers),
+            buffers.num_rows,
+        );
+    }
+
+    // only the order of the values is reversed, nulls are always placed first
+    let values = match order {
+        SortOrder::Ascending => values,
+        SortOrder::Descending => Box::new(move |a, b| values(b, a)),
+    };
+
+    let Some(validity) = mapping.get_validity() else {
+        return Ok(values);
+    };
+    let validity = &buffers.u1[validity];
+    Ok(Box::new(move |a, b| {
+        match (get_bit(validity, a), get_bit(validity, b)) {
+            (true, true) => values(a, b),
+            (false, false) => Ordering::Equal,
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+        }
+    }))
+}
+
+fn bytes_comparator<T: ToBytes + Ord>(values: &[T::Bytes]) -> Comparator<'_>
+where
+    T::Bytes: Clone,
+{
+    Box::new(move |a, b| T::from_bytes(values[a].clone()).cmp(&T::from_bytes(values[b].clone())))
+}
@@ -0,0 +1,317 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use half::f16;
+
+use crate::internal::{
+    common::{ArrayMapping, DictionaryValue, MutableBitBuffer},
+    conversions::ToBytes,
+    value::Value,
+};
+
+use super::interpreter::{field_len, MutableBuffers};
+
+/// Statistics of a column collected while building arrays
+///
+/// The statistics are collected by [`ArrowBuilder`][crate::ArrowBuilder] and
+/// [`Arrow2Builder`][crate::Arrow2Builder], if the
+/// [`column_statistics`][crate::utils::SerializationOptions::column_statistics]
+/// option is set, and describe the arrays returned by the last call to
+/// `build_arrays`, e.g., to populate the column statistics of Parquet files.
+///
+/// The minimum, maximum and the number of distinct values are only computed
+/// for primitive and string columns (including dictionary encoded strings).
+/// NaN values are ignored when determining the minimum and maximum. The
+/// number of distinct values is estimated with a HyperLogLog sketch, except
+/// for dictionaries, for which it is exact.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ColumnStatistics {
+    /// The name of the column
+    pub name: String,
+    /// The number of rows
+    pub len: usize,
+    /// The number of null rows
+    pub null_count: usize,
+    /// The smallest non-null value, if any
+    pub min: Option<Value>,
+    /// The largest non-null value, if any
+    pub max: Option<Value>,
+    /// The (estimated) number of distinct non-null values
+    pub distinct_count: Option<u64>,
+}
+
+pub fn compute_statistics(
+    mappings: &[ArrayMapping],
+    buffers: &MutableBuffers,
+) -> Vec<ColumnStatistics> {
+    mappings
+        .iter()
+        .map(|mapping| compute_column_statistics(mapping, buffers))
+        .collect()
+}
+
+fn compute_column_statistics(mapping: &ArrayMapping, buffers: &MutableBuffers) -> ColumnStatistics {
+    use ArrayMapping as M;
+
+    let len = field_len(mapping, buffers);
+    let validity = mapping.get_validity().map(|idx| &buffers.u1[idx]);
+    let is_valid = |idx: usize| validity.map(|v| get_bit(v, idx)).unwrap_or(true);
+
+    let null_count = match mapping {
+        M::Null { .. } => len,
+        _ => (0..len).filter(|&idx| !is_valid(idx)).count(),
+    };
+
+    let summary = match mapping {
+        &M::Bool { buffer, .. } => summarize(
+            (0..len).map(|idx| get_bit(&buffers.u1[buffer], idx)),
+            is_valid,
+            |v| *v,
+            Value::Bool,
+        ),
+        &M::U8 { buffer, .. } => summarize_bytes::<u8>(&buffers.u8[buffer], is_valid, Value::U8),
+        &M::U16 { buffer, .. } => {
+            summarize_bytes::<u16>(&buffers.u16[buffer], is_valid, Value::U16)
+        }
+        &M::U32 { buffer, .. } => {
+            summarize_bytes::<u32>(&buffers.u32[buffer], is_valid, Value::U32)
+        }
+        &M::U64 { buffer, .. } => {
+            summarize_bytes::<u64>(&buffers.u64[buffer], is_valid, Value::U64)
+        }
+        &M::I8 { buffer, .. } => summarize_bytes::<i8>(&buffers.u8[buffer], is_valid, Value::I8),
+        &M::I16 { buffer, .. } => {
+            summarize_bytes::<i16>(&buffers.u16[buffer], is_valid, Value::I16)
+        }
+        &M::I32 { buffer, .. } => {
+            summarize_bytes::<i32>(&buffers.u32[buffer], is_valid, Value::I32)
+        }
+        &M::I64 { buffer, .. } | &M::Date64 { buffer, .. } => {
+            summarize_bytes::<i64>(&buffers.u64[buffer], is_valid, Value::I64)
+        }
+        &M::F16 { buffer, .. } => summarize(
+            buffers.u16[buffer]
+                .iter()
+                .map(|&v| f16::from_bits(v).to_f32()),
+            is_valid,
+            |v| v.to_bits(),
+            Value::F32,
+        ),
+        &M::F32 { buffer, .. } => summarize(
+            buffers.u32[buffer].iter().map(|&v| f32::from_bits(v)),
+            is_valid,
+            |v| v.to_bits(),
+            Value::F32,
+        ),
+        &M::F64 { buffer, .. } => summarize(
+            buffers.u64[buffer].iter().map(|&v| f64::from_bits(v)),
+            is_valid,
+            |v| v.to_bits(),
+            Value::F64,
+        ),
+        &M::Utf8 {
+            buffer, offsets, ..
+        } => summarize_strs(
+            &buffers.u8[buffer],
+            buffers.u32_offsets[offsets]
+                .offsets
+                .iter()
+                .map(|&o| o as usize),
+            is_valid,
+        ),
+        &M::LargeUtf8 {
+            buffer, offsets, ..
+        } => summarize_strs(
+            &buffers.u8[buffer],
+            buffers.u64_offsets[offsets]
+                .offsets
+                .iter()
+                .map(|&o| o as usize),
+            is_valid,
+        ),
+        M::Dictionary { dictionary, .. } => {
+            // all values of the dictionary are used by at least one row
+            let (buffer, offsets) = match *dictionary {
+                DictionaryValue::Utf8 { buffer, offsets } => (
+                    &buffers.u8[buffer],
+                    buffers.u32_offsets[offsets]
+                        .offsets
+                        .iter()
+                        .map(|&o| o as usize)
+                        .collect::<Vec<_>>(),
+                ),
+                DictionaryValue::LargeUtf8 { buffer, offsets } => (
+                    &buffers.u8[buffer],
+                    buffers.u64_offsets[offsets]
+                        .offsets
+                        .iter()
+                        .map(|&o| o as usize)
+                        .collect::<Vec<_>>(),
+                ),
+            };
+            let (min, max, _) = summarize_strs(buffer, offsets.iter().copied(), |_| true);
+            (min, max, Some(offsets.len().saturating_sub(1) as u64))
+        }
+        _ => (None, None, None),
+    };
+    let (min, max, distinct_count) = summary;
+
+    ColumnStatistics {
+        name: mapping.get_field().name.clone(),
+        len,
+        null_count,
+        min,
+        max,
+        distinct_count,
+    }
+}
+
+type Summary = (Option<Value>, Option<Value>, Option<u64>);
+
+fn summarize<T, K, I>(
+    values: I,
+    is_valid: impl Fn(usize) -> bool,
+    key: impl Fn(&T) -> K,
+    to_value: impl Fn(T) -> Value,
+) -> Summary
+where
+    T: PartialOrd + Clone,
+    K: Hash,
+    I: IntoIterator<Item = T>,
+{
+    let mut min: Option<T> = None;
+    let mut max: Option<T> = None;
+    let mut sketch = DistinctSketch::new();
+
+    for (idx, value) in values.into_iter().enumerate() {
+        if !is_valid(idx) {
+            continue;
+        }
+        sketch.insert(key(&value));
+
+        // skip values without an order, i.e., NaNs
+        if value.partial_cmp(&value).is_none() {
+            continue;
+        }
+        if min.as_ref().map(|min| value < *min).unwrap_or(true) {
+            min = Some(value.clone());
+        }
+        if max.as_ref().map(|max| value > *max).unwrap_or(true) {
+            max = Some(value);
+        }
+    }
+
+    (
+        min.map(&to_value),
+        max.map(&to_value),
+        Some(sketch.estimate()),
+    )
+}
+
+fn summarize_bytes<T>(
+    values: &[T::Bytes],
+    is_valid: impl Fn(usize) -> bool,
+    to_value: impl Fn(T) -> Value,
+) -> Summary
+where
+    T: ToBytes + PartialOrd + Clone + Hash,
+    T::Bytes: Clone,
+{
+    summarize(
+        values.iter().cloned().map(T::from_bytes),
+        is_valid,
+        |v| v.clone(),
+        to_value,
+    )
+}
+
+fn summarize_strs(
+    data: &[u8],
+    offsets: impl Iterator<Item = usize>,
+    is_valid: impl Fn(usize) -> bool,
+) -> Summary {
+    let mut offsets = offsets.peekable();
+    let values = std::iter::from_fn(|| {
+        let start = offsets.next()?;
+        let end = *offsets.peek()?;
+        Some(std::str::from_utf8(&data[start..end]).unwrap_or_default())
+    });
+    summarize(values, is_valid, |v| *v, |v| Value::Str(v.to_owned()))
+}
+
+pub(crate) fn get_bit(buffer: &MutableBitBuffer, idx: usize) -> bool {
+    buffer.buffer[idx / 8] & (1 << (idx % 8)) != 0
+}
+
+/// The number of bits of the hash used to select the register
+const SKETCH_BITS: u32 = 10;
+
+/// A HyperLogLog sketch to estimate the number of distinct values
+struct DistinctSketch {
+    registers: Vec<u8>,
+}
+
+impl DistinctSketch {
+    fn new() -> Self {
+        Self {
+            registers: vec![0; 1 << SKETCH_BITS],
+        }
+    }
+
+    fn insert<K: Hash>(&mut self, key: K) {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let idx = (hash >> (64 - SKETCH_BITS)) as usize;
+        // the sentinel bit limits the rank to the number of remaining bits
+        let rank = ((hash << SKETCH_BITS) | (1 << (SKETCH_BITS - 1))).leading_zeros() + 1;
+        self.registers[idx] = self.registers[idx].max(rank as u8);
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum = self
+            .registers
+            .iter()
+            .map(|&r| 2.0_f64.powi(-i32::from(r)))
+            .sum::<f64>();
+        let raw = alpha * m * m / sum;
+
+        // use linear counting for small cardinalities
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if raw <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            raw.round() as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DistinctSketch;
+
+    #[test]
+    fn distinct_sketch_estimates() {
+        for num_values in [0_u64, 1, 10, 100, 1_000, 10_000, 100_000] {
+            let mut sketch = DistinctSketch::new();
+            for value in 0..num_values {
+                // insert each value twice
+                sketch.insert(value);
+                sketch.insert(value);
+            }
+
+            let estimate = sketch.estimate() as f64;
+            let expected = num_values as f64;
+            assert!(
+                (estimate - expected).abs() <= 0.1 * expected,
+                "estimate {estimate} for {num_values} distinct values"
+            );
+        }
+    }
+}
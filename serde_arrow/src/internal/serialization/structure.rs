@@ -56,6 +56,7 @@ pub struct NullDefinition {
     pub u16: Vec<usize>,
     pub u32: Vec<usize>,
     pub u64: Vec<usize>,
+    pub u128: Vec<usize>,
     pub u32_offsets: Vec<usize>,
     pub u64_offsets: Vec<usize>,
 }
@@ -161,6 +162,12 @@ impl NullDefinition {
                 self.u64.push(buffer);
                 self.u1.extend(validity);
             }
+            &ArrayMapping::Decimal128 {
+                buffer, validity, ..
+            } => {
+                self.u128.push(buffer);
+                self.u1.extend(validity);
+            }
             ArrayMapping::Struct {
                 fields, validity, ..
             } => {
@@ -173,7 +180,7 @@ impl NullDefinition {
                 offsets, validity, ..
             } => {
                 // NOTE: the entries is not included
-                self.u64_offsets.push(offsets);
+                self.u32_offsets.push(offsets);
                 self.u1.extend(validity);
             }
             &ArrayMapping::LargeList {
@@ -183,6 +190,20 @@ impl NullDefinition {
                 self.u64_offsets.push(offsets);
                 self.u1.extend(validity);
             }
+            ArrayMapping::FixedSizeList {
+                item,
+                n,
+                count,
+                validity,
+                ..
+            } => {
+                // NOTE: null lists still contain n (null) items
+                self.u0.push(*count);
+                for _ in 0..*n {
+                    self.update_from_array_mapping(item)?;
+                }
+                self.u1.extend(validity.iter().copied());
+            }
             &ArrayMapping::Dictionary {
                 indices, validity, ..
             } => {
@@ -209,6 +230,7 @@ impl NullDefinition {
         self.u16.sort();
         self.u32.sort();
         self.u64.sort();
+        self.u128.sort();
         self.u32_offsets.sort();
         self.u64_offsets.sort();
     }
@@ -0,0 +1,234 @@
+//! The shapes of the values handed to serde during deserialization
+//!
+use crate::internal::{
+    error::{fail, Result},
+    schema::{GenericDataType, GenericField, SerdeArrowSchema, Strategy},
+};
+
+/// The kind of value the deserializer hands to serde for a field
+///
+/// The variants follow the serde data model. Nested values are described by
+/// the shapes of their child fields, which can be queried with their own
+/// paths.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SerdeShape {
+    /// A unit value, only nulls are stored
+    Null,
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+    /// A string
+    Str,
+    /// A number parsed from a string, given as an `i64`, `u64` or `f64` (see
+    /// [`Strategy::NumberAsStr`])
+    Number,
+    /// A sequence of items of the child field
+    Seq,
+    /// A tuple with the child fields as its elements
+    Tuple(usize),
+    /// A struct with the given field names
+    Struct(Vec<String>),
+    /// A map with the `key` and `value` children (for maps stored as structs,
+    /// the field names are the keys)
+    Map,
+    /// An enum with the given variant names
+    Enum(Vec<String>),
+}
+
+/// The expected serde shape of a field, see
+/// [`SerdeArrowSchema::expected_shape`]
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExpectedShape {
+    /// The path of the field
+    pub path: String,
+    /// The kind of value handed to serde
+    pub shape: SerdeShape,
+    /// Whether the value may be missing, in which case the deserializer
+    /// issues `visit_none` and wraps present values in `visit_some`
+    pub nullable: bool,
+    /// The value deserialized in place of nulls (see
+    /// [`NULL_DEFAULT_KEY`][crate::schema::NULL_DEFAULT_KEY])
+    pub null_default: Option<String>,
+}
+
+impl SerdeArrowSchema {
+    /// Describe the values handed to serde when deserializing the field at
+    /// `path`
+    ///
+    /// Paths start with `$` followed by the names of the fields separated by
+    /// `.`, e.g., `$.items.element` for the items of the list `items`. The
+    /// keys and values of maps are addressed as `key` and `value`. The
+    /// dictionary encoding of a field is transparent, i.e., the path of a
+    /// dictionary field describes its values.
+    ///
+    /// This way, frameworks that map rows dynamically can validate their
+    /// bindings before deserializing any data.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, SerdeShape};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(&serde_json::json!([
+    ///     {"name": "items", "data_type": "LargeList", "children": [
+    ///         {"name": "element", "data_type": "Struct", "nullable": true, "children": [
+    ///             {"name": "a", "data_type": "U8"},
+    ///             {"name": "b", "data_type": "LargeUtf8"},
+    ///         ]},
+    ///     ]},
+    /// ]))?;
+    ///
+    /// let shape = schema.expected_shape("$.items")?;
+    /// assert_eq!(shape.shape, SerdeShape::Seq);
+    ///
+    /// let shape = schema.expected_shape("$.items.element")?;
+    /// assert_eq!(shape.shape, SerdeShape::Struct(vec![String::from("a"), String::from("b")]));
+    /// assert!(shape.nullable);
+    ///
+    /// let shape = schema.expected_shape("$.items.element.b")?;
+    /// assert_eq!(shape.shape, SerdeShape::Str);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expected_shape(&self, path: &str) -> Result<ExpectedShape> {
+        let Some(rest) = path.strip_prefix('$') else {
+            fail!("Invalid path {path:?}: paths must start with \"$\"");
+        };
+        let Some(rest) = rest.strip_prefix('.') else {
+            fail!("Invalid path {path:?}: paths must refer to a field, e.g., \"$.field\"");
+        };
+
+        let mut parts = rest.split('.');
+        let name = parts.next().unwrap_or_default();
+        let Some(mut field) = self.fields.iter().find(|field| field.name == name) else {
+            fail!("Invalid path {path:?}: unknown field {name:?}");
+        };
+        for name in parts {
+            let Some(child) = find_child(field, name) else {
+                fail!(
+                    "Invalid path {path:?}: field {:?} has no child {name:?}",
+                    field.name
+                );
+            };
+            field = child;
+        }
+
+        Ok(ExpectedShape {
+            path: path.to_owned(),
+            shape: get_shape(field)?,
+            nullable: field.nullable,
+            null_default: field.null_default.clone(),
+        })
+    }
+
+    /// The expected shapes of all fields in depth-first order, see
+    /// [`expected_shape`][SerdeArrowSchema::expected_shape]
+    ///
+    pub fn expected_shapes(&self) -> Result<Vec<ExpectedShape>> {
+        let mut res = Vec::new();
+        for field in &self.fields {
+            collect_shapes(&mut res, field, &format!("$.{}", field.name))?;
+        }
+        Ok(res)
+    }
+}
+
+/// The children addressable by path, dictionaries and map entries are skipped
+fn path_children(field: &GenericField) -> Vec<&GenericField> {
+    match &field.data_type {
+        GenericDataType::Map => field
+            .children
+            .iter()
+            .flat_map(|entries| &entries.children)
+            .collect(),
+        GenericDataType::Dictionary => Vec::new(),
+        _ => field.children.iter().collect(),
+    }
+}
+
+fn find_child<'a>(field: &'a GenericField, name: &str) -> Option<&'a GenericField> {
+    let children = path_children(field);
+    if let GenericDataType::Map = &field.data_type {
+        let idx = match name {
+            "key" => 0,
+            "value" => 1,
+            _ => return None,
+        };
+        return children.get(idx).copied();
+    }
+    children.into_iter().find(|child| child.name == name)
+}
+
+fn collect_shapes(res: &mut Vec<ExpectedShape>, field: &GenericField, path: &str) -> Result<()> {
+    res.push(ExpectedShape {
+        path: path.to_owned(),
+        shape: get_shape(field)?,
+        nullable: field.nullable,
+        null_default: field.null_default.clone(),
+    });
+
+    let is_map = matches!(field.data_type, GenericDataType::Map);
+    for (idx, child) in path_children(field).into_iter().enumerate() {
+        let name = match (is_map, idx) {
+            (true, 0) => "key",
+            (true, _) => "value",
+            (false, _) => child.name.as_str(),
+        };
+        collect_shapes(res, child, &format!("{path}.{name}"))?;
+    }
+    Ok(())
+}
+
+fn get_shape(field: &GenericField) -> Result<SerdeShape> {
+    use {GenericDataType as D, SerdeShape as S};
+
+    let shape = match &field.data_type {
+        D::Null => S::Null,
+        D::Bool => S::Bool,
+        D::I8 => S::I8,
+        D::I16 => S::I16,
+        D::I32 => S::I32,
+        D::I64 => S::I64,
+        D::U8 => S::U8,
+        D::U16 => S::U16,
+        D::U32 => S::U32,
+        D::U64 => S::U64,
+        D::F16 | D::F32 => S::F32,
+        D::F64 => S::F64,
+        D::Utf8 | D::LargeUtf8 => match field.strategy.as_ref() {
+            Some(Strategy::NumberAsStr) => S::Number,
+            _ => S::Str,
+        },
+        D::Decimal128(_, _) => S::Str,
+        D::Date64 | D::Timestamp(_, _) => match field.strategy.as_ref() {
+            Some(Strategy::UtcStrAsDate64 | Strategy::NaiveStrAsDate64) => S::Str,
+            _ => S::I64,
+        },
+        D::List | D::LargeList | D::FixedSizeList(_) => S::Seq,
+        D::Map => S::Map,
+        D::Dictionary => {
+            let Some(values) = field.children.get(1) else {
+                fail!("Dictionary field {:?} without value field", field.name);
+            };
+            get_shape(values)?
+        }
+        D::Struct => match field.strategy.as_ref() {
+            Some(Strategy::TupleAsStruct) => S::Tuple(field.children.len()),
+            Some(Strategy::MapAsStruct) => S::Map,
+            _ => S::Struct(field.children.iter().map(|f| f.name.clone()).collect()),
+        },
+        D::Union => S::Enum(field.children.iter().map(|f| f.name.clone()).collect()),
+    };
+    Ok(shape)
+}
@@ -6,8 +6,12 @@ use serde::ser::{
 };
 
 use crate::internal::{
-    error::{Error, Result},
+    converters::Converter,
+    error::{fail, Error, Result},
     event::Event,
+    markers::TypeMarker,
+    source::deserialize_from_source,
+    value::Value,
 };
 
 /// Serialize a type into an [EventSink]
@@ -104,6 +108,29 @@ pub trait EventSink {
     fn accept_f64(&mut self, val: f64) -> Result<()>;
     fn accept(&mut self, event: Event<'_>) -> Result<()>;
     fn finish(&mut self) -> Result<()>;
+
+    /// The conversion for newtype structs with the given name, if any
+    fn get_converter(&self, _name: &str) -> Option<Converter> {
+        None
+    }
+
+    /// Fix the representation of the next value, see [`TypeMarker`]
+    fn accept_type_marker(&mut self, _marker: TypeMarker) -> Result<()> {
+        Ok(())
+    }
+
+    /// Whether the next value is written into a map
+    ///
+    /// If `true`, sequences are serialized as maps and their elements, e.g.,
+    /// `(K, V)` tuples, as key-value pairs.
+    fn expects_map(&self) -> bool {
+        false
+    }
+
+    /// The value reported by `Serializer::is_human_readable()`
+    fn is_human_readable(&self) -> bool {
+        true
+    }
 }
 
 impl EventSink for Vec<Event<'static>> {
@@ -125,7 +152,7 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
     type Ok = ();
     type Error = Error;
 
-    type SerializeSeq = Self;
+    type SerializeSeq = SequenceSerializer<'a, S>;
     type SerializeStruct = Self;
     type SerializeTuple = Self;
     type SerializeTupleStruct = Self;
@@ -137,6 +164,10 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
         self.0.accept_bool(val)
     }
 
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+
     fn serialize_i8(self, val: i8) -> Result<()> {
         self.0.accept_i8(val)
     }
@@ -214,15 +245,34 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
 
     fn serialize_newtype_struct<T: ?Sized + Serialize>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<()> {
-        value.serialize(self)
+        if let Some(marker) = TypeMarker::from_name(name) {
+            self.0.accept_type_marker(marker)?;
+            return value.serialize(self);
+        }
+        let Some(converter) = self.0.get_converter(name) else {
+            return value.serialize(self);
+        };
+
+        let mut events = Vec::<Event<'static>>::new();
+        serialize_into_sink(&mut events, value)?;
+        let value: Value = deserialize_from_source(&events)?;
+        converter.convert(value)?.serialize(self)
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
-        self.0.accept_start_sequence()?;
-        Ok(self)
+        let as_map = self.0.expects_map();
+        if as_map {
+            self.0.accept_start_map()?;
+        } else {
+            self.0.accept_start_sequence()?;
+        }
+        Ok(SequenceSerializer {
+            sink: self.0,
+            as_map,
+        })
     }
 
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
@@ -296,22 +346,112 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
     }
 }
 
-impl<'a, S: EventSink> SerializeSeq for EventSerializer<'a, S> {
+/// Serialize sequences, either as sequences or as maps of key-value pairs
+pub(crate) struct SequenceSerializer<'a, S> {
+    sink: &'a mut S,
+    as_map: bool,
+}
+
+impl<'a, S: EventSink> SerializeSeq for SequenceSerializer<'a, S> {
     type Ok = ();
     type Error = Error;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-        self.0.accept_item()?;
-        value.serialize(EventSerializer(&mut *self.0))?;
+        self.sink.accept_item()?;
+        if self.as_map {
+            value.serialize(EventSerializer(&mut PairSink::new(&mut *self.sink)))?;
+        } else {
+            value.serialize(EventSerializer(&mut *self.sink))?;
+        }
         Ok(())
     }
 
     fn end(self) -> Result<()> {
-        self.0.accept_end_sequence()?;
+        if self.as_map {
+            self.sink.accept_end_map()?;
+        } else {
+            self.sink.accept_end_sequence()?;
+        }
         Ok(())
     }
 }
 
+/// Forward a `(key, value)` tuple as the key and value of a map entry
+///
+/// The start and end of the tuple and the item markers of its elements are
+/// dropped, all other events are forwarded.
+struct PairSink<'a> {
+    wrapped: &'a mut dyn EventSink,
+    depth: usize,
+    num_elements: usize,
+}
+
+impl<'a> PairSink<'a> {
+    fn new(wrapped: &'a mut dyn EventSink) -> Self {
+        Self {
+            wrapped,
+            depth: 0,
+            num_elements: 0,
+        }
+    }
+}
+
+impl<'a> EventSink for PairSink<'a> {
+    macros::forward_specialized_to_generic!();
+
+    fn accept(&mut self, event: Event<'_>) -> Result<()> {
+        match (self.depth, event) {
+            (0, Event::StartTuple) => {
+                self.depth = 1;
+                Ok(())
+            }
+            (0, event) => fail!("Map entries must be serialized as pairs, found {event}"),
+            (1, Event::Item) => {
+                self.num_elements += 1;
+                if self.num_elements > 2 {
+                    fail!("Map entries must be serialized as pairs, found more elements");
+                }
+                Ok(())
+            }
+            (1, Event::EndTuple) => {
+                self.depth = 0;
+                if self.num_elements != 2 {
+                    fail!("Map entries must be serialized as pairs, found fewer elements");
+                }
+                Ok(())
+            }
+            (depth, event) => {
+                if event.is_start() {
+                    self.depth = depth + 1;
+                } else if event.is_end() {
+                    self.depth = depth - 1;
+                }
+                self.wrapped.accept(event)
+            }
+        }
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        self.wrapped.get_converter(name)
+    }
+
+    fn accept_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        self.wrapped.accept_type_marker(marker)
+    }
+
+    fn expects_map(&self) -> bool {
+        self.wrapped.expects_map()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.wrapped.is_human_readable()
+    }
+}
+
 impl<'a, S: EventSink> SerializeTuple for EventSerializer<'a, S> {
     type Ok = ();
     type Error = Error;
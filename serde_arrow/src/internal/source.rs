@@ -1,12 +1,18 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, marker::PhantomData};
 
 use serde::de::{
-    self, Deserialize, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+    self, Deserialize, DeserializeOwned, DeserializeSeed, EnumAccess, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
 };
 
 use crate::internal::{
-    error::{error, fail, Error, Result},
+    cancellation::CancellationToken,
+    conversions::ConvertNumber,
+    deserialization::ArraySource,
+    error::{error, fail, Error, ErrorKind, Result, RowError},
     event::Event,
+    options::{DeserializationOptions, Overflow, UnknownColumns},
+    progress::Progress,
 };
 
 /// Deserialize a type from an [EventSource]
@@ -21,10 +27,67 @@ pub fn deserialize_from_source<
 >(
     source: S,
 ) -> Result<T> {
+    deserialize_from_source_with_options(source, &DeserializationOptions::default())
+}
+
+/// Deserialize a type from an [EventSource] with the given options
+pub fn deserialize_from_source_with_options<
+    'de,
+    'event,
+    T: Deserialize<'de>,
+    S: IntoEventSource<'event> + 'event,
+>(
+    source: S,
+    options: &DeserializationOptions,
+) -> Result<T> {
+    deserialize_seed_from_source_with_options(PhantomData::<T>, source, options)
+}
+
+/// Deserialize a sequence of items from an [EventSource] and append them to
+/// the given vector
+///
+/// Items deserialized before an error are kept in the vector.
+pub fn deserialize_into_vec_from_source_with_options<
+    'de,
+    'event,
+    T: Deserialize<'de>,
+    S: IntoEventSource<'event> + 'event,
+>(
+    source: S,
+    options: &DeserializationOptions,
+    items: &mut Vec<T>,
+) -> Result<()> {
+    deserialize_seed_from_source_with_options(ExtendVec(items), source, options)
+}
+
+fn deserialize_seed_from_source_with_options<
+    'de,
+    'event,
+    T: DeserializeSeed<'de>,
+    S: IntoEventSource<'event> + 'event,
+>(
+    seed: T,
+    source: S,
+    options: &DeserializationOptions,
+) -> Result<T::Value> {
     let mut deserializer = Deserializer {
         source: PeekableEventSource::new(source.into_event_source()),
+        unknown_columns: options.unknown_columns,
+        strict: options.strict,
+        overflow: options.overflow,
+        human_readable: options.human_readable,
+        path: None,
+        variants: &[],
+        unknown_variant: false,
+        variant_from_str: false,
+        known_fields: Vec::new(),
+        struct_keys: Vec::new(),
+        exhausted_struct: None,
+        cancellation: options.cancellation.clone(),
+        progress: options.progress.clone().map(Progress::new),
+        num_rows: 0,
     };
-    let res = T::deserialize(&mut deserializer)?;
+    let res = seed.deserialize(&mut deserializer)?;
 
     if deserializer.source.next()?.is_some() {
         fail!("from_record_batch: Trailing content");
@@ -33,6 +96,132 @@ pub fn deserialize_from_source<
     Ok(res)
 }
 
+/// Deserialize a sequence by appending its items to an existing vector
+struct ExtendVec<'a, T>(&'a mut Vec<T>);
+
+impl<'de, 'a, T: Deserialize<'de>> DeserializeSeed<'de> for ExtendVec<'a, T> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a, T: Deserialize<'de>> Visitor<'de> for ExtendVec<'a, T> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        if let Some(len) = seq.size_hint() {
+            self.0.reserve(len);
+        }
+        while let Some(item) = seq.next_element()? {
+            self.0.push(item);
+        }
+        Ok(())
+    }
+}
+
+/// Deserialize a sequence of rows from an [EventSource], collecting the errors
+/// of individual rows
+///
+/// Rows that fail to deserialize are skipped and reported as [RowError]. Errors
+/// of the source itself abort the deserialization.
+pub fn deserialize_rows_from_source_with_options<
+    'de,
+    'event,
+    T: Deserialize<'de>,
+    S: IntoEventSource<'event> + 'event,
+>(
+    source: S,
+    options: &DeserializationOptions,
+) -> Result<(Vec<T>, Vec<RowError>)> {
+    let mut deserializer = Deserializer::new_for_rows(source.into_event_source(), options);
+    deserializer.start_rows()?;
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for row in 0.. {
+        match deserializer.next_row(row)? {
+            Some(Ok(item)) => rows.push(item),
+            Some(Err(error)) => errors.push(error),
+            None => break,
+        }
+    }
+
+    if deserializer.source.next()?.is_some() {
+        fail!("from_record_batch: Trailing content");
+    }
+
+    Ok((rows, errors))
+}
+
+/// An iterator over the rows of arrays, that deserializes each row on demand
+///
+/// Rows that fail to deserialize are returned as [RowError] and the iteration
+/// continues with the next row. Errors of the underlying arrays are returned
+/// once and end the iteration. Stopping the iteration early, e.g., via
+/// [`Iterator::take`], does not process the remaining rows.
+///
+/// See [`from_arrow_iter`][crate::from_arrow_iter] and
+/// [`from_arrow2_iter`][crate::from_arrow2_iter].
+pub struct RowIter<'event, T> {
+    deserializer: Deserializer<'event, ArraySource<'event>>,
+    row: usize,
+    done: bool,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'event, T> RowIter<'event, T> {
+    pub(crate) fn new(
+        source: ArraySource<'event>,
+        options: &DeserializationOptions,
+    ) -> Result<Self> {
+        let mut deserializer = Deserializer::new_for_rows(source, options);
+        deserializer.start_rows()?;
+
+        Ok(Self {
+            deserializer,
+            row: 0,
+            done: false,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'event, T: DeserializeOwned> Iterator for RowIter<'event, T> {
+    type Item = std::result::Result<T, RowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let row = self.row;
+        self.row += 1;
+
+        match self.deserializer.next_row(row) {
+            Ok(Some(res)) => Some(res),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(RowError {
+                    row,
+                    path: String::new(),
+                    error,
+                }))
+            }
+        }
+    }
+}
+
 /// A source of [Events][Event] that can be used to deserialize rust objects
 ///
 /// **Note**: implementations are not expected to yield `Some` events for
@@ -47,6 +236,13 @@ pub trait EventSource<'a> {
 pub struct PeekableEventSource<'a, S: EventSource<'a> + 'a> {
     source: S,
     peeked: Option<Option<Event<'a>>>,
+    /// The number of events returned by `next`
+    num_consumed: usize,
+    /// The nesting level after the last event returned by `next`
+    depth: usize,
+    /// Whether the last event returned by `next` is a marker that must be
+    /// followed by a value
+    pending_value: bool,
 }
 
 impl<'a, S: EventSource<'a> + 'a> PeekableEventSource<'a, S> {
@@ -54,6 +250,22 @@ impl<'a, S: EventSource<'a> + 'a> PeekableEventSource<'a, S> {
         Self {
             source,
             peeked: None,
+            num_consumed: 0,
+            depth: 0,
+            pending_value: false,
+        }
+    }
+
+    /// Skip the next value, including any nested values and markers
+    pub fn skip_value(&mut self) -> Result<()> {
+        let depth = self.depth;
+        loop {
+            if self.next()?.is_none() {
+                fail!("Unexpected end of events while skipping a value");
+            }
+            if self.depth <= depth && !self.pending_value {
+                return Ok(());
+            }
         }
     }
 
@@ -70,11 +282,29 @@ impl<'a, S: EventSource<'a> + 'a> PeekableEventSource<'a, S> {
 
 impl<'a, S: EventSource<'a> + 'a> EventSource<'a> for PeekableEventSource<'a, S> {
     fn next(&mut self) -> Result<Option<Event<'a>>> {
-        if let Some(peeked) = self.peeked.take() {
-            Ok(peeked)
+        let ev = if let Some(peeked) = self.peeked.take() {
+            peeked
         } else {
-            self.source.next()
+            self.source.next()?
+        };
+
+        if let Some(ev) = ev.as_ref() {
+            self.num_consumed += 1;
+            if ev.is_start() {
+                self.depth += 1;
+            } else if ev.is_end() {
+                self.depth = self.depth.saturating_sub(1);
+            }
+            self.pending_value = matches!(
+                ev,
+                Event::Item
+                    | Event::Some
+                    | Event::Default
+                    | Event::Variant(_, _)
+                    | Event::OwnedVariant(_, _)
+            );
         }
+        Ok(ev)
     }
 }
 
@@ -94,6 +324,373 @@ impl<'a, S: EventSource<'a>> IntoEventSource<'a> for S {
 
 pub struct Deserializer<'event, S: EventSource<'event>> {
     source: PeekableEventSource<'event, S>,
+    unknown_columns: UnknownColumns,
+    /// If `true`, primitives are only deserialized from events of the same
+    /// type
+    strict: bool,
+    /// How to handle integers that are out of range for the requested type
+    overflow: Overflow,
+    /// The value reported by `is_human_readable()`
+    human_readable: bool,
+    /// The keys of the currently deserialized structs and maps, only tracked
+    /// if `Some`
+    path: Option<Vec<String>>,
+    /// The variants declared by the currently deserialized enum
+    variants: &'static [&'static str],
+    /// Whether the current variant is not declared by the enum, i.e., it is
+    /// mapped to a `#[serde(other)]` variant and its value must be skipped
+    unknown_variant: bool,
+    /// Whether the current variant was read from a string, i.e., it is a unit
+    /// variant without a value
+    variant_from_str: bool,
+    /// The fields declared by the currently deserialized structs, `None` for
+    /// maps
+    known_fields: Vec<Option<&'static [&'static str]>>,
+    /// The keys of the currently deserialized structs, used to suggest
+    /// columns for missing fields
+    struct_keys: Vec<&'event str>,
+    /// The depth of `known_fields` of the last struct whose keys were
+    /// exhausted, i.e., the struct that raises missing field errors
+    exhausted_struct: Option<usize>,
+    /// The token checked before each top-level row, if any
+    cancellation: Option<CancellationToken>,
+    /// The rows counted for the progress callback, if any
+    progress: Option<Progress>,
+    /// The number of top-level rows started
+    num_rows: usize,
+}
+
+impl<'event, S: EventSource<'event>> Deserializer<'event, S> {
+    fn new_for_rows(source: S, options: &DeserializationOptions) -> Self {
+        Self {
+            source: PeekableEventSource::new(source),
+            unknown_columns: options.unknown_columns,
+            strict: options.strict,
+            overflow: options.overflow,
+            human_readable: options.human_readable,
+            path: Some(Vec::new()),
+            variants: &[],
+            unknown_variant: false,
+            variant_from_str: false,
+            known_fields: Vec::new(),
+            struct_keys: Vec::new(),
+            exhausted_struct: None,
+            cancellation: options.cancellation.clone(),
+            progress: options.progress.clone().map(Progress::new),
+            num_rows: 0,
+        }
+    }
+
+    fn start_rows(&mut self) -> Result<()> {
+        match self.source.next()? {
+            Some(Event::StartSequence) => Ok(()),
+            Some(ev) => fail!("Invalid event {ev}, expected StartSequence"),
+            None => fail!("Invalid event None, expected StartSequence"),
+        }
+    }
+
+    /// Run the hooks before each top-level row: fail if the deserialization
+    /// was cancelled and count the row for the progress callback
+    fn start_row(&mut self) -> Result<()> {
+        if let Some(token) = self.cancellation.as_ref() {
+            token.check(self.num_rows)?;
+        }
+        self.num_rows += 1;
+        if let Some(progress) = self.progress.as_mut() {
+            progress.advance();
+        }
+        Ok(())
+    }
+
+    /// Deserialize the next row, returns `None` at the end of the rows
+    ///
+    /// If the row fails to deserialize, its remaining events are skipped and
+    /// the error is returned as a [RowError]. Errors of the source are
+    /// returned directly.
+    fn next_row<'de, T: Deserialize<'de>>(
+        &mut self,
+        row: usize,
+    ) -> Result<Option<std::result::Result<T, RowError>>> {
+        match self.source.peek()? {
+            Some(Event::EndSequence) => {
+                self.source.next()?;
+                return Ok(None);
+            }
+            Some(Event::Item) => {
+                self.source.next()?;
+            }
+            _ => {}
+        }
+        self.start_row()?;
+
+        let depth = self.source.depth;
+        let num_consumed = self.source.num_consumed;
+
+        match T::deserialize(&mut *self) {
+            Ok(item) => Ok(Some(Ok(item))),
+            Err(error) => {
+                let path = self.path.replace(Vec::new()).unwrap_or_default();
+                self.known_fields.clear();
+                self.struct_keys.clear();
+                self.exhausted_struct = None;
+
+                // skip the remaining events of the row
+                let source = &mut self.source;
+                while source.depth > depth
+                    || source.pending_value
+                    || source.num_consumed == num_consumed
+                {
+                    if source.next()?.is_none() {
+                        fail!("Unexpected end of events while skipping row {row}");
+                    }
+                }
+
+                Ok(Some(Err(RowError {
+                    row,
+                    path: path.join("."),
+                    error,
+                })))
+            }
+        }
+    }
+
+    /// Get the next value event of a non-`Option` value, replacing nulls with
+    /// their defaults
+    ///
+    /// A null with a default is encoded as `Event::Default` followed by the
+    /// default value. This function is only used if the requested type is not
+    /// an `Option`, e.g., to deserialize nullable fields with defaults into
+    /// non-`Option` types. Therefore, the `Some` marker of the value carries no
+    /// information and is dropped. Options (and self-describing types via
+    /// `deserialize_any`) are deserialized with `deserialize_option`, which
+    /// forwards the marker to the visitor.
+    fn next_value(&mut self) -> Result<Event<'event>> {
+        self.next_raw_value()?.format_timestamp()
+    }
+
+    /// Get the next value without formatting timestamps as strings
+    fn next_raw_value(&mut self) -> Result<Event<'event>> {
+        match required(self.source.next()?)? {
+            Event::Default | Event::Some => required(self.source.next()?),
+            event => Ok(event),
+        }
+    }
+
+    /// Fail if the current variant was read from a string, as strings cannot
+    /// contain the values of non-unit variants
+    fn ensure_variant_with_value(&mut self) -> Result<()> {
+        if std::mem::take(&mut self.variant_from_str) {
+            fail!("Only unit variants can be deserialized from strings");
+        }
+        Ok(())
+    }
+
+    /// Get the next primitive value and convert it to the requested type
+    ///
+    /// In strict mode, the event must be of the same type as the requested
+    /// value, as checked by `is_exact`. Otherwise, the event is converted with
+    /// the `TryFrom<Event>` implementations.
+    fn next_primitive<T>(&mut self, is_exact: fn(&Event<'_>) -> bool, ty: &str) -> Result<T>
+    where
+        T: TryFrom<Event<'event>, Error = Error>,
+    {
+        let event = self.next_value()?;
+        if self.strict && !is_exact(&event) {
+            fail!("Strict deserialization: cannot deserialize {event} as {ty}");
+        }
+        event.try_into()
+    }
+
+    /// Get the next integer value and convert it to the requested type,
+    /// following the overflow policy
+    fn next_integer<T>(&mut self, is_exact: fn(&Event<'_>) -> bool, ty: &str) -> Result<T>
+    where
+        T: TryFrom<Event<'event>, Error = Error>
+            + ConvertNumber<u8>
+            + ConvertNumber<u16>
+            + ConvertNumber<u32>
+            + ConvertNumber<u64>
+            + ConvertNumber<i8>
+            + ConvertNumber<i16>
+            + ConvertNumber<i32>
+            + ConvertNumber<i64>,
+    {
+        // timestamps are deserialized as their raw value into integers
+        let event = match self.next_raw_value()? {
+            Event::Timestamp(val, _, _) => Event::I64(val),
+            event => event,
+        };
+        if self.strict && !is_exact(&event) {
+            fail!("Strict deserialization: cannot deserialize {event} as {ty}");
+        }
+        match event {
+            Event::U8(val) => convert_integer(val, ty, self.overflow),
+            Event::U16(val) => convert_integer(val, ty, self.overflow),
+            Event::U32(val) => convert_integer(val, ty, self.overflow),
+            Event::U64(val) => convert_integer(val, ty, self.overflow),
+            Event::I8(val) => convert_integer(val, ty, self.overflow),
+            Event::I16(val) => convert_integer(val, ty, self.overflow),
+            Event::I32(val) => convert_integer(val, ty, self.overflow),
+            Event::I64(val) => convert_integer(val, ty, self.overflow),
+            event => event.try_into(),
+        }
+    }
+
+    /// Deserialize a struct, `fields` are the declared fields of the target
+    /// type or `None` if unknown
+    fn deserialize_struct_with_fields<'de, V: Visitor<'de>>(
+        &mut self,
+        fields: Option<&'static [&'static str]>,
+        visitor: V,
+    ) -> Result<V::Value> {
+        if !matches!(self.source.next()?, Some(Event::StartStruct)) {
+            fail!("Expected start of struct");
+        }
+
+        let keys_start = self.struct_keys.len();
+        self.known_fields.push(fields);
+        let res = visitor.visit_map(&mut *self);
+        let depth = self.known_fields.len();
+        self.known_fields.pop();
+
+        let res = match res {
+            Ok(res) => res,
+            Err(err) if self.exhausted_struct == Some(depth) => {
+                self.exhausted_struct = None;
+                let keys = &self.struct_keys[keys_start..];
+                let err = suggest_columns(err, fields.unwrap_or_default(), keys);
+                self.struct_keys.truncate(keys_start);
+                return Err(err);
+            }
+            Err(err) => {
+                self.struct_keys.truncate(keys_start);
+                return Err(err);
+            }
+        };
+        self.struct_keys.truncate(keys_start);
+
+        if !matches!(self.source.next()?, Some(Event::EndStruct)) {
+            fail!("Expected end of struct");
+        }
+        Ok(res)
+    }
+    /// Deserialize a struct as a tuple by matching its fields by position
+    ///
+    /// This way, rows of multiple columns can be deserialized into tuples and
+    /// tuple structs, e.g., `Vec<(String, i64, f64)>`. The field names are
+    /// ignored.
+    fn deserialize_struct_as_tuple<'de, V: Visitor<'de>>(
+        &mut self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        if !matches!(self.source.next()?, Some(Event::StartStruct)) {
+            fail!("Expected start of struct");
+        }
+
+        // shorter structs are rejected by the visitor
+        let res = visitor.visit_seq(StructFields(&mut *self))?;
+
+        match self.source.peek()? {
+            Some(Event::EndStruct) => {
+                self.source.next()?;
+                Ok(res)
+            }
+            Some(Event::Item | Event::Str(_) | Event::OwnedStr(_)) => fail!(
+                "Cannot deserialize a struct with more than {len} fields into a tuple of length {len}"
+            ),
+            _ => fail!("Expected end of struct"),
+        }
+    }
+}
+
+/// Add the columns with names similar to a missing field to its error
+///
+/// Only columns that do not match a declared field are suggested. Names are
+/// compared ignoring case and the separators `_` and `-`, so that mismatches
+/// between `snake_case` and `camelCase` are found.
+fn suggest_columns(err: Error, fields: &[&str], keys: &[&str]) -> Error {
+    let ErrorKind::MissingField { field } = err.kind() else {
+        return err;
+    };
+
+    let normalized_field = normalize_name(field);
+    let max_distance = std::cmp::max(1, normalized_field.chars().count() / 3);
+
+    let mut candidates = Vec::new();
+    for key in keys {
+        if fields.contains(key) || candidates.iter().any(|(_, candidate)| candidate == key) {
+            continue;
+        }
+        let distance = edit_distance(&normalized_field, &normalize_name(key));
+        if distance <= max_distance {
+            candidates.push((distance, *key));
+        }
+    }
+    if candidates.is_empty() {
+        return err;
+    }
+    candidates.sort_by_key(|(distance, _)| *distance);
+
+    let suggestions = candidates
+        .iter()
+        .take(3)
+        .map(|(_, key)| format!("{key:?}"))
+        .collect::<Vec<_>>();
+    let message = if let [suggestion] = suggestions.as_slice() {
+        format!("{}. Did you mean column {suggestion}?", err.message())
+    } else {
+        format!(
+            "{}. Did you mean one of the columns {}?",
+            err.message(),
+            suggestions.join(", ")
+        )
+    };
+    Error::with_kind(err.kind().clone(), message)
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+fn convert_integer<S, T>(val: S, ty: &str, overflow: Overflow) -> Result<T>
+where
+    S: Copy + std::fmt::Display,
+    T: ConvertNumber<S>,
+{
+    let Some(res) = T::convert(val, overflow) else {
+        fail!(
+            concat!(
+                "Cannot convert {val} ({src}) to {ty} without overflow. ",
+                "Consider setting the `overflow` deserialization option to saturate or wrap ",
+                "out of range values.",
+            ),
+            val = val,
+            src = std::any::type_name::<S>(),
+            ty = ty,
+        );
+    };
+    Ok(res)
 }
 
 impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
@@ -101,8 +698,15 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
 {
     type Error = Error;
 
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
     fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         match self.source.peek()? {
+            // keep the distinction between nulls and values, the default is
+            // only used if a non-option value is requested
+            Some(Event::Null | Event::Some | Event::Default) => self.deserialize_option(visitor),
             Some(Event::Bool(_)) => self.deserialize_bool(visitor),
             Some(Event::I8(_)) => self.deserialize_i8(visitor),
             Some(Event::I16(_)) => self.deserialize_i16(visitor),
@@ -115,8 +719,11 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
             Some(Event::F32(_)) => self.deserialize_f32(visitor),
             Some(Event::F64(_)) => self.deserialize_f64(visitor),
             Some(Event::Str(_)) => self.deserialize_str(visitor),
-            Some(Event::OwnedStr(_)) => self.deserialize_string(visitor),
-            Some(Event::StartStruct) => self.deserialize_struct("", &[], visitor),
+            Some(Event::OwnedStr(_) | Event::Timestamp(_, _, _)) => {
+                self.deserialize_string(visitor)
+            }
+            // the fields are unknown, do not check for unknown columns
+            Some(Event::StartStruct) => self.deserialize_struct_with_fields(None, visitor),
             Some(Event::StartMap) => self.deserialize_map(visitor),
             Some(Event::StartSequence) => self.deserialize_seq(visitor),
             Some(Event::StartTuple) => self.deserialize_tuple(0, visitor),
@@ -129,51 +736,51 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_bool(required(self.source.next()?)?.try_into()?)
+        visitor.visit_bool(self.next_primitive(|ev| matches!(ev, Event::Bool(_)), "bool")?)
     }
 
     fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_i8(required(self.source.next()?)?.try_into()?)
+        visitor.visit_i8(self.next_integer(|ev| matches!(ev, Event::I8(_)), "i8")?)
     }
 
     fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_i16(required(self.source.next()?)?.try_into()?)
+        visitor.visit_i16(self.next_integer(|ev| matches!(ev, Event::I16(_)), "i16")?)
     }
 
     fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_i32(required(self.source.next()?)?.try_into()?)
+        visitor.visit_i32(self.next_integer(|ev| matches!(ev, Event::I32(_)), "i32")?)
     }
 
     fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_i64(required(self.source.next()?)?.try_into()?)
+        visitor.visit_i64(self.next_integer(|ev| matches!(ev, Event::I64(_)), "i64")?)
     }
 
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_u8(required(self.source.next()?)?.try_into()?)
+        visitor.visit_u8(self.next_integer(|ev| matches!(ev, Event::U8(_)), "u8")?)
     }
 
     fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_u16(required(self.source.next()?)?.try_into()?)
+        visitor.visit_u16(self.next_integer(|ev| matches!(ev, Event::U16(_)), "u16")?)
     }
 
     fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_u32(required(self.source.next()?)?.try_into()?)
+        visitor.visit_u32(self.next_integer(|ev| matches!(ev, Event::U32(_)), "u32")?)
     }
 
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_u64(required(self.source.next()?)?.try_into()?)
+        visitor.visit_u64(self.next_integer(|ev| matches!(ev, Event::U64(_)), "u64")?)
     }
 
     fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_f32(required(self.source.next()?)?.try_into()?)
+        visitor.visit_f32(self.next_primitive(|ev| matches!(ev, Event::F32(_)), "f32")?)
     }
 
     fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        visitor.visit_f64(required(self.source.next()?)?.try_into()?)
+        visitor.visit_f64(self.next_primitive(|ev| matches!(ev, Event::F64(_)), "f64")?)
     }
 
     fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        match required(self.source.next()?)? {
+        match self.next_value()? {
             Event::U32(val) => {
                 visitor.visit_char(char::from_u32(val).ok_or_else(|| error!("Invalid character"))?)
             }
@@ -185,17 +792,28 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        match required(self.source.next()?)? {
-            Event::Str(val) => visitor.visit_str(val),
-            Event::OwnedStr(val) => visitor.visit_str(&val),
+        match self.next_value()? {
+            Event::Str(val) => visitor
+                .visit_str(val)
+                .map_err(|err| with_timezone_hint(err, val)),
+            Event::OwnedStr(val) => visitor
+                .visit_str(&val)
+                .map_err(|err| with_timezone_hint(err, &val)),
             ev => fail!("Invalid event {}, expected str", ev),
         }
     }
 
     fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        match required(self.source.next()?)? {
-            Event::Str(val) => visitor.visit_string(val.to_owned()),
-            Event::OwnedStr(val) => visitor.visit_string(val),
+        match self.next_value()? {
+            Event::Str(val) => visitor
+                .visit_string(val.to_owned())
+                .map_err(|err| with_timezone_hint(err, val)),
+            Event::OwnedStr(val) => {
+                let hint_val = val.clone();
+                visitor
+                    .visit_string(val)
+                    .map_err(|err| with_timezone_hint(err, &hint_val))
+            }
             ev => fail!("Invalid event {}, expected string", ev),
         }
     }
@@ -209,16 +827,25 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        if let Some(Event::Null) = self.source.peek()? {
-            self.source.next()?;
-            visitor.visit_none()
-        } else {
-            // Support deserializing options both with and without Some markers
-            if let Some(Event::Some) = self.source.peek()? {
+        match self.source.peek()? {
+            Some(Event::Null) => {
+                self.source.next()?;
+                visitor.visit_none()
+            }
+            // nulls with defaults are deserialized as None for options
+            Some(Event::Default) => {
+                self.source.next()?;
                 self.source.next()?;
+                visitor.visit_none()
             }
+            _ => {
+                // Support deserializing options both with and without Some markers
+                if let Some(Event::Some) = self.source.peek()? {
+                    self.source.next()?;
+                }
 
-            visitor.visit_some(self)
+                visitor.visit_some(self)
+            }
         }
     }
 
@@ -246,8 +873,17 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        if !matches!(self.source.next()?, Some(Event::StartSequence)) {
-            fail!("Expected start of sequence");
+        match self.source.next()? {
+            Some(Event::StartSequence) => {}
+            // maps can be deserialized as sequences of key-value pairs
+            Some(Event::StartMap) => {
+                let res = visitor.visit_seq(MapEntries(&mut *self))?;
+                if !matches!(self.source.next()?, Some(Event::EndMap)) {
+                    fail!("Expected end of map");
+                }
+                return Ok(res);
+            }
+            _ => fail!("Expected start of sequence"),
         }
 
         let res = visitor.visit_seq(&mut *self)?;
@@ -258,25 +894,41 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
         Ok(res)
     }
 
-    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
-        if !matches!(self.source.next()?, Some(Event::StartTuple)) {
-            fail!("Expected start of tuple");
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        if matches!(self.source.peek()?, Some(Event::StartStruct)) {
+            return self.deserialize_struct_as_tuple(len, visitor);
         }
 
+        // tuples, e.g., arrays, can be deserialized from sequences, e.g.,
+        // fixed size lists
+        let is_sequence = match self.source.next()? {
+            Some(Event::StartTuple) => false,
+            Some(Event::StartSequence) => true,
+            _ => fail!("Expected start of tuple"),
+        };
+
+        // shorter sequences are rejected by the visitor
         let res = visitor.visit_seq(&mut *self)?;
 
-        if !matches!(self.source.next()?, Some(Event::EndTuple)) {
-            fail!("Expected end of tuple");
+        match (self.source.next()?, is_sequence) {
+            (Some(Event::EndTuple), false) | (Some(Event::EndSequence), true) => Ok(res),
+            (_, true) => fail!(
+                "Cannot deserialize a sequence with more than {len} items into a tuple or array of length {len}"
+            ),
+            _ => fail!("Expected end of tuple"),
         }
-        Ok(res)
     }
 
     fn deserialize_tuple_struct<V: Visitor<'de>>(
         self,
         _name: &'static str,
-        _len: usize,
+        len: usize,
         visitor: V,
     ) -> Result<V::Value> {
+        if matches!(self.source.peek()?, Some(Event::StartStruct)) {
+            return self.deserialize_struct_as_tuple(len, visitor);
+        }
+
         if !matches!(self.source.next()?, Some(Event::StartTuple)) {
             fail!("Expected start of tuple");
         }
@@ -296,7 +948,10 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
             None => fail!("Expected StartMap, got None"),
         }
 
-        let res = visitor.visit_map(&mut *self)?;
+        self.known_fields.push(None);
+        let res = visitor.visit_map(&mut *self);
+        self.known_fields.pop();
+        let res = res?;
 
         match self.source.next()? {
             Some(Event::EndMap) | Some(Event::EndStruct) => {}
@@ -310,27 +965,19 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     fn deserialize_struct<V: Visitor<'de>>(
         self,
         _name: &'static str,
-        _fields: &'static [&'static str],
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
-        if !matches!(self.source.next()?, Some(Event::StartStruct)) {
-            fail!("Expected start of struct");
-        }
-
-        let res = visitor.visit_map(&mut *self)?;
-
-        if !matches!(self.source.next()?, Some(Event::EndStruct)) {
-            fail!("Expected end of struct");
-        }
-        Ok(res)
+        self.deserialize_struct_with_fields(Some(fields), visitor)
     }
 
     fn deserialize_enum<V: Visitor<'de>>(
         self,
         _name: &'static str,
-        _variants: &'static [&'static str],
+        variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
+        self.variants = variants;
         visitor.visit_enum(&mut *self)
     }
 
@@ -353,6 +1000,9 @@ impl<'de, 'a, 'event, S: EventSource<'event>> SeqAccess<'de> for &'a mut Deseria
         if matches!(self.source.peek()?, Some(Event::EndSequence)) {
             return Ok(None);
         }
+        if self.source.depth == 1 {
+            self.start_row()?;
+        }
         // ignore event markers to be forwards compatible
         if matches!(self.source.peek()?, Some(Event::Item)) {
             self.source.next()?;
@@ -369,7 +1019,10 @@ impl<'de, 'a, 'event, S: EventSource<'event>> MapAccess<'de> for &'a mut Deseria
         K: DeserializeSeed<'de>,
     {
         match self.source.peek()? {
-            Some(Event::EndStruct) | Some(Event::EndMap) => return Ok(None),
+            Some(Event::EndStruct) | Some(Event::EndMap) => {
+                self.exhausted_struct = Some(self.known_fields.len());
+                return Ok(None);
+            }
             // allow optional item markers. E.g., structs are currently
             // serialized without item markers.
             Some(Event::Item) => {
@@ -378,6 +1031,48 @@ impl<'de, 'a, 'event, S: EventSource<'event>> MapAccess<'de> for &'a mut Deseria
             _ => {}
         }
 
+        if let Some(path) = self.path.as_mut() {
+            // timestamp keys are deserialized as their date time strings
+            let key = match self
+                .source
+                .peek()?
+                .map(Event::format_timestamp)
+                .transpose()?
+            {
+                Some(Event::Str(key)) => key.to_owned(),
+                Some(Event::OwnedStr(key)) => key,
+                Some(ev) => ev.to_string(),
+                None => String::new(),
+            };
+            path.push(key);
+        }
+
+        if let (Some(Some(_)), Some(Event::Str(key))) =
+            (self.known_fields.last(), self.source.peek()?)
+        {
+            self.struct_keys.push(key);
+        }
+
+        if self.unknown_columns == UnknownColumns::Error {
+            if let Some(Some(fields)) = self.known_fields.last() {
+                let next = self
+                    .source
+                    .peek()?
+                    .map(Event::format_timestamp)
+                    .transpose()?;
+                let key = match &next {
+                    Some(Event::Str(key)) => Some(*key),
+                    Some(Event::OwnedStr(key)) => Some(key.as_str()),
+                    _ => None,
+                };
+                if let Some(key) = key {
+                    if !fields.contains(&key) {
+                        fail!("Unknown column {key:?}, expected one of {fields:?}");
+                    }
+                }
+            }
+        }
+
         seed.deserialize(&mut **self).map(Some)
     }
 
@@ -385,7 +1080,11 @@ impl<'de, 'a, 'event, S: EventSource<'event>> MapAccess<'de> for &'a mut Deseria
     where
         V: DeserializeSeed<'de>,
     {
-        seed.deserialize(&mut **self)
+        let res = seed.deserialize(&mut **self)?;
+        if let Some(path) = self.path.as_mut() {
+            path.pop();
+        }
+        Ok(res)
     }
 }
 
@@ -397,11 +1096,16 @@ impl<'de, 'a, 'event, S: EventSource<'event>> EnumAccess<'de> for &'a mut Deseri
     where
         V: DeserializeSeed<'de>,
     {
-        let (name, idx) = match required(self.source.next()?)? {
+        let (name, idx) = match required(self.source.next()?)?.format_timestamp()? {
             Event::Variant(name, idx) => (Cow::Borrowed(name), idx),
             Event::OwnedVariant(name, idx) => (Cow::Owned(name), idx),
+            // strings, e.g., of dictionary encoded columns or formatted
+            // timestamps, name unit variants
+            Event::Str(name) => (Cow::Borrowed(name), usize::MAX),
+            Event::OwnedStr(name) => (Cow::Owned(name), usize::MAX),
             ev => fail!("variant_seed: Cannot handle {}", ev),
         };
+        self.variant_from_str = idx == usize::MAX;
 
         struct SeedDeserializer<'a> {
             idx: usize,
@@ -465,10 +1169,23 @@ impl<'de, 'a, 'event, S: EventSource<'event>> EnumAccess<'de> for &'a mut Deseri
             unimplemented!('de, deserialize_ignored_any);
         }
 
-        let val = seed.deserialize(SeedDeserializer {
+        // enums deserialized via deserialize_any do not declare their variants
+        let variants = std::mem::take(&mut self.variants);
+        self.unknown_variant = !variants.is_empty() && !variants.contains(&name.as_ref());
+
+        let val = match seed.deserialize(SeedDeserializer {
             idx,
             name: name.as_ref(),
-        })?;
+        }) {
+            Ok(val) => val,
+            Err(_) if self.unknown_variant => fail!(
+                kind = ErrorKind::UnknownVariant {
+                    variant: name.to_string()
+                },
+                "Unknown variant {name:?}, expected one of {variants:?}. Declare a #[serde(other)] variant to accept unknown variants"
+            ),
+            Err(err) => return Err(err),
+        };
         Ok((val, self))
     }
 }
@@ -482,6 +1199,7 @@ impl<'de, 'a, 'event, S: EventSource<'event>> VariantAccess<'de>
     where
         T: DeserializeSeed<'de>,
     {
+        self.ensure_variant_with_value()?;
         seed.deserialize(&mut *self)
     }
 
@@ -493,6 +1211,7 @@ impl<'de, 'a, 'event, S: EventSource<'event>> VariantAccess<'de>
     where
         V: Visitor<'de>,
     {
+        self.ensure_variant_with_value()?;
         de::Deserializer::deserialize_struct(self, "", fields, visitor)
     }
 
@@ -500,10 +1219,20 @@ impl<'de, 'a, 'event, S: EventSource<'event>> VariantAccess<'de>
     where
         V: Visitor<'de>,
     {
+        self.ensure_variant_with_value()?;
         de::Deserializer::deserialize_tuple(self, len, visitor)
     }
 
     fn unit_variant(self) -> Result<(), Self::Error> {
+        if std::mem::take(&mut self.variant_from_str) {
+            // the string is the complete value
+            self.unknown_variant = false;
+            return Ok(());
+        }
+        if std::mem::take(&mut self.unknown_variant) {
+            // skip the value of variants mapped to #[serde(other)]
+            return self.source.skip_value();
+        }
         match required(self.source.next()?)? {
             Event::Null => Ok(()),
             ev => fail!("deserialize_unit: Cannot handle {}", ev),
@@ -511,6 +1240,108 @@ impl<'de, 'a, 'event, S: EventSource<'event>> VariantAccess<'de>
     }
 }
 
+/// Access the entries of a map as a sequence of key-value pairs
+struct MapEntries<'a, 'event, S: EventSource<'event>>(&'a mut Deserializer<'event, S>);
+
+impl<'de, 'a, 'event, S: EventSource<'event>> SeqAccess<'de> for MapEntries<'a, 'event, S> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if matches!(self.0.source.peek()?, Some(Event::EndMap)) {
+            return Ok(None);
+        }
+        if matches!(self.0.source.peek()?, Some(Event::Item)) {
+            self.0.source.next()?;
+        }
+        seed.deserialize(MapEntry(&mut *self.0)).map(Some)
+    }
+}
+
+/// Deserialize a single map entry as a sequence of its key and value
+struct MapEntry<'a, 'event, S: EventSource<'event>>(&'a mut Deserializer<'event, S>);
+
+impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de> for MapEntry<'a, 'event, S> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(MapEntryElements {
+            deserializer: self.0,
+            remaining: 2,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.human_readable
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct MapEntryElements<'a, 'event, S: EventSource<'event>> {
+    deserializer: &'a mut Deserializer<'event, S>,
+    remaining: usize,
+}
+
+impl<'de, 'a, 'event, S: EventSource<'event>> SeqAccess<'de> for MapEntryElements<'a, 'event, S> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Access the fields of a struct as a sequence of their values
+struct StructFields<'a, 'event, S: EventSource<'event>>(&'a mut Deserializer<'event, S>);
+
+impl<'de, 'a, 'event, S: EventSource<'event>> SeqAccess<'de> for StructFields<'a, 'event, S> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if matches!(self.0.source.peek()?, Some(Event::EndStruct)) {
+            return Ok(None);
+        }
+        if matches!(self.0.source.peek()?, Some(Event::Item)) {
+            self.0.source.next()?;
+        }
+
+        let key = match required(self.0.source.next()?)?.format_timestamp()? {
+            Event::Str(key) => key.to_owned(),
+            Event::OwnedStr(key) => key,
+            ev => fail!("Invalid event {ev}, expected the name of a struct field"),
+        };
+        if let Some(path) = self.0.path.as_mut() {
+            path.push(key);
+        }
+
+        let res = seed.deserialize(&mut *self.0)?;
+        if let Some(path) = self.0.path.as_mut() {
+            path.pop();
+        }
+        Ok(Some(res))
+    }
+}
+
 pub struct SliceSource<'items, 'event> {
     items: &'items [Event<'event>],
     next: usize,
@@ -550,6 +1381,29 @@ impl<'items, 'event> IntoEventSource<'event> for &'items Vec<Event<'event>> {
     }
 }
 
+/// Explain errors caused by deserializing timestamps into types with a
+/// different timezone handling, e.g., a UTC timestamp into a
+/// `chrono::NaiveDateTime`
+fn with_timezone_hint(err: Error, val: &str) -> Error {
+    use chrono::{DateTime, NaiveDateTime};
+
+    let hint = if DateTime::parse_from_rfc3339(val).is_ok() {
+        concat!(
+            "the timezone-aware timestamp cannot be deserialized into a timezone-naive type ",
+            "(e.g., chrono::NaiveDateTime). Use TimezoneConversion::DropOffset to drop the offset",
+        )
+    } else if val.parse::<NaiveDateTime>().is_ok() {
+        concat!(
+            "the timezone-naive timestamp cannot be deserialized into a timezone-aware type ",
+            "(e.g., chrono::DateTime<Utc>). Use TimezoneConversion::AssumeUtc to interpret it as UTC",
+        )
+    } else {
+        return err;
+    };
+    let message = format!("{msg}: {val:?}, {hint}", msg = err.message());
+    err.with_message(message)
+}
+
 fn required(event: Option<Event<'_>>) -> Result<Event<'_>> {
     event.ok_or_else(|| error!("Unexpected no event"))
 }
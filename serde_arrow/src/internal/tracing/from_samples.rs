@@ -2,14 +2,17 @@
 #[cfg(test)]
 mod test_error_messages;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::Serialize;
 
 use crate::internal::{
+    common::DecimalParts,
+    converters::Converter,
     error::{fail, Result},
     event::Event,
-    schema::{GenericDataType, Strategy},
+    markers::TypeMarker,
+    schema::{GenericDataType, Strategy, MAX_DECIMAL128_PRECISION},
     sink::macros,
     sink::{serialize_into_sink, EventSink},
     tracing::tracer::{
@@ -80,6 +83,7 @@ impl<E: EventSink> EventSink for StripOuterSequenceSink<E> {
             OwnedStr(val) => self.accept_str(&val),
             Variant(name, idx) => self.accept_variant(name, idx),
             OwnedVariant(name, idx) => self.accept_variant(&name, idx),
+            ev @ Timestamp(_, _, _) => self.accept(ev.format_timestamp()?),
         }
     }
 
@@ -152,6 +156,21 @@ impl<E: EventSink> EventSink for StripOuterSequenceSink<E> {
     fn finish(&mut self) -> Result<()> {
         self.wrapped.finish()
     }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        self.wrapped.get_converter(name)
+    }
+
+    fn accept_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        match self.state {
+            StripOuterSequenceState::Item(_) => self.wrapped.accept_type_marker(marker),
+            state => fail!("Invalid marker {marker} in state {state:?} for StripOuterSequence"),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.wrapped.is_human_readable()
+    }
 }
 
 impl<'a> EventSink for &'a mut Tracer {
@@ -164,6 +183,18 @@ impl<'a> EventSink for &'a mut Tracer {
     fn finish(&mut self) -> Result<()> {
         (*self).finish()
     }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        (**self).get_converter(name)
+    }
+
+    fn accept_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        (**self).accept_type_marker(marker)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        (**self).is_human_readable()
+    }
 }
 
 impl EventSink for Tracer {
@@ -275,6 +306,48 @@ impl EventSink for Tracer {
     fn finish(&mut self) -> Result<()> {
         Tracer::finish(self)
     }
+
+    fn get_converter(&self, name: &str) -> Option<Converter> {
+        self.get_options().converters.get(name).cloned()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.get_options().human_readable
+    }
+
+    fn accept_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        // route the marker to the tracer of the next value
+        let tracer = match self {
+            Self::Unknown(_) | Self::Primitive(_) => return self.ensure_type_marker(marker),
+            Self::List(tracer) => match tracer.state {
+                ListTracerState::InItem(_) => Some(&mut *tracer.item_tracer),
+                _ => None,
+            },
+            Self::Struct(tracer) => match tracer.state {
+                StructTracerState::InValue(field, _) => Some(&mut tracer.fields[field].tracer),
+                _ => None,
+            },
+            Self::Tuple(tracer) => match tracer.state {
+                TupleTracerState::InItem(field, _) => Some(tracer.field_tracer(field)),
+                _ => None,
+            },
+            Self::Union(tracer) => match tracer.state {
+                UnionTracerState::InVariant(idx, _) => tracer.variants[idx]
+                    .as_mut()
+                    .map(|variant| &mut variant.tracer),
+                _ => None,
+            },
+            Self::Map(tracer) => match tracer.state {
+                MapTracerState::InKey(_) => Some(&mut *tracer.key_tracer),
+                MapTracerState::InValue(_) => Some(&mut *tracer.value_tracer),
+                _ => None,
+            },
+        };
+        match tracer {
+            Some(tracer) => tracer.accept_type_marker(marker),
+            None => self.ensure_type_marker(marker),
+        }
+    }
 }
 
 impl StructTracer {
@@ -288,6 +361,7 @@ impl StructTracer {
             nullable,
             state: StructTracerState::WaitForKey,
             seen_samples: 0,
+            fields_in_sample: HashSet::new(),
         }
     }
 }
@@ -312,6 +386,13 @@ impl EventSink for StructTracer {
                     let Some(field) = self.fields.get_mut(field_idx) else {
                         fail!("invalid state");
                     };
+                    if !self.fields_in_sample.insert(field_idx) {
+                        fail!(
+                            "Duplicate field \"{path}.{key}\" in sample {sample}",
+                            path = self.path,
+                            sample = self.seen_samples,
+                        );
+                    }
                     field.last_seen_in_sample = self.seen_samples;
 
                     InValue(field_idx, 0)
@@ -334,6 +415,7 @@ impl EventSink for StructTracer {
                     let field_idx = self.fields.len();
                     self.fields.push(field);
                     self.index.insert(key.to_owned(), field_idx);
+                    self.fields_in_sample.insert(field_idx);
                     InValue(field_idx, 0)
                 }
             }
@@ -345,6 +427,7 @@ impl EventSink for StructTracer {
                     }
                 }
                 self.seen_samples += 1;
+                self.fields_in_sample.clear();
 
                 WaitForKey
             }
@@ -656,6 +739,25 @@ impl EventSink for PrimitiveTracer {
         use GenericDataType::*;
         use Strategy as S;
 
+        if let Some(marker) = self.type_marker {
+            // the representation is fixed, the strings are not inspected
+            match event {
+                Event::Some | Event::Null => self.nullable = true,
+                Event::U16(_) if marker == TypeMarker::BFloat16 => {}
+                Event::Str(_) | Event::OwnedStr(_) if marker != TypeMarker::BFloat16 => {}
+                ev => fail!(
+                    "Cannot accept event {ev} for field marked as {marker} ({path})",
+                    path = self.path
+                ),
+            }
+            self.seen_samples += 1;
+            return Ok(());
+        }
+
+        if self.options.layout_hints {
+            self.layout_stats.observe(&event);
+        }
+
         let (ev_type, ev_strategy) = match event {
             Event::Some | Event::Null => (Null, None),
             Event::Bool(_) => (Bool, None),
@@ -701,6 +803,9 @@ impl EventSink for PrimitiveTracer {
             ((Date64, Some(S::UtcStrAsDate64)), (Date64, Some(S::UtcStrAsDate64))) => {
                 (Date64, Some(S::UtcStrAsDate64))
             }
+            ((Decimal128(_, _), None), (Decimal128(precision, scale), None)) => {
+                (Decimal128(precision, scale), None)
+            }
             ((Date64, Some(S::NaiveStrAsDate64)), (Date64, Some(S::UtcStrAsDate64))) => {
                 (LargeUtf8, None)
             }
@@ -722,6 +827,22 @@ impl EventSink for PrimitiveTracer {
                     (LargeUtf8, None)
                 }
             }
+            // decimals mixed with other strings, coerce to string
+            (
+                (Decimal128(_, _), None),
+                (LargeUtf8, None) | (Date64, Some(S::NaiveStrAsDate64) | Some(S::UtcStrAsDate64)),
+            )
+            | (
+                (Date64, Some(S::NaiveStrAsDate64) | Some(S::UtcStrAsDate64)),
+                (Decimal128(_, _), None),
+            ) => (LargeUtf8, None),
+            ((LargeUtf8, None), (Decimal128(precision, scale), None)) => {
+                if self.seen_samples == 0 {
+                    (Decimal128(precision, scale), None)
+                } else {
+                    (LargeUtf8, None)
+                }
+            }
             ((ty, None), (ev, None)) if self.options.coerce_numbers => match (ty, ev) {
                 // unsigned x unsigned -> u64
                 (U8 | U16 | U32 | U64, U8 | U16 | U32 | U64) => (U64, None),
@@ -754,15 +875,41 @@ impl EventSink for PrimitiveTracer {
 }
 
 impl PrimitiveTracer {
-    fn get_string_type_and_strategy(&self, s: &str) -> (GenericDataType, Option<Strategy>) {
+    fn get_string_type_and_strategy(&mut self, s: &str) -> (GenericDataType, Option<Strategy>) {
+        self.record_string(s);
+
         if self.options.guess_dates && matches_naive_datetime(s) {
             (GenericDataType::Date64, Some(Strategy::NaiveStrAsDate64))
         } else if self.options.guess_dates && matches_utc_datetime(s) {
             (GenericDataType::Date64, Some(Strategy::UtcStrAsDate64))
+        } else if let Some(data_type) = self.guess_decimal_type(s) {
+            (data_type, None)
         } else {
             (GenericDataType::LargeUtf8, None)
         }
     }
+
+    /// Update the observed decimal digits and return the decimal type covering
+    /// all decimals seen so far
+    fn guess_decimal_type(&mut self, s: &str) -> Option<GenericDataType> {
+        if !self.options.guess_decimals {
+            return None;
+        }
+        let parts = DecimalParts::parse(s)?;
+
+        let (integer, fraction) = self.decimal_digits;
+        let integer = integer.max(parts.integer.len());
+        let fraction = fraction.max(parts.fraction.len());
+
+        let precision = u8::try_from((integer + fraction).max(1)).ok()?;
+        if precision > MAX_DECIMAL128_PRECISION {
+            return None;
+        }
+        let scale = i8::try_from(fraction).ok()?;
+
+        self.decimal_digits = (integer, fraction);
+        Some(GenericDataType::Decimal128(precision, scale))
+    }
 }
 
 mod parsing {
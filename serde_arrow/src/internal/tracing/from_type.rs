@@ -3,12 +3,18 @@
 mod test_error_messages;
 
 use serde::{
-    de::{DeserializeSeed, Visitor},
+    de::{
+        value::{StrDeserializer, U16Deserializer},
+        DeserializeSeed, Visitor,
+    },
     Deserialize, Deserializer,
 };
 
 use crate::internal::{
+    arrow_repr::ARROW_REPR_MARKER,
     error::{fail, Error, Result},
+    markers::TypeMarker,
+    schema::GenericDataType,
     tracing::tracer::{StructField, Tracer},
 };
 
@@ -50,6 +56,10 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
         ));
     }
 
+    fn is_human_readable(&self) -> bool {
+        self.0.get_options().human_readable
+    }
+
     fn deserialize_bool<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         self.0.ensure_bool()?;
         visitor.visit_bool(Default::default())
@@ -149,10 +159,25 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_newtype_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
-        visitor.visit_newtype_struct(self)
+        if name == ARROW_REPR_MARKER {
+            visitor.visit_newtype_struct(TraceArrowRepr(self.0))
+        } else if let Some(marker) = TypeMarker::from_name(name) {
+            // the value is not traced, as the representation is fixed
+            self.0.ensure_type_marker(marker)?;
+            if marker == TypeMarker::BFloat16 {
+                visitor.visit_newtype_struct(U16Deserializer::<Error>::new(0))
+            } else {
+                visitor.visit_newtype_struct(StrDeserializer::<Error>::new(marker.example_value()))
+            }
+        } else if let Some(converter) = self.0.get_options().converters.get(name) {
+            let data_type = converter.data_type.clone();
+            trace_with_data_type(self.0, data_type, visitor)
+        } else {
+            visitor.visit_newtype_struct(self)
+        }
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -458,3 +483,48 @@ impl<'de, 'a> serde::de::Deserializer<'de> for IdentifierDeserializer<'a> {
     unimplemented!('de, deserialize_enum, _: &'static str, _: &'static [&'static str]);
     unimplemented!('de, deserialize_ignored_any);
 }
+
+/// Fix the data type of the field and let the visitor consume the value
+///
+/// The value itself is traced with a throw-away tracer, as it may not match
+/// the data type.
+fn trace_with_data_type<'de, V: Visitor<'de>>(
+    tracer: &mut Tracer,
+    data_type: GenericDataType,
+    visitor: V,
+) -> Result<V::Value> {
+    tracer.ensure_arrow_repr(data_type)?;
+
+    let mut value_tracer = Tracer::new(tracer.get_path().to_owned(), tracer.get_options().clone());
+    visitor.visit_newtype_struct(TraceAny(&mut value_tracer))
+}
+
+/// Trace a value with a custom representation, see
+/// [`ArrowRepr`][crate::internal::arrow_repr::ArrowRepr]
+///
+/// The name of the newtype struct is the data type of the field.
+struct TraceArrowRepr<'a>(&'a mut Tracer);
+
+impl<'de, 'a> serde::de::Deserializer<'de> for TraceArrowRepr<'a> {
+    type Error = Error;
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        trace_with_data_type(self.0, name.parse()?, visitor)
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value> {
+        fail!(
+            "Values with a custom representation must be newtype structs named after the data type"
+        )
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
@@ -0,0 +1,130 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use crate::internal::event::Event;
+
+/// The maximum number of distinct values tracked per field
+const MAX_DISTINCT_VALUES: usize = 1024;
+
+/// A value observed by the layout statistics
+///
+/// Floats are stored as their bits to allow hashing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ObservedValue {
+    Bool(bool),
+    Int(i128),
+    Float(u64),
+    Str(String),
+}
+
+impl ObservedValue {
+    fn from_event(event: &Event<'_>) -> Option<Self> {
+        match event {
+            Event::Bool(v) => Some(Self::Bool(*v)),
+            Event::I8(v) => Some(Self::Int(*v as i128)),
+            Event::I16(v) => Some(Self::Int(*v as i128)),
+            Event::I32(v) => Some(Self::Int(*v as i128)),
+            Event::I64(v) => Some(Self::Int(*v as i128)),
+            Event::U8(v) => Some(Self::Int(*v as i128)),
+            Event::U16(v) => Some(Self::Int(*v as i128)),
+            Event::U32(v) => Some(Self::Int(*v as i128)),
+            Event::U64(v) => Some(Self::Int(*v as i128)),
+            Event::F32(v) => Some(Self::Float((*v as f64).to_bits())),
+            Event::F64(v) => Some(Self::Float(v.to_bits())),
+            Event::Str(v) => Some(Self::Str(v.to_string())),
+            Event::OwnedStr(v) => Some(Self::Str(v.clone())),
+            _ => None,
+        }
+    }
+
+    /// Compare two values, numbers of different kinds are compared as floats
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        use ObservedValue as V;
+        match (self, other) {
+            (V::Bool(a), V::Bool(b)) => Some(a.cmp(b)),
+            (V::Int(a), V::Int(b)) => Some(a.cmp(b)),
+            (V::Float(a), V::Float(b)) => Some(f64::from_bits(*a).total_cmp(&f64::from_bits(*b))),
+            (V::Int(a), V::Float(b)) => Some((*a as f64).total_cmp(&f64::from_bits(*b))),
+            (V::Float(a), V::Int(b)) => Some(f64::from_bits(*a).total_cmp(&(*b as f64))),
+            (V::Str(a), V::Str(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+/// Statistics of the values of a primitive field used to derive layout hints
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutStats {
+    /// The number of non-null values seen
+    seen_values: usize,
+    /// The distinct values seen, cleared once more than `MAX_DISTINCT_VALUES`
+    /// are seen
+    distinct_values: HashSet<ObservedValue>,
+    /// Whether more than `MAX_DISTINCT_VALUES` distinct values were seen
+    many_distinct_values: bool,
+    /// The last value seen
+    last_value: Option<ObservedValue>,
+    /// Whether all values seen so far are in ascending order
+    sorted: bool,
+}
+
+impl Default for LayoutStats {
+    fn default() -> Self {
+        Self {
+            seen_values: 0,
+            distinct_values: HashSet::new(),
+            many_distinct_values: false,
+            last_value: None,
+            sorted: true,
+        }
+    }
+}
+
+impl LayoutStats {
+    pub fn observe(&mut self, event: &Event<'_>) {
+        let Some(value) = ObservedValue::from_event(event) else {
+            return;
+        };
+        self.seen_values += 1;
+
+        if let Some(last_value) = self.last_value.as_ref() {
+            if !matches!(
+                last_value.compare(&value),
+                Some(Ordering::Less | Ordering::Equal)
+            ) {
+                self.sorted = false;
+            }
+        }
+
+        if !self.many_distinct_values && !self.distinct_values.contains(&value) {
+            self.distinct_values.insert(value.clone());
+            if self.distinct_values.len() > MAX_DISTINCT_VALUES {
+                self.many_distinct_values = true;
+                self.distinct_values = HashSet::new();
+            }
+        }
+        self.last_value = Some(value);
+    }
+
+    /// The hints in the format of [`LAYOUT_HINTS_KEY`][crate::schema::LAYOUT_HINTS_KEY]
+    pub fn to_hints(&self) -> Option<String> {
+        if self.seen_values == 0 {
+            return None;
+        }
+
+        let mut hints = Vec::new();
+        if !self.many_distinct_values {
+            let distinct = self.distinct_values.len();
+            hints.push(format!("distinct={distinct}"));
+            if distinct * 10 <= self.seen_values {
+                hints.push(String::from("low_cardinality"));
+            }
+        }
+        if self.sorted && self.seen_values > 1 {
+            hints.push(String::from("sorted"));
+        }
+        if hints.is_empty() {
+            return None;
+        }
+        Some(hints.join(","))
+    }
+}
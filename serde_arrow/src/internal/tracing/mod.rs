@@ -1,9 +1,17 @@
 pub mod from_samples;
 pub mod from_type;
+pub mod layout_hints;
 pub mod tracer;
 
 pub use tracer::Tracer;
 
+use std::collections::HashMap;
+
+use crate::internal::{
+    converters::Converters,
+    schema::{GenericDataType, GenericTimeUnit},
+};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TracingMode {
     Unknown,
@@ -25,16 +33,23 @@ pub enum TracingMode {
 /// The defaults are:
 ///
 /// ```rust
-/// # use serde_arrow::schema::TracingOptions;
+/// # use serde_arrow::schema::{DatetimeRepr, FieldOrder, TracingOptions};
 /// assert_eq!(
 ///     TracingOptions::default(),
 ///     TracingOptions::new()
 ///         .allow_null_fields(false)
 ///         .map_as_struct(true)
 ///         .string_dictionary_encoding(false)
+///         .string_dictionary_ratio(None)
 ///         .coerce_numbers(false)
 ///         .guess_dates(false)
-///         .from_type_budget(100),
+///         .datetime_as(DatetimeRepr::Date64)
+///         .guess_decimals(false)
+///         .decimal_precision(None)
+///         .layout_hints(false)
+///         .from_type_budget(100)
+///         .field_order(FieldOrder::Traced)
+///         .human_readable(true),
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -52,12 +67,36 @@ pub struct TracingOptions {
     /// If `true` serialize strings dictionary encoded. The default is `false`.
     ///
     /// If `true`, strings are traced as `Dictionary(UInt32, LargeUtf8)`. If
-    /// `false`, strings are traced as `LargeUtf8`.
+    /// `false`, strings are traced as `LargeUtf8`. This also applies to the
+    /// string keys of maps traced as `Map` fields, e.g., low-cardinality metric
+    /// names.
     ///
     /// Note: the 32 bit offsets are chosen, as they are supported by the
     /// default polars package.
     pub string_dictionary_encoding: bool,
 
+    /// If given, dictionary encode string fields with few distinct values. The
+    /// default is `None`.
+    ///
+    /// When tracing from samples, a string field is traced as
+    /// `Dictionary(UInt32, LargeUtf8)` if the ratio of distinct values to
+    /// non-null values is at most the given value, e.g., `0.1` encodes string
+    /// fields where on average each value is repeated at least ten times. This
+    /// option has no effect in `from_type`, as no values are observed.
+    ///
+    /// Limitations:
+    ///
+    /// - The encoding is decided once, when the schema is traced. The
+    ///   cardinality is not monitored while building the arrays: a field
+    ///   traced as `Dictionary(UInt32, LargeUtf8)` stays dictionary encoded
+    ///   even if the values pushed later are mostly distinct, and a field
+    ///   traced as `LargeUtf8` is never switched to a dictionary. Trace from
+    ///   samples representative of the data to build.
+    /// - At most 65536 distinct values are tracked per field. Fields with more
+    ///   distinct values in the samples are traced as `LargeUtf8`, independent
+    ///   of the ratio.
+    pub string_dictionary_ratio: Option<f64>,
+
     /// If `true`, coerce different numeric types.
     ///
     /// This option may be helpful when dealing with data formats that do not
@@ -80,15 +119,75 @@ pub struct TracingOptions {
     /// For string fields where all values are either missing or conform to one
     /// of the format the data type is set as `Date64` with strategy
     /// [`NaiveStrAsDate64`][crate::schema::Strategy::NaiveStrAsDate64] or
-    /// [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64].
+    /// [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64]. The data
+    /// type can be changed with [`datetime_as`](#structfield.datetime_as).
     pub guess_dates: bool,
 
+    /// The data type of datetimes detected with
+    /// [`guess_dates`](#structfield.guess_dates). See [`DatetimeRepr`] for the
+    /// available representations. The default is `Date64`.
+    pub datetime_as: DatetimeRepr,
+
+    /// If `true`, try to auto detect decimal numbers in string columns
+    ///
+    /// For string fields where all values are either missing or decimal
+    /// numbers (`[+-]digits[.digits]`, e.g., as produced by `rust_decimal`),
+    /// the data type is set to `Decimal128(precision, scale)`. The precision
+    /// and scale are chosen as the smallest values that cover all observed
+    /// values. Fields that would require a precision above 38 are traced as
+    /// strings.
+    pub guess_decimals: bool,
+
+    /// If given, use this precision for decimals detected with
+    /// [`guess_decimals`](#structfield.guess_decimals) instead of the smallest
+    /// precision covering the observed values
+    ///
+    /// This option is useful to leave room for values not part of the
+    /// samples. Tracing fails if the observed values require a larger
+    /// precision.
+    pub decimal_precision: Option<u8>,
+
+    /// If `true`, store layout hints in the metadata of traced primitive
+    /// fields. The default is `false`.
+    ///
+    /// When tracing from samples, the number of distinct values and whether
+    /// the values are sorted are recorded under
+    /// [`LAYOUT_HINTS_KEY`][crate::schema::LAYOUT_HINTS_KEY], e.g., to let
+    /// Parquet writers choose dictionary or delta encodings. This option has
+    /// no effect in `from_type`, as no values are observed.
+    pub layout_hints: bool,
+
     /// How many tracing iterations to perform in `from_type`.
     ///
     /// The default value may be too conservative for deeply nested types or
     /// enums with many variants.
     pub from_type_budget: usize,
 
+    /// Conversions for newtype structs with the registered names. With
+    /// `from_type`, fields with a registered name are traced with the
+    /// registered data type. With `from_samples`, the converted values are
+    /// traced. See [`Converters`] for details.
+    pub converters: Converters,
+
+    /// The order of the fields of traced structs. See [`FieldOrder`] for the
+    /// available orders.
+    pub field_order: FieldOrder,
+
+    /// The value reported by `is_human_readable()` of the serializer in
+    /// `from_samples` and the deserializer in `from_type`. It must match the
+    /// value of
+    /// [`SerializationOptions::human_readable`][crate::utils::SerializationOptions#structfield.human_readable]
+    /// used to build the arrays. The default is `true`.
+    pub human_readable: bool,
+
+    /// The names of the fields of traced tuples, keyed by the path of the
+    /// tuple field with components separated by `.`, e.g., `"parent.child"`
+    /// or `"parent.list.element"` for the elements of a list. Tuples without
+    /// names use the names `"0"`, `"1"`, ... The fields of
+    /// tuples are matched by position, therefore the names do not affect
+    /// serialization or deserialization.
+    pub tuple_field_names: HashMap<String, Vec<String>>,
+
     /// Internal field to improve error messages for the different tracing
     /// functions
     pub(crate) tracing_mode: TracingMode,
@@ -100,9 +199,18 @@ impl Default for TracingOptions {
             allow_null_fields: false,
             map_as_struct: true,
             string_dictionary_encoding: false,
+            string_dictionary_ratio: None,
             coerce_numbers: false,
             guess_dates: false,
+            datetime_as: DatetimeRepr::default(),
+            guess_decimals: false,
+            decimal_precision: None,
+            layout_hints: false,
             from_type_budget: 100,
+            converters: Converters::default(),
+            field_order: FieldOrder::default(),
+            human_readable: true,
+            tuple_field_names: HashMap::new(),
             tracing_mode: TracingMode::Unknown,
         }
     }
@@ -131,6 +239,15 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`string_dictionary_ratio`](#structfield.string_dictionary_ratio)
+    ///
+    /// Note that the encoding is only decided during tracing, see the
+    /// limitations documented on the field.
+    pub fn string_dictionary_ratio(mut self, value: Option<f64>) -> Self {
+        self.string_dictionary_ratio = value;
+        self
+    }
+
     /// Set [`coerce_numbers`](#structfield.coerce_numbers)
     pub fn coerce_numbers(mut self, value: bool) -> Self {
         self.coerce_numbers = value;
@@ -143,14 +260,197 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`datetime_as`](#structfield.datetime_as)
+    pub fn datetime_as(mut self, value: DatetimeRepr) -> Self {
+        self.datetime_as = value;
+        self
+    }
+
+    /// Set [`guess_decimals`](#structfield.guess_decimals)
+    pub fn guess_decimals(mut self, value: bool) -> Self {
+        self.guess_decimals = value;
+        self
+    }
+
+    /// Set [`decimal_precision`](#structfield.decimal_precision)
+    pub fn decimal_precision(mut self, value: Option<u8>) -> Self {
+        self.decimal_precision = value;
+        self
+    }
+
+    /// Set [`layout_hints`](#structfield.layout_hints)
+    pub fn layout_hints(mut self, value: bool) -> Self {
+        self.layout_hints = value;
+        self
+    }
+
     /// Set [`from_type_budget`](#structfield.from_type_budget)
     pub fn from_type_budget(mut self, value: usize) -> Self {
         self.from_type_budget = value;
         self
     }
 
+    /// Set [`converters`](#structfield.converters)
+    pub fn converters(mut self, value: Converters) -> Self {
+        self.converters = value;
+        self
+    }
+
+    /// Set [`field_order`](#structfield.field_order)
+    pub fn field_order(mut self, value: FieldOrder) -> Self {
+        self.field_order = value;
+        self
+    }
+
+    /// Set [`human_readable`](#structfield.human_readable)
+    pub fn human_readable(mut self, value: bool) -> Self {
+        self.human_readable = value;
+        self
+    }
+
+    /// Set the field names of a single tuple, see
+    /// [`tuple_field_names`](#structfield.tuple_field_names)
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::Field;
+    /// use serde::Deserialize;
+    /// use serde_arrow::schema::{SchemaLike, TracingOptions};
+    ///
+    /// ##[derive(Deserialize)]
+    /// struct Record {
+    ///     position: (f64, f64),
+    /// }
+    ///
+    /// let options = TracingOptions::default().tuple_field_names("position", &["lat", "lon"]);
+    /// let fields = Vec::<Field>::from_type::<Record>(options)?;
+    ///
+    /// let arrow::datatypes::DataType::Struct(children) = fields[0].data_type() else {
+    ///     panic!();
+    /// };
+    /// assert_eq!(children[0].name(), "lat");
+    /// assert_eq!(children[1].name(), "lon");
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() { }
+    /// ```
+    pub fn tuple_field_names(mut self, path: &str, names: &[&str]) -> Self {
+        self.tuple_field_names.insert(
+            path.to_owned(),
+            names.iter().map(|name| name.to_string()).collect(),
+        );
+        self
+    }
+
     pub(crate) fn tracing_mode(mut self, value: TracingMode) -> Self {
         self.tracing_mode = value;
         self
     }
 }
+
+/// The order of the fields of traced structs
+///
+/// The order of the traced fields determines the order of the arrays built
+/// with the traced schema. Arrays built for an existing schema can be brought
+/// into the order of another schema with
+/// [`reorder_columns`][crate::utils::reorder_columns].
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::Deserialize;
+/// use serde_arrow::schema::{FieldOrder, SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// let options = TracingOptions::default().field_order(FieldOrder::Alphabetical);
+/// let fields = Vec::<Field>::from_type::<Record>(options)?;
+/// assert_eq!(fields[0].name(), "a");
+/// assert_eq!(fields[1].name(), "b");
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum FieldOrder {
+    /// Keep the order in which the fields were traced (the default): the
+    /// order of the struct definition with `from_type` and the order of first
+    /// occurrence with `from_samples`. Maps traced as structs are always
+    /// sorted by name.
+    #[default]
+    Traced,
+    /// Sort the fields of each struct by name
+    Alphabetical,
+}
+
+/// The data type of datetimes detected during tracing
+///
+/// Naive datetimes are stored without timezone, UTC datetimes with the
+/// timezone `"UTC"` for the timestamp representations. Different consumers
+/// prefer different types, e.g., some query engines do not support `Date64`
+/// columns, while others only support a single timestamp unit.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field, TimeUnit};
+/// use serde::Serialize;
+/// use serde_arrow::schema::{DatetimeRepr, SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     date: String,
+/// }
+///
+/// let options = TracingOptions::default()
+///     .guess_dates(true)
+///     .datetime_as(DatetimeRepr::TimestampMicros);
+/// let samples = [Record { date: String::from("2015-09-18T23:56:04Z") }];
+/// let fields = Vec::<Field>::from_samples(&samples, options)?;
+/// assert_eq!(
+///     fields[0].data_type(),
+///     &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum DatetimeRepr {
+    /// Store datetimes as `Date64` (the default)
+    #[default]
+    Date64,
+    /// Store datetimes as timestamps with millisecond resolution
+    TimestampMillis,
+    /// Store datetimes as timestamps with microsecond resolution
+    TimestampMicros,
+    /// Store datetimes as timestamps with nanosecond resolution
+    TimestampNanos,
+}
+
+impl DatetimeRepr {
+    pub(crate) fn data_type(self, utc: bool) -> GenericDataType {
+        let unit = match self {
+            Self::Date64 => return GenericDataType::Date64,
+            Self::TimestampMillis => GenericTimeUnit::Millisecond,
+            Self::TimestampMicros => GenericTimeUnit::Microsecond,
+            Self::TimestampNanos => GenericTimeUnit::Nanosecond,
+        };
+        let timezone = if utc { Some(String::from("UTC")) } else { None };
+        GenericDataType::Timestamp(unit, timezone)
+    }
+}
@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::internal::{
     error::{fail, Result},
+    markers::TypeMarker,
     schema::{GenericDataType, GenericField, SerdeArrowSchema, Strategy},
-    tracing::TracingOptions,
+    tracing::{FieldOrder, TracingOptions},
 };
 
-use super::TracingMode;
+use super::{layout_hints::LayoutStats, TracingMode};
 
 // TODO: allow to customize
 const MAX_TYPE_DEPTH: usize = 20;
@@ -170,6 +171,7 @@ impl Tracer {
                     mode: StructMode::Struct,
                     state: StructTracerState::WaitForKey,
                     seen_samples: 0,
+                    fields_in_sample: HashSet::new(),
                 };
                 *this = Self::Struct(tracer);
                 Ok(())
@@ -265,7 +267,7 @@ impl Tracer {
                     options: this.get_options().clone(),
                     nullable: this.get_nullable(),
                     item_tracer: Box::new(Tracer::new(
-                        format!("{}.item", this.get_path()),
+                        format!("{}.element", this.get_path()),
                         this.get_options().clone(),
                     )),
                     state: ListTracerState::WaitForStart,
@@ -354,6 +356,81 @@ impl Tracer {
     }
 }
 
+impl Tracer {
+    /// Ensure the field has the data type of a custom representation
+    pub fn ensure_arrow_repr(&mut self, data_type: GenericDataType) -> Result<()> {
+        if matches!(
+            data_type,
+            GenericDataType::Struct
+                | GenericDataType::List
+                | GenericDataType::LargeList
+                | GenericDataType::FixedSizeList(_)
+                | GenericDataType::Union
+                | GenericDataType::Map
+                | GenericDataType::Dictionary
+        ) {
+            fail!(
+                "Custom representations must use primitive data types, found {data_type} ({path})",
+                path = self.get_path(),
+            );
+        }
+
+        match self {
+            this @ Self::Unknown(_) => {
+                let tracer = PrimitiveTracer::new(
+                    this.get_path().to_owned(),
+                    this.get_options().clone(),
+                    data_type,
+                    this.get_nullable(),
+                );
+                *this = Self::Primitive(tracer);
+                Ok(())
+            }
+            Self::Primitive(tracer) if tracer.item_type == data_type => Ok(()),
+            _ => fail!(
+                "mismatched types, previous {:?}, current {:?}",
+                self.get_type(),
+                data_type
+            ),
+        }
+    }
+}
+
+impl Tracer {
+    /// Ensure the field has the representation requested by a marker
+    pub fn ensure_type_marker(&mut self, marker: TypeMarker) -> Result<()> {
+        match self {
+            this @ Self::Unknown(_) => {
+                let mut tracer = PrimitiveTracer::new(
+                    this.get_path().to_owned(),
+                    this.get_options().clone(),
+                    marker.data_type(),
+                    this.get_nullable(),
+                );
+                tracer.strategy = marker.strategy();
+                tracer.type_marker = Some(marker);
+                *this = Self::Primitive(tracer);
+                Ok(())
+            }
+            Self::Primitive(tracer) if tracer.type_marker == Some(marker) => Ok(()),
+            // only nulls were seen so far
+            Self::Primitive(tracer)
+                if tracer.type_marker.is_none() && tracer.item_type == GenericDataType::Null =>
+            {
+                tracer.item_type = marker.data_type();
+                tracer.strategy = marker.strategy();
+                tracer.type_marker = Some(marker);
+                Ok(())
+            }
+            _ => fail!(
+                "mismatched types, previous {:?}, current {marker} ({path})",
+                self.get_type(),
+                path = self.get_path(),
+            ),
+        }
+    }
+}
+
 macro_rules! impl_primitive_ensures {
     (
         $(
@@ -586,7 +663,7 @@ impl ListTracer {
         Self {
             path: path.clone(),
             options: options.clone(),
-            item_tracer: Box::new(Tracer::new(path, options)),
+            item_tracer: Box::new(Tracer::new(format!("{path}.element"), options)),
             nullable,
             state: ListTracerState::WaitForStart,
         }
@@ -683,9 +760,25 @@ impl TupleTracer {
             fail!("Cannot build field {name} from unfinished tracer");
         }
 
+        let path = self.path.strip_prefix("$.").unwrap_or(&self.path);
+        let names = self.options.tuple_field_names.get(path);
+        if let Some(names) = names {
+            if names.len() != self.field_tracers.len() {
+                fail!(
+                    "Tuple {path:?} has {} fields, but {} names were given",
+                    self.field_tracers.len(),
+                    names.len(),
+                );
+            }
+        }
+
         let mut field = GenericField::new(name, GenericDataType::Struct, self.nullable);
         for (idx, tracer) in self.field_tracers.iter().enumerate() {
-            field.children.push(tracer.to_field(&idx.to_string())?);
+            let child = match names {
+                Some(names) => tracer.to_field(&names[idx])?,
+                None => tracer.to_field(&idx.to_string())?,
+            };
+            field.children.push(child);
         }
         field.strategy = Some(Strategy::TupleAsStruct);
 
@@ -746,6 +839,8 @@ pub struct StructTracer {
     pub state: StructTracerState,
     /// Count how many samples were seen by this tracer
     pub seen_samples: usize,
+    /// The fields encountered in the current sample, used to detect duplicates
+    pub fields_in_sample: HashSet<usize>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -801,6 +896,8 @@ impl StructTracer {
         if let StructMode::Map = self.mode {
             res_field.children.sort_by(|a, b| a.name.cmp(&b.name));
             res_field.strategy = Some(Strategy::MapAsStruct);
+        } else if let FieldOrder::Alphabetical = self.options.field_order {
+            res_field.children.sort_by(|a, b| a.name.cmp(&b.name));
         }
         Ok(res_field)
     }
@@ -982,6 +1079,22 @@ pub struct PrimitiveTracer {
     pub state: PrimitiveTracerState,
     /// Count how many samples were seen by this tracer
     pub seen_samples: usize,
+    /// The number of string values seen, only tracked if
+    /// `string_dictionary_ratio` is set
+    pub seen_strings: usize,
+    /// The distinct string values seen, only tracked if
+    /// `string_dictionary_ratio` is set and at most
+    /// [`MAX_DISTINCT_STRINGS`] values were seen
+    pub distinct_strings: HashSet<String>,
+    /// Whether more than [`MAX_DISTINCT_STRINGS`] distinct strings were seen
+    pub many_distinct_strings: bool,
+    /// The maximum number of integer and fractional digits of the decimal
+    /// strings seen, only tracked if `guess_decimals` is set
+    pub decimal_digits: (usize, usize),
+    /// The representation requested by a marker, if any
+    pub type_marker: Option<TypeMarker>,
+    /// Statistics of the values seen, only tracked if `layout_hints` is set
+    pub layout_stats: LayoutStats,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -1005,6 +1118,12 @@ impl PrimitiveTracer {
             strategy: None,
             state: PrimitiveTracerState::Unfinished,
             seen_samples: 0,
+            seen_strings: 0,
+            distinct_strings: HashSet::new(),
+            many_distinct_strings: false,
+            decimal_digits: (0, 0),
+            type_marker: None,
+            layout_stats: LayoutStats::default(),
         }
     }
 
@@ -1022,6 +1141,16 @@ impl PrimitiveTracer {
     }
 
     pub fn to_field(&self, name: &str) -> Result<GenericField> {
+        let mut field = self.to_field_without_hints(name)?;
+        if self.options.layout_hints
+            && !matches!(field.data_type, GenericDataType::Decimal128(_, _))
+        {
+            field.layout_hints = self.layout_stats.to_hints();
+        }
+        Ok(field)
+    }
+
+    fn to_field_without_hints(&self, name: &str) -> Result<GenericField> {
         type D = GenericDataType;
 
         if !matches!(self.state, PrimitiveTracerState::Finished) {
@@ -1038,7 +1167,10 @@ impl PrimitiveTracer {
         match &self.item_type {
             D::Null => Ok(GenericField::new(name, D::Null, true)),
             dt @ (D::LargeUtf8 | D::Utf8) => {
-                if !self.options.string_dictionary_encoding {
+                let as_dictionary = self.type_marker == Some(TypeMarker::Dictionary)
+                    || self.options.string_dictionary_encoding
+                    || self.has_few_distinct_strings();
+                if !as_dictionary {
                     Ok(GenericField::new(name, dt.clone(), self.nullable))
                 } else {
                     let field = GenericField::new(name, D::Dictionary, self.nullable)
@@ -1047,9 +1179,72 @@ impl PrimitiveTracer {
                     Ok(field)
                 }
             }
-            dt => Ok(GenericField::new(name, dt.clone(), self.nullable)
-                .with_optional_strategy(self.strategy.clone())),
+            &D::Decimal128(precision, scale) => {
+                let precision = match self.options.decimal_precision {
+                    Some(override_precision) if override_precision < precision => fail!(
+                        "The decimal precision {override_precision} is too small for field {name}, the samples require a precision of at least {precision}",
+                    ),
+                    Some(override_precision) => override_precision,
+                    None => precision,
+                };
+                let field = GenericField::new(name, D::Decimal128(precision, scale), self.nullable);
+                field.validate_decimal128()?;
+                Ok(field)
+            }
+            D::Date64
+                if matches!(
+                    self.strategy,
+                    Some(Strategy::NaiveStrAsDate64 | Strategy::UtcStrAsDate64)
+                ) =>
+            {
+                let utc = matches!(self.strategy, Some(Strategy::UtcStrAsDate64));
+                let data_type = self.options.datetime_as.data_type(utc);
+                Ok(GenericField::new(name, data_type, self.nullable)
+                    .with_optional_strategy(self.strategy.clone()))
+            }
+            dt => {
+                let mut field = GenericField::new(name, dt.clone(), self.nullable)
+                    .with_optional_strategy(self.strategy.clone());
+                field.extension_name = self
+                    .type_marker
+                    .and_then(TypeMarker::extension_name)
+                    .map(str::to_owned);
+                Ok(field)
+            }
+        }
+    }
+}
+
+/// The maximum number of distinct strings tracked per field to determine
+/// whether to dictionary encode it
+pub const MAX_DISTINCT_STRINGS: usize = 1 << 16;
+
+impl PrimitiveTracer {
+    pub fn record_string(&mut self, s: &str) {
+        if self.options.string_dictionary_ratio.is_none() {
+            return;
+        }
+        self.seen_strings += 1;
+        if self.many_distinct_strings || self.distinct_strings.contains(s) {
+            return;
+        }
+        if self.distinct_strings.len() >= MAX_DISTINCT_STRINGS {
+            // release the memory, the field is never dictionary encoded
+            self.distinct_strings = HashSet::new();
+            self.many_distinct_strings = true;
+            return;
+        }
+        self.distinct_strings.insert(s.to_owned());
+    }
+
+    fn has_few_distinct_strings(&self) -> bool {
+        let Some(ratio) = self.options.string_dictionary_ratio else {
+            return false;
+        };
+        if self.seen_strings == 0 || self.many_distinct_strings {
+            return false;
         }
+        (self.distinct_strings.len() as f64) <= ratio * (self.seen_strings as f64)
     }
 }
 
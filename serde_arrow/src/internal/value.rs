@@ -0,0 +1,198 @@
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Serialize,
+};
+
+/// A dynamically typed value
+///
+/// `Value` allows to serialize and deserialize rows whose shape is only known
+/// at runtime. The variants mirror the data model of the schema: each
+/// primitive data type has its own variant, Arrow lists are represented as
+/// [`Value::List`], structs as [`Value::Struct`] and maps as [`Value::Map`].
+///
+/// During deserialization, integers and floats keep the width of the Arrow
+/// type. As Arrow structs and maps cannot be distinguished during
+/// deserialization, maps with string keys are deserialized as
+/// [`Value::Struct`] and all other maps as [`Value::Map`]. Both variants are
+/// accepted by struct and map fields during serialization. Unions are not
+/// supported.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::_impl::PanicOnError<()> {
+/// use serde_arrow::Value;
+///
+/// let row = Value::Struct(vec![
+///     (String::from("a"), Value::I64(1)),
+///     (String::from("b"), Value::List(vec![Value::Str(String::from("x"))])),
+///     (String::from("c"), Value::Null),
+/// ]);
+/// assert_eq!(
+///     serde_json::to_string(&row)?,
+///     r#"{"a":1,"b":["x"],"c":null}"#,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A missing value
+    Null,
+    /// A boolean
+    Bool(bool),
+    /// A signed 8 bit integer
+    I8(i8),
+    /// A signed 16 bit integer
+    I16(i16),
+    /// A signed 32 bit integer
+    I32(i32),
+    /// A signed 64 bit integer
+    I64(i64),
+    /// An unsigned 8 bit integer
+    U8(u8),
+    /// An unsigned 16 bit integer
+    U16(u16),
+    /// An unsigned 32 bit integer
+    U32(u32),
+    /// An unsigned 64 bit integer
+    U64(u64),
+    /// A 32 bit float
+    F32(f32),
+    /// A 64 bit float
+    F64(f64),
+    /// A string
+    Str(String),
+    /// A list of values
+    List(Vec<Value>),
+    /// A struct given by its fields in order
+    Struct(Vec<(String, Value)>),
+    /// A map given by its entries in order
+    Map(Vec<(Value, Value)>),
+}
+
+impl Serialize for Value {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Bool(val) => serializer.serialize_bool(*val),
+            Value::I8(val) => serializer.serialize_i8(*val),
+            Value::I16(val) => serializer.serialize_i16(*val),
+            Value::I32(val) => serializer.serialize_i32(*val),
+            Value::I64(val) => serializer.serialize_i64(*val),
+            Value::U8(val) => serializer.serialize_u8(*val),
+            Value::U16(val) => serializer.serialize_u16(*val),
+            Value::U32(val) => serializer.serialize_u32(*val),
+            Value::U64(val) => serializer.serialize_u64(*val),
+            Value::F32(val) => serializer.serialize_f32(*val),
+            Value::F64(val) => serializer.serialize_f64(*val),
+            Value::Str(val) => serializer.serialize_str(val),
+            Value::List(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Value::Struct(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            Value::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+macro_rules! visit_primitive {
+    ($name:ident, $ty:ty, $variant:ident) => {
+        fn $name<E: serde::de::Error>(self, val: $ty) -> Result<Value, E> {
+            Ok(Value::$variant(val))
+        }
+    };
+}
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a value")
+    }
+
+    visit_primitive!(visit_bool, bool, Bool);
+    visit_primitive!(visit_i8, i8, I8);
+    visit_primitive!(visit_i16, i16, I16);
+    visit_primitive!(visit_i32, i32, I32);
+    visit_primitive!(visit_i64, i64, I64);
+    visit_primitive!(visit_u8, u8, U8);
+    visit_primitive!(visit_u16, u16, U16);
+    visit_primitive!(visit_u32, u32, U32);
+    visit_primitive!(visit_u64, u64, U64);
+    visit_primitive!(visit_f32, f32, F32);
+    visit_primitive!(visit_f64, f64, F64);
+    visit_primitive!(visit_string, String, Str);
+
+    fn visit_str<E: serde::de::Error>(self, val: &str) -> Result<Value, E> {
+        Ok(Value::Str(val.to_owned()))
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::List(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Vec::<(Value, Value)>::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        if !entries.iter().all(|(key, _)| matches!(key, Value::Str(_))) {
+            return Ok(Value::Map(entries));
+        }
+
+        let fields = entries
+            .into_iter()
+            .filter_map(|(key, value)| match key {
+                Value::Str(key) => Some((key, value)),
+                _ => None,
+            })
+            .collect();
+        Ok(Value::Struct(fields))
+    }
+}
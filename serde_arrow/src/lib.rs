@@ -107,6 +107,23 @@
 //! the highest version is selected, if multiple features are activated. E.g,
 //! when selecting  `arrow2-0-17` and `arrow2-0-18`, `arrow2=0.18` will be used.
 //!
+//! In addition, each enabled `arrow` version is available in its own module,
+//! e.g., `serde_arrow::arrow_48` for the `arrow-48` feature. These modules
+//! contain `to_arrow`, `from_arrow` and `ArrowBuilder` for the given version.
+//! This way, libraries can support multiple arrow versions without forcing a
+//! specific one onto their users. Schemas can be converted to the fields of
+//! any enabled version via [`SchemaLike`][schema::SchemaLike] and the
+//! `TryFrom` implementations of
+//! [`SerdeArrowSchema`][schema::SerdeArrowSchema].
+//!
+//! `serde_arrow` does not rely on OS functionality, e.g., it uses `chrono`
+//! without its `clock` feature. Therefore, it can be used on
+//! `wasm32-unknown-unknown`. To hand records to Arrow JS, encode them as IPC
+//! bytes with [`Arrow2StreamWriter`][crate::Arrow2StreamWriter] (`arrow2-ipc`
+//! feature), which can be read with `tableFromIPC`. The only
+//! exception are the durations reported to metrics callbacks, which require a
+//! platform clock and are always zero on `wasm32-unknown-unknown`.
+//!
 //! Available features:
 //!
 //! | Arrow Feature | Arrow Version |
@@ -128,11 +145,92 @@
 //! | `arrow2-0-18` | `arrow2=0.18` |
 //! | `arrow2-0-17` | `arrow2=0.17` |
 //! | `arrow2-0-16` | `arrow2=0.16` |
+//!
+//! The optional `arrow2-ipc` feature adds
+//! [`Arrow2StreamDecoder`][crate::Arrow2StreamDecoder] to decode rows from
+//! `arrow2` IPC streams and [`Arrow2StreamWriter`][crate::Arrow2StreamWriter]
+//! to encode rows as `arrow2` IPC streams. It requires one of the `arrow2-*`
+//! features.
+//!
+//! The optional `object-store` feature adds
+//! [`Arrow2ObjectStoreSink`][crate::Arrow2ObjectStoreSink] to upload records
+//! to object stores as `arrow2` IPC files. It requires one of the `arrow2-*`
+//! features.
+//!
+//! The optional `prost` feature adds
+//! [`SerdeArrowSchema::from_protobuf`][crate::schema::SerdeArrowSchema::from_protobuf]
+//! to build schemas from protobuf descriptors.
+//!
+//! The optional `pyo3` feature adds [`to_pyarrow`][crate::to_pyarrow] to hand
+//! arrays to Python as `pyarrow` record batches. It requires one of the
+//! `arrow-*` features.
+//!
+//! The optional `derive` feature adds `#[derive(ArrowColumns)]` to generate
+//! typed views of the columns of record batches, see
+//! [`ArrowColumns`][crate::ArrowColumns]. It requires one of the `arrow-*`
+//! features.
+//!
+//! The optional `tracing` feature instruments the compilation of programs,
+//! serialization, building arrays, and deserialization with debug spans of
+//! the [`tracing`](https://docs.rs/tracing) crate. Each built or separately
+//! decoded column is reported as a debug event with its timing and size.
 
 // be more forgiving without any active implementation
 #[cfg_attr(all(not(has_arrow), not(has_arrow2)), allow(unused))]
 mod internal;
 
+// NOTE: defined at the top-level to be usable by `_impl` and the version modules
+#[rustfmt::skip]
+#[allow(unused)]
+macro_rules! build_arrow_crate {
+    ($arrow_array:ident, $arrow_buffer:ident, $arrow_data:ident, $arrow_schema:ident) => {
+        /// A "fake" arrow crate re-exporting the relevant definitions of the
+        /// used arrow-* subcrates
+        #[doc(hidden)]
+        pub mod arrow {
+            /// The raw arrow packages
+            pub mod _raw {
+                pub use $arrow_array as array;
+                pub use $arrow_buffer as buffer;
+                pub use $arrow_data as data;
+                pub use $arrow_schema as schema;
+            }
+            pub mod array {
+                pub use $arrow_array::array::{
+                    make_array, Array, ArrayRef, ArrowPrimitiveType, BooleanArray,
+                    DictionaryArray, FixedSizeListArray, GenericListArray, LargeStringArray,
+                    MapArray, NullArray, OffsetSizeTrait, PrimitiveArray, StringArray,
+                    StructArray, UnionArray,
+                };
+                pub use $arrow_data::ArrayData;
+            }
+            pub mod buffer {
+                pub use $arrow_buffer::buffer::{Buffer, ScalarBuffer};
+            }
+            pub mod datatypes {
+                pub use $arrow_array::types::{
+                    ArrowPrimitiveType, Date64Type, Decimal128Type, Float16Type, Float32Type, Float64Type,
+                    Int16Type, Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
+                    UInt64Type, UInt8Type,
+                };
+                pub use $arrow_buffer::ArrowNativeType;
+                pub use $arrow_schema::{DataType, Field, Schema, TimeUnit, UnionMode};
+            }
+            pub mod record_batch {
+                pub use $arrow_array::{RecordBatch, RecordBatchOptions};
+            }
+            pub mod error {
+                pub use $arrow_schema::ArrowError;
+            }
+            #[cfg(feature = "pyo3")]
+            pub mod ffi {
+                pub use $arrow_data::ffi::FFI_ArrowArray;
+                pub use $arrow_schema::ffi::FFI_ArrowSchema;
+            }
+        }
+    };
+}
+
 /// *Internal. Do not use*
 ///
 /// This module is an internal implementation detail and not subject to any
@@ -154,47 +252,6 @@ pub mod _impl {
     #[cfg(has_arrow2_0_17)] build_arrow2_crate!(arrow2_0_17);
     #[cfg(has_arrow2_0_16)] build_arrow2_crate!(arrow2_0_16);
 
-    #[allow(unused)]
-    macro_rules! build_arrow_crate {
-        ($arrow_array:ident, $arrow_buffer:ident, $arrow_data:ident, $arrow_schema:ident) => {
-            /// A "fake" arrow crate re-exporting the relevant definitions of the
-            /// used arrow-* subcrates
-            #[doc(hidden)]
-            pub mod arrow {
-                /// The raw arrow packages
-                pub mod _raw {
-                    pub use $arrow_array as array;
-                    pub use $arrow_buffer as buffer;
-                    pub use $arrow_data as data;
-                    pub use $arrow_schema as schema;
-                }
-                pub mod array {
-                    pub use $arrow_array::array::{
-                        make_array, Array, ArrayRef, ArrowPrimitiveType, BooleanArray,
-                        DictionaryArray, GenericListArray, LargeStringArray, MapArray, NullArray,
-                        OffsetSizeTrait, PrimitiveArray, StringArray, StructArray, UnionArray,
-                    };
-                    pub use $arrow_data::ArrayData;
-                }
-                pub mod buffer {
-                    pub use $arrow_buffer::buffer::{Buffer, ScalarBuffer};
-                }
-                pub mod datatypes {
-                    pub use $arrow_array::types::{
-                        ArrowPrimitiveType, Date64Type, Float16Type, Float32Type, Float64Type,
-                        Int16Type, Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
-                        UInt64Type, UInt8Type,
-                    };
-                    pub use $arrow_buffer::ArrowNativeType;
-                    pub use $arrow_schema::{DataType, Field, TimeUnit, UnionMode};
-                }
-                pub mod error {
-                    pub use $arrow_schema::ArrowError;
-                }
-            }
-        };
-    }
-
     // arrow-version:insert: #[cfg(has_arrow_{version})] build_arrow_crate!(arrow_array_{version}, arrow_buffer_{version}, arrow_data_{version}, arrow_schema_{version});
 #[cfg(has_arrow_49)] build_arrow_crate!(arrow_array_49, arrow_buffer_49, arrow_data_49, arrow_schema_49);
     #[cfg(has_arrow_48)] build_arrow_crate!(arrow_array_48, arrow_buffer_48, arrow_data_48, arrow_schema_48);
@@ -229,6 +286,15 @@ pub mod _impl {
     // Reexport for tests
     #[doc(hidden)]
     pub use crate::internal::error::PanicOnError;
+
+    // Used by the `schema!` macro
+    #[doc(hidden)]
+    pub use crate::internal::schema_macro;
+
+    // Used by `#[derive(ArrowColumns)]`
+    #[cfg(has_arrow)]
+    #[doc(hidden)]
+    pub use crate::arrow_impl::columns;
 }
 
 #[cfg(all(test, has_arrow, has_arrow2))]
@@ -240,13 +306,121 @@ mod test_end_to_end;
 #[cfg(test)]
 mod test;
 
-pub use crate::internal::error::{Error, Result};
+pub use crate::internal::{
+    builder::GenericBuilder,
+    error::{Error, ErrorKind, Result, RowError},
+    rows_view::RowsView,
+    source::RowIter,
+    value::Value,
+};
 
 #[cfg(has_arrow)]
 mod arrow_impl;
 
 #[cfg(has_arrow)]
-pub use arrow_impl::api::{from_arrow, to_arrow, ArrowBuilder};
+pub use arrow_impl::api::{
+    empty_arrays, empty_record_batch, from_arrow, from_arrow_collect_errors, from_arrow_into,
+    from_arrow_iter, from_arrow_struct, from_arrow_struct_array, from_arrow_view,
+    from_arrow_with_options, from_record_batch_auto, to_arrow, to_arrow_auto, to_arrow_filtered,
+    to_arrow_struct_array, to_arrow_with_options, ArrowBuilder, PartitionedBuilder, VariantBuilder,
+};
+
+#[cfg(all(has_arrow, feature = "pyo3"))]
+pub use arrow_impl::pyarrow::to_pyarrow;
+
+#[cfg(has_arrow)]
+pub use arrow_impl::columns::{ArrowColumnType, ArrowColumns};
+
+#[cfg(all(has_arrow, feature = "derive"))]
+pub use serde_arrow_derive::ArrowColumns;
+
+#[allow(unused)]
+macro_rules! build_arrow_module {
+    (
+        $name:ident,
+        $version:literal,
+        $has_arrow_version:ident,
+        $arrow_array:ident,
+        $arrow_buffer:ident,
+        $arrow_data:ident,
+        $arrow_schema:ident
+    ) => {
+        #[doc = concat!("Support for `arrow=", $version, "` (*requires the `arrow-", $version, "` feature*)")]
+        ///
+        /// The module allows to use a specific arrow version, independent of
+        /// other enabled versions. If it is the highest enabled version, the
+        /// items are identical to the top-level items. Schemas can be converted
+        /// via the `SchemaLike` and `TryFrom` implementations for the fields of
+        /// this version.
+        // NOTE: the path is used as the directory of the nested `arrow_impl`
+        // module, which is included once per version
+        #[path = "arrow_impl"]
+        pub mod $name {
+            #[cfg($has_arrow_version)]
+            pub use crate::{
+                empty_arrays, empty_record_batch, from_arrow, from_arrow_collect_errors,
+                from_arrow_into, from_arrow_iter, from_arrow_struct, from_arrow_view,
+                from_arrow_with_options, from_record_batch_auto, from_arrow_struct_array, to_arrow,
+                to_arrow_auto, to_arrow_filtered, to_arrow_struct_array, to_arrow_with_options,
+                ArrowBuilder, PartitionedBuilder, VariantBuilder,
+            };
+
+            #[cfg(all($has_arrow_version, feature = "pyo3"))]
+            pub use crate::to_pyarrow;
+
+            #[cfg(not($has_arrow_version))]
+            #[allow(unused)]
+            mod _impl {
+                build_arrow_crate!($arrow_array, $arrow_buffer, $arrow_data, $arrow_schema);
+            }
+
+            #[cfg(not($has_arrow_version))]
+            #[allow(dead_code, deprecated, clippy::duplicate_mod)]
+            #[path = "mod.rs"]
+            mod arrow_impl;
+
+            #[cfg(not($has_arrow_version))]
+            pub use arrow_impl::api::{
+                empty_arrays, empty_record_batch, from_arrow, from_arrow_collect_errors,
+                from_arrow_into, from_arrow_iter, from_arrow_struct, from_arrow_view,
+                from_arrow_with_options, from_record_batch_auto, from_arrow_struct_array, to_arrow,
+                to_arrow_auto, to_arrow_filtered, to_arrow_struct_array, to_arrow_with_options,
+                ArrowBuilder, PartitionedBuilder, VariantBuilder,
+            };
+
+            #[cfg(all(not($has_arrow_version), feature = "pyo3"))]
+            pub use arrow_impl::pyarrow::to_pyarrow;
+        }
+    };
+}
+
+// arrow-version:insert: #[cfg(has_arrow_module_{version})]{\n}build_arrow_module! {{ arrow_{version}, "{version}", has_arrow_{version}, arrow_array_{version}, arrow_buffer_{version}, arrow_data_{version}, arrow_schema_{version} }}
+#[cfg(has_arrow_module_49)]
+build_arrow_module! { arrow_49, "49", has_arrow_49, arrow_array_49, arrow_buffer_49, arrow_data_49, arrow_schema_49 }
+#[cfg(has_arrow_module_48)]
+build_arrow_module! { arrow_48, "48", has_arrow_48, arrow_array_48, arrow_buffer_48, arrow_data_48, arrow_schema_48 }
+#[cfg(has_arrow_module_47)]
+build_arrow_module! { arrow_47, "47", has_arrow_47, arrow_array_47, arrow_buffer_47, arrow_data_47, arrow_schema_47 }
+#[cfg(has_arrow_module_46)]
+build_arrow_module! { arrow_46, "46", has_arrow_46, arrow_array_46, arrow_buffer_46, arrow_data_46, arrow_schema_46 }
+#[cfg(has_arrow_module_45)]
+build_arrow_module! { arrow_45, "45", has_arrow_45, arrow_array_45, arrow_buffer_45, arrow_data_45, arrow_schema_45 }
+#[cfg(has_arrow_module_44)]
+build_arrow_module! { arrow_44, "44", has_arrow_44, arrow_array_44, arrow_buffer_44, arrow_data_44, arrow_schema_44 }
+#[cfg(has_arrow_module_43)]
+build_arrow_module! { arrow_43, "43", has_arrow_43, arrow_array_43, arrow_buffer_43, arrow_data_43, arrow_schema_43 }
+#[cfg(has_arrow_module_42)]
+build_arrow_module! { arrow_42, "42", has_arrow_42, arrow_array_42, arrow_buffer_42, arrow_data_42, arrow_schema_42 }
+#[cfg(has_arrow_module_41)]
+build_arrow_module! { arrow_41, "41", has_arrow_41, arrow_array_41, arrow_buffer_41, arrow_data_41, arrow_schema_41 }
+#[cfg(has_arrow_module_40)]
+build_arrow_module! { arrow_40, "40", has_arrow_40, arrow_array_40, arrow_buffer_40, arrow_data_40, arrow_schema_40 }
+#[cfg(has_arrow_module_39)]
+build_arrow_module! { arrow_39, "39", has_arrow_39, arrow_array_39, arrow_buffer_39, arrow_data_39, arrow_schema_39 }
+#[cfg(has_arrow_module_38)]
+build_arrow_module! { arrow_38, "38", has_arrow_38, arrow_array_38, arrow_buffer_38, arrow_data_38, arrow_schema_38 }
+#[cfg(has_arrow_module_37)]
+build_arrow_module! { arrow_37, "37", has_arrow_37, arrow_array_37, arrow_buffer_37, arrow_data_37, arrow_schema_37 }
 
 #[cfg(has_arrow)]
 #[deprecated = "The items in serde_arrow::arrow are deprecated. See the individual items for suitable replacements"]
@@ -266,7 +440,19 @@ pub mod arrow {
 mod arrow2_impl;
 
 #[cfg(has_arrow2)]
-pub use arrow2_impl::api::{from_arrow2, to_arrow2, Arrow2Builder};
+pub use arrow2_impl::api::{
+    empty_arrow2_arrays, from_arrow2, from_arrow2_collect_errors, from_arrow2_into,
+    from_arrow2_iter, from_arrow2_struct, from_arrow2_struct_array, from_arrow2_view,
+    from_arrow2_with_options, to_arrow2, to_arrow2_auto, to_arrow2_filtered,
+    to_arrow2_struct_array, to_arrow2_with_options, Arrow2Builder, Arrow2PartitionedBuilder,
+    Arrow2VariantBuilder,
+};
+
+#[cfg(all(has_arrow2, feature = "arrow2-ipc"))]
+pub use arrow2_impl::ipc::{Arrow2StreamDecoder, Arrow2StreamWriter};
+
+#[cfg(all(has_arrow2, feature = "object-store"))]
+pub use arrow2_impl::object_store::Arrow2ObjectStoreSink;
 
 #[cfg(has_arrow2)]
 #[deprecated = "The items in serde_arrow::arrow2 are deprecated. See the individual items for suitable replacements"]
@@ -282,6 +468,12 @@ pub mod arrow2 {
     pub type ArraysBuilder = crate::arrow2_impl::api::Arrow2Builder;
 }
 
+#[deny(missing_docs)]
+pub mod display;
+
+#[deny(missing_docs)]
+pub mod prelude;
+
 #[deny(missing_docs)]
 pub mod schema;
 
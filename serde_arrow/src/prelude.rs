@@ -0,0 +1,37 @@
+//! Re-export the most commonly used items
+//!
+//! Importing the prelude brings the schema helpers, the wrappers for
+//! collections of items, the builders and the top-level conversion functions
+//! into scope:
+//!
+//! ```rust
+//! # #[cfg(has_arrow)]
+//! # fn main() -> serde_arrow::Result<()> {
+//! # use serde_arrow::_impl::arrow;
+//! use arrow::datatypes::Field;
+//! use serde_arrow::prelude::*;
+//!
+//! let fields = Vec::<Field>::from_type::<Item<u32>>(TracingOptions::default())?;
+//! let arrays = to_arrow(&fields, &Items(&[1_u32, 2, 3]))?;
+//!
+//! let mut builder = ArrowBuilder::new(&fields)?;
+//! builder.push(&Item(4_u32))?;
+//! assert_eq!(builder.build_arrays()?[0].len(), 1);
+//!
+//! let Items(items): Items<Vec<u32>> = from_arrow(&fields, &arrays)?;
+//! assert_eq!(items, vec![1, 2, 3]);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(has_arrow))]
+//! # fn main() { }
+//! ```
+pub use crate::{
+    schema::{SchemaLike, TracingOptions},
+    utils::{Item, Items},
+};
+
+#[cfg(has_arrow)]
+pub use crate::{from_arrow, to_arrow, ArrowBuilder};
+
+#[cfg(has_arrow2)]
+pub use crate::{from_arrow2, to_arrow2, Arrow2Builder};
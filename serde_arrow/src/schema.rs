@@ -38,8 +38,14 @@
 //! # fn main() {}
 //! ```
 pub use crate::internal::{
-    schema::{SchemaLike, SerdeArrowSchema, Strategy, STRATEGY_KEY},
-    tracing::TracingOptions,
+    avro::AvroSchema,
+    builder::BuilderField,
+    schema::{
+        FixedShapeTensor, SchemaLike, SerdeArrowSchema, Strategy, LAYOUT_HINTS_KEY,
+        NULL_DEFAULT_KEY, STRATEGY_KEY,
+    },
+    shape::{ExpectedShape, SerdeShape},
+    tracing::{DatetimeRepr, FieldOrder, TracingOptions},
 };
 
 /// Renamed to [`SerdeArrowSchema`]
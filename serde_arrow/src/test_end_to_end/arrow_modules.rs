@@ -0,0 +1,72 @@
+//! Test that the module of the highest arrow version matches the top-level API
+//! and that the modules of lower versions can be used next to it
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::datatypes::Field,
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions},
+};
+
+// arrow-version:replace: use crate::arrow_{version} as arrow_module;
+use crate::arrow_49 as arrow_module;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+    b: Option<String>,
+}
+
+#[test]
+fn roundtrip() -> PanicOnError<()> {
+    let items = vec![
+        Record { a: 1, b: None },
+        Record {
+            a: 2,
+            b: Some(String::from("foo")),
+        },
+    ];
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let arrays = arrow_module::to_arrow(&fields, &items)?;
+    assert_eq!(arrays, crate::to_arrow(&fields, &items)?);
+
+    let mut builder = arrow_module::ArrowBuilder::new(&fields)?;
+    builder.extend(&items)?;
+    assert_eq!(builder.build_arrays()?, arrays);
+
+    let round_trip: Vec<Record> = arrow_module::from_arrow(&fields, &arrays)?;
+    assert_eq!(round_trip, items);
+
+    Ok(())
+}
+
+/// Requires building with multiple arrow versions, e.g., `--features
+/// arrow-49,arrow-48`
+#[cfg(has_arrow_module_48)]
+#[test]
+fn roundtrip_lower_version() -> PanicOnError<()> {
+    use arrow_schema_48::Field;
+
+    let items = vec![
+        Record { a: 1, b: None },
+        Record {
+            a: 2,
+            b: Some(String::from("foo")),
+        },
+    ];
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let arrays = crate::arrow_48::to_arrow(&fields, &items)?;
+    assert_eq!(arrays.len(), 2);
+    assert_eq!(arrays[0].len(), 2);
+
+    let mut builder = crate::arrow_48::ArrowBuilder::new(&fields)?;
+    builder.extend(&items)?;
+    assert_eq!(builder.build_arrays()?, arrays);
+
+    let round_trip: Vec<Record> = crate::arrow_48::from_arrow(&fields, &arrays)?;
+    assert_eq!(round_trip, items);
+
+    Ok(())
+}
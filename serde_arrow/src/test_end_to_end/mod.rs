@@ -1,3 +1,4 @@
 //! Test end to end examples to ensure the API works as designed
 //!
+mod arrow_modules;
 mod issue_90;
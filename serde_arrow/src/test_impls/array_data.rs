@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::{make_array, Array},
+        datatypes::Field,
+    },
+    schema::{SchemaLike, TracingOptions},
+    utils::SerializationOptions,
+    ArrowBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: Option<u8>,
+    b: Vec<String>,
+    c: Inner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    d: bool,
+}
+
+fn items() -> Vec<Record> {
+    (0..3)
+        .map(|i| Record {
+            a: (i != 1).then_some(i),
+            b: vec![i.to_string(); i as usize],
+            c: Inner { d: i % 2 == 0 },
+        })
+        .collect()
+}
+
+#[test]
+fn array_data_matches_the_arrays() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let items = items();
+
+    let mut builder = ArrowBuilder::new(&fields).unwrap();
+    builder.extend(&items).unwrap();
+    let data = builder.build_array_data().unwrap();
+
+    builder.extend(&items).unwrap();
+    let arrays = builder.build_arrays().unwrap();
+
+    assert_eq!(data.len(), arrays.len());
+    for (data, array) in data.iter().zip(&arrays) {
+        assert_eq!(data, &array.to_data());
+    }
+}
+
+#[test]
+fn array_data_can_be_sliced() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let items = items();
+
+    let mut builder = ArrowBuilder::new(&fields).unwrap();
+    builder.extend(&items).unwrap();
+    let arrays = builder
+        .build_array_data()
+        .unwrap()
+        .into_iter()
+        .map(|data| make_array(data.slice(1, 2)))
+        .collect::<Vec<_>>();
+
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items[1..]);
+}
+
+#[test]
+fn array_data_is_validated() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+    let mut builder = ArrowBuilder::with_options(
+        &fields,
+        SerializationOptions::default().validate_arrays(true),
+    )
+    .unwrap();
+    builder.extend(&items()).unwrap();
+    let data = builder.build_array_data().unwrap();
+    assert_eq!(data[0].len(), 3);
+}
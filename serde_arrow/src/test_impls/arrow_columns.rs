@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, StringArray},
+        datatypes::{DataType, Field, Schema},
+        record_batch::RecordBatch,
+    },
+    internal::tracing::TracingOptions,
+    schema::SchemaLike,
+    test_impls::macros::expect_error,
+    to_arrow,
+    utils::AsDictionary,
+    ArrowColumns,
+};
+
+#[derive(Serialize, ArrowColumns)]
+#[arrow_columns(crate = "crate")]
+struct Record {
+    a: f64,
+    b: Option<i32>,
+    c: String,
+    tags: Vec<String>,
+    label: AsDictionary<String>,
+    #[serde(rename = "renamed")]
+    r#type: bool,
+    #[serde(skip)]
+    #[allow(dead_code)]
+    skipped: (),
+}
+
+fn record_batch<T: Serialize>(items: &[T]) -> RecordBatch {
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+    let arrays = to_arrow(&fields, items).unwrap();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).unwrap()
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: 1.0,
+            b: Some(2),
+            c: String::from("foo"),
+            tags: vec![String::from("x")],
+            label: AsDictionary(String::from("l")),
+            r#type: true,
+            skipped: (),
+        },
+        Record {
+            a: 3.0,
+            b: None,
+            c: String::from("bar"),
+            tags: vec![],
+            label: AsDictionary(String::from("l")),
+            r#type: false,
+            skipped: (),
+        },
+    ]
+}
+
+#[test]
+fn columns_are_typed() {
+    let batch = record_batch(&items());
+    let columns = RecordColumns::from_record_batch(&batch).unwrap();
+
+    assert_eq!(columns.a.values().iter().sum::<f64>(), 4.0);
+    assert_eq!(columns.b.value(0), 2);
+    assert!(columns.b.is_null(1));
+    assert_eq!(columns.c.value(1), "bar");
+    assert_eq!(columns.tags.value_length(0), 1);
+    assert_eq!(columns.label.values().len(), 1);
+    assert!(columns.r#type.value(0));
+}
+
+#[test]
+fn columns_via_the_trait() {
+    let batch = record_batch(&items());
+    let columns = Record::columns(&batch).unwrap();
+    assert_eq!(columns.a.len(), 2);
+}
+
+#[test]
+fn missing_columns_are_reported() {
+    #[derive(Serialize)]
+    struct Other {
+        a: f64,
+    }
+
+    let batch = record_batch(&[Other { a: 1.0 }]);
+    let res = RecordColumns::from_record_batch(&batch);
+    expect_error(&res, "The record batch has no column \"b\"");
+}
+
+#[test]
+fn mismatched_array_types_are_reported() {
+    #[derive(Serialize)]
+    struct Other {
+        a: i64,
+    }
+
+    #[allow(dead_code)]
+    #[derive(ArrowColumns)]
+    #[arrow_columns(crate = "crate")]
+    struct View {
+        a: f64,
+    }
+
+    let batch = record_batch(&[Other { a: 1 }]);
+    let res = ViewColumns::from_record_batch(&batch);
+    expect_error(
+        &res,
+        "Column \"a\" with data type Int64 cannot be viewed as",
+    );
+}
+
+#[test]
+fn array_types_can_be_overwritten() {
+    #[derive(Serialize)]
+    struct Record {
+        a: &'static str,
+    }
+
+    #[allow(dead_code)]
+    #[derive(ArrowColumns)]
+    #[arrow_columns(crate = "crate")]
+    struct View {
+        #[arrow_columns(array = StringArray)]
+        a: String,
+    }
+
+    let fields = vec![Field::new("a", DataType::Utf8, false)];
+    let arrays = to_arrow(&fields, &[Record { a: "foo" }]).unwrap();
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).unwrap();
+
+    let columns = ViewColumns::from_record_batch(&batch).unwrap();
+    assert_eq!(columns.a.value(0), "foo");
+}
+
+#[test]
+fn serde_renames_are_honored() {
+    #[derive(Serialize, ArrowColumns)]
+    #[arrow_columns(crate = "crate")]
+    #[serde(rename_all = "camelCase")]
+    struct Record {
+        user_id: u32,
+        #[serde(rename(serialize = "userName", deserialize = "name"))]
+        name: String,
+        #[serde(rename = "SCORE")]
+        total_score: f64,
+    }
+
+    let batch = record_batch(&[Record {
+        user_id: 1,
+        name: String::from("foo"),
+        total_score: 2.0,
+    }]);
+    let names = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["userId", "userName", "SCORE"]);
+
+    let columns = RecordColumns::from_record_batch(&batch).unwrap();
+    assert_eq!(columns.user_id.value(0), 1);
+    assert_eq!(columns.name.value(0), "foo");
+    assert_eq!(columns.total_score.value(0), 2.0);
+}
+
+#[test]
+fn serde_rename_all_with_separate_rules() {
+    #[derive(Serialize, ArrowColumns)]
+    #[arrow_columns(crate = "crate")]
+    #[serde(rename_all(serialize = "SCREAMING-KEBAB-CASE", deserialize = "snake_case"))]
+    struct Record {
+        user_id: u32,
+    }
+
+    let batch = record_batch(&[Record { user_id: 1 }]);
+    assert_eq!(batch.schema().field(0).name(), "USER-ID");
+
+    let columns = RecordColumns::from_record_batch(&batch).unwrap();
+    assert_eq!(columns.user_id.value(0), 1);
+}
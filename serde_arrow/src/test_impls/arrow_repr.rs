@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    internal::{
+        schema::{GenericDataType, GenericField, SchemaLike, SerdeArrowSchema},
+        tracing::TracingOptions,
+    },
+    utils::ArrowRepr,
+};
+
+use super::macros::{expect_error, test_generic};
+
+#[derive(Debug, PartialEq)]
+struct Millis(u32);
+
+impl ArrowRepr for Millis {
+    const DATA_TYPE: &'static str = "Date64";
+    type Repr = i64;
+
+    fn to_repr(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn from_repr(repr: i64) -> crate::Result<Self> {
+        Ok(Millis(u32::try_from(repr)?))
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    #[serde(with = "crate::utils::arrow_repr")]
+    time: Millis,
+    value: u8,
+}
+
+#[test]
+fn from_type_uses_the_data_type_of_the_trait() {
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("time", GenericDataType::Date64, false),
+            GenericField::new("value", GenericDataType::U8, false),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn from_type_rejects_nested_data_types() {
+    struct Nested;
+
+    impl ArrowRepr for Nested {
+        const DATA_TYPE: &'static str = "Struct";
+        type Repr = ();
+
+        fn to_repr(&self) {}
+
+        fn from_repr(_: ()) -> crate::Result<Self> {
+            Ok(Nested)
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Record {
+        #[serde(with = "crate::utils::arrow_repr")]
+        #[allow(unused)]
+        nested: Nested,
+    }
+
+    let res = SerdeArrowSchema::from_type::<Record>(TracingOptions::default());
+    expect_error(&res, "Custom representations must use primitive data types");
+}
+
+#[test]
+fn json_uses_the_representation() {
+    let item = Record {
+        time: Millis(13),
+        value: 2,
+    };
+    let json = serde_json::to_string(&item).unwrap();
+    assert_eq!(json, r#"{"time":13,"value":2}"#);
+
+    let roundtripped: Record = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtripped, item);
+}
+
+test_generic!(
+    fn roundtrip() {
+        use crate::test_impls::arrow_repr::{Millis, Record};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let items = vec![
+            Record {
+                time: Millis(13),
+                value: 2,
+            },
+            Record {
+                time: Millis(21),
+                value: 4,
+            },
+        ];
+
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn conversion_errors_are_reported() {
+        use crate::test_impls::arrow_repr::Record;
+        use crate::test_impls::macros::expect_error;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("time", GenericDataType::Date64, false)).unwrap(),
+            Field::try_from(&GenericField::new("value", GenericDataType::U8, false)).unwrap(),
+        ];
+        let items = vec![crate::Value::Struct(vec![
+            (String::from("time"), crate::Value::I64(-1)),
+            (String::from("value"), crate::Value::U8(0)),
+        ])];
+
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let res = from_arrow::<Vec<Record>, _>(&fields, &arrays);
+        expect_error(&res, "out of range");
+    }
+);
@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    avro::AvroSchema,
+    schema::{SchemaLike, SerdeArrowSchema},
+    tracing::TracingOptions,
+};
+
+use super::macros::expect_error;
+
+fn to_avro(fields: serde_json::Value) -> serde_json::Value {
+    let schema = SerdeArrowSchema::from_value(&fields).unwrap();
+    serde_json::to_value(schema.to_avro("Record").unwrap()).unwrap()
+}
+
+fn from_avro(avro: serde_json::Value) -> crate::Result<SerdeArrowSchema> {
+    let avro: AvroSchema = serde_json::from_value(avro).unwrap();
+    SerdeArrowSchema::from_avro(&avro)
+}
+
+#[test]
+fn primitives_to_avro() {
+    let actual = to_avro(json!([
+        {"name": "a", "data_type": "Null", "nullable": true},
+        {"name": "b", "data_type": "Bool"},
+        {"name": "c", "data_type": "U8"},
+        {"name": "d", "data_type": "I64", "nullable": true},
+        {"name": "e", "data_type": "F16"},
+        {"name": "f", "data_type": "F64"},
+        {"name": "g", "data_type": "Utf8"},
+        {"name": "h", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+    ]));
+    let expected = json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [
+            {"name": "a", "type": "null"},
+            {"name": "b", "type": "boolean"},
+            {"name": "c", "type": "int"},
+            {"name": "d", "type": ["null", "long"]},
+            {"name": "e", "type": "float"},
+            {"name": "f", "type": "double"},
+            {"name": "g", "type": "string"},
+            {"name": "h", "type": "string"},
+        ],
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn logical_types_to_avro() {
+    let actual = to_avro(json!([
+        {"name": "a", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "b", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+        {"name": "c", "data_type": "Timestamp(Microsecond, Some(\"UTC\"))"},
+        {"name": "d", "data_type": "Decimal128(5, 2)", "nullable": true},
+    ]));
+    let expected = json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [
+            {"name": "a", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+            {"name": "b", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+            {"name": "c", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+            {"name": "d", "type": ["null", {"type": "bytes", "logicalType": "decimal", "precision": 5, "scale": 2}]},
+        ],
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn nested_types_to_avro() {
+    let actual = to_avro(json!([
+        {"name": "items", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct", "nullable": true, "children": [
+                {"name": "a", "data_type": "I32"},
+            ]},
+        ]},
+        {"name": "scores", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "F64"},
+            ]},
+        ]},
+        {"name": "element", "data_type": "Struct", "children": [
+            {"name": "b", "data_type": "Bool"},
+        ]},
+    ]));
+    let expected = json!({
+        "type": "record",
+        "name": "Record",
+        "fields": [
+            {"name": "items", "type": {"type": "array", "items": ["null", {
+                "type": "record",
+                "name": "Element",
+                "fields": [{"name": "a", "type": "int"}],
+            }]}},
+            {"name": "scores", "type": {"type": "map", "values": "double"}},
+            {"name": "element", "type": {
+                "type": "record",
+                "name": "Element1",
+                "fields": [{"name": "b", "type": "boolean"}],
+            }},
+        ],
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unsupported_types_to_avro() {
+    let schema = SerdeArrowSchema::from_value(&json!([
+        {"name": "a", "data_type": "Union", "children": [
+            {"name": "A", "data_type": "I32"},
+        ]},
+    ]))
+    .unwrap();
+    expect_error(
+        &schema.to_avro("Record"),
+        "Cannot convert union field \"a\"",
+    );
+
+    let schema = SerdeArrowSchema::from_value(&json!([
+        {"name": "a", "data_type": "Timestamp(Second, None)"},
+    ]))
+    .unwrap();
+    expect_error(&schema.to_avro("Record"), "Cannot convert field \"a\"");
+
+    let schema = SerdeArrowSchema::from_value(&json!([
+        {"name": "0", "data_type": "I32"},
+    ]))
+    .unwrap();
+    expect_error(&schema.to_avro("Record"), "not a valid Avro name");
+}
+
+#[test]
+fn records_from_avro() {
+    let actual = from_avro(json!({
+        "type": "record",
+        "name": "Record",
+        "namespace": "example",
+        "doc": "ignored",
+        "fields": [
+            {"name": "a", "type": "boolean"},
+            {"name": "b", "type": ["long", "null"], "default": null},
+            {"name": "c", "type": {"type": "array", "items": "string"}},
+            {"name": "d", "type": {"type": "map", "values": ["null", "double"]}},
+            {"name": "e", "type": {"type": "record", "name": "Point", "fields": [
+                {"name": "x", "type": "float"},
+            ]}},
+            {"name": "f", "type": "Point"},
+            {"name": "g", "type": {"type": "enum", "name": "Color", "symbols": ["Red", "Green"]}},
+            {"name": "h", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "i", "type": {"type": "long", "logicalType": "local-timestamp-micros"}},
+            {"name": "j", "type": {"type": "fixed", "name": "Amount", "size": 8, "logicalType": "decimal", "precision": 10, "scale": 3}},
+        ],
+    }))
+    .unwrap();
+    let expected = SerdeArrowSchema::from_value(&json!([
+        {"name": "a", "data_type": "Bool"},
+        {"name": "b", "data_type": "I64", "nullable": true},
+        {"name": "c", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "d", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "F64", "nullable": true},
+            ]},
+        ]},
+        {"name": "e", "data_type": "Struct", "children": [
+            {"name": "x", "data_type": "F32"},
+        ]},
+        {"name": "f", "data_type": "Struct", "children": [
+            {"name": "x", "data_type": "F32"},
+        ]},
+        {"name": "g", "data_type": "LargeUtf8"},
+        {"name": "h", "data_type": "I32"},
+        {"name": "i", "data_type": "Timestamp(Microsecond, None)"},
+        {"name": "j", "data_type": "Decimal128(10, 3)"},
+    ]))
+    .unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unsupported_types_from_avro() {
+    let res = from_avro(json!("long"));
+    expect_error(&res, "Avro schemas must be records");
+
+    let res = from_avro(json!({"type": "record", "name": "R", "fields": [
+        {"name": "a", "type": ["int", "string"]},
+    ]}));
+    expect_error(&res, "only unions of null and a single type are supported");
+
+    let res = from_avro(json!({"type": "record", "name": "R", "fields": [
+        {"name": "a", "type": "bytes"},
+    ]}));
+    expect_error(&res, "Cannot convert the Avro type bytes of field \"a\"");
+
+    let res = from_avro(json!({"type": "record", "name": "R", "fields": [
+        {"name": "a", "type": "Unknown"},
+    ]}));
+    expect_error(&res, "Unknown Avro type \"Unknown\" of field \"a\"");
+}
+
+#[test]
+fn traced_schemas_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        a: Option<i64>,
+        b: Vec<String>,
+        c: Inner,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Inner {
+        d: f64,
+        e: bool,
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let avro = schema.to_avro("Record").unwrap();
+
+    let json = serde_json::to_string(&avro).unwrap();
+    let avro: AvroSchema = serde_json::from_str(&json).unwrap();
+    assert_eq!(SerdeArrowSchema::from_avro(&avro).unwrap(), schema);
+}
@@ -0,0 +1,60 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn progress() {
+        use serde_json::json;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::List, false).with_child(
+                    GenericField::new("element", GenericDataType::LargeUtf8, false),
+                ),
+            )
+            .unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.push(&json!({"a": 1, "b": ["foo", "bar"]})).unwrap();
+        builder.push(&json!({"a": 2, "b": []})).unwrap();
+
+        let actual = format!("{builder:?}");
+        assert!(actual.contains("rows: 2"), "{actual}");
+        assert!(
+            actual.contains(r#""a": Field { len: 2, bytes: 8 }"#),
+            "{actual}"
+        );
+        assert!(
+            actual.contains(r#""b": Field { len: 2, bytes: 12, children: {"element": Field { len: 2, bytes: 30 }} }"#),
+            "{actual}",
+        );
+    }
+);
+
+test_generic!(
+    fn state_after_error() {
+        use serde_json::json;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        let res = builder.push(&json!({"a": 1, "b": 2}));
+        assert!(res.is_err());
+
+        let actual = format!("{builder:?}");
+        assert!(actual.contains("rows: 1"), "{actual}");
+        assert!(
+            actual.contains(r#"state: "PushLargeUtf8 at b""#),
+            "{actual}"
+        );
+        assert!(
+            actual.contains(r#""a": Field { len: 1, bytes: 4 }"#),
+            "{actual}"
+        );
+        assert!(
+            actual.contains(r#""b": Field { len: 0, bytes: 8 }"#),
+            "{actual}"
+        );
+    }
+);
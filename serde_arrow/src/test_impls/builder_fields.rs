@@ -0,0 +1,349 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn rename_and_reorder_keep_buffered_rows() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Serialize)]
+        struct Record {
+            a: u8,
+            b: i32,
+            c: bool,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Renamed {
+            c: bool,
+            x: u8,
+            b: i32,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::I32, false)).unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::Bool, false)).unwrap(),
+        ];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder
+            .push(&Record {
+                a: 1,
+                b: 2,
+                c: true,
+            })
+            .unwrap();
+
+        builder.rename_field("a", "x").unwrap();
+        builder.with_field_order(&["c", "x", "b"]).unwrap();
+
+        builder
+            .push(&Record {
+                a: 3,
+                b: 4,
+                c: false,
+            })
+            .unwrap();
+        builder
+            .extend_from_slice(&[Record {
+                a: 5,
+                b: 6,
+                c: true,
+            }])
+            .unwrap();
+
+        let fields = builder.fields().unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Vec<Renamed> = from_arrow(&fields, &arrays).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                Renamed {
+                    c: true,
+                    x: 1,
+                    b: 2
+                },
+                Renamed {
+                    c: false,
+                    x: 3,
+                    b: 4
+                },
+                Renamed {
+                    c: true,
+                    x: 5,
+                    b: 6
+                },
+            ]
+        );
+
+        // the changes are kept for the next batch
+        builder
+            .push(&Record {
+                a: 7,
+                b: 8,
+                c: false,
+            })
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Vec<Renamed> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![Renamed {
+                c: false,
+                x: 7,
+                b: 8
+            }]
+        );
+    }
+);
+
+test_generic!(
+    fn rename_field_errors() {
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+
+        let res = builder.rename_field("c", "d");
+        crate::test_impls::macros::expect_error(&res, "does not exist");
+
+        let res = builder.rename_field("a", "b");
+        crate::test_impls::macros::expect_error(&res, "already exists");
+
+        builder.rename_field("a", "a").unwrap();
+    }
+);
+
+test_generic!(
+    fn with_field_order_errors() {
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+
+        let res = builder.with_field_order(&["a"]);
+        crate::test_impls::macros::expect_error(&res, "exactly once");
+
+        let res = builder.with_field_order(&["a", "c"]);
+        crate::test_impls::macros::expect_error(&res, "does not exist");
+
+        let res = builder.with_field_order(&["a", "a"]);
+        crate::test_impls::macros::expect_error(&res, "duplicate field");
+
+        // failed calls do not modify the builder
+        let fields = builder.fields().unwrap();
+        let names = fields
+            .iter()
+            .map(|field| GenericField::try_from(field).unwrap().name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+);
+
+test_generic!(
+    fn into_columns_and_from_columns() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: Option<u8>,
+            b: Vec<String>,
+            c: Inner,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            d: String,
+        }
+
+        #[derive(Serialize)]
+        struct A {
+            a: Option<u8>,
+        }
+
+        #[derive(Serialize)]
+        struct B {
+            b: Vec<String>,
+        }
+
+        #[derive(Serialize)]
+        struct C {
+            c: Inner,
+        }
+
+        let fields = Vec::<Field>::from_type::<Record>(
+            TracingOptions::default().string_dictionary_encoding(true),
+        )
+        .unwrap();
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder
+            .push(&Record {
+                a: Some(1),
+                b: vec![String::from("x")],
+                c: Inner {
+                    d: String::from("y"),
+                },
+            })
+            .unwrap();
+
+        let mut columns = builder.into_columns().unwrap();
+        assert_eq!(columns.len(), 3);
+
+        columns[0].push(&A { a: None }).unwrap();
+        columns[1]
+            .push(&B {
+                b: vec![String::from("z"), String::from("x")],
+            })
+            .unwrap();
+        columns[2]
+            .push(&C {
+                c: Inner {
+                    d: String::from("y"),
+                },
+            })
+            .unwrap();
+
+        let mut builder = ArrowBuilder::from_columns(columns).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    a: Some(1),
+                    b: vec![String::from("x")],
+                    c: Inner {
+                        d: String::from("y"),
+                    },
+                },
+                Record {
+                    a: None,
+                    b: vec![String::from("z"), String::from("x")],
+                    c: Inner {
+                        d: String::from("y"),
+                    },
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn from_columns_keeps_the_order_of_the_builders() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: u8,
+            b: u16,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U16, false)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.push(&Record { a: 1, b: 2 }).unwrap();
+
+        let mut columns = builder.into_columns().unwrap();
+        columns.reverse();
+        let mut builder = ArrowBuilder::from_columns(columns).unwrap();
+        builder.push(&Record { a: 3, b: 4 }).unwrap();
+
+        let fields = builder.fields().unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Record { a: 1, b: 2 }, Record { a: 3, b: 4 }]);
+        assert_eq!(GenericField::try_from(&fields[0]).unwrap().name, "b");
+    }
+);
+
+test_generic!(
+    fn from_columns_errors() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct A {
+            a: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+
+        let res = ArrowBuilder::from_columns(Vec::new());
+        crate::test_impls::macros::expect_error(&res, "empty list of columns");
+
+        let mut columns = ArrowBuilder::new(&fields).unwrap().into_columns().unwrap();
+        columns[0].push(&A { a: 1 }).unwrap();
+        let res = ArrowBuilder::from_columns(columns);
+        crate::test_impls::macros::expect_error(&res, "different numbers of rows");
+
+        let columns = vec![
+            ArrowBuilder::new(&fields[..1]).unwrap(),
+            ArrowBuilder::new(&fields[..1]).unwrap(),
+        ];
+        let res = ArrowBuilder::from_columns(columns);
+        crate::test_impls::macros::expect_error(&res, "Duplicate field");
+    }
+);
+
+test_generic!(
+    fn extend_and_extend_from_slice_agree_after_remapping() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: u8,
+            b: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let items = [Record { a: 1, b: 2 }];
+
+        let build = |remap: &dyn Fn(&mut ArrowBuilder), from_slice: bool| {
+            let mut builder = ArrowBuilder::new(&fields).unwrap();
+            remap(&mut builder);
+            if from_slice {
+                builder.extend_from_slice(&items).unwrap();
+            } else {
+                builder.extend(&items).unwrap();
+            }
+            let fields = builder.fields().unwrap();
+            let arrays = builder.build_arrays().unwrap();
+            let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+            actual
+        };
+
+        let swap_names = |builder: &mut ArrowBuilder| {
+            builder.rename_field("a", "t").unwrap();
+            builder.rename_field("b", "a").unwrap();
+            builder.rename_field("t", "b").unwrap();
+        };
+        let expected = vec![Record { a: 2, b: 1 }];
+        assert_eq!(build(&swap_names, false), expected);
+        assert_eq!(build(&swap_names, true), expected);
+
+        let swap_names_and_order = |builder: &mut ArrowBuilder| {
+            swap_names(builder);
+            builder.with_field_order(&["a", "b"]).unwrap();
+        };
+        assert_eq!(build(&swap_names_and_order, false), expected);
+        assert_eq!(build(&swap_names_and_order, true), expected);
+
+        let reorder = |builder: &mut ArrowBuilder| {
+            builder.with_field_order(&["b", "a"]).unwrap();
+        };
+        let expected = vec![Record { a: 1, b: 2 }];
+        assert_eq!(build(&reorder, false), expected);
+        assert_eq!(build(&reorder, true), expected);
+    }
+);
@@ -0,0 +1,121 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn cancelled_builders_keep_the_rows_pushed_before() {
+        use crate::{
+            utils::{CancellationToken, SerializationOptions},
+            ErrorKind,
+        };
+
+        let token = CancellationToken::new();
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().cancellation(token.clone()),
+        )
+        .unwrap();
+
+        builder.extend(&Items(&[1_u32, 2])).unwrap();
+        token.cancel();
+
+        let Err(err) = builder.extend(&Items(&[3_u32, 4])) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 2 });
+
+        let Err(err) = builder.extend_from_slice(&[Item(5_u32)]) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 2 });
+
+        let Err(err) = builder.push(&Item(6_u32)) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 2 });
+
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].len(), 2);
+    }
+);
+
+test_generic!(
+    fn cancellation_stops_serialization_between_rows() {
+        use std::cell::Cell;
+
+        use serde::{Serialize, Serializer};
+
+        use crate::{
+            utils::{CancellationToken, SerializationOptions},
+            ErrorKind,
+        };
+
+        /// Cancel the token once the given number of items were serialized
+        struct CancelAfter<'a> {
+            token: &'a CancellationToken,
+            remaining: &'a Cell<usize>,
+        }
+
+        impl<'a> Serialize for CancelAfter<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let remaining = self.remaining.get().saturating_sub(1);
+                self.remaining.set(remaining);
+                if remaining == 0 {
+                    self.token.cancel();
+                }
+                Item(1_u32).serialize(serializer)
+            }
+        }
+
+        let token = CancellationToken::new();
+        let remaining = Cell::new(3);
+        let items = (0..10)
+            .map(|_| CancelAfter {
+                token: &token,
+                remaining: &remaining,
+            })
+            .collect::<Vec<_>>();
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().cancellation(token.clone()),
+        )
+        .unwrap();
+
+        let Err(err) = builder.extend(&items) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 3 });
+        assert_eq!(builder.build_arrays().unwrap()[0].len(), 3);
+    }
+);
+
+test_generic!(
+    fn cancelled_deserialization_reports_the_rows_before() {
+        use crate::{
+            utils::{CancellationToken, DeserializationOptions},
+            ErrorKind,
+        };
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u32, 2, 3])).unwrap();
+
+        let token = CancellationToken::new();
+        let options = DeserializationOptions::default().cancellation(token.clone());
+
+        let Items(items): Items<Vec<u32>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, [1, 2, 3]);
+
+        token.cancel();
+        let res: crate::Result<Items<Vec<u32>>> =
+            from_arrow_with_options(&fields, &arrays, &options);
+        let Err(err) = res else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.kind(), &ErrorKind::Cancelled { rows: 0 });
+    }
+);
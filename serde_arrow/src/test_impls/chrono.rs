@@ -1,4 +1,4 @@
-use super::macros::test_example;
+use super::macros::{test_example, test_generic};
 
 test_example!(
     test_name = utc_as_str,
@@ -155,6 +155,77 @@ test_example!(
     },
 );
 
+test_example!(
+    test_name = utc_as_timestamp_micros_tracing,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default()
+        .guess_dates(true)
+        .datetime_as(DatetimeRepr::TimestampMicros),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Timestamp(GenericTimeUnit::Microsecond, Some("UTC".into())),
+        false
+    )
+    .with_strategy(Strategy::UtcStrAsDate64),
+    ty = DateTime<Utc>,
+    values = [
+        DateTime::<Utc>::from_timestamp(1662921288, 123_456_000).unwrap(),
+        DateTime::<Utc>::from_timestamp(-2208936075, 0).unwrap(),
+    ],
+    nulls = [false, false],
+    define = {
+        use chrono::{DateTime, Utc};
+        use crate::schema::DatetimeRepr;
+    },
+);
+
+test_example!(
+    test_name = naive_as_timestamp_nanos_tracing,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default()
+        .guess_dates(true)
+        .datetime_as(DatetimeRepr::TimestampNanos),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Timestamp(GenericTimeUnit::Nanosecond, None),
+        false
+    )
+    .with_strategy(Strategy::NaiveStrAsDate64),
+    ty = NaiveDateTime,
+    values = [
+        NaiveDateTime::from_timestamp_opt(1662921288, 123_456_789).unwrap(),
+        NaiveDateTime::from_timestamp_opt(-2208936075, 0).unwrap(),
+    ],
+    nulls = [false, false],
+    define = {
+        use crate::schema::DatetimeRepr;
+        use chrono::NaiveDateTime;
+    },
+);
+
+test_example!(
+    test_name = utc_as_timestamp_millis_tracing_string_only,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default()
+        .guess_dates(true)
+        .datetime_as(DatetimeRepr::TimestampMillis),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some("UTC".into())),
+        false
+    )
+    .with_strategy(Strategy::UtcStrAsDate64),
+    ty = String,
+    values = [
+        String::from("2015-09-18T23:56:04Z"),
+        String::from("2023-08-14T17:00:04Z"),
+    ],
+    nulls = [false, false],
+    define = {
+        use crate::schema::DatetimeRepr;
+    },
+);
+
 test_example!(
     test_name = utc_as_date64_tracing_string_only,
     test_bytecode_deserialization = true,
@@ -253,3 +324,424 @@ test_example!(
     ],
     nulls = [false, false],
 );
+
+test_generic!(
+    fn utc_str_with_multiple_formats() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{SerializationOptions, TimestampFormat};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::UtcStrAsDate64),
+        )
+        .unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().timestamp_formats(vec![
+                TimestampFormat::Rfc3339,
+                TimestampFormat::Rfc2822,
+                TimestampFormat::Custom(String::from("%Y-%m-%d %H:%M:%S")),
+                TimestampFormat::EpochSeconds,
+            ]),
+        )
+        .unwrap();
+        builder
+            .extend(&Items(&[
+                "2015-09-18T23:56:04Z",
+                "Fri, 18 Sep 2015 23:56:04 +0000",
+                "2015-09-18 23:56:04",
+                "1442620564",
+            ]))
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<String>> = from_arrow(&fields, &arrays).unwrap();
+        let expected = String::from("2015-09-18T23:56:04Z");
+        assert_eq!(actual, vec![expected; 4]);
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().timestamp_formats(vec![TimestampFormat::Rfc3339]),
+        )
+        .unwrap();
+        let res = builder.extend(&Items(&["1442620564"]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot parse \"1442620564\" as timestamp with any of the formats [RFC 3339]",
+        );
+    }
+);
+
+test_generic!(
+    fn naive_str_with_field_formats() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{SerializationOptions, TimestampFormat};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::NaiveStrAsDate64),
+        )
+        .unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default()
+                .timestamp_formats(vec![TimestampFormat::Rfc3339])
+                .field_timestamp_formats(
+                    "item",
+                    vec![
+                        TimestampFormat::Custom(String::from("%d.%m.%Y")),
+                        TimestampFormat::EpochMillis,
+                    ],
+                ),
+        )
+        .unwrap();
+        builder
+            .extend(&Items(&["18.09.2015", "1442534400000"]))
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<String>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                String::from("2015-09-18T00:00:00"),
+                String::from("2015-09-18T00:00:00")
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn utc_timestamps_into_naive_values() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{DeserializationOptions, TimezoneConversion};
+        use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::UtcStrAsDate64),
+        )
+        .unwrap()];
+        let values = [Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&values)).unwrap();
+
+        let res = from_arrow::<Items<Vec<NaiveDateTime>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "TimezoneConversion::DropOffset");
+
+        let Items(actual): Items<Vec<NaiveDateTime>> = from_arrow_with_options(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().timezone_conversion(TimezoneConversion::DropOffset),
+        )
+        .unwrap();
+        assert_eq!(actual, vec![values[0].naive_utc()]);
+
+        let Items(actual): Items<Vec<DateTime<Utc>>> = from_arrow_with_options(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().timezone_conversion(TimezoneConversion::AssumeUtc),
+        )
+        .unwrap();
+        assert_eq!(actual, values);
+    }
+);
+
+test_generic!(
+    fn naive_timestamps_into_utc_values() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{DeserializationOptions, TimezoneConversion};
+        use chrono::{DateTime, NaiveDateTime, Utc};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::NaiveStrAsDate64),
+        )
+        .unwrap()];
+        let values = [NaiveDateTime::from_timestamp_millis(1662921288000).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&values)).unwrap();
+
+        let res = from_arrow::<Items<Vec<DateTime<Utc>>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "TimezoneConversion::AssumeUtc");
+
+        let Items(actual): Items<Vec<DateTime<Utc>>> = from_arrow_with_options(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().timezone_conversion(TimezoneConversion::AssumeUtc),
+        )
+        .unwrap();
+        assert_eq!(actual, vec![values[0].and_utc()]);
+    }
+);
+
+test_generic!(
+    fn per_field_timezone_conversion() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{DeserializationOptions, TimezoneConversion};
+        use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("naive", GenericDataType::Date64, false)
+                    .with_strategy(Strategy::UtcStrAsDate64),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("utc", GenericDataType::Date64, false)
+                    .with_strategy(Strategy::UtcStrAsDate64),
+            )
+            .unwrap(),
+        ];
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Written {
+            naive: DateTime<Utc>,
+            utc: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Read {
+            naive: NaiveDateTime,
+            utc: DateTime<Utc>,
+        }
+
+        let value = Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap();
+        let arrays = to_arrow(
+            &fields,
+            &[Written {
+                naive: value,
+                utc: value,
+            }],
+        )
+        .unwrap();
+
+        let res = from_arrow::<Vec<Read>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "TimezoneConversion::DropOffset");
+
+        let actual: Vec<Read> = from_arrow_with_options(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default()
+                .field_timezone_conversion("naive", TimezoneConversion::DropOffset),
+        )
+        .unwrap();
+        assert_eq!(
+            actual,
+            vec![Read {
+                naive: value.naive_utc(),
+                utc: value,
+            }]
+        );
+    }
+);
+
+test_generic!(
+    fn timestamps_into_integers() {
+        use crate::internal::schema::{GenericTimeUnit, Strategy};
+        use chrono::{DateTime, Utc};
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("date", GenericDataType::Date64, false)
+                    .with_strategy(Strategy::UtcStrAsDate64),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new(
+                    "timestamp",
+                    GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None),
+                    true,
+                )
+                .with_strategy(Strategy::NaiveStrAsDate64),
+            )
+            .unwrap(),
+        ];
+
+        #[derive(serde::Serialize)]
+        struct Source {
+            date: DateTime<Utc>,
+            timestamp: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Raw {
+            date: i64,
+            timestamp: Option<u64>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Mixed {
+            date: DateTime<Utc>,
+            timestamp: Option<i64>,
+        }
+
+        let date = DateTime::<Utc>::from_timestamp(1662921288, 0).unwrap();
+        let items = [
+            Source {
+                date,
+                timestamp: Some(String::from("2022-09-11T18:34:48")),
+            },
+            Source {
+                date,
+                timestamp: None,
+            },
+        ];
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let actual: Vec<Raw> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            [
+                Raw {
+                    date: 1662921288000,
+                    timestamp: Some(1662921288000),
+                },
+                Raw {
+                    date: 1662921288000,
+                    timestamp: None,
+                },
+            ]
+        );
+
+        let actual: Vec<Mixed> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            [
+                Mixed {
+                    date,
+                    timestamp: Some(1662921288000),
+                },
+                Mixed {
+                    date,
+                    timestamp: None,
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn timestamps_into_integers_in_strict_mode() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::DeserializationOptions;
+        use chrono::{DateTime, Utc};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::UtcStrAsDate64),
+        )
+        .unwrap()];
+        let values = [DateTime::<Utc>::from_timestamp(1662921288, 0).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&values)).unwrap();
+
+        let options = DeserializationOptions::default().strict(true);
+        let Items(actual): Items<Vec<i64>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(actual, [1662921288000]);
+
+        let res = from_arrow_with_options::<Items<Vec<u64>>, _>(&fields, &arrays, &options);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "cannot deserialize I64(1662921288000) as u64",
+        );
+    }
+);
+
+test_generic!(
+    fn timestamps_with_units_into_integers() {
+        use crate::internal::schema::{GenericTimeUnit, Strategy};
+        use chrono::NaiveDateTime;
+
+        let values = [NaiveDateTime::from_timestamp_opt(1662921288, 123_456_789).unwrap()];
+        for (unit, expected) in [
+            (GenericTimeUnit::Second, 1662921288),
+            (GenericTimeUnit::Millisecond, 1662921288123),
+            (GenericTimeUnit::Microsecond, 1662921288123456),
+            (GenericTimeUnit::Nanosecond, 1662921288123456789),
+        ] {
+            let fields = vec![Field::try_from(
+                &GenericField::new("item", GenericDataType::Timestamp(unit, None), false)
+                    .with_strategy(Strategy::NaiveStrAsDate64),
+            )
+            .unwrap()];
+            let arrays = to_arrow(&fields, &Items(&values)).unwrap();
+
+            let Items(actual): Items<Vec<i64>> = from_arrow(&fields, &arrays).unwrap();
+            assert_eq!(actual, [expected]);
+        }
+    }
+);
+
+test_generic!(
+    fn timestamps_as_map_keys_and_variants() {
+        use std::collections::BTreeMap;
+
+        use crate::internal::schema::Strategy;
+        use chrono::{DateTime, Utc};
+
+        let date64 = |name: &str| {
+            GenericField::new(name, GenericDataType::Date64, false)
+                .with_strategy(Strategy::UtcStrAsDate64)
+        };
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("map", GenericDataType::Map, false).with_child(
+                    GenericField::new("entries", GenericDataType::Struct, false)
+                        .with_child(date64("key"))
+                        .with_child(GenericField::new("value", GenericDataType::I32, false)),
+                ),
+            )
+            .unwrap(),
+            Field::try_from(&date64("variant")).unwrap(),
+        ];
+
+        #[derive(serde::Serialize)]
+        struct Source {
+            map: BTreeMap<DateTime<Utc>, i32>,
+            variant: DateTime<Utc>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        enum Variant {
+            #[serde(rename = "2022-09-11T18:34:48.500Z")]
+            Start,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Target<V> {
+            map: BTreeMap<String, V>,
+            variant: Variant,
+        }
+
+        let date = DateTime::<Utc>::from_timestamp(1662921288, 500_000_000).unwrap();
+        let items = [
+            Source {
+                map: BTreeMap::from([(date, 1)]),
+                variant: date,
+            },
+            Source {
+                map: BTreeMap::from([(date, -1)]),
+                variant: date,
+            },
+        ];
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let (actual, errors) =
+            from_arrow_collect_errors::<Target<u8>, _>(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            [Target {
+                map: BTreeMap::from([(String::from("2022-09-11T18:34:48.500Z"), 1)]),
+                variant: Variant::Start,
+            }]
+        );
+
+        // the error paths contain the formatted keys
+        let errors = errors
+            .iter()
+            .map(|err| (err.row, err.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(errors, [(1, "map.2022-09-11T18:34:48.500Z")]);
+    }
+);
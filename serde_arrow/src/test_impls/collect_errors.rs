@@ -0,0 +1,109 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn collect_errors_skips_failing_rows() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            a: u8,
+            nested: Nested,
+            c: String,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Nested {
+            b: u32,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U16, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("nested", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("b", GenericDataType::U32, true)),
+            )
+            .unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[
+                serde_json::json!({"a": 1, "nested": {"b": 10}, "c": "x"}),
+                serde_json::json!({"a": 300, "nested": {"b": 20}, "c": "y"}),
+                serde_json::json!({"a": 3, "nested": {"b": null}, "c": "z"}),
+                serde_json::json!({"a": 4, "nested": {"b": 40}, "c": "w"}),
+            ],
+        )
+        .unwrap();
+
+        let (rows, errors) = from_arrow_collect_errors::<Record, _>(&fields, &arrays).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                Record {
+                    a: 1,
+                    nested: Nested { b: 10 },
+                    c: String::from("x"),
+                },
+                Record {
+                    a: 4,
+                    nested: Nested { b: 40 },
+                    c: String::from("w"),
+                },
+            ]
+        );
+
+        let errors = errors
+            .iter()
+            .map(|err| (err.row, err.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(errors, vec![(1, "a"), (2, "nested.b")]);
+    }
+);
+
+test_generic!(
+    fn collect_errors_without_errors() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_i64, 2, 3])).unwrap();
+
+        let (rows, errors) = from_arrow_collect_errors::<Item<i64>, _>(&fields, &arrays).unwrap();
+        let rows = rows.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(rows, vec![1, 2, 3]);
+        assert!(errors.is_empty());
+    }
+);
+
+test_generic!(
+    fn collect_errors_with_unknown_columns() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Record {
+            a: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, true)).unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[
+                serde_json::json!({"a": 1, "b": 2}),
+                serde_json::json!({"a": 3, "b": null}),
+            ],
+        )
+        .unwrap();
+
+        let (rows, errors) = from_arrow_collect_errors::<Record, _>(&fields, &arrays).unwrap();
+        assert!(rows.is_empty());
+
+        let errors = errors
+            .iter()
+            .map(|err| (err.row, err.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(errors, vec![(0, "b"), (1, "b")]);
+    }
+);
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    schema::{GenericDataType, GenericField, SchemaLike, SerdeArrowSchema},
+    tracing::{FieldOrder, TracingOptions},
+};
+
+use super::macros::test_generic;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    y: u8,
+    x: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    c: u8,
+    a: Inner,
+    b: u8,
+}
+
+#[test]
+fn traced_order_follows_the_struct_definition() {
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["c", "a", "b"]);
+}
+
+#[test]
+fn alphabetical_order_from_type() {
+    let schema = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default().field_order(FieldOrder::Alphabetical),
+    )
+    .unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("a", GenericDataType::Struct, false)
+                .with_child(GenericField::new("x", GenericDataType::U8, false))
+                .with_child(GenericField::new("y", GenericDataType::U8, false)),
+            GenericField::new("b", GenericDataType::U8, false),
+            GenericField::new("c", GenericDataType::U8, false),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn alphabetical_order_from_samples() {
+    let items = [Record {
+        c: 1,
+        a: Inner { y: 2, x: 3 },
+        b: 4,
+    }];
+    let schema = SerdeArrowSchema::from_samples(
+        &items,
+        TracingOptions::default().field_order(FieldOrder::Alphabetical),
+    )
+    .unwrap();
+    let expected = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default().field_order(FieldOrder::Alphabetical),
+    )
+    .unwrap();
+    assert_eq!(schema, expected);
+}
+
+test_generic!(
+    fn reorder_columns() {
+        use crate::test_impls::column_order::Record;
+        use crate::utils::reorder_columns;
+
+        let items = vec![Record {
+            c: 1,
+            a: crate::test_impls::column_order::Inner { y: 2, x: 3 },
+            b: 4,
+        }];
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let target = Vec::<Field>::from_type::<Record>(
+            TracingOptions::default().field_order(crate::schema::FieldOrder::Alphabetical),
+        )
+        .unwrap();
+        let target = vec![target[1].clone(), target[2].clone()];
+        let arrays = reorder_columns(&fields, arrays, &target).unwrap();
+        assert_eq!(arrays.len(), 2);
+
+        let actual: Vec<crate::Value> = from_arrow(&target, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![crate::Value::Struct(vec![
+                (String::from("b"), crate::Value::U8(4)),
+                (String::from("c"), crate::Value::U8(1)),
+            ])]
+        );
+    }
+);
+
+test_generic!(
+    fn reorder_columns_errors() {
+        use crate::test_impls::macros::expect_error;
+        use crate::utils::reorder_columns;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U16, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2})]).unwrap();
+
+        let target =
+            vec![Field::try_from(&GenericField::new("c", GenericDataType::U8, false)).unwrap()];
+        let res = reorder_columns(&fields, arrays.clone(), &target);
+        expect_error(&res, "Missing column \"c\"");
+
+        let target =
+            vec![Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap()];
+        let res = reorder_columns(&fields, arrays.clone(), &target);
+        expect_error(&res, "Column \"b\" has data type U16, expected U8");
+
+        let target = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+        ];
+        let res = reorder_columns(&fields, arrays, &target);
+        expect_error(&res, "Duplicate column \"a\"");
+    }
+);
@@ -0,0 +1,182 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn column_statistics_primitives() {
+        use serde::Serialize;
+
+        use crate::{
+            utils::{ColumnStatistics, SerializationOptions},
+            Value,
+        };
+
+        #[derive(Serialize)]
+        struct Record {
+            a: Option<i32>,
+            b: f64,
+            c: String,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::I32, true)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::F64, false)).unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let items = [
+            Record {
+                a: Some(3),
+                b: 1.5,
+                c: String::from("foo"),
+            },
+            Record {
+                a: None,
+                b: f64::NAN,
+                c: String::from("bar"),
+            },
+            Record {
+                a: Some(-2),
+                b: -0.5,
+                c: String::from("foo"),
+            },
+        ];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().column_statistics(true),
+        )
+        .unwrap();
+        builder.extend(&items).unwrap();
+        let _arrays = builder.build_arrays().unwrap();
+
+        let stats = builder.column_statistics();
+        assert_eq!(stats.len(), 3);
+
+        let simplify = |stats: &ColumnStatistics| {
+            (
+                stats.name.clone(),
+                stats.len,
+                stats.null_count,
+                stats.min.clone(),
+                stats.max.clone(),
+                stats.distinct_count,
+            )
+        };
+
+        assert_eq!(
+            simplify(&stats[0]),
+            (
+                String::from("a"),
+                3,
+                1,
+                Some(Value::I32(-2)),
+                Some(Value::I32(3)),
+                Some(2)
+            ),
+        );
+        assert_eq!(
+            simplify(&stats[1]),
+            (
+                String::from("b"),
+                3,
+                0,
+                Some(Value::F64(-0.5)),
+                Some(Value::F64(1.5)),
+                Some(3)
+            ),
+        );
+        assert_eq!(
+            simplify(&stats[2]),
+            (
+                String::from("c"),
+                3,
+                0,
+                Some(Value::Str(String::from("bar"))),
+                Some(Value::Str(String::from("foo"))),
+                Some(2)
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn column_statistics_dictionary_and_nested() {
+        use crate::{utils::SerializationOptions, Value};
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("a", GenericDataType::Dictionary, true)
+                    .with_child(GenericField::new("key", GenericDataType::U32, false))
+                    .with_child(GenericField::new("value", GenericDataType::Utf8, false)),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::LargeList, true)
+                    .with_child(GenericField::new("element", GenericDataType::I64, false)),
+            )
+            .unwrap(),
+        ];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().column_statistics(true),
+        )
+        .unwrap();
+        builder
+            .extend(&[
+                serde_json::json!({"a": "y", "b": [1, 2]}),
+                serde_json::json!({"a": null, "b": null}),
+                serde_json::json!({"a": "x", "b": []}),
+                serde_json::json!({"a": "y", "b": null}),
+            ])
+            .unwrap();
+        let _arrays = builder.build_arrays().unwrap();
+
+        let stats = builder.column_statistics();
+        assert_eq!(stats[0].null_count, 1);
+        assert_eq!(stats[0].min, Some(Value::Str(String::from("x"))));
+        assert_eq!(stats[0].max, Some(Value::Str(String::from("y"))));
+        assert_eq!(stats[0].distinct_count, Some(2));
+
+        assert_eq!(stats[1].len, 4);
+        assert_eq!(stats[1].null_count, 2);
+        assert_eq!(stats[1].min, None);
+        assert_eq!(stats[1].max, None);
+        assert_eq!(stats[1].distinct_count, None);
+    }
+);
+
+test_generic!(
+    fn column_statistics_are_reset_per_batch() {
+        use crate::{utils::SerializationOptions, Value};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().column_statistics(true),
+        )
+        .unwrap();
+        assert!(builder.column_statistics().is_empty());
+
+        builder.extend(&Items(&[1_u8, 2, 3])).unwrap();
+        let _arrays = builder.build_arrays().unwrap();
+        assert_eq!(builder.column_statistics()[0].max, Some(Value::U8(3)));
+
+        builder.extend(&Items(&[4_u8])).unwrap();
+        let _arrays = builder.build_arrays().unwrap();
+        assert_eq!(builder.column_statistics()[0].min, Some(Value::U8(4)));
+        assert_eq!(builder.column_statistics()[0].len, 1);
+    }
+);
+
+test_generic!(
+    fn column_statistics_are_disabled_by_default() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.extend(&Items(&[1_u8, 2, 3])).unwrap();
+        let _arrays = builder.build_arrays().unwrap();
+        assert!(builder.column_statistics().is_empty());
+    }
+);
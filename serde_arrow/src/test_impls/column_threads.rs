@@ -0,0 +1,197 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn matches_serial_deserialization() {
+        use crate::utils::DeserializationOptions;
+        use serde::{Deserialize, Serialize};
+        use std::collections::BTreeMap;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            x: i16,
+            y: Option<Vec<String>>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Kind {
+            A(u8),
+            B { b: bool },
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: u32,
+            b: Option<String>,
+            c: Vec<Option<f64>>,
+            d: Option<Inner>,
+            e: BTreeMap<String, u64>,
+            f: Kind,
+            g: (u8, bool),
+        }
+
+        let items = (0..20_u32)
+            .map(|idx| Record {
+                a: idx,
+                b: (idx % 3 != 0).then(|| idx.to_string()),
+                c: (0..idx % 4)
+                    .map(|val| (val % 2 == 0).then_some(val as f64))
+                    .collect(),
+                d: (idx % 2 == 0).then(|| Inner {
+                    x: -(idx as i16),
+                    y: (idx % 4 == 0).then(|| vec![String::from("y"); idx as usize % 3]),
+                }),
+                e: BTreeMap::from([(format!("k{idx}"), idx as u64)]),
+                f: if idx % 2 == 0 {
+                    Kind::A(idx as u8)
+                } else {
+                    Kind::B { b: idx % 3 == 0 }
+                },
+                g: (idx as u8, idx % 5 == 0),
+            })
+            .collect::<Vec<_>>();
+
+        let fields =
+            Vec::<Field>::from_samples(&items, TracingOptions::default().map_as_struct(false))
+                .unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        for column_threads in [0, 1, 2, 3, 7, 64] {
+            let options = DeserializationOptions::default().column_threads(column_threads);
+            let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+            assert_eq!(actual, items, "column_threads: {column_threads}");
+        }
+    }
+);
+
+test_generic!(
+    fn empty_arrays() {
+        use crate::utils::DeserializationOptions;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: u32,
+            b: Option<String>,
+        }
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Vec::<Record>::new()).unwrap();
+
+        let options = DeserializationOptions::default().column_threads(2);
+        let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+);
+
+test_generic!(
+    fn respects_the_other_options() {
+        use crate::utils::{DeserializationOptions, UnknownColumns};
+        use serde::Deserialize;
+        use std::collections::HashMap;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            a: u8,
+            renamed: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2, "c": 3})]).unwrap();
+
+        let options = DeserializationOptions::default()
+            .column_threads(2)
+            .rename(HashMap::from([(
+                String::from("b"),
+                String::from("renamed"),
+            )]));
+        let items: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, vec![Record { a: 1, renamed: 2 }]);
+
+        let options = options.unknown_columns(UnknownColumns::Error);
+        let res = from_arrow_with_options::<Vec<Record>, _>(&fields, &arrays, &options);
+        crate::test_impls::macros::expect_error(&res, "Unknown column \"c\"");
+    }
+);
+
+test_generic!(
+    fn null_defaults() {
+        use crate::utils::DeserializationOptions;
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            country: String,
+            count: u32,
+        }
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("country", GenericDataType::LargeUtf8, true)
+                    .with_null_default("unknown"),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("count", GenericDataType::U32, true).with_null_default("0"),
+            )
+            .unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[
+                serde_json::json!({"country": "de", "count": null}),
+                serde_json::json!({"country": null, "count": 2}),
+            ],
+        )
+        .unwrap();
+
+        let options = DeserializationOptions::default().column_threads(2);
+        let items: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(
+            items,
+            vec![
+                Record {
+                    country: String::from("de"),
+                    count: 0,
+                },
+                Record {
+                    country: String::from("unknown"),
+                    count: 2,
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn rows_spanning_multiple_chunks() {
+        use crate::utils::DeserializationOptions;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: u32,
+            b: Option<String>,
+            c: Vec<u8>,
+        }
+
+        // more rows than decoded per chunk, with a partial last chunk
+        let items = (0..10_000_u32)
+            .map(|idx| Record {
+                a: idx,
+                b: (idx % 3 != 0).then(|| idx.to_string()),
+                c: vec![idx as u8; idx as usize % 3],
+            })
+            .collect::<Vec<_>>();
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let options = DeserializationOptions::default().column_threads(2);
+        let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(actual, items);
+    }
+);
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    internal::{
+        schema::{GenericDataType, GenericField, SchemaLike, SerdeArrowSchema},
+        tracing::TracingOptions,
+    },
+    utils::Converters,
+    Error, Value,
+};
+
+use super::macros::{expect_error, test_generic};
+
+/// A type that cannot be annotated, serialized as a string with a unit
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meters(String);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    distance: Meters,
+    optional: Option<Meters>,
+}
+
+fn converters() -> Converters {
+    Converters::new()
+        .register("Meters", "F64", |value| match value {
+            Value::Str(s) => match s.trim_end_matches('m').parse() {
+                Ok(value) => Ok(Value::F64(value)),
+                Err(err) => Err(Error::custom_from(format!("Invalid distance {s:?}"), err)),
+            },
+            value => Ok(value),
+        })
+        .unwrap()
+}
+
+#[test]
+fn from_type_uses_the_registered_data_type() {
+    let schema =
+        SerdeArrowSchema::from_type::<Record>(TracingOptions::default().converters(converters()))
+            .unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("distance", GenericDataType::F64, false),
+            GenericField::new("optional", GenericDataType::F64, true),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn from_type_without_converters_uses_the_inner_type() {
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("distance", GenericDataType::LargeUtf8, false),
+            GenericField::new("optional", GenericDataType::LargeUtf8, true),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn from_samples_traces_the_converted_values() {
+    let items = [Record {
+        distance: Meters(String::from("1.5m")),
+        optional: None,
+    }];
+    let schema = SerdeArrowSchema::from_samples(
+        &items,
+        TracingOptions::default()
+            .allow_null_fields(true)
+            .converters(converters()),
+    )
+    .unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("distance", GenericDataType::F64, false),
+            GenericField::new("optional", GenericDataType::Null, true),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn invalid_data_types_are_rejected() {
+    let res = Converters::new().register("Meters", "NotAType", Ok);
+    expect_error(&res, "cannot parse data type");
+}
+
+#[test]
+fn nested_data_types_are_rejected() {
+    let converters = Converters::new().register("Meters", "Struct", Ok).unwrap();
+    let res =
+        SerdeArrowSchema::from_type::<Record>(TracingOptions::default().converters(converters));
+    expect_error(&res, "Custom representations must use primitive data types");
+}
+
+test_generic!(
+    fn values_are_converted() {
+        use crate::test_impls::converters::{converters, Meters, Record};
+        use crate::utils::SerializationOptions;
+
+        let fields =
+            Vec::<Field>::from_type::<Record>(TracingOptions::default().converters(converters()))
+                .unwrap();
+        let items = vec![
+            Record {
+                distance: Meters(String::from("1.5m")),
+                optional: Some(Meters(String::from("2m"))),
+            },
+            Record {
+                distance: Meters(String::from("3m")),
+                optional: None,
+            },
+        ];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().converters(converters()),
+        )
+        .unwrap();
+        builder.extend(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let actual: Vec<crate::Value> = from_arrow(&fields, &arrays).unwrap();
+        let row = |distance: f64, optional: Option<f64>| {
+            crate::Value::Struct(vec![
+                (String::from("distance"), crate::Value::F64(distance)),
+                (
+                    String::from("optional"),
+                    optional
+                        .map(crate::Value::F64)
+                        .unwrap_or(crate::Value::Null),
+                ),
+            ])
+        };
+        assert_eq!(actual, vec![row(1.5, Some(2.0)), row(3.0, None)]);
+    }
+);
+
+test_generic!(
+    fn conversion_errors_are_reported() {
+        use crate::test_impls::converters::{converters, Meters, Record};
+        use crate::test_impls::macros::expect_error;
+        use crate::utils::SerializationOptions;
+
+        let fields =
+            Vec::<Field>::from_type::<Record>(TracingOptions::default().converters(converters()))
+                .unwrap();
+        let items = vec![Record {
+            distance: Meters(String::from("far")),
+            optional: None,
+        }];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().converters(converters()),
+        )
+        .unwrap();
+        let res = builder.extend(&items);
+        expect_error(&res, "Invalid distance \"far\"");
+    }
+);
@@ -0,0 +1,126 @@
+use super::macros::{test_example, test_generic};
+
+test_example!(
+    test_name = decimal_from_str,
+    test_bytecode_deserialization = true,
+    field = GenericField::new("item", GenericDataType::LargeUtf8, false),
+    overwrite_field = GenericField::new("item", GenericDataType::Decimal128(5, 2), false),
+    ty = String,
+    values = [
+        String::from("0.10"),
+        String::from("-12.34"),
+        String::from("100.00"),
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = decimal_from_str_nullable,
+    test_bytecode_deserialization = true,
+    field = GenericField::new("item", GenericDataType::LargeUtf8, true),
+    overwrite_field = GenericField::new("item", GenericDataType::Decimal128(5, 2), true),
+    ty = Option<String>,
+    values = [Some(String::from("0.10")), None, Some(String::from("-12.34"))],
+    nulls = [false, true, false],
+);
+
+test_example!(
+    test_name = decimal_tracing_string_only,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().guess_decimals(true),
+    field = GenericField::new("item", GenericDataType::Decimal128(5, 3), false),
+    ty = String,
+    values = [
+        String::from("0.125"),
+        String::from("-12.300"),
+        String::from("10.000"),
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = decimal_tracing_string_nullable,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().guess_decimals(true),
+    field = GenericField::new("item", GenericDataType::Decimal128(3, 1), true),
+    ty = Option<String>,
+    values = [Some(String::from("1.5")), None, Some(String::from("42.0"))],
+    nulls = [false, true, false],
+);
+
+test_example!(
+    test_name = decimal_tracing_string_only_with_invalid,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().guess_decimals(true),
+    field = GenericField::new("item", GenericDataType::LargeUtf8, false),
+    ty = String,
+    values = [
+        String::from("0.125"),
+        String::from("-12.3"),
+        String::from("not a number"),
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = decimal_tracing_with_precision,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default()
+        .guess_decimals(true)
+        .decimal_precision(Some(20)),
+    field = GenericField::new("item", GenericDataType::Decimal128(20, 2), false),
+    ty = String,
+    values = [String::from("0.25"), String::from("-1.50")],
+    nulls = [false, false],
+);
+
+test_generic!(
+    fn decimal_tracing_with_too_small_precision() {
+        let res = Vec::<Field>::from_samples(
+            &Items(&["123.45"]),
+            TracingOptions::default()
+                .guess_decimals(true)
+                .decimal_precision(Some(3)),
+        );
+        crate::test_impls::macros::expect_error(&res, "too small");
+    }
+);
+
+test_generic!(
+    fn decimal_from_numbers() {
+        let fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::Decimal128(6, 2),
+            false,
+        ))
+        .unwrap()];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.push(&Item(42_u32)).unwrap();
+        builder.push(&Item(-7_i64)).unwrap();
+        builder.push(&Item(1.25_f64)).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<String>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec!["42.00", "-7.00", "1.25"]);
+    }
+);
+
+test_generic!(
+    fn decimal_with_too_many_digits() {
+        let fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::Decimal128(4, 2),
+            false,
+        ))
+        .unwrap()];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        let res = builder.push(&Item("1.234"));
+        crate::test_impls::macros::expect_error(&res, "without loss of precision");
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        let res = builder.push(&Item("123.4"));
+        crate::test_impls::macros::expect_error(&res, "too many digits");
+    }
+);
@@ -0,0 +1,206 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn unknown_columns() {
+        use crate::utils::{DeserializationOptions, UnknownColumns};
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            a: u8,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Outer {
+            nested: Record,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2})]).unwrap();
+
+        let items: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![Record { a: 1 }]);
+
+        let res = from_arrow_with_options::<Vec<Record>, _>(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().unknown_columns(UnknownColumns::Error),
+        );
+        crate::test_impls::macros::expect_error(&res, "Unknown column \"b\"");
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("nested", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::U8, false))
+                .with_child(GenericField::new("c", GenericDataType::U8, false)),
+        )
+        .unwrap()];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"nested": {"a": 1, "c": 2}})]).unwrap();
+
+        let res = from_arrow_with_options::<Vec<Outer>, _>(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().unknown_columns(UnknownColumns::Error),
+        );
+        crate::test_impls::macros::expect_error(&res, "Unknown column \"c\"");
+    }
+);
+
+test_generic!(
+    fn unknown_columns_maps() {
+        use crate::utils::{DeserializationOptions, UnknownColumns};
+        use std::collections::BTreeMap;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2})]).unwrap();
+
+        let items = from_arrow_with_options::<Vec<BTreeMap<String, u8>>, _>(
+            &fields,
+            &arrays,
+            &DeserializationOptions::default().unknown_columns(UnknownColumns::Error),
+        )
+        .unwrap();
+        assert_eq!(
+            items,
+            vec![BTreeMap::from([
+                (String::from("a"), 1),
+                (String::from("b"), 2)
+            ])]
+        );
+    }
+);
+
+test_generic!(
+    fn strict() {
+        use crate::utils::DeserializationOptions;
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Exact {
+            a: i32,
+            b: f32,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Widened {
+            a: i64,
+            b: f64,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::I32, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::F32, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2.0})]).unwrap();
+        let strict = DeserializationOptions::default().strict(true);
+
+        let items: Vec<Widened> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![Widened { a: 1, b: 2.0 }]);
+
+        let items: Vec<Exact> = from_arrow_with_options(&fields, &arrays, &strict).unwrap();
+        assert_eq!(items, vec![Exact { a: 1, b: 2.0 }]);
+
+        let res = from_arrow_with_options::<Vec<Widened>, _>(&fields, &arrays, &strict);
+        crate::test_impls::macros::expect_error(&res, "cannot deserialize I32(1) as i64");
+    }
+);
+
+test_generic!(
+    fn strict_optional_values() {
+        use crate::utils::DeserializationOptions;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, true)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[Some(1_u8), None])).unwrap();
+        let strict = DeserializationOptions::default().strict(true);
+
+        let Items(items): Items<Vec<Option<u8>>> =
+            from_arrow_with_options(&fields, &arrays, &strict).unwrap();
+        assert_eq!(items, vec![Some(1), None]);
+
+        let res = from_arrow_with_options::<Items<Vec<Option<u16>>>, _>(&fields, &arrays, &strict);
+        crate::test_impls::macros::expect_error(&res, "Strict deserialization");
+    }
+);
+
+test_generic!(
+    fn overflow_error() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_i64, -1])).unwrap();
+
+        let res = from_arrow::<Items<Vec<u64>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot convert -1 (i64) to u64 without overflow",
+        );
+    }
+);
+
+test_generic!(
+    fn overflow_saturate() {
+        use crate::utils::{DeserializationOptions, Overflow};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u64, u64::MAX])).unwrap();
+        let options = DeserializationOptions::default().overflow(Overflow::Saturate);
+
+        let Items(items): Items<Vec<i64>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, vec![1, i64::MAX]);
+    }
+);
+
+test_generic!(
+    fn overflow_wrap() {
+        use crate::utils::{DeserializationOptions, Overflow};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_i64, -1])).unwrap();
+        let options = DeserializationOptions::default().overflow(Overflow::Wrap);
+
+        let Items(items): Items<Vec<u64>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, vec![1, u64::MAX]);
+    }
+);
+
+test_generic!(
+    fn rename() {
+        use crate::utils::{DeserializationOptions, UnknownColumns};
+        use serde::Deserialize;
+        use std::collections::HashMap;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[allow(non_snake_case)]
+        struct Record {
+            userId: u8,
+            name: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("user_id", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("name", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"user_id": 1, "name": 2})]).unwrap();
+
+        let options = DeserializationOptions::default()
+            .unknown_columns(UnknownColumns::Error)
+            .rename(HashMap::from([
+                (String::from("user_id"), String::from("userId")),
+                (String::from("missing"), String::from("other")),
+            ]));
+        let items: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, vec![Record { userId: 1, name: 2 }]);
+
+        let res = from_arrow::<Vec<Record>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "missing field `userId`");
+    }
+);
@@ -0,0 +1,49 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn from_arrow_into_appends_items() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let first = to_arrow(&fields, &Items(&[1_i64, 2])).unwrap();
+        let second = to_arrow(&fields, &Items(&[3_i64])).unwrap();
+
+        let mut items = Vec::<Item<i64>>::new();
+        from_arrow_into(&fields, &first, &mut items).unwrap();
+        from_arrow_into(&fields, &second, &mut items).unwrap();
+
+        let items = items.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+);
+
+test_generic!(
+    fn from_arrow_into_reuses_allocation() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_i64, 2, 3])).unwrap();
+
+        let mut items = Vec::<Item<i64>>::with_capacity(10);
+        for _ in 0..3 {
+            items.clear();
+            from_arrow_into(&fields, &arrays, &mut items).unwrap();
+        }
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items.capacity(), 10);
+    }
+);
+
+test_generic!(
+    fn from_arrow_into_keeps_items_before_errors() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u16, 300, 3])).unwrap();
+
+        let mut items = vec![Item(0_u8)];
+        let res = from_arrow_into(&fields, &arrays, &mut items);
+        crate::test_impls::macros::expect_error(&res, "300");
+
+        let items = items.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(items, vec![0, 1]);
+    }
+);
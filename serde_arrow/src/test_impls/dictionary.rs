@@ -1,4 +1,4 @@
-use super::macros::test_example;
+use super::macros::{test_example, test_generic};
 
 test_example!(
     test_name = string_dict_u32,
@@ -597,3 +597,136 @@ test_example!(
     values = [Some(String::from("a")), None, Some(String::from("a"))],
     nulls = [false, true, false],
 );
+
+test_example!(
+    test_name = string_dict_ratio_few_distinct_values,
+    tracing_options = TracingOptions::default().string_dictionary_ratio(Some(0.5)),
+    field = GenericField::new("item", GenericDataType::Dictionary, true)
+        .with_child(GenericField::new("key", GenericDataType::U32, false))
+        .with_child(GenericField::new("value", GenericDataType::LargeUtf8, false)),
+    ty = Option<String>,
+    values = [
+        Some(String::from("a")),
+        None,
+        Some(String::from("a")),
+        Some(String::from("b")),
+        Some(String::from("a")),
+    ],
+    nulls = [false, true, false, false, false],
+);
+
+test_example!(
+    test_name = string_dict_ratio_many_distinct_values,
+    tracing_options = TracingOptions::default().string_dictionary_ratio(Some(0.5)),
+    field = GenericField::new("item", GenericDataType::LargeUtf8, false),
+    ty = String,
+    values = [String::from("a"), String::from("b"), String::from("c")],
+    nulls = [false, false, false],
+);
+
+test_generic!(
+    fn string_dict_ratio_stops_tracking_many_distinct_values() {
+        // each value is repeated twice, but there are too many distinct values
+        let items = (0..(1 << 17) + 2)
+            .map(|idx| (idx / 2).to_string())
+            .collect::<Vec<_>>();
+
+        let fields = Vec::<Field>::from_samples(
+            &Items(&items),
+            TracingOptions::default().string_dictionary_ratio(Some(0.5)),
+        )
+        .unwrap();
+        let field = GenericField::try_from(&fields[0]).unwrap();
+        assert_eq!(field.data_type, GenericDataType::LargeUtf8);
+    }
+);
+
+test_generic!(
+    fn string_dict_into_unit_enum() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Color {
+            Red,
+            Green,
+            #[serde(other)]
+            Other,
+        }
+
+        let field = GenericField::new("item", GenericDataType::Dictionary, true)
+            .with_child(GenericField::new("key", GenericDataType::U32, false))
+            .with_child(GenericField::new(
+                "value",
+                GenericDataType::LargeUtf8,
+                false,
+            ));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items = [Some("Red"), None, Some("Green"), Some("Blue"), Some("Red")];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Option<Color>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Some(Color::Red),
+                None,
+                Some(Color::Green),
+                Some(Color::Other),
+                Some(Color::Red),
+            ],
+        );
+    }
+);
+
+test_generic!(
+    fn string_dict_into_enum_with_unknown_variant() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Color {
+            Red,
+            Green,
+        }
+
+        let field = GenericField::new("item", GenericDataType::Dictionary, false)
+            .with_child(GenericField::new("key", GenericDataType::I32, false))
+            .with_child(GenericField::new("value", GenericDataType::Utf8, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&["Red", "Green"])).unwrap();
+        let Items(actual): Items<Vec<Color>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Color::Red, Color::Green]);
+
+        let arrays = to_arrow(&fields, &Items(&["Red", "Blue"])).unwrap();
+        let res = from_arrow::<Items<Vec<Color>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "Unknown variant \"Blue\"");
+    }
+);
+
+test_generic!(
+    fn string_dict_into_non_unit_variant() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Value {
+            Int(i64),
+        }
+
+        let field = GenericField::new("item", GenericDataType::Dictionary, false)
+            .with_child(GenericField::new("key", GenericDataType::U32, false))
+            .with_child(GenericField::new(
+                "value",
+                GenericDataType::LargeUtf8,
+                false,
+            ));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&["Int"])).unwrap();
+        let res = from_arrow::<Items<Vec<Value>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Only unit variants can be deserialized from strings",
+        );
+    }
+);
@@ -0,0 +1,199 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn primitives() {
+        use serde_json::json;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::F32, true)).unwrap(),
+            Field::try_from(&GenericField::new("bc", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let items = json!([{"a": 1.5, "bc": "foo"}, {"a": null, "bc": "x"}]);
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let actual = crate::display::pretty(&fields, &arrays, 10).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+------+-----+\n",
+                "| a    | bc  |\n",
+                "+------+-----+\n",
+                "| 1.5  | foo |\n",
+                "| null | x   |\n",
+                "+------+-----+\n",
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn limit() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u8, 2, 3])).unwrap();
+
+        let actual = crate::display::pretty(&fields, &arrays, 1).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+------+\n",
+                "| item |\n",
+                "+------+\n",
+                "| 1    |\n",
+                "+------+\n",
+                "... 2 more rows (3 in total)\n",
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn nested() {
+        use serde_json::json;
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("a", GenericDataType::LargeList, false)
+                    .with_child(GenericField::new("element", GenericDataType::I32, false)),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("x", GenericDataType::Bool, false))
+                    .with_child(GenericField::new("y", GenericDataType::U8, true)),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("c", GenericDataType::Map, false).with_child(
+                    GenericField::new("entries", GenericDataType::Struct, false)
+                        .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                        .with_child(GenericField::new("value", GenericDataType::I64, false)),
+                ),
+            )
+            .unwrap(),
+        ];
+        let items = json!([{"a": [1, 2], "b": {"x": true, "y": null}, "c": {"k": 3}}]);
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let actual = crate::display::pretty(&fields, &arrays, 10).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+--------+--------------------+--------+\n",
+                "| a      | b                  | c      |\n",
+                "+--------+--------------------+--------+\n",
+                "| [1, 2] | {x: true, y: null} | {k: 3} |\n",
+                "+--------+--------------------+--------+\n",
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn timestamps() {
+        use crate::internal::schema::{GenericTimeUnit, Strategy};
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::Date64, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::Date64, false)
+                    .with_strategy(Strategy::UtcStrAsDate64),
+            )
+            .unwrap(),
+            Field::try_from(&GenericField::new(
+                "c",
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(String::from("UTC"))),
+                false,
+            ))
+            .unwrap(),
+        ];
+
+        #[derive(serde::Serialize)]
+        struct Record {
+            a: i64,
+            b: &'static str,
+            c: &'static str,
+        }
+
+        let arrays = to_arrow(
+            &fields,
+            &[Record {
+                a: 1_500,
+                b: "2023-01-01T12:00:00Z",
+                c: "1970-01-02T00:00:00Z",
+            }],
+        )
+        .unwrap();
+
+        let actual = crate::display::pretty(&fields, &arrays, 10).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+-------------------------+----------------------+----------------------+\n",
+                "| a                       | b                    | c                    |\n",
+                "+-------------------------+----------------------+----------------------+\n",
+                "| 1970-01-01T00:00:01.500 | 2023-01-01T12:00:00Z | 1970-01-02T00:00:00Z |\n",
+                "+-------------------------+----------------------+----------------------+\n",
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn line_breaks_are_escaped() {
+        let fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::LargeUtf8,
+            false,
+        ))
+        .unwrap()];
+        let arrays = to_arrow(&fields, &Items(&["a\nb", "c\r\nd"])).unwrap();
+
+        let actual = crate::display::pretty(&fields, &arrays, 10).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+--------+\n",
+                "| item   |\n",
+                "+--------+\n",
+                "| a\\nb   |\n",
+                "| c\\r\\nd |\n",
+                "+--------+\n",
+            ),
+        );
+    }
+);
+
+test_generic!(
+    fn limit_only_deserializes_displayed_rows() {
+        use crate::internal::schema::Strategy;
+
+        let fields =
+            vec![
+                Field::try_from(&GenericField::new("item", GenericDataType::Date64, false))
+                    .unwrap(),
+            ];
+        let arrays = to_arrow(&fields, &Items(&[1_500_i64, i64::MAX])).unwrap();
+
+        // the second row cannot be deserialized as a datetime string
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Date64, false)
+                .with_strategy(Strategy::UtcStrAsDate64),
+        )
+        .unwrap()];
+        assert!(crate::display::pretty(&fields, &arrays, 2).is_err());
+
+        let actual = crate::display::pretty(&fields, &arrays, 1).unwrap();
+        assert_eq!(
+            actual,
+            concat!(
+                "+--------------------------+\n",
+                "| item                     |\n",
+                "+--------------------------+\n",
+                "| 1970-01-01T00:00:01.500Z |\n",
+                "+--------------------------+\n",
+                "... 1 more rows (2 in total)\n",
+            ),
+        );
+    }
+);
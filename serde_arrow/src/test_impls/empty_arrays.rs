@@ -0,0 +1,77 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn empty_arrays_have_the_types_of_the_fields() {
+        use std::collections::BTreeMap;
+
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: Option<u8>,
+            b: String,
+            c: Vec<f32>,
+            d: Inner,
+            e: BTreeMap<String, i64>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            f: bool,
+        }
+
+        let fields = Vec::<Field>::from_type::<Record>(
+            TracingOptions::default()
+                .string_dictionary_encoding(true)
+                .map_as_struct(false),
+        )
+        .unwrap();
+
+        let arrays = empty_arrays(&fields).unwrap();
+        assert_eq!(arrays.len(), fields.len());
+        for (field, array) in fields.iter().zip(&arrays) {
+            assert_eq!(array.len(), 0);
+            assert_eq!(array.data_type(), field.data_type());
+        }
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+);
+
+test_generic!(
+    fn empty_arrays_without_fields() {
+        let arrays = empty_arrays(&[]).unwrap();
+        assert!(arrays.is_empty());
+    }
+);
+
+mod record_batch {
+    use std::sync::Arc;
+
+    use crate::_impl::arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn empty_record_batch_has_the_schema() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt8, false),
+            Field::new(
+                "b",
+                DataType::Struct(vec![Field::new("c", DataType::Utf8, true)].into()),
+                true,
+            ),
+        ]);
+
+        let batch = crate::empty_record_batch(&schema).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema(), Arc::new(schema));
+    }
+
+    #[test]
+    fn empty_record_batch_without_fields() {
+        let batch = crate::empty_record_batch(&Schema::empty()).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.num_columns(), 0);
+    }
+}
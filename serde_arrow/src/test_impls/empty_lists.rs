@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<u8>>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record { tags: None },
+        Record { tags: Some(vec![]) },
+        Record {
+            tags: Some(vec![1, 2]),
+        },
+    ]
+}
+
+test_generic!(
+    fn preserve_distinguishes_null_and_empty_lists() {
+        use crate::test_impls::empty_lists::{records, Record};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &records()).unwrap();
+        assert_eq!(arrays[0].null_count(), 1);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, records());
+    }
+);
+
+test_generic!(
+    fn serialize_null_as_empty() {
+        use crate::test_impls::empty_lists::{records, Record};
+        use crate::utils::{EmptyListPolicy, SerializationOptions};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let options = SerializationOptions::default().empty_lists(EmptyListPolicy::NullAsEmpty);
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder.extend(&records()).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].null_count(), 0);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        let expected = [Some(vec![]), Some(vec![]), Some(vec![1, 2])]
+            .into_iter()
+            .map(|tags| Record { tags })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+);
+
+test_generic!(
+    fn serialize_empty_as_null() {
+        use crate::test_impls::empty_lists::{records, Record};
+        use crate::utils::{EmptyListPolicy, SerializationOptions};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let options = SerializationOptions::default().empty_lists(EmptyListPolicy::EmptyAsNull);
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder.extend(&records()).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].null_count(), 2);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        let expected = [None, None, Some(vec![1, 2])]
+            .into_iter()
+            .map(|tags| Record { tags })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+);
+
+test_generic!(
+    fn deserialize_with_policies() {
+        use crate::test_impls::empty_lists::{records, Record};
+        use crate::utils::{DeserializationOptions, EmptyListPolicy};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &records()).unwrap();
+
+        for (policy, expected) in [
+            (
+                EmptyListPolicy::NullAsEmpty,
+                [Some(vec![]), Some(vec![]), Some(vec![1, 2])],
+            ),
+            (EmptyListPolicy::EmptyAsNull, [None, None, Some(vec![1, 2])]),
+        ] {
+            let options = DeserializationOptions::default().empty_lists(policy);
+            let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+            let expected = expected
+                .into_iter()
+                .map(|tags| Record { tags })
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{policy:?}");
+        }
+    }
+);
+
+test_generic!(
+    fn nested_lists_with_policies() {
+        use crate::utils::{DeserializationOptions, EmptyListPolicy, SerializationOptions};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            groups: Vec<Option<Vec<u8>>>,
+            after: u8,
+        }
+
+        let items = vec![
+            Record {
+                groups: vec![None, Some(vec![]), Some(vec![1])],
+                after: 1,
+            },
+            Record {
+                groups: vec![Some(vec![2, 3]), None],
+                after: 2,
+            },
+        ];
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let options = SerializationOptions::default().empty_lists(EmptyListPolicy::EmptyAsNull);
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder.extend(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let options = DeserializationOptions::default().empty_lists(EmptyListPolicy::NullAsEmpty);
+        let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    groups: vec![Some(vec![]), Some(vec![]), Some(vec![1])],
+                    after: 1,
+                },
+                Record {
+                    groups: vec![Some(vec![2, 3]), Some(vec![])],
+                    after: 2,
+                },
+            ]
+        );
+    }
+);
@@ -0,0 +1,254 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn null_in_non_nullable() {
+        use crate::ErrorKind;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Struct, false)
+                .with_child(GenericField::new("value", GenericDataType::U8, false)),
+        )
+        .unwrap()];
+
+        let items = [
+            serde_json::json!({"value": 1}),
+            serde_json::json!({"value": 2}),
+            serde_json::json!({"value": null}),
+        ];
+        let err = to_arrow(&fields, &Items(&items)).unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::NullInNonNullable {
+                path: String::from("item.value"),
+                row: 2
+            }
+        );
+    }
+);
+
+test_generic!(
+    fn schema_mismatch() {
+        use crate::ErrorKind;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let err = to_arrow(&fields, &Items(&["foo"])).unwrap_err();
+
+        let ErrorKind::SchemaMismatch { found, path, .. } = err.kind() else {
+            panic!("unexpected error kind: {:?}", err.kind());
+        };
+        assert_eq!(found, "Str");
+        assert_eq!(path, "item");
+
+        // the path is only part of the kind, the message is unchanged
+        assert!(!err.message().contains("item"));
+    }
+);
+
+test_generic!(
+    fn custom() {
+        use crate::ErrorKind;
+
+        let err = crate::Error::custom(String::from("foo"));
+        assert_eq!(err.kind(), &ErrorKind::Custom);
+        assert_eq!(err.message(), "foo");
+    }
+);
+
+test_generic!(
+    fn array_data_type_mismatch() {
+        use crate::ErrorKind;
+
+        let utf8_fields =
+            vec![
+                Field::try_from(&GenericField::new("item", GenericDataType::Utf8, false)).unwrap(),
+            ];
+        let large_utf8_fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::LargeUtf8,
+            false,
+        ))
+        .unwrap()];
+
+        let arrays = to_arrow(&utf8_fields, &Items(&["foo", "bar"])).unwrap();
+        let Err(err) = from_arrow::<Vec<Item<String>>, _>(&large_utf8_fields, &arrays) else {
+            panic!("expected an error");
+        };
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::SchemaMismatch {
+                expected: String::from("LargeUtf8"),
+                found: String::from("Utf8"),
+                path: String::from("item"),
+            }
+        );
+    }
+);
+
+test_generic!(
+    fn array_timezones_are_not_compared() {
+        use crate::internal::schema::GenericTimeUnit;
+
+        let utc_fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(String::from("UTC"))),
+            false,
+        ))
+        .unwrap()];
+        let naive_fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None),
+            false,
+        ))
+        .unwrap()];
+
+        let arrays = to_arrow(&utc_fields, &Items(&["2020-12-24T08:30:00Z"])).unwrap();
+        let items: Vec<Item<i64>> = from_arrow(&naive_fields, &arrays).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].0, 1_608_798_600_000);
+    }
+);
+
+#[cfg(has_arrow)]
+#[test]
+fn unsupported_array_data_type() {
+    use std::sync::Arc;
+
+    use crate::{
+        _impl::arrow::{
+            _raw::array::BinaryArray,
+            array::ArrayRef,
+            datatypes::{DataType, Field},
+        },
+        ErrorKind,
+    };
+
+    let fields = vec![Field::new("item", DataType::Utf8, false)];
+    let arrays: Vec<ArrayRef> = vec![Arc::new(BinaryArray::from_vec(vec![b"foo"]))];
+
+    let Err(err) = crate::from_arrow::<Vec<crate::utils::Item<String>>, _>(&fields, &arrays) else {
+        panic!("expected an error");
+    };
+    assert_eq!(
+        err.kind(),
+        &ErrorKind::UnsupportedDataType {
+            data_type: String::from("Binary"),
+        }
+    );
+}
+
+test_generic!(
+    fn missing_field_suggests_similar_columns() {
+        use crate::ErrorKind;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[allow(dead_code, non_snake_case)]
+        struct Record {
+            userId: u8,
+            name: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("user_id", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("name", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("other", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[serde_json::json!({"user_id": 1, "name": 2, "other": 3})],
+        )
+        .unwrap();
+
+        let Err(err) = from_arrow::<Vec<Record>, _>(&fields, &arrays) else {
+            panic!("Expected error");
+        };
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::MissingField {
+                field: String::from("userId")
+            }
+        );
+        assert_eq!(
+            err.message(),
+            "serde::de::Error: missing field `userId`. Did you mean column \"user_id\"?"
+        );
+    }
+);
+
+test_generic!(
+    fn missing_field_without_similar_columns() {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Record {
+            a: u8,
+            timestamp: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(&fields, &[serde_json::json!({"a": 1, "b": 2})]).unwrap();
+
+        let Err(err) = from_arrow::<Vec<Record>, _>(&fields, &arrays) else {
+            panic!("Expected error");
+        };
+        assert_eq!(err.message(), "serde::de::Error: missing field `timestamp`");
+    }
+);
+
+test_generic!(
+    fn missing_nested_field_suggests_columns_of_the_nested_struct() {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Record {
+            a: u8,
+            inner: Inner,
+        }
+
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Inner {
+            item_count: u8,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("inner", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("itemCount", GenericDataType::U8, false))
+                    .with_child(GenericField::new("item-counts", GenericDataType::U8, false)),
+            )
+            .unwrap(),
+            Field::try_from(&GenericField::new("item_cnt", GenericDataType::U8, false)).unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[serde_json::json!({
+                "a": 1,
+                "inner": {"itemCount": 2, "item-counts": 3},
+                "item_cnt": 4,
+            })],
+        )
+        .unwrap();
+
+        let Err(err) = from_arrow::<Vec<Record>, _>(&fields, &arrays) else {
+            panic!("Expected error");
+        };
+        assert_eq!(
+            err.message(),
+            concat!(
+                "serde::de::Error: missing field `item_count`. ",
+                "Did you mean one of the columns \"itemCount\", \"item-counts\"?",
+            ),
+        );
+    }
+);
@@ -0,0 +1,65 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn counts_values_offsets_and_validity() {
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::I64, true)).unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        let empty_size = builder.estimated_size();
+
+        builder
+            .extend(&[
+                serde_json::json!({"a": 1, "b": 2, "c": "foo"}),
+                serde_json::json!({"a": 3, "b": null, "c": "barbaz"}),
+            ])
+            .unwrap();
+
+        // 2 x u32 values, 2 x i64 values, 1 byte of validity bits, 9 bytes of
+        // string data and 2 additional i64 offsets
+        assert_eq!(
+            builder.estimated_size() - empty_size,
+            2 * 4 + 2 * 8 + 1 + 9 + 2 * 8
+        );
+
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].len(), 2);
+        assert_eq!(builder.estimated_size(), empty_size);
+    }
+);
+
+test_generic!(
+    fn callback_is_invoked_once_per_batch() {
+        use crate::utils::Item;
+        use std::sync::{Arc, Mutex};
+
+        let sizes = Arc::new(Mutex::new(Vec::new()));
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U64, false)).unwrap()];
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.on_size_exceeded(32, {
+            let sizes = Arc::clone(&sizes);
+            move |size| sizes.lock().unwrap().push(size)
+        });
+
+        for item in 0..4_u64 {
+            builder.push(&Item(item)).unwrap();
+        }
+        assert_eq!(*sizes.lock().unwrap(), Vec::<usize>::new());
+
+        builder.push(&Item(4_u64)).unwrap();
+        builder.push(&Item(5_u64)).unwrap();
+        builder.extend(&[Item(6_u64), Item(7)]).unwrap();
+        assert_eq!(*sizes.lock().unwrap(), vec![40]);
+
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].len(), 8);
+
+        builder
+            .extend_from_slice(&[Item(0_u64), Item(1), Item(2), Item(3), Item(4), Item(5)])
+            .unwrap();
+        assert_eq!(*sizes.lock().unwrap(), vec![40, 48]);
+    }
+);
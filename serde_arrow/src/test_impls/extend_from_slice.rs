@@ -0,0 +1,162 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn extend_from_slice_flat_records() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: i64,
+            b: f32,
+            c: bool,
+            d: u8,
+        }
+
+        let items = (0..100)
+            .map(|i| Record {
+                a: -i,
+                b: i as f32 / 2.0,
+                c: i % 3 == 0,
+                d: i as u8,
+            })
+            .collect::<Vec<_>>();
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.extend_from_slice(&items).unwrap();
+        builder.extend_from_slice(&items[..10]).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.extend(&items).unwrap();
+        builder.extend(&items[..10]).unwrap();
+        let expected = builder.build_arrays().unwrap();
+
+        assert_eq!(arrays, expected);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual[..100], items);
+        assert_eq!(actual[100..], items[..10]);
+    }
+);
+
+test_generic!(
+    fn extend_from_slice_items() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder
+            .extend_from_slice(&[Item(1_u16), Item(2), Item(3)])
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let items: Vec<Item<u16>> = from_arrow(&fields, &arrays).unwrap();
+        let items = items.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+);
+
+test_generic!(
+    fn extend_from_slice_falls_back_for_other_records() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Record {
+            b: u32,
+            a: Option<i64>,
+            c: String,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::I64, true)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::U64, false)).unwrap(),
+            Field::try_from(&GenericField::new("c", GenericDataType::LargeUtf8, false)).unwrap(),
+        ];
+        let items = [
+            Record {
+                b: 1,
+                a: None,
+                c: String::from("x"),
+            },
+            Record {
+                b: 2,
+                a: Some(3),
+                c: String::from("y"),
+            },
+        ];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.extend_from_slice(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let expected = to_arrow(&fields, &items).unwrap();
+        assert_eq!(arrays, expected);
+    }
+);
+
+test_generic!(
+    fn extend_from_slice_falls_back_for_coerced_values() {
+        use serde::Serialize;
+
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        #[derive(Serialize)]
+        struct Record {
+            a: u32,
+            b: f64,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U64, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::F64, true)).unwrap(),
+        ];
+        let items = [
+            Record { a: 1, b: 0.5 },
+            Record { a: 2, b: f64::NAN },
+            Record { a: 3, b: 1.5 },
+        ];
+        let options = SerializationOptions::default().non_finite(NonFinite::Null);
+
+        let mut builder = ArrowBuilder::with_options(&fields, options.clone()).unwrap();
+        builder.extend_from_slice(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder.extend(&items).unwrap();
+        let expected = builder.build_arrays().unwrap();
+
+        assert_eq!(arrays, expected);
+        assert_eq!(arrays[1].null_count(), 1);
+    }
+);
+
+test_generic!(
+    fn extend_from_slice_non_finite_floats() {
+        use serde::Serialize;
+
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        #[derive(Serialize)]
+        struct Record {
+            a: f64,
+        }
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("a", GenericDataType::F64, false)).unwrap()];
+        let items = [Record { a: 0.5 }, Record { a: f64::INFINITY }];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.extend_from_slice(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].len(), 2);
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().non_finite(NonFinite::Error),
+        )
+        .unwrap();
+        let res = builder.extend_from_slice(&items);
+        crate::test_impls::macros::expect_error(&res, "inf");
+    }
+);
@@ -0,0 +1,57 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn failed_items_are_skipped() {
+        use crate::utils::FailedItems;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let items = vec![Ok(Item(1_i64)), Err("invalid"), Ok(Item(3_i64))];
+
+        let arrays = to_arrow_filtered(&fields, items, FailedItems::Skip).unwrap();
+        let items: Vec<Item<i64>> = from_arrow(&fields, &arrays).unwrap();
+        let items = items.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(items, vec![1, 3]);
+    }
+);
+
+test_generic!(
+    fn failed_items_are_written_as_nulls() {
+        use crate::utils::FailedItems;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, true)).unwrap()];
+        let items = vec![Err("invalid"), Ok(Item(Some(2_i64))), Err("invalid")];
+
+        let arrays = to_arrow_filtered(&fields, items, FailedItems::Null).unwrap();
+        let items: Vec<Item<Option<i64>>> = from_arrow(&fields, &arrays).unwrap();
+        let items = items.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(items, vec![None, Some(2), None]);
+    }
+);
+
+test_generic!(
+    fn failed_items_as_nulls_require_nullable_fields() {
+        use crate::utils::FailedItems;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let items = vec![Ok(Item(1_i64)), Err("invalid")];
+
+        let res = to_arrow_filtered(&fields, items, FailedItems::Null);
+        crate::test_impls::macros::expect_error(&res, "Cannot serialize a null record");
+    }
+);
+
+test_generic!(
+    fn failed_items_with_serialization_errors() {
+        use crate::utils::FailedItems;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let items = vec![Ok(Item(1_u32)), Err("invalid"), Ok(Item(300_u32))];
+
+        let res = to_arrow_filtered(&fields, items, FailedItems::Skip);
+        crate::test_impls::macros::expect_error(&res, "300");
+    }
+);
@@ -0,0 +1,208 @@
+use super::macros::{test_example, test_generic};
+
+test_example!(
+    test_name = fixed_size_list_f32,
+    field = GenericField::new("item", GenericDataType::LargeList, false)
+        .with_child(GenericField::new("element", GenericDataType::F32, false)),
+    overwrite_field = GenericField::new("item", GenericDataType::FixedSizeList(2), false)
+        .with_child(GenericField::new("element", GenericDataType::F32, false)),
+    ty = Vec<f32>,
+    values = [vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = fixed_size_list_array,
+    field = GenericField::new("item", GenericDataType::Struct, false)
+        .with_strategy(Strategy::TupleAsStruct)
+        .with_child(GenericField::new("0", GenericDataType::U16, false))
+        .with_child(GenericField::new("1", GenericDataType::U16, false))
+        .with_child(GenericField::new("2", GenericDataType::U16, false)),
+    overwrite_field = GenericField::new("item", GenericDataType::FixedSizeList(3), false)
+        .with_child(GenericField::new("element", GenericDataType::U16, false)),
+    ty = [u16; 3],
+    values = [[1, 2, 3], [4, 5, 6]],
+    nulls = [false, false],
+);
+
+test_example!(
+    test_name = nullable_fixed_size_list,
+    field = GenericField::new("item", GenericDataType::LargeList, true)
+        .with_child(GenericField::new("element", GenericDataType::I64, true)),
+    overwrite_field = GenericField::new("item", GenericDataType::FixedSizeList(2), true)
+        .with_child(GenericField::new("element", GenericDataType::I64, true)),
+    ty = Option<Vec<Option<i64>>>,
+    values = [Some(vec![Some(1), None]), None, Some(vec![None, Some(4)])],
+    nulls = [false, true, false],
+);
+
+test_generic!(
+    fn fixed_size_list_length_mismatch() {
+        let field = GenericField::new("item", GenericDataType::FixedSizeList(2), false)
+            .with_child(GenericField::new("element", GenericDataType::F32, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[vec![1.0_f32, 2.0], vec![3.0]]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Expected 2 items for fixed size list, found 1",
+        );
+    }
+);
+
+test_generic!(
+    fn fixed_shape_tensor() {
+        use crate::schema::FixedShapeTensor;
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            id: u32,
+            matrix: [f32; 4],
+        }
+
+        let mut field = GenericField::new("matrix", GenericDataType::FixedSizeList(4), false)
+            .with_child(GenericField::new("element", GenericDataType::F32, false));
+        field.extension_name = Some(String::from("arrow.fixed_shape_tensor"));
+        field.extension_metadata = Some(String::from(r#"{"shape":[2,2]}"#));
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("id", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(&field).unwrap(),
+        ];
+        let rt = GenericField::try_from(&fields[1]).unwrap();
+        assert_eq!(rt, field);
+        assert_eq!(
+            fields[1]
+                .clone()
+                .with_metadata(FixedShapeTensor::new(&[2, 2]).into()),
+            fields[1]
+        );
+
+        let items = vec![
+            Record {
+                id: 0,
+                matrix: [1.0, 0.0, 0.0, 1.0],
+            },
+            Record {
+                id: 1,
+                matrix: [0.0, 1.0, 1.0, 0.0],
+            },
+        ];
+        let arrays = to_arrow(&fields, &items).unwrap();
+        assert_eq!(arrays[1].len(), 2);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn fixed_shape_tensor_invalid_shape() {
+        let mut field = GenericField::new("matrix", GenericDataType::FixedSizeList(4), false)
+            .with_child(GenericField::new("element", GenericDataType::F32, false));
+        field.extension_name = Some(String::from("arrow.fixed_shape_tensor"));
+        field.extension_metadata = Some(String::from(r#"{"shape":[2,3]}"#));
+
+        let res = field.validate();
+        crate::test_impls::macros::expect_error(&res, "has 6 elements, but the list has size 4");
+    }
+);
+
+test_generic!(
+    fn fixed_shape_tensor_metadata_with_other_keys() {
+        let mut field = GenericField::new("matrix", GenericDataType::FixedSizeList(4), false)
+            .with_child(GenericField::new("element", GenericDataType::F32, false));
+        field.extension_name = Some(String::from("arrow.fixed_shape_tensor"));
+        field.extension_metadata = Some(String::from(
+            r#"{"dim_names":["shape","[3]"],"permutation":[1,0],"shape":[2,2]}"#,
+        ));
+        field.validate().unwrap();
+
+        field.extension_metadata = Some(String::from(r#"{"dim_names":["shape"]}"#));
+        let res = field.validate();
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Missing shape in fixed shape tensor metadata",
+        );
+    }
+);
+
+test_generic!(
+    fn fixed_shape_tensor_overflowing_shape() {
+        let mut field = GenericField::new("matrix", GenericDataType::FixedSizeList(4), false)
+            .with_child(GenericField::new("element", GenericDataType::F32, false));
+        field.extension_name = Some(String::from("arrow.fixed_shape_tensor"));
+        field.extension_metadata = Some(format!(r#"{{"shape":[{},{}]}}"#, usize::MAX, 2));
+
+        let res = field.validate();
+        crate::test_impls::macros::expect_error(&res, "has too many elements");
+    }
+);
+
+test_generic!(
+    fn fixed_size_list_into_arrays_with_other_sizes() {
+        let field = GenericField::new("item", GenericDataType::FixedSizeList(3), false)
+            .with_child(GenericField::new("element", GenericDataType::U16, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[[1_u16, 2, 3], [4, 5, 6]])).unwrap();
+
+        let res = from_arrow::<Items<Vec<[u16; 2]>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot deserialize a sequence with more than 2 items into a tuple or array of length 2",
+        );
+
+        let res = from_arrow::<Items<Vec<[u16; 4]>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "invalid length 3, expected an array of length 4",
+        );
+
+        let res = from_arrow::<Items<Vec<(u16, u16)>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot deserialize a sequence with more than 2 items into a tuple or array of length 2",
+        );
+
+        let Items(actual): Items<Vec<(u16, u16, u16)>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, [(1, 2, 3), (4, 5, 6)]);
+    }
+);
+
+test_generic!(
+    fn fixed_size_list_into_svector() {
+        use nalgebra::SVector;
+
+        let field = GenericField::new("item", GenericDataType::FixedSizeList(3), false)
+            .with_child(GenericField::new("element", GenericDataType::F64, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items = [
+            SVector::<f64, 3>::new(1.0, 2.0, 3.0),
+            SVector::<f64, 3>::new(4.0, 5.0, 6.0),
+        ];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<SVector<f64, 3>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+
+        let res = from_arrow::<Items<Vec<SVector<f64, 2>>>, _>(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "invalid length");
+    }
+);
+
+test_generic!(
+    fn fixed_size_list_into_small_vec() {
+        use smallvec::{smallvec, SmallVec};
+
+        let field = GenericField::new("item", GenericDataType::FixedSizeList(2), false)
+            .with_child(GenericField::new("element", GenericDataType::U32, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items: Vec<SmallVec<[u32; 2]>> = vec![smallvec![1, 2], smallvec![3, 4]];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<SmallVec<[u32; 2]>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        datatypes::{Field, Schema},
+        record_batch::RecordBatch,
+    },
+    from_record_batch_auto,
+    internal::tracing::TracingOptions,
+    schema::SchemaLike,
+    test_impls::macros::expect_error,
+    to_arrow,
+    utils::Coercion,
+};
+
+fn record_batch<T: Serialize>(items: &[T]) -> RecordBatch {
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+    let arrays = to_arrow(&fields, items).unwrap();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).unwrap()
+}
+
+#[test]
+fn matching_columns_are_not_coerced() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        a: u32,
+        b: String,
+    }
+
+    let items = vec![
+        Record {
+            a: 1,
+            b: String::from("foo"),
+        },
+        Record {
+            a: 2,
+            b: String::from("bar"),
+        },
+    ];
+
+    let (actual, coercions) = from_record_batch_auto::<Record>(&record_batch(&items)).unwrap();
+    assert_eq!(actual, items);
+    assert_eq!(coercions, []);
+}
+
+#[test]
+fn columns_are_widened() {
+    #[derive(Serialize)]
+    struct Source {
+        a: i8,
+        b: Option<u16>,
+        c: f32,
+        d: u32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Target {
+        a: i64,
+        b: Option<f32>,
+        c: f64,
+        d: f64,
+    }
+
+    let batch = record_batch(&[
+        Source {
+            a: -1,
+            b: Some(2),
+            c: 0.5,
+            d: u32::MAX,
+        },
+        Source {
+            a: 3,
+            b: None,
+            c: 1.5,
+            d: 0,
+        },
+    ]);
+
+    let (actual, coercions) = from_record_batch_auto::<Target>(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [
+            Target {
+                a: -1,
+                b: Some(2.0),
+                c: 0.5,
+                d: u32::MAX as f64,
+            },
+            Target {
+                a: 3,
+                b: None,
+                c: 1.5,
+                d: 0.0,
+            },
+        ]
+    );
+
+    let widened = |column: &str, from: &str, to: &str| Coercion::Widened {
+        column: column.to_owned(),
+        from: from.to_owned(),
+        to: to.to_owned(),
+    };
+    assert_eq!(
+        coercions,
+        [
+            widened("a", "Int8", "Int64"),
+            widened("b", "UInt16", "Float32"),
+            widened("c", "Float32", "Float64"),
+            widened("d", "UInt32", "Float64"),
+        ]
+    );
+}
+
+#[test]
+fn lossy_casts_are_not_applied() {
+    #[derive(Serialize)]
+    struct Source {
+        a: i64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Target {
+        #[allow(dead_code)]
+        a: f64,
+    }
+
+    let res = from_record_batch_auto::<Target>(&record_batch(&[Source { a: 1 }]));
+    expect_error(&res, "invalid conversion from I64(1) to f64");
+}
+
+#[test]
+fn missing_nullable_columns_are_null() {
+    #[derive(Serialize)]
+    struct Source {
+        a: u32,
+        extra: bool,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Target {
+        a: u32,
+        b: Option<String>,
+        c: Option<Vec<u8>>,
+    }
+
+    let batch = record_batch(&[Source { a: 1, extra: true }]);
+    let (actual, coercions) = from_record_batch_auto::<Target>(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [Target {
+            a: 1,
+            b: None,
+            c: None
+        }]
+    );
+    assert_eq!(
+        coercions,
+        [
+            Coercion::MissingAsNull {
+                column: String::from("b")
+            },
+            Coercion::MissingAsNull {
+                column: String::from("c")
+            },
+            Coercion::Ignored {
+                column: String::from("extra")
+            },
+        ]
+    );
+}
+
+#[test]
+fn missing_non_nullable_columns_are_errors() {
+    #[derive(Serialize)]
+    struct Source {
+        a: u32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Target {
+        a: u32,
+        b: u32,
+    }
+
+    let res = from_record_batch_auto::<Target>(&record_batch(&[Source { a: 1 }]));
+    expect_error(&res, "missing field `b`");
+}
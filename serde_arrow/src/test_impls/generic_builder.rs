@@ -0,0 +1,65 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn generic_builder_builds_the_arrays_of_the_field_type() {
+        use serde::{Deserialize, Serialize};
+
+        use crate::{schema::BuilderField, GenericBuilder};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: Option<u8>,
+            b: String,
+        }
+
+        fn build<F: BuilderField>(fields: &[F], items: &[Record]) -> crate::Result<Vec<F::Array>> {
+            let mut builder = GenericBuilder::new(fields)?;
+            builder.push(&items[0])?;
+            builder.extend(&items[1..])?;
+            builder.build_arrays()
+        }
+
+        let items = vec![
+            Record {
+                a: Some(1),
+                b: String::from("x"),
+            },
+            Record {
+                a: None,
+                b: String::from("y"),
+            },
+        ];
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = build(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn generic_builder_fields_and_options() {
+        use crate::{
+            utils::{Overflow, SerializationOptions},
+            GenericBuilder,
+        };
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let mut builder = GenericBuilder::with_options(
+            &fields,
+            SerializationOptions::default().overflow(Overflow::Saturate),
+        )
+        .unwrap();
+        builder.push(&Item(300_u32)).unwrap();
+
+        builder.rename_field("item", "value").unwrap();
+        let renamed = builder.fields().unwrap();
+        assert_eq!(GenericField::try_from(&renamed[0]).unwrap().name, "value");
+        builder.rename_field("value", "item").unwrap();
+
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Items<Vec<u8>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual.0, vec![255]);
+    }
+);
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::macros::test_generic;
+
+/// A flag serialized as `"on"` / `"off"` in human-readable formats and as a
+/// bool otherwise
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Switch(bool);
+
+impl Serialize for Switch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(if self.0 { "on" } else { "off" })
+        } else {
+            serializer.serialize_bool(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Switch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Ok(Switch(s == "on"))
+        } else {
+            Ok(Switch(bool::deserialize(deserializer)?))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    switch: Switch,
+}
+
+test_generic!(
+    fn human_readable_by_default() {
+        use crate::test_impls::human_readable::{Record, Switch};
+
+        let items = [
+            Record {
+                id: 0,
+                switch: Switch(true),
+            },
+            Record {
+                id: 1,
+                switch: Switch(false),
+            },
+        ];
+
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[1]).unwrap().data_type,
+            GenericDataType::LargeUtf8,
+        );
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[1]).unwrap().data_type,
+            GenericDataType::LargeUtf8,
+        );
+
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn compact_representation() {
+        use crate::test_impls::human_readable::{Record, Switch};
+        use crate::utils::{DeserializationOptions, SerializationOptions};
+
+        let items = [
+            Record {
+                id: 0,
+                switch: Switch(true),
+            },
+            Record {
+                id: 1,
+                switch: Switch(false),
+            },
+        ];
+
+        let tracing_options = TracingOptions::default().human_readable(false);
+        let fields = Vec::<Field>::from_samples(&items, tracing_options.clone()).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[1]).unwrap().data_type,
+            GenericDataType::Bool,
+        );
+
+        let fields = Vec::<Field>::from_type::<Record>(tracing_options).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[1]).unwrap().data_type,
+            GenericDataType::Bool,
+        );
+
+        // both the fast path for flat records and the interpreter are used
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().human_readable(false),
+        )
+        .unwrap();
+        builder.extend_from_slice(&items).unwrap();
+        builder.push(&items[0]).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let options = DeserializationOptions::default().human_readable(false);
+        let actual: Vec<Record> = from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(actual, [items[0], items[1], items[0]]);
+
+        // the default options expect strings
+        let res = from_arrow::<Vec<Record>, _>(&fields, &arrays);
+        assert!(res.is_err());
+    }
+);
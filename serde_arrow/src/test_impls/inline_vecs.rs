@@ -0,0 +1,108 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn small_vec() {
+        use smallvec::{smallvec, SmallVec};
+
+        let items: Vec<SmallVec<[u32; 2]>> = vec![smallvec![1, 2], smallvec![], smallvec![3, 4, 5]];
+
+        let fields =
+            Vec::<Field>::from_type::<Item<SmallVec<[u32; 2]>>>(TracingOptions::default()).unwrap();
+        let expected = Field::try_from(
+            &GenericField::new("item", GenericDataType::LargeList, false)
+                .with_child(GenericField::new("element", GenericDataType::U32, false)),
+        )
+        .unwrap();
+        assert_eq!(fields, vec![expected]);
+
+        let traced = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        assert_eq!(traced, fields);
+
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+        let Items(actual): Items<Vec<SmallVec<[u32; 2]>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn small_vec_only_empty() {
+        use smallvec::SmallVec;
+
+        let items: Vec<SmallVec<[u32; 2]>> = vec![SmallVec::new(), SmallVec::new()];
+
+        let fields =
+            Vec::<Field>::from_type::<Item<SmallVec<[u32; 2]>>>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+        assert_eq!(arrays[0].len(), 2);
+
+        let Items(actual): Items<Vec<SmallVec<[u32; 2]>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn array_vec() {
+        use arrayvec::ArrayVec;
+
+        let items: Vec<ArrayVec<i64, 3>> = vec![
+            ArrayVec::from([1, 2, 3]),
+            ArrayVec::new(),
+            [4].into_iter().collect(),
+        ];
+
+        let fields =
+            Vec::<Field>::from_type::<Item<ArrayVec<i64, 3>>>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<ArrayVec<i64, 3>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn array_vec_over_capacity() {
+        use arrayvec::ArrayVec;
+
+        let items: Vec<Vec<i64>> = vec![vec![1, 2], vec![3, 4, 5]];
+
+        let fields = Vec::<Field>::from_type::<Item<Vec<i64>>>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let res: Result<Items<Vec<ArrayVec<i64, 2>>>, _> = from_arrow(&fields, &arrays);
+        crate::test_impls::macros::expect_error(&res, "invalid length 3");
+    }
+);
+
+test_generic!(
+    fn tiny_vec() {
+        use tinyvec::{tiny_vec, TinyVec};
+
+        // the last item exceeds the inline capacity and is stored on the heap
+        let items: Vec<TinyVec<[u8; 2]>> = vec![tiny_vec!(), tiny_vec!(1, 2), tiny_vec!(3, 4, 5)];
+
+        let fields =
+            Vec::<Field>::from_type::<Item<TinyVec<[u8; 2]>>>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<TinyVec<[u8; 2]>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn small_vec_only_empty_from_samples() {
+        use smallvec::SmallVec;
+
+        // as for `Vec`, the item type of empty sequences is unknown
+        let items: Vec<SmallVec<[u32; 2]>> = vec![SmallVec::new(), SmallVec::new()];
+        let res = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default());
+        crate::test_impls::macros::expect_error(&res, "allow_null_fields");
+
+        let options = TracingOptions::default().allow_null_fields(true);
+        let fields = Vec::<Field>::from_samples(&Items(&items), options).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<SmallVec<[u32; 2]>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
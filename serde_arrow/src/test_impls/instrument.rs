@@ -0,0 +1,163 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{
+    field::{Field as TracingField, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+use crate::{
+    _impl::arrow::datatypes::Field, internal::tracing::TracingOptions, schema::SchemaLike,
+    utils::DeserializationOptions,
+};
+
+/// Record the names of the spans and the columns of the events
+#[derive(Default, Clone)]
+struct Recorder {
+    records: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Recorder {
+    fn records(&self) -> Vec<String> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+#[derive(Default)]
+struct ColumnVisitor {
+    message: String,
+    column: String,
+    has_bytes: bool,
+}
+
+impl Visit for ColumnVisitor {
+    fn record_str(&mut self, field: &TracingField, value: &str) {
+        if field.name() == "column" {
+            self.column = value.to_owned();
+        }
+    }
+
+    fn record_u64(&mut self, field: &TracingField, _value: u64) {
+        if field.name() == "bytes" {
+            self.has_bytes = true;
+        }
+    }
+
+    fn record_debug(&mut self, field: &TracingField, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        self.records
+            .lock()
+            .unwrap()
+            .push(format!("span {}", span.metadata().name()));
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = ColumnVisitor::default();
+        event.record(&mut visitor);
+        let mut record = format!("{} {}", visitor.message, visitor.column);
+        if visitor.has_bytes {
+            record.push_str(" (bytes)");
+        }
+        self.records.lock().unwrap().push(record);
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+    b: String,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: 1,
+            b: String::from("foo"),
+        },
+        Record {
+            a: 2,
+            b: String::from("bar"),
+        },
+    ]
+}
+
+#[test]
+fn serialization_is_instrumented() {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        crate::to_arrow(&fields, &items()).unwrap();
+    });
+
+    assert_eq!(
+        recorder.records(),
+        vec![
+            "span compile_serialization",
+            "span serialize",
+            "span build_arrays",
+            "built column a (bytes)",
+            "built column b (bytes)",
+        ]
+    );
+}
+
+#[test]
+fn deserialization_is_instrumented() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let arrays = crate::to_arrow(&fields, &items()).unwrap();
+
+    let recorder = Recorder::default();
+    let actual = tracing::subscriber::with_default(recorder.clone(), || {
+        crate::from_arrow::<Vec<Record>, _>(&fields, &arrays).unwrap()
+    });
+    assert_eq!(actual, items());
+    assert_eq!(
+        recorder.records(),
+        vec!["span deserialize", "span compile_deserialization"]
+    );
+}
+
+#[test]
+fn parallel_deserialization_reports_the_columns() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let arrays = crate::to_arrow(&fields, &items()).unwrap();
+    let options = DeserializationOptions::default().column_threads(2);
+
+    let recorder = Recorder::default();
+    let actual = tracing::subscriber::with_default(recorder.clone(), || {
+        crate::from_arrow_with_options::<Vec<Record>, _>(&fields, &arrays, &options).unwrap()
+    });
+    assert_eq!(actual, items());
+
+    let mut columns = recorder
+        .records()
+        .into_iter()
+        .filter(|record| record.starts_with("decoded column"))
+        .collect::<Vec<_>>();
+    columns.sort();
+    assert_eq!(columns, vec!["decoded column a", "decoded column b"]);
+}
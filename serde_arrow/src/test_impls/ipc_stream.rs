@@ -0,0 +1,348 @@
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow2::{
+        chunk::Chunk,
+        datatypes::{Field, Schema},
+        io::ipc::write::{StreamWriter, WriteOptions},
+    },
+    schema::{SchemaLike, TracingOptions},
+    Arrow2StreamDecoder, Arrow2StreamWriter,
+};
+
+use super::macros::expect_error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: Option<u32>,
+    b: String,
+    c: Vec<f64>,
+}
+
+fn batches() -> Vec<Vec<Record>> {
+    vec![
+        vec![
+            Record {
+                a: Some(1),
+                b: String::from("x"),
+                c: vec![1.0],
+            },
+            Record {
+                a: None,
+                b: String::from("y"),
+                c: vec![],
+            },
+        ],
+        vec![Record {
+            a: Some(3),
+            b: String::from("x"),
+            c: vec![2.0, 3.0],
+        }],
+    ]
+}
+
+fn write_stream(options: TracingOptions, batches: &[Vec<Record>]) -> (Vec<Field>, Vec<u8>) {
+    let fields = Vec::<Field>::from_type::<Record>(options).unwrap();
+
+    let mut bytes = Vec::new();
+    let mut writer = StreamWriter::new(&mut bytes, WriteOptions { compression: None });
+    writer.start(&Schema::from(fields.clone()), None).unwrap();
+    for batch in batches {
+        let arrays = crate::to_arrow2(&fields, batch).unwrap();
+        writer.write(&Chunk::new(arrays), None).unwrap();
+    }
+    writer.finish().unwrap();
+
+    (fields, bytes)
+}
+
+#[test]
+fn feed_complete_stream() {
+    let batches = batches();
+    let (fields, bytes) = write_stream(TracingOptions::default(), &batches);
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let rows: Vec<Record> = decoder.feed(&bytes).unwrap();
+
+    assert_eq!(rows, batches.concat());
+    assert_eq!(decoder.fields(), fields);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn feed_byte_by_byte() {
+    let batches = batches();
+    let (_, bytes) = write_stream(TracingOptions::default(), &batches);
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let mut decoded = Vec::new();
+    for byte in &bytes {
+        let rows: Vec<Record> = decoder.feed(std::slice::from_ref(byte)).unwrap();
+        if !rows.is_empty() {
+            decoded.push(rows);
+        }
+    }
+
+    assert_eq!(decoded, batches);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn feed_dictionary_batches() {
+    let batches = batches();
+    let (fields, bytes) = write_stream(
+        TracingOptions::default().string_dictionary_encoding(true),
+        &batches,
+    );
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let mut rows = Vec::<Record>::new();
+    for frame in bytes.chunks(7) {
+        rows.extend(decoder.feed::<Record>(frame).unwrap());
+    }
+
+    assert_eq!(rows, batches.concat());
+    assert_eq!(decoder.fields(), fields);
+}
+
+#[test]
+fn feed_without_end_of_stream() {
+    let batches = batches();
+    let (_, bytes) = write_stream(TracingOptions::default(), &batches);
+
+    // drop the end of stream marker (continuation marker and zero length)
+    let mut decoder = Arrow2StreamDecoder::new();
+    let rows: Vec<Record> = decoder.feed(&bytes[..bytes.len() - 8]).unwrap();
+
+    assert_eq!(rows, batches.concat());
+    assert!(!decoder.is_finished());
+
+    let rows: Vec<Record> = decoder.feed(&bytes[bytes.len() - 8..]).unwrap();
+    assert!(rows.is_empty());
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn feed_invalid_messages() {
+    let mut decoder = Arrow2StreamDecoder::new();
+    let res = decoder.feed::<Record>(&[0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff]);
+    expect_error(&res, "Invalid IPC message: negative metadata length -2");
+}
+
+#[test]
+fn feed_after_invalid_message_is_rejected() {
+    let mut decoder = Arrow2StreamDecoder::new();
+    let res = decoder.feed::<Record>(&[0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff]);
+    assert!(res.is_err());
+
+    let res = decoder.feed::<Record>(&[]);
+    expect_error(&res, "after a previous error");
+    assert!(!decoder.is_finished());
+}
+
+#[test]
+fn feed_continues_after_deserialization_errors() {
+    #[derive(Debug, Deserialize)]
+    struct Other {
+        #[allow(dead_code)]
+        a: bool,
+    }
+
+    let batches = batches();
+    let (_, bytes) = write_stream(TracingOptions::default(), &batches);
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let res = decoder.feed::<Other>(&bytes);
+    assert!(res.is_err());
+    assert!(!decoder.is_finished());
+
+    // the messages after the failing batch are decoded by the next call
+    let rows: Vec<Record> = decoder.feed(&[]).unwrap();
+    assert_eq!(rows, batches[1]);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn feed_collect_errors() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct RequiredRecord {
+        a: u32,
+    }
+
+    let batches = batches();
+    let (_, bytes) = write_stream(TracingOptions::default(), &batches);
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for frame in bytes.chunks(16) {
+        let (frame_rows, frame_errors) = decoder
+            .feed_collect_errors::<RequiredRecord>(frame)
+            .unwrap();
+        rows.extend(frame_rows);
+        errors.extend(frame_errors);
+    }
+
+    assert_eq!(rows, vec![RequiredRecord { a: 1 }, RequiredRecord { a: 3 }]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].row, 1);
+    assert_eq!(errors[0].path, "a");
+    assert!(decoder.is_finished());
+}
+
+/// A writer that allows to inspect the bytes written so far
+#[derive(Debug, Default, Clone)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writer_writes_batches_while_pushing() {
+    let items = batches().concat();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+    let buffer = SharedBuffer::default();
+    let mut writer = Arrow2StreamWriter::new(buffer.clone(), &fields)
+        .unwrap()
+        .with_batch_size(2);
+
+    // the schema is written on construction
+    let schema_len = buffer.0.borrow().len();
+    assert!(schema_len > 0);
+
+    writer.push(&items[0]).unwrap();
+    assert_eq!(buffer.0.borrow().len(), schema_len);
+
+    writer.push(&items[1]).unwrap();
+    assert!(buffer.0.borrow().len() > schema_len);
+
+    writer.push(&items[2]).unwrap();
+    writer.finish().unwrap();
+
+    let bytes = buffer.0.borrow().clone();
+    let mut decoder = Arrow2StreamDecoder::new();
+    let mut decoded = Vec::new();
+    for byte in &bytes {
+        let rows: Vec<Record> = decoder.feed(std::slice::from_ref(byte)).unwrap();
+        if !rows.is_empty() {
+            decoded.push(rows);
+        }
+    }
+
+    assert_eq!(decoded, [items[0..2].to_vec(), items[2..].to_vec()]);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn writer_extend_from_slice_and_flush() {
+    let items = batches().concat();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+    let mut writer = Arrow2StreamWriter::new(Vec::new(), &fields)
+        .unwrap()
+        .with_batch_size(2);
+    writer.push(&items[0]).unwrap();
+    writer.flush().unwrap();
+    writer.extend_from_slice(&items).unwrap();
+    let bytes = writer.finish().unwrap();
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let rows: Vec<Record> = decoder.feed(&bytes).unwrap();
+
+    let mut expected = vec![items[0].clone()];
+    expected.extend(items);
+    assert_eq!(rows, expected);
+    assert_eq!(decoder.fields(), fields);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn writer_extend_from_slice_fills_the_pushed_batch() {
+    use crate::_impl::arrow2::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
+
+    let items = batches().concat();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+    let mut writer = Arrow2StreamWriter::new(Vec::new(), &fields)
+        .unwrap()
+        .with_batch_size(2);
+    writer.push(&items[0]).unwrap();
+    writer.extend_from_slice(&items).unwrap();
+    let bytes = writer.finish().unwrap();
+
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = read_stream_metadata(&mut reader).unwrap();
+    let mut batch_lens = Vec::new();
+    for state in StreamReader::new(reader, metadata, None) {
+        match state.unwrap() {
+            StreamState::Some(chunk) => batch_lens.push(chunk.len()),
+            StreamState::Waiting => unreachable!(),
+        }
+    }
+
+    let mut expected = vec![2; (items.len() + 1) / 2];
+    if (items.len() + 1) % 2 != 0 {
+        expected.push(1);
+    }
+    assert_eq!(batch_lens, expected);
+}
+
+#[test]
+fn writer_without_records() {
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let bytes = Arrow2StreamWriter::new(Vec::new(), &fields)
+        .unwrap()
+        .finish()
+        .unwrap();
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let rows: Vec<Record> = decoder.feed(&bytes).unwrap();
+    assert!(rows.is_empty());
+    assert_eq!(decoder.fields(), fields);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn writer_with_nullable_null_policy() {
+    use crate::utils::{NullPolicy, SerializationOptions};
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    assert!(!fields[1].is_nullable);
+
+    let options = SerializationOptions::default().null_policy(NullPolicy::Nullable);
+    let mut writer = Arrow2StreamWriter::with_options(Vec::new(), &fields, options).unwrap();
+    writer
+        .push(&serde_json::json!({"a": 1, "b": null, "c": [1.0]}))
+        .unwrap();
+    let bytes = writer.finish().unwrap();
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct NullableRecord {
+        a: Option<u32>,
+        b: Option<String>,
+        c: Option<Vec<Option<f64>>>,
+    }
+
+    let mut decoder = Arrow2StreamDecoder::new();
+    let rows: Vec<NullableRecord> = decoder.feed(&bytes).unwrap();
+    assert_eq!(
+        rows,
+        vec![NullableRecord {
+            a: Some(1),
+            b: None,
+            c: Some(vec![Some(1.0)]),
+        }]
+    );
+    assert!(decoder.fields().iter().all(|field| field.is_nullable));
+}
@@ -0,0 +1,168 @@
+use serde_json::json;
+
+use crate::internal::schema::{SchemaLike, SerdeArrowSchema};
+
+fn to_json_schema(fields: serde_json::Value) -> serde_json::Value {
+    let schema = SerdeArrowSchema::from_value(&fields).unwrap();
+    serde_json::to_value(schema.to_json_schema().unwrap()).unwrap()
+}
+
+/// The schema of the single property of a schema with a single field
+fn property_schema(field: serde_json::Value) -> serde_json::Value {
+    let name = field["name"].as_str().unwrap().to_owned();
+    to_json_schema(json!([field]))["properties"][name].clone()
+}
+
+#[test]
+fn document_structure() {
+    let actual = to_json_schema(json!([
+        {"name": "a", "data_type": "Bool"},
+        {"name": "b", "data_type": "Null", "nullable": true},
+        {"name": "c", "data_type": "F32", "nullable": true},
+    ]));
+    let expected = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "a": {"type": "boolean"},
+            "b": {"type": "null"},
+            "c": {"type": ["number", "null"]},
+        },
+        "required": ["a"],
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn integers_have_bounds() {
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "U8"})),
+        json!({"type": "integer", "minimum": 0, "maximum": 255}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "I16", "nullable": true})),
+        json!({"type": ["integer", "null"], "minimum": -32768, "maximum": 32767}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "U64"})),
+        json!({"type": "integer", "minimum": 0, "maximum": u64::MAX}),
+    );
+}
+
+#[test]
+fn strings_and_temporal_formats() {
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "LargeUtf8"})),
+        json!({"type": "string"}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Utf8", "strategy": "NumberAsStr"})),
+        json!({"type": "number"}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Decimal128(5, 2)"})),
+        json!({"type": "string"}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Date64", "strategy": "UtcStrAsDate64"})),
+        json!({"type": "string", "format": "date-time"}),
+    );
+    assert_eq!(
+        property_schema(
+            json!({"name": "a", "data_type": "Date64", "strategy": "NaiveStrAsDate64"})
+        ),
+        json!({"type": "string"}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Timestamp(Second, None)"})),
+        json!({"type": "integer"}),
+    );
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]})),
+        json!({"type": "string"}),
+    );
+}
+
+#[test]
+fn lists_and_maps() {
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Bool", "nullable": true},
+        ]})),
+        json!({"type": "array", "items": {"type": ["boolean", "null"]}}),
+    );
+    assert_eq!(
+        property_schema(
+            json!({"name": "a", "data_type": "FixedSizeList(2)", "children": [
+                {"name": "element", "data_type": "F64"},
+            ]})
+        ),
+        json!({"type": "array", "items": {"type": "number"}, "minItems": 2, "maxItems": 2}),
+    );
+    assert_eq!(
+        property_schema(
+            json!({"name": "a", "data_type": "Map", "nullable": true, "children": [
+                {"name": "entries", "data_type": "Struct", "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ]},
+            ]})
+        ),
+        json!({"type": ["object", "null"], "additionalProperties": {"type": "string"}}),
+    );
+}
+
+#[test]
+fn structs_tuples_and_unions() {
+    assert_eq!(
+        property_schema(json!({"name": "a", "data_type": "Struct", "children": [
+            {"name": "b", "data_type": "Bool"},
+            {"name": "c", "data_type": "Bool", "nullable": true},
+        ]})),
+        json!({
+            "type": "object",
+            "properties": {
+                "b": {"type": "boolean"},
+                "c": {"type": ["boolean", "null"]},
+            },
+            "required": ["b"],
+        }),
+    );
+    assert_eq!(
+        property_schema(
+            json!({"name": "a", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+                {"name": "0", "data_type": "Bool"},
+                {"name": "1", "data_type": "LargeUtf8"},
+            ]})
+        ),
+        json!({
+            "type": "array",
+            "prefixItems": [{"type": "boolean"}, {"type": "string"}],
+            "minItems": 2,
+            "maxItems": 2,
+        }),
+    );
+    assert_eq!(
+        property_schema(
+            json!({"name": "a", "data_type": "Union", "nullable": true, "children": [
+                {"name": "A", "data_type": "Null", "nullable": true},
+                {"name": "B", "data_type": "Bool"},
+            ]})
+        ),
+        json!({"anyOf": [
+            {"oneOf": [
+                {"const": "A"},
+                {
+                    "type": "object",
+                    "properties": {"B": {"type": "boolean"}},
+                    "required": ["B"],
+                    "additionalProperties": false,
+                },
+            ]},
+            {"type": "null"},
+        ]}),
+    );
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    category: String,
+    value: f64,
+    label: Option<String>,
+}
+
+fn records(n: u64) -> Vec<Record> {
+    (0..n)
+        .map(|id| Record {
+            id,
+            category: String::from(if id % 2 == 0 { "even" } else { "odd" }),
+            value: ((id * 7) % 5) as f64,
+            label: None,
+        })
+        .collect()
+}
+
+test_generic!(
+    fn layout_hints_from_samples() {
+        use crate::test_impls::layout_hints::{records, Record};
+
+        let options = TracingOptions::default()
+            .allow_null_fields(true)
+            .layout_hints(true);
+        let fields = Vec::<Field>::from_samples(&records(20), options).unwrap();
+
+        // the hints are read from the metadata of the fields
+        let hints = fields
+            .iter()
+            .map(|field| GenericField::try_from(field).unwrap().layout_hints)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hints,
+            [
+                Some(String::from("distinct=20,sorted")),
+                Some(String::from("distinct=2,low_cardinality")),
+                Some(String::from("distinct=5")),
+                None,
+            ]
+        );
+        // the hints do not affect serialization
+        let arrays = to_arrow(&fields, &records(20)).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, records(20));
+    }
+);
+
+test_generic!(
+    fn layout_hints_are_opt_in() {
+        use crate::test_impls::layout_hints::{records, Record};
+
+        let options = TracingOptions::default().allow_null_fields(true);
+        let fields = Vec::<Field>::from_samples(&records(20), options).unwrap();
+        for field in &fields {
+            assert_eq!(GenericField::try_from(field).unwrap().layout_hints, None);
+        }
+
+        let options = TracingOptions::default()
+            .allow_null_fields(true)
+            .layout_hints(true);
+        let fields = Vec::<Field>::from_type::<Record>(options).unwrap();
+        for field in &fields {
+            assert_eq!(GenericField::try_from(field).unwrap().layout_hints, None);
+        }
+    }
+);
+
+test_generic!(
+    fn layout_hints_without_distinct_count() {
+        #[derive(serde::Serialize)]
+        struct Record {
+            value: i64,
+        }
+
+        let items = (0..2000_i64)
+            .rev()
+            .map(|value| Record { value })
+            .collect::<Vec<_>>();
+
+        let options = TracingOptions::default().layout_hints(true);
+        let fields = Vec::<Field>::from_samples(&items, options).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[0]).unwrap().layout_hints,
+            None
+        );
+
+        let items = (0..2000_i64)
+            .map(|value| Record { value })
+            .collect::<Vec<_>>();
+        let options = TracingOptions::default().layout_hints(true);
+        let fields = Vec::<Field>::from_samples(&items, options).unwrap();
+        assert_eq!(
+            GenericField::try_from(&fields[0]).unwrap().layout_hints,
+            Some(String::from("sorted")),
+        );
+    }
+);
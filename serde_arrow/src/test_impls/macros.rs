@@ -306,7 +306,7 @@ macro_rules! test_events {
 
                 println!("buffers: {:?}", interpreter.buffers);
 
-                interpreter.build_arrow_arrays().unwrap();
+                crate::arrow_impl::serialization::build_arrays(&mut interpreter).unwrap();
             }
         }
     };
@@ -421,8 +421,13 @@ macro_rules! test_generic {
 
             mod arrow {
                 use super::*;
-                use crate::{to_arrow, from_arrow};
-                use crate::_impl::arrow::datatypes::Field;
+                use crate::{
+                    to_arrow, from_arrow, from_arrow_collect_errors, from_arrow_into,
+                    from_arrow_iter, from_arrow_struct, from_arrow_struct_array, from_arrow_view,
+                    from_arrow_with_options, to_arrow_filtered, to_arrow_auto, to_arrow_struct_array,
+                    to_arrow_with_options, empty_arrays, ArrowBuilder, PartitionedBuilder, VariantBuilder,
+                };
+                use crate::_impl::arrow::{array::{Array, StructArray}, datatypes::Field};
 
                 $(#[ignore = $ignore])?
                 #[test]
@@ -432,8 +437,26 @@ macro_rules! test_generic {
             }
             mod arrow2 {
                 use super::*;
-                use crate::{to_arrow2 as to_arrow, from_arrow2 as from_arrow};
-                use crate::_impl::arrow2::datatypes::Field;
+                use crate::{
+                    to_arrow2 as to_arrow,
+                    to_arrow2_filtered as to_arrow_filtered,
+                    to_arrow2_auto as to_arrow_auto,
+                    to_arrow2_with_options as to_arrow_with_options,
+                    from_arrow2 as from_arrow,
+                    from_arrow2_with_options as from_arrow_with_options,
+                    from_arrow2_collect_errors as from_arrow_collect_errors,
+                    from_arrow2_into as from_arrow_into,
+                    from_arrow2_iter as from_arrow_iter,
+                    from_arrow2_view as from_arrow_view,
+                    from_arrow2_struct as from_arrow_struct,
+                    from_arrow2_struct_array as from_arrow_struct_array,
+                    to_arrow2_struct_array as to_arrow_struct_array,
+                    empty_arrow2_arrays as empty_arrays,
+                    Arrow2Builder as ArrowBuilder,
+                    Arrow2PartitionedBuilder as PartitionedBuilder,
+                    Arrow2VariantBuilder as VariantBuilder,
+                };
+                use crate::_impl::arrow2::{array::{Array, StructArray}, datatypes::Field};
 
                 $(#[ignore = $ignore])?
                 #[test]
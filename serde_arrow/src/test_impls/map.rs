@@ -1,4 +1,4 @@
-use super::macros::{test_events, test_example};
+use super::macros::{test_events, test_example, test_generic};
 
 // NOTE: Use BTreeMap to guarantee the order of fields
 
@@ -271,3 +271,227 @@ test_events!(
         Event::EndSequence,
     ],
 );
+
+test_generic!(
+    fn map_as_pairs() {
+        let field = GenericField::new("item", GenericDataType::Map, false).with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(GenericField::new("value", GenericDataType::U32, false)),
+        );
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        // the order and duplicate keys are preserved
+        let items: Vec<Vec<(String, u32)>> = vec![
+            vec![(String::from("b"), 1), (String::from("a"), 2)],
+            vec![],
+            vec![(String::from("a"), 3), (String::from("a"), 4)],
+        ];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Vec<(String, u32)>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+
+        let Items(actual): Items<Vec<std::collections::BTreeMap<String, u32>>> =
+            from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual[0].get("a"), Some(&2));
+        assert_eq!(actual[2].get("a"), Some(&4));
+    }
+);
+
+test_generic!(
+    fn map_as_nullable_pairs() {
+        let field = GenericField::new("item", GenericDataType::Map, true).with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::I64, false))
+                .with_child(GenericField::new("value", GenericDataType::Bool, false)),
+        );
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        type Pairs = Vec<(i64, bool)>;
+
+        let items: Vec<Option<Pairs>> = vec![Some(vec![(1, true), (0, false)]), None, Some(vec![])];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Option<Pairs>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn map_from_maps_into_pairs() {
+        let field = GenericField::new("item", GenericDataType::Map, false).with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::I64, false))
+                .with_child(GenericField::new("value", GenericDataType::Bool, false)),
+        );
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items: Vec<std::collections::BTreeMap<i64, bool>> =
+            vec![crate::test_impls::macros::btree_map! { 0_i64 => true, 1_i64 => false }];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Vec<(i64, bool)>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![vec![(0, true), (1, false)]]);
+    }
+);
+
+test_generic!(
+    fn map_entries_must_be_pairs() {
+        use crate::test_impls::macros::expect_error;
+
+        let field = GenericField::new("item", GenericDataType::Map, false).with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::I64, false))
+                .with_child(GenericField::new("value", GenericDataType::I64, false)),
+        );
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[vec![(0_i64, 1_i64, 2_i64)]]));
+        expect_error(&res, "Map entries must be serialized as pairs");
+
+        let res = to_arrow(&fields, &Items(&[vec![0_i64, 1_i64]]));
+        expect_error(&res, "Map entries must be serialized as pairs");
+    }
+);
+
+test_example!(
+    test_name = map_of_maps,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().map_as_struct(false),
+    field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(
+                    GenericField::new("value", GenericDataType::Map, false)
+                        .with_child(
+                            GenericField::new("entries", GenericDataType::Struct, false)
+                                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                                .with_child(GenericField::new("value", GenericDataType::U32, false))
+                        )
+                )
+        ),
+    ty = BTreeMap<String, BTreeMap<String, u32>>,
+    values = [
+        btree_map!{ "a" => btree_map!{ "x" => 1_u32, "y" => 2_u32 }, "b" => btree_map!{} },
+        btree_map!{},
+        btree_map!{ "c" => btree_map!{ "z" => 3_u32 } },
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = map_of_lists_of_maps,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().map_as_struct(false),
+    field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(
+                    GenericField::new("value", GenericDataType::LargeList, false)
+                        .with_child(
+                            GenericField::new("element", GenericDataType::Map, false)
+                                .with_child(
+                                    GenericField::new("entries", GenericDataType::Struct, false)
+                                        .with_child(GenericField::new("key", GenericDataType::I64, false))
+                                        .with_child(GenericField::new("value", GenericDataType::Bool, false))
+                                )
+                        )
+                )
+        ),
+    ty = BTreeMap<String, Vec<BTreeMap<i64, bool>>>,
+    values = [
+        btree_map!{
+            "a" => vec![btree_map!{ 1_i64 => true, 2_i64 => false }, btree_map!{}],
+            "b" => vec![]
+        },
+        btree_map!{},
+        btree_map!{ "c" => vec![btree_map!{ 3_i64 => true }] },
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = nullable_map_of_nullable_maps,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().map_as_struct(false),
+    field = GenericField::new("item", GenericDataType::Map, true)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(
+                    GenericField::new("value", GenericDataType::Map, true)
+                        .with_child(
+                            GenericField::new("entries", GenericDataType::Struct, false)
+                                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                                .with_child(GenericField::new("value", GenericDataType::U32, false))
+                        )
+                )
+        ),
+    ty = Option<BTreeMap<String, Option<BTreeMap<String, u32>>>>,
+    values = [
+        Some(btree_map!{ "a" => Some(btree_map!{ "x" => 1_u32 }), "b" => None }),
+        None,
+        Some(btree_map!{ "c" => None, "d" => Some(btree_map!{ "y" => 2_u32, "z" => 3_u32 }) }),
+    ],
+    nulls = [false, true, false],
+);
+
+test_example!(
+    test_name = map_with_dictionary_keys,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().map_as_struct(false).string_dictionary_encoding(true),
+    field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(
+                    GenericField::new("key", GenericDataType::Dictionary, false)
+                        .with_child(GenericField::new("", GenericDataType::U32, false))
+                        .with_child(GenericField::new("", GenericDataType::LargeUtf8, false))
+                )
+                .with_child(GenericField::new("value", GenericDataType::F64, false))
+        ),
+    overwrite_field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(
+                    GenericField::new("key", GenericDataType::Dictionary, false)
+                        .with_child(GenericField::new("", GenericDataType::U8, false))
+                        .with_child(GenericField::new("", GenericDataType::Utf8, false))
+                )
+                .with_child(GenericField::new("value", GenericDataType::F64, false))
+        ),
+    ty = BTreeMap<String, f64>,
+    values = [
+        btree_map!{ "cpu" => 0.5, "mem" => 0.25 },
+        btree_map!{},
+        btree_map!{ "cpu" => 0.75 },
+    ],
+    nulls = [false, false, false],
+);
+
+test_example!(
+    test_name = nullable_map_with_dictionary_keys,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().map_as_struct(false).string_dictionary_encoding(true),
+    field = GenericField::new("item", GenericDataType::Map, true)
+        .with_child(
+            GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(
+                    GenericField::new("key", GenericDataType::Dictionary, false)
+                        .with_child(GenericField::new("", GenericDataType::U32, false))
+                        .with_child(GenericField::new("", GenericDataType::LargeUtf8, false))
+                )
+                .with_child(GenericField::new("value", GenericDataType::U64, true))
+        ),
+    ty = Option<HashMap<String, Option<u64>>>,
+    values = [
+        Some(hash_map!{ "requests" => Some(10_u64) }),
+        None,
+        Some(hash_map!{ "errors" => None }),
+        Some(hash_map!{}),
+    ],
+    nulls = [false, true, false, false],
+);
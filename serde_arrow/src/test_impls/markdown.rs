@@ -0,0 +1,72 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::internal::{
+    schema::{GenericDataType, GenericField, SchemaLike, SerdeArrowSchema},
+    tracing::TracingOptions,
+};
+
+const HEADER: &str = concat!(
+    "| Field | Type | Nullable | Strategy | Metadata |\n",
+    "|---|---|---|---|---|\n",
+);
+
+#[test]
+fn empty_schema() {
+    assert_eq!(SerdeArrowSchema::new().to_markdown_table(), HEADER);
+}
+
+#[test]
+fn nested_fields_are_listed_with_their_path() {
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Point {
+        x: f64,
+        y: Option<f64>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Record {
+        point: Point,
+        tags: Vec<String>,
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let expected = String::from(HEADER)
+        + "| `point` | `Struct` | no |  |  |\n"
+        + "| `point.x` | `F64` | no |  |  |\n"
+        + "| `point.y` | `F64` | yes |  |  |\n"
+        + "| `tags` | `LargeList` | no |  |  |\n"
+        + "| `tags.element` | `LargeUtf8` | no |  |  |\n";
+    assert_eq!(schema.to_markdown_table(), expected);
+}
+
+#[test]
+fn extension_metadata() {
+    let mut field = GenericField::new("t", GenericDataType::FixedSizeList(2), false)
+        .with_child(GenericField::new("element", GenericDataType::F32, false));
+    field.extension_name = Some(String::from("arrow.fixed_shape_tensor"));
+    field.extension_metadata = Some(String::from(r#"{"shape":[2]}"#));
+
+    let schema = SerdeArrowSchema {
+        fields: vec![field],
+    };
+    let expected = String::from(HEADER)
+        + "| `t` | `FixedSizeList(2)` | no |  | `ARROW:extension:name`: `arrow.fixed_shape_tensor`, `ARROW:extension:metadata`: `{\"shape\":[2]}` |\n"
+        + "| `t.element` | `F32` | no |  |  |\n";
+    assert_eq!(schema.to_markdown_table(), expected);
+}
+
+#[test]
+fn pipes_and_backticks_are_escaped() {
+    let schema = SerdeArrowSchema::from_value(&json!([
+        {"name": "a|b", "data_type": "Bool"},
+        {"name": "`c`", "data_type": "LargeUtf8", "null_default": "x|y"},
+    ]))
+    .unwrap();
+    let expected = String::from(HEADER)
+        + "| `a\\|b` | `Bool` | no |  |  |\n"
+        + "| `` `c` `` | `LargeUtf8` | no |  | `SERDE_ARROW:null_default`: `x\\|y` |\n";
+    assert_eq!(schema.to_markdown_table(), expected);
+}
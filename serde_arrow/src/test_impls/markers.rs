@@ -0,0 +1,213 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    internal::{
+        schema::{
+            GenericDataType, GenericField, GenericTimeUnit, SchemaLike, SerdeArrowSchema, Strategy,
+        },
+        tracing::TracingOptions,
+    },
+    utils::{AsDictionary, TimestampMillis},
+};
+
+use super::macros::{expect_error, test_generic};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    utc: TimestampMillis<DateTime<Utc>>,
+    naive: Option<TimestampMillis<NaiveDateTime>>,
+    label: AsDictionary<String>,
+}
+
+fn expected_schema() -> SerdeArrowSchema {
+    SerdeArrowSchema {
+        fields: vec![
+            GenericField::new(
+                "utc",
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(String::from("UTC"))),
+                false,
+            )
+            .with_strategy(Strategy::UtcStrAsDate64),
+            GenericField::new(
+                "naive",
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None),
+                true,
+            )
+            .with_strategy(Strategy::NaiveStrAsDate64),
+            GenericField::new("label", GenericDataType::Dictionary, false)
+                .with_child(GenericField::new("key", GenericDataType::U32, false))
+                .with_child(GenericField::new(
+                    "value",
+                    GenericDataType::LargeUtf8,
+                    false,
+                )),
+        ],
+    }
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            utc: TimestampMillis("2020-12-24T08:30:00Z".parse().unwrap()),
+            naive: None,
+            label: AsDictionary(String::from("2020-12-24T08:30:00")),
+        },
+        Record {
+            utc: TimestampMillis("2023-05-05T16:06:00Z".parse().unwrap()),
+            naive: Some(TimestampMillis("2023-05-05T16:06:00".parse().unwrap())),
+            label: AsDictionary(String::from("foo")),
+        },
+    ]
+}
+
+#[test]
+fn from_type_uses_the_markers() {
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    assert_eq!(schema, expected_schema());
+}
+
+#[test]
+fn from_samples_uses_the_markers() {
+    let schema = SerdeArrowSchema::from_samples(&items(), TracingOptions::default()).unwrap();
+    assert_eq!(schema, expected_schema());
+}
+
+#[test]
+fn from_samples_ignores_guessed_dates_of_marked_fields() {
+    let options = TracingOptions::default().guess_dates(true);
+    let schema = SerdeArrowSchema::from_samples(&items(), options).unwrap();
+    assert_eq!(schema, expected_schema());
+}
+
+#[test]
+fn from_samples_rejects_marked_non_strings() {
+    #[derive(Serialize)]
+    struct Record {
+        label: AsDictionary<u32>,
+    }
+
+    let res = SerdeArrowSchema::from_samples(
+        &[Record {
+            label: AsDictionary(13),
+        }],
+        TracingOptions::default(),
+    );
+    expect_error(
+        &res,
+        "Cannot accept event U32(13) for field marked as AsDictionary",
+    );
+}
+
+#[test]
+fn json_ignores_the_markers() {
+    let item = Record {
+        utc: TimestampMillis("2020-12-24T08:30:00Z".parse().unwrap()),
+        naive: None,
+        label: AsDictionary(String::from("foo")),
+    };
+    let json = serde_json::to_string(&item).unwrap();
+    assert_eq!(
+        json,
+        r#"{"utc":"2020-12-24T08:30:00Z","naive":null,"label":"foo"}"#
+    );
+
+    let roundtripped: Record = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtripped, item);
+}
+
+test_generic!(
+    fn roundtrip() {
+        use crate::test_impls::markers::{items, Record};
+
+        let items = items();
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+mod bfloat16 {
+    use half::bf16;
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        internal::{
+            schema::{GenericDataType, GenericField, SchemaLike, SerdeArrowSchema},
+            tracing::TracingOptions,
+        },
+        test_impls::macros::{expect_error, test_generic},
+        utils::BFloat16,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Record {
+        pub weight: BFloat16,
+        pub bias: Option<BFloat16>,
+    }
+
+    pub fn items() -> Vec<Record> {
+        vec![
+            Record {
+                weight: BFloat16(bf16::from_f32(0.15625)),
+                bias: None,
+            },
+            Record {
+                weight: BFloat16(bf16::NEG_INFINITY),
+                bias: Some(BFloat16(bf16::from_bits(0x7fc1))),
+            },
+        ]
+    }
+
+    fn expected_schema() -> SerdeArrowSchema {
+        let mut weight = GenericField::new("weight", GenericDataType::U16, false);
+        weight.extension_name = Some(String::from("serde_arrow.bfloat16"));
+        let mut bias = GenericField::new("bias", GenericDataType::U16, true);
+        bias.extension_name = Some(String::from("serde_arrow.bfloat16"));
+        SerdeArrowSchema {
+            fields: vec![weight, bias],
+        }
+    }
+
+    #[test]
+    fn from_type_attaches_the_extension() {
+        let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+        assert_eq!(schema, expected_schema());
+    }
+
+    #[test]
+    fn from_samples_attaches_the_extension() {
+        let schema = SerdeArrowSchema::from_samples(&items(), TracingOptions::default()).unwrap();
+        assert_eq!(schema, expected_schema());
+    }
+
+    #[test]
+    fn extension_requires_u16_fields() {
+        let mut field = GenericField::new("weight", GenericDataType::F32, false);
+        field.extension_name = Some(String::from("serde_arrow.bfloat16"));
+        expect_error(
+            &field.validate(),
+            "The extension serde_arrow.bfloat16 of field \"weight\" requires a UInt16 field",
+        );
+    }
+
+    test_generic!(
+        fn roundtrip_keeps_the_bits() {
+            use crate::test_impls::markers::bfloat16::{items, Record};
+
+            let items = items();
+            let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+            let arrays = to_arrow(&fields, &items).unwrap();
+            let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+            // compare the bits, as the NaN payload must be kept
+            let bits = |items: &[Record]| {
+                items
+                    .iter()
+                    .map(|item| (item.weight.0.to_bits(), item.bias.map(|b| b.0.to_bits())))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(bits(&actual), bits(&items));
+        }
+    );
+}
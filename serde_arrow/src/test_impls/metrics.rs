@@ -0,0 +1,156 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn serialization_metrics_are_reported_per_batch() {
+        use std::sync::{Arc, Mutex};
+
+        use serde::Serialize;
+
+        use crate::utils::{ConversionMetrics, SerializationOptions};
+
+        #[derive(Serialize)]
+        struct Record {
+            a: u32,
+            b: Option<i64>,
+        }
+
+        let reported = Arc::new(Mutex::new(Vec::<ConversionMetrics>::new()));
+        let options = SerializationOptions::default().on_metrics({
+            let reported = Arc::clone(&reported);
+            move |metrics| reported.lock().unwrap().push(metrics.clone())
+        });
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::I64, true)).unwrap(),
+        ];
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+
+        builder
+            .extend(&[Record { a: 1, b: None }, Record { a: 2, b: Some(3) }])
+            .unwrap();
+        let _ = builder.build_arrays().unwrap();
+
+        builder.push(&Record { a: 4, b: Some(5) }).unwrap();
+        let _ = builder.build_arrays().unwrap();
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 2);
+
+        assert_eq!(reported[0].num_rows, 2);
+        let columns = reported[0]
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["a", "b"]);
+        assert_eq!(reported[0].columns[0].bytes, 8);
+        assert!(reported[0].columns[1].bytes >= 16);
+        assert_eq!(reported[0].total_bytes(), 8 + reported[0].columns[1].bytes);
+
+        assert_eq!(reported[1].num_rows, 1);
+        assert_eq!(reported[1].columns[0].bytes, 4);
+        assert_eq!(reported[1].compile_time, reported[0].compile_time);
+    }
+);
+
+test_generic!(
+    fn serialization_metrics_are_only_reported_when_building() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use crate::utils::SerializationOptions;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let options = SerializationOptions::default().on_metrics({
+            let calls = Arc::clone(&calls);
+            move |_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder.push(&Item(3_u8)).unwrap();
+        builder.extend(&Items(&[4_u8, 5])).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let _ = builder.build_arrays().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+);
+
+test_generic!(
+    fn deserialization_metrics_are_reported() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::utils::{ConversionMetrics, DeserializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I32, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_i32, 2, 3])).unwrap();
+
+        let reported = Arc::new(Mutex::new(Vec::<ConversionMetrics>::new()));
+        let options = DeserializationOptions::default().on_metrics({
+            let reported = Arc::clone(&reported);
+            move |metrics| reported.lock().unwrap().push(metrics.clone())
+        });
+
+        let Items(items): Items<Vec<i32>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, [1, 2, 3]);
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].num_rows, 3);
+        assert!(reported[0].columns.is_empty());
+    }
+);
+
+test_generic!(
+    fn options_with_the_same_metrics_callback_compare_equal() {
+        use crate::utils::SerializationOptions;
+
+        let options = SerializationOptions::default().on_metrics(|_| {});
+        assert_eq!(options.clone(), options);
+        assert_ne!(options, SerializationOptions::default().on_metrics(|_| {}));
+    }
+);
+
+#[test]
+fn deserialization_metrics_count_the_consumed_rows() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::_impl::arrow::datatypes::Field;
+    use crate::arrow_impl::api::deserialize_arrays;
+    use crate::internal::{
+        schema::{GenericDataType, GenericField},
+        source::RowIter,
+    };
+    use crate::utils::{ConversionMetrics, DeserializationOptions, Item, Items};
+
+    let fields =
+        vec![Field::try_from(&GenericField::new("item", GenericDataType::I32, false)).unwrap()];
+    let values = (0..1000).collect::<Vec<i32>>();
+    let arrays = crate::to_arrow(&fields, &Items(&values)).unwrap();
+
+    let reported = Arc::new(Mutex::new(Vec::<ConversionMetrics>::new()));
+    let options = DeserializationOptions::default().on_metrics({
+        let reported = Arc::clone(&reported);
+        move |metrics| reported.lock().unwrap().push(metrics.clone())
+    });
+
+    let items = deserialize_arrays(&fields, &arrays, &options, |source| {
+        let rows = RowIter::<Item<i32>>::new(source, &options)?;
+        Ok(rows.take(2).map(|row| row.unwrap().0).collect::<Vec<_>>())
+    })
+    .unwrap();
+    assert_eq!(items, [0, 1]);
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 1);
+    assert_eq!(reported[0].num_rows, 2);
+}
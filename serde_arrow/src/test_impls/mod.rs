@@ -1,14 +1,76 @@
+mod array_data;
+#[cfg(feature = "derive")]
+mod arrow_columns;
+mod arrow_repr;
+mod avro;
+mod builder_debug;
+mod builder_fields;
+mod cancellation;
 mod chrono;
+mod collect_errors;
+mod column_order;
+mod column_statistics;
+mod column_threads;
+mod converters;
+mod decimal;
+mod deserialization_options;
+mod deserialize_into;
 mod dictionary;
+mod display;
+mod empty_arrays;
+mod empty_lists;
+mod error_kinds;
+mod estimated_size;
 mod examples;
+mod extend_from_slice;
+mod failed_items;
+mod fixed_size_list;
+mod from_record_batch_auto;
+mod generic_builder;
+mod human_readable;
+mod inline_vecs;
+#[cfg(feature = "tracing")]
+mod instrument;
+#[cfg(feature = "arrow2-ipc")]
+mod ipc_stream;
+mod json_schema;
 mod json_values;
+mod layout_hints;
 mod list;
 mod macros;
 mod map;
+mod markdown;
+mod markers;
+mod metrics;
+mod mutable_arrays;
+mod null_default;
+mod number_as_str;
+mod numeric_coercion;
+#[cfg(feature = "object-store")]
+mod object_store_sink;
+mod optional_records;
+mod partitioned_builder;
 mod primitives;
+mod progress;
+#[cfg(feature = "prost")]
+mod protobuf;
+#[cfg(all(has_arrow, feature = "pyo3"))]
+mod pyarrow;
+mod row_iter;
+mod rows_view;
+mod rust_types;
+mod schema_macro;
+mod serialization_options;
+mod shapes;
+mod smart_pointers;
+mod sort_by;
 mod r#struct;
+mod struct_array;
+mod to_arrow_auto;
 mod tuple;
 mod r#union;
+mod value;
+mod variant_builder;
 mod wrappers;
 
 mod issue_74_unknown_fields;
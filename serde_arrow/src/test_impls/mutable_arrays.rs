@@ -0,0 +1,147 @@
+use serde::Serialize;
+
+use crate::{
+    _impl::arrow2::{
+        array::{MutableArray, MutableBooleanArray, MutablePrimitiveArray, MutableUtf8Array},
+        datatypes::{DataType, Field},
+    },
+    Arrow2Builder,
+};
+
+use super::macros::expect_error;
+
+#[derive(Serialize)]
+struct Record {
+    a: Option<i32>,
+    b: bool,
+    c: String,
+}
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Utf8, false),
+    ]
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: Some(1),
+            b: true,
+            c: String::from("x"),
+        },
+        Record {
+            a: None,
+            b: false,
+            c: String::from("y"),
+        },
+    ]
+}
+
+#[test]
+fn build_mutable_arrays() {
+    let fields = fields();
+    let mut builder = Arrow2Builder::new(&fields).unwrap();
+    builder.extend(&items()).unwrap();
+
+    let mut arrays = builder.build_mutable_arrays().unwrap();
+    assert_eq!(arrays.len(), 3);
+    for (array, field) in arrays.iter().zip(&fields) {
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.data_type(), field.data_type());
+    }
+
+    let a = arrays[0]
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<i32>>()
+        .unwrap();
+    a.push(Some(3));
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.values().as_slice(), &[1, 0, 3]);
+
+    let c = arrays[2]
+        .as_mut_any()
+        .downcast_mut::<MutableUtf8Array<i32>>()
+        .unwrap();
+    assert_eq!(c.value(1), "y");
+
+    // the builder starts a new batch
+    assert_eq!(builder.build_arrays().unwrap()[0].len(), 0);
+}
+
+#[test]
+fn append_to_mutable_arrays() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    let mut b = MutableBooleanArray::new();
+    let mut c = MutableUtf8Array::<i32>::new();
+    a.push(Some(0));
+    b.push(Some(false));
+    c.push(Some("w"));
+
+    let mut builder = Arrow2Builder::new(&fields()).unwrap();
+    builder.extend(&items()).unwrap();
+    builder
+        .append_to_mutable_arrays(&mut [&mut a, &mut b, &mut c])
+        .unwrap();
+
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.validity().unwrap().unset_bits(), 1);
+    assert_eq!(b.len(), 3);
+    assert_eq!(c.len(), 3);
+    assert_eq!(c.value(2), "y");
+}
+
+#[test]
+fn append_to_mutable_arrays_errors() {
+    let mut builder = Arrow2Builder::new(&fields()).unwrap();
+    builder.extend(&items()).unwrap();
+
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    let mut b = MutableBooleanArray::new();
+    let res = builder.append_to_mutable_arrays(&mut [&mut a, &mut b]);
+    expect_error(&res, "Cannot append 3 arrays to 2 mutable arrays");
+
+    let mut a = MutablePrimitiveArray::<i64>::new();
+    let mut c = MutableUtf8Array::<i32>::new();
+    builder.extend(&items()).unwrap();
+    let res = builder.append_to_mutable_arrays(&mut [&mut a, &mut b, &mut c]);
+    expect_error(&res, "expected a mutable array with data type Int32");
+}
+
+#[test]
+fn type_mismatch_keeps_targets_and_rows() {
+    let mut builder = Arrow2Builder::new(&fields()).unwrap();
+    builder.extend(&items()).unwrap();
+
+    // the mismatch of the last target is detected before appending to the others
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    let mut b = MutableBooleanArray::new();
+    let mut c = MutableUtf8Array::<i64>::new();
+    let res = builder.append_to_mutable_arrays(&mut [&mut a, &mut b, &mut c]);
+    expect_error(&res, "expected a mutable array with data type Utf8");
+    assert_eq!(a.len(), 0);
+    assert_eq!(b.len(), 0);
+
+    // the rows are kept in the builder
+    let mut c = MutableUtf8Array::<i32>::new();
+    builder
+        .append_to_mutable_arrays(&mut [&mut a, &mut b, &mut c])
+        .unwrap();
+    assert_eq!(a.len(), 2);
+    assert_eq!(c.value(1), "y");
+}
+
+#[test]
+fn nested_fields_are_not_supported() {
+    let fields = vec![Field::new(
+        "a",
+        DataType::Struct(vec![Field::new("b", DataType::UInt8, false)]),
+        false,
+    )];
+
+    let mut builder = Arrow2Builder::new(&fields).unwrap();
+    let res = builder.build_mutable_arrays();
+    expect_error(&res, "Cannot convert the array of field a");
+}
@@ -0,0 +1,114 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn null_default_replaces_nulls() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            country: String,
+            count: u32,
+        }
+
+        let fields = vec![
+            Field::try_from(
+                &GenericField::new("country", GenericDataType::LargeUtf8, true)
+                    .with_null_default("unknown"),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("count", GenericDataType::U32, true).with_null_default("0"),
+            )
+            .unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[
+                serde_json::json!({"country": "de", "count": 2}),
+                serde_json::json!({"country": null, "count": null}),
+            ],
+        )
+        .unwrap();
+
+        let items: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            items,
+            vec![
+                Record {
+                    country: String::from("de"),
+                    count: 2
+                },
+                Record {
+                    country: String::from("unknown"),
+                    count: 0
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn null_default_keeps_nulls_for_options() {
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::I64, true).with_null_default("-1"),
+        )
+        .unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[Some(1_i64), None])).unwrap();
+
+        let Items(items): Items<Vec<Option<i64>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![Some(1), None]);
+
+        let Items(items): Items<Vec<i64>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![1, -1]);
+    }
+);
+
+test_generic!(
+    fn null_default_roundtrips_through_metadata() {
+        let field =
+            GenericField::new("item", GenericDataType::Bool, true).with_null_default("true");
+        let arrow_field = Field::try_from(&field).unwrap();
+        assert_eq!(GenericField::try_from(&arrow_field).unwrap(), field);
+    }
+);
+
+test_generic!(
+    fn null_default_invalid() {
+        fn check(field: GenericField, expected: &str) {
+            let field = Field::try_from(&field).unwrap();
+            let res = GenericField::try_from(&field);
+            crate::test_impls::macros::expect_error(&res, expected);
+        }
+
+        check(
+            GenericField::new("item", GenericDataType::U8, true).with_null_default("foo"),
+            "Cannot parse null default \"foo\" as U8",
+        );
+        check(
+            GenericField::new("item", GenericDataType::U8, false).with_null_default("0"),
+            "requires the field to be nullable",
+        );
+        check(
+            GenericField::new("item", GenericDataType::LargeList, true)
+                .with_child(GenericField::new("element", GenericDataType::U8, false))
+                .with_null_default("[]"),
+            "not supported",
+        );
+    }
+);
+
+test_generic!(
+    fn null_default_keeps_nulls_for_self_describing_types() {
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::I64, true).with_null_default("-1"),
+        )
+        .unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[Some(1_i64), None])).unwrap();
+
+        let Items(items): Items<Vec<serde_json::Value>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![serde_json::json!(1), serde_json::Value::Null]);
+
+        let Items(items): Items<Vec<Option<Option<i64>>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(items, vec![Some(Some(1)), None]);
+    }
+);
@@ -0,0 +1,107 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn large_integers_roundtrip() {
+        use crate::internal::schema::Strategy;
+
+        let items = [i64::MAX, -1, i64::MIN];
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Utf8, false)
+                .with_strategy(Strategy::NumberAsStr),
+        )
+        .unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<i64>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+
+        let plain_fields =
+            vec![
+                Field::try_from(&GenericField::new("item", GenericDataType::Utf8, false)).unwrap(),
+            ];
+        let Items(actual): Items<Vec<String>> = from_arrow(&plain_fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                String::from("9223372036854775807"),
+                String::from("-1"),
+                String::from("-9223372036854775808"),
+            ],
+        );
+    }
+);
+
+test_generic!(
+    fn unsigned_integers_roundtrip() {
+        use crate::internal::schema::Strategy;
+
+        let items = [u64::MAX, 0, 42];
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::LargeUtf8, false)
+                .with_strategy(Strategy::NumberAsStr),
+        )
+        .unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<u64>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn nullable_floats_roundtrip() {
+        use crate::internal::schema::Strategy;
+
+        let items = [Some(1.5_f64), None, Some(-3.0)];
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Utf8, true)
+                .with_strategy(Strategy::NumberAsStr),
+        )
+        .unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Option<f64>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn invalid_numbers_are_rejected() {
+        use crate::internal::schema::Strategy;
+
+        let plain_fields =
+            vec![
+                Field::try_from(&GenericField::new("item", GenericDataType::Utf8, false)).unwrap(),
+            ];
+        let arrays = to_arrow(&plain_fields, &Items(&["1", "foo"])).unwrap();
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Utf8, false)
+                .with_strategy(Strategy::NumberAsStr),
+        )
+        .unwrap()];
+        let res: Result<Items<Vec<i64>>, _> = from_arrow(&fields, &arrays);
+        let Err(err) = res else {
+            panic!("expected an error");
+        };
+        assert!(err.to_string().contains("Cannot parse \"foo\" as a number"));
+    }
+);
+
+test_generic!(
+    fn strategy_is_rejected_for_non_string_fields() {
+        use crate::internal::schema::Strategy;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::I64, false)
+                .with_strategy(Strategy::NumberAsStr),
+        )
+        .unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[1_i64]));
+        crate::test_impls::macros::expect_error(&res, "invalid strategy for I64: NumberAsStr");
+    }
+);
@@ -0,0 +1,91 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn floats_to_integers() {
+        use crate::internal::schema::Strategy;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::I64, true)
+                .with_strategy(Strategy::NumericCoercion),
+        )
+        .unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&[Some(3.0_f64), None, Some(-2.0)])).unwrap();
+
+        let Items(actual): Items<Vec<Option<i64>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Some(3), None, Some(-2)]);
+    }
+);
+
+test_generic!(
+    fn fractional_floats_to_integers() {
+        use crate::internal::schema::Strategy;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::U32, false)
+                .with_strategy(Strategy::NumericCoercion),
+        )
+        .unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[1.0_f64, 2.5]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot convert 2.5 in row 1 to an integer without loss",
+        );
+    }
+);
+
+test_generic!(
+    fn integers_to_floats() {
+        use crate::internal::schema::Strategy;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::F32, false)
+                .with_strategy(Strategy::NumericCoercion),
+        )
+        .unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&[1_i64, -2, 3])).unwrap();
+
+        let Items(actual): Items<Vec<f32>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![1.0, -2.0, 3.0]);
+    }
+);
+
+test_generic!(
+    fn non_finite_null_with_coercion() {
+        use crate::internal::schema::Strategy;
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::F64, true)
+                .with_strategy(Strategy::NumericCoercion),
+        )
+        .unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().non_finite(NonFinite::Null),
+        )
+        .unwrap();
+        builder.push(&Item(Some(1_i64))).unwrap();
+        builder.push(&Item(Some(f64::NAN))).unwrap();
+        builder.push(&Item(None::<f64>)).unwrap();
+        builder.push(&Item(Some(2.5_f32))).unwrap();
+        builder.push(&Item(Some(f64::NEG_INFINITY))).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<Option<f64>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Some(1.0), None, None, Some(2.5), None]);
+    }
+);
+
+test_generic!(
+    fn without_strategy_floats_are_rejected() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[3.0_f64]));
+        assert!(res.is_err());
+    }
+);
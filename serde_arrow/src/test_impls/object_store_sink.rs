@@ -0,0 +1,156 @@
+use std::{io::Cursor, sync::Arc};
+
+use futures::executor::block_on;
+use object_store::{memory::InMemory, path::Path, ObjectStore};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow2::{
+        datatypes::Field,
+        io::ipc::read::{read_file_metadata, FileReader},
+    },
+    schema::{SchemaLike, TracingOptions},
+    Arrow2ObjectStoreSink,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+    b: Option<String>,
+}
+
+fn records(n: u32) -> Vec<Record> {
+    (0..n)
+        .map(|a| Record {
+            a,
+            b: (a % 2 == 0).then(|| a.to_string()),
+        })
+        .collect()
+}
+
+fn fields() -> Vec<Field> {
+    Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap()
+}
+
+/// Read the records of all parts, checking the number of batches per part
+fn read_parts(store: &InMemory, parts: &[Path], batches_per_part: usize) -> Vec<Record> {
+    let fields = fields();
+    let mut res = Vec::new();
+    for path in parts {
+        let bytes = block_on(async { store.get(path).await?.bytes().await }).unwrap();
+        let mut reader = Cursor::new(bytes);
+        let metadata = read_file_metadata(&mut reader).unwrap();
+        assert_eq!(metadata.blocks.len(), batches_per_part);
+
+        for chunk in FileReader::new(reader, metadata, None, None) {
+            let chunk = chunk.unwrap();
+            let items: Vec<Record> = crate::from_arrow2(&fields, chunk.arrays()).unwrap();
+            res.extend(items);
+        }
+    }
+    res
+}
+
+#[test]
+fn single_part() {
+    let store = Arc::new(InMemory::new());
+    let items = records(25);
+
+    let parts = block_on(async {
+        let mut sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("data"), &fields())?
+            .with_batch_size(10);
+        for item in &items {
+            sink.push(item).await?;
+        }
+        sink.finish().await
+    })
+    .unwrap();
+
+    assert_eq!(parts, vec![Path::from("data/part-00000.arrow")]);
+    assert_eq!(read_parts(&store, &parts, 3), items);
+}
+
+#[test]
+fn parts_are_rotated_by_size() {
+    let store = Arc::new(InMemory::new());
+    let items = records(40);
+
+    // every batch exceeds the part size, i.e., each part contains one batch
+    let parts = block_on(async {
+        let mut sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("data"), &fields())?
+            .with_batch_size(10)
+            .with_part_size(1);
+        sink.extend_from_slice(&items).await?;
+        sink.finish().await
+    })
+    .unwrap();
+
+    assert_eq!(
+        parts,
+        vec![
+            Path::from("data/part-00000.arrow"),
+            Path::from("data/part-00001.arrow"),
+            Path::from("data/part-00002.arrow"),
+            Path::from("data/part-00003.arrow"),
+        ]
+    );
+    assert_eq!(read_parts(&store, &parts, 1), items);
+}
+
+#[test]
+fn extend_from_slice_fills_the_pushed_batch() {
+    let store = Arc::new(InMemory::new());
+    let items = records(25);
+
+    // each part contains one batch, i.e., the parts reflect the batch sizes
+    let parts = block_on(async {
+        let mut sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("data"), &fields())?
+            .with_batch_size(10)
+            .with_part_size(1);
+        for item in &items[..5] {
+            sink.push(item).await?;
+        }
+        sink.extend_from_slice(&items[5..]).await?;
+        sink.finish().await
+    })
+    .unwrap();
+
+    assert_eq!(parts.len(), 3);
+    assert_eq!(read_parts(&store, &parts, 1), items);
+
+    let part_lens = parts
+        .iter()
+        .map(|path| read_parts(&store, std::slice::from_ref(path), 1).len())
+        .collect::<Vec<_>>();
+    assert_eq!(part_lens, [10, 10, 5]);
+}
+
+#[test]
+fn empty_sinks_do_not_upload_parts() {
+    let store = Arc::new(InMemory::new());
+
+    let parts = block_on(async {
+        let sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("data"), &fields())?;
+        sink.finish().await
+    })
+    .unwrap();
+
+    assert_eq!(parts, Vec::<Path>::new());
+    let listed = block_on(async {
+        use futures::TryStreamExt;
+        store.list(None).try_collect::<Vec<_>>().await
+    })
+    .unwrap();
+    assert!(listed.is_empty());
+}
+
+#[test]
+fn serialization_errors_are_reported() {
+    let store = Arc::new(InMemory::new());
+
+    let res = block_on(async {
+        let mut sink = Arrow2ObjectStoreSink::new(store.clone(), Path::from("data"), &fields())?;
+        sink.push(&("not", "a", "record")).await
+    });
+    assert!(res.is_err());
+}
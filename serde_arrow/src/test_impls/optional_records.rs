@@ -0,0 +1,79 @@
+use super::macros::test_generic;
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Record {
+    a: Option<u32>,
+    b: Option<String>,
+}
+
+test_generic!(
+    fn optional_records() {
+        use crate::test_impls::optional_records::Record;
+
+        let items = [
+            Some(Record {
+                a: Some(1),
+                b: Some(String::from("foo")),
+            }),
+            None,
+            Some(Record { a: None, b: None }),
+        ];
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+        assert_eq!(arrays[0].len(), 3);
+        assert_eq!(arrays[0].null_count(), 2);
+        assert_eq!(arrays[1].null_count(), 2);
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    a: Some(1),
+                    b: Some(String::from("foo")),
+                },
+                Record { a: None, b: None },
+                Record { a: None, b: None },
+            ],
+        );
+    }
+);
+
+test_generic!(
+    fn optional_records_builder() {
+        use crate::test_impls::optional_records::Record;
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        builder.push(&None::<Record>).unwrap();
+        builder
+            .push(&Some(Record {
+                a: Some(2),
+                b: None,
+            }))
+            .unwrap();
+        builder.extend(&[None::<Record>, None]).unwrap();
+
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays[0].len(), 4);
+        assert_eq!(arrays[0].null_count(), 3);
+        assert_eq!(arrays[1].null_count(), 4);
+    }
+);
+
+test_generic!(
+    fn optional_records_with_non_nullable_fields() {
+        #[derive(serde::Serialize)]
+        struct Record {
+            a: u32,
+        }
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap()];
+        let res = to_arrow(&fields, &[Some(Record { a: 1 }), None]);
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot serialize a null record: field \"a\" is not nullable",
+        );
+    }
+);
@@ -0,0 +1,128 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn rows_are_routed_by_key() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+        struct Record {
+            region: String,
+            value: u32,
+        }
+
+        let items = [
+            Record {
+                region: String::from("eu"),
+                value: 1,
+            },
+            Record {
+                region: String::from("us"),
+                value: 2,
+            },
+            Record {
+                region: String::from("eu"),
+                value: 3,
+            },
+            Record {
+                region: String::from("asia"),
+                value: 4,
+            },
+        ];
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let mut builder = PartitionedBuilder::new(&fields).unwrap();
+        for item in &items {
+            builder.push(item.region.clone(), item).unwrap();
+        }
+        assert_eq!(builder.num_partitions(), 3);
+
+        let partitions = builder.finish().unwrap();
+        let keys = partitions
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["eu", "us", "asia"]);
+
+        let actual: Vec<Record> = from_arrow(&fields, &partitions[0].1).unwrap();
+        assert_eq!(actual, [items[0].clone(), items[2].clone()]);
+
+        let actual: Vec<Record> = from_arrow(&fields, &partitions[2].1).unwrap();
+        assert_eq!(actual, [items[3].clone()]);
+
+        // the builder starts over after finishing
+        assert_eq!(builder.num_partitions(), 0);
+        builder.push(String::from("us"), &items[1]).unwrap();
+        let partitions = builder.finish().unwrap();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].0, "us");
+    }
+);
+
+test_generic!(
+    fn failed_rows_do_not_create_partitions() {
+        use crate::utils::Item;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let mut builder = PartitionedBuilder::new(&fields).unwrap();
+
+        builder.push(0, &Item(1_u8)).unwrap();
+        assert!(builder.push(1, &Item("not a number")).is_err());
+        assert_eq!(builder.num_partitions(), 1);
+
+        let partitions = builder.finish().unwrap();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].0, 0);
+        assert_eq!(partitions[0].1[0].len(), 1);
+    }
+);
+
+test_generic!(
+    fn unsupported_fields_fail_on_construction() {
+        use crate::internal::schema::GenericTimeUnit;
+
+        let fields = vec![Field::try_from(&GenericField::new(
+            "item",
+            GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some("Europe/Berlin".into())),
+            false,
+        ))
+        .unwrap()];
+        assert!(PartitionedBuilder::<u32>::new(&fields).is_err());
+    }
+);
+
+#[test]
+fn partitions_built_before_a_failure_are_kept() {
+    use crate::internal::{
+        error::{fail, Result},
+        generic::{GenericBuilder, GenericPartitionedBuilder},
+        schema::{GenericDataType, GenericField},
+    };
+    use crate::utils::Item;
+
+    let fields = vec![GenericField::new("item", GenericDataType::U8, false)];
+    let mut builder = GenericPartitionedBuilder::new(fields, Default::default()).unwrap();
+    for key in 0..3 {
+        builder.push(key, &Item(key as u8)).unwrap();
+    }
+
+    let mut built = Vec::new();
+    let mut num_calls = 0;
+    let res = builder.finish_with(&mut built, |_: &mut GenericBuilder| -> Result<()> {
+        num_calls += 1;
+        if num_calls == 2 {
+            fail!("cannot build partition");
+        }
+        Ok(())
+    });
+    assert!(res.is_err());
+    assert_eq!(built, vec![(0, ())]);
+    assert_eq!(builder.num_partitions(), 1);
+
+    builder.push(3, &Item(3_u8)).unwrap();
+    builder.push(2, &Item(2_u8)).unwrap();
+    assert_eq!(builder.num_partitions(), 2);
+
+    let partitions = builder.finish_with(&mut built, |_| Ok(())).unwrap();
+    assert_eq!(partitions, vec![(0, ()), (2, ()), (3, ())]);
+    assert!(built.is_empty());
+    assert_eq!(builder.num_partitions(), 0);
+}
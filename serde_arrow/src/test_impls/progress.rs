@@ -0,0 +1,88 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn serialization_progress_is_counted_across_batches() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::utils::SerializationOptions;
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let options = SerializationOptions::default().on_progress(2, {
+            let reported = Arc::clone(&reported);
+            move |rows| reported.lock().unwrap().push(rows)
+        });
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+
+        builder.extend(&Items(&[1_u32, 2, 3])).unwrap();
+        let _ = builder.build_arrays().unwrap();
+
+        builder.push(&Item(4_u32)).unwrap();
+        builder
+            .extend_from_slice(&[Item(5_u32), Item(6_u32)])
+            .unwrap();
+        let _ = builder.build_arrays().unwrap();
+
+        assert_eq!(*reported.lock().unwrap(), [2, 4, 6]);
+    }
+);
+
+test_generic!(
+    fn deserialization_progress_is_reported() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::utils::DeserializationOptions;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u32, 2, 3, 4, 5])).unwrap();
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let options = DeserializationOptions::default().on_progress(2, {
+            let reported = Arc::clone(&reported);
+            move |rows| reported.lock().unwrap().push(rows)
+        });
+
+        let Items(items): Items<Vec<u32>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+        assert_eq!(*reported.lock().unwrap(), [2, 4]);
+    }
+);
+
+test_generic!(
+    fn nested_sequences_are_not_counted_as_rows() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::utils::{DeserializationOptions, SerializationOptions};
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let options = SerializationOptions::default().on_progress(1, {
+            let reported = Arc::clone(&reported);
+            move |rows| reported.lock().unwrap().push(rows)
+        });
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::LargeList, false)
+                .with_child(GenericField::new("element", GenericDataType::U32, false)),
+        )
+        .unwrap()];
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder
+            .extend(&Items(&[vec![1_u32, 2, 3], vec![4, 5]]))
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(*reported.lock().unwrap(), [1, 2]);
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let options = DeserializationOptions::default().on_progress(1, {
+            let reported = Arc::clone(&reported);
+            move |rows| reported.lock().unwrap().push(rows)
+        });
+        let Items(_items): Items<Vec<Vec<u32>>> =
+            from_arrow_with_options(&fields, &arrays, &options).unwrap();
+        assert_eq!(*reported.lock().unwrap(), [1, 2]);
+    }
+);
@@ -0,0 +1,280 @@
+use prost_types::{
+    field_descriptor_proto::{Label, Type},
+    DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, MessageOptions,
+    OneofDescriptorProto,
+};
+use serde_json::json;
+
+use crate::internal::schema::{SchemaLike, SerdeArrowSchema};
+
+use super::macros::{expect_error, test_generic};
+
+fn field(name: &str, ty: Type, label: Label) -> FieldDescriptorProto {
+    FieldDescriptorProto {
+        name: Some(name.to_owned()),
+        r#type: Some(ty.into()),
+        label: Some(label.into()),
+        ..Default::default()
+    }
+}
+
+fn message_field(name: &str, type_name: &str, label: Label) -> FieldDescriptorProto {
+    FieldDescriptorProto {
+        type_name: Some(type_name.to_owned()),
+        ..field(name, Type::Message, label)
+    }
+}
+
+fn message(name: &str, fields: Vec<FieldDescriptorProto>) -> DescriptorProto {
+    DescriptorProto {
+        name: Some(name.to_owned()),
+        field: fields,
+        ..Default::default()
+    }
+}
+
+fn file_set(syntax: &str, messages: Vec<DescriptorProto>) -> FileDescriptorSet {
+    FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            package: Some(String::from("test")),
+            syntax: Some(syntax.to_owned()),
+            message_type: messages,
+            ..Default::default()
+        }],
+    }
+}
+
+fn expected(fields: serde_json::Value) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(&fields).unwrap()
+}
+
+/// message Record {
+///     int32 a = 1;
+///     optional uint64 b = 2;
+///     repeated string c = 3;
+///     Inner d = 4;
+///     map<string, Inner> e = 5;
+///     oneof f { float g = 6; Inner h = 7; }
+///     Kind i = 8;
+///     message Inner { bool value = 1; }
+/// }
+fn record_files() -> FileDescriptorSet {
+    let b = FieldDescriptorProto {
+        proto3_optional: Some(true),
+        oneof_index: Some(1),
+        ..field("b", Type::Uint64, Label::Optional)
+    };
+    let g = FieldDescriptorProto {
+        oneof_index: Some(0),
+        ..field("g", Type::Float, Label::Optional)
+    };
+    let h = FieldDescriptorProto {
+        oneof_index: Some(0),
+        ..message_field("h", ".test.Record.Inner", Label::Optional)
+    };
+    let i = FieldDescriptorProto {
+        type_name: Some(String::from(".test.Kind")),
+        ..field("i", Type::Enum, Label::Optional)
+    };
+    let entry = DescriptorProto {
+        options: Some(MessageOptions {
+            map_entry: Some(true),
+            ..Default::default()
+        }),
+        ..message(
+            "EEntry",
+            vec![
+                field("key", Type::String, Label::Optional),
+                message_field("value", ".test.Record.Inner", Label::Optional),
+            ],
+        )
+    };
+
+    let record = DescriptorProto {
+        nested_type: vec![
+            message("Inner", vec![field("value", Type::Bool, Label::Optional)]),
+            entry,
+        ],
+        oneof_decl: vec![
+            OneofDescriptorProto {
+                name: Some(String::from("f")),
+                ..Default::default()
+            },
+            OneofDescriptorProto {
+                name: Some(String::from("_b")),
+                ..Default::default()
+            },
+        ],
+        ..message(
+            "Record",
+            vec![
+                field("a", Type::Int32, Label::Optional),
+                b,
+                field("c", Type::String, Label::Repeated),
+                message_field("d", ".test.Record.Inner", Label::Optional),
+                message_field("e", ".test.Record.EEntry", Label::Repeated),
+                g,
+                h,
+                i,
+            ],
+        )
+    };
+    file_set("proto3", vec![record])
+}
+
+fn record_schema() -> SerdeArrowSchema {
+    expected(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "U64", "nullable": true},
+        {"name": "c", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "d", "data_type": "Struct", "nullable": true, "children": [
+            {"name": "value", "data_type": "Bool"},
+        ]},
+        {"name": "e", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "Struct", "children": [
+                    {"name": "value", "data_type": "Bool"},
+                ]},
+            ]},
+        ]},
+        {"name": "f", "data_type": "Union", "nullable": true, "children": [
+            {"name": "G", "data_type": "F32"},
+            {"name": "H", "data_type": "Struct", "children": [
+                {"name": "value", "data_type": "Bool"},
+            ]},
+        ]},
+        {"name": "i", "data_type": "I32"},
+    ]))
+}
+
+#[test]
+fn proto3_messages() {
+    let actual = SerdeArrowSchema::from_protobuf(&record_files(), "test.Record").unwrap();
+    assert_eq!(actual, record_schema());
+
+    // leading dots are accepted
+    let actual = SerdeArrowSchema::from_protobuf(&record_files(), ".test.Record").unwrap();
+    assert_eq!(actual, record_schema());
+}
+
+#[test]
+fn proto2_optional_fields_are_nullable() {
+    let files = file_set(
+        "proto2",
+        vec![message(
+            "Record",
+            vec![
+                field("a", Type::Int64, Label::Optional),
+                field("b", Type::Double, Label::Required),
+                field("c", Type::Sint32, Label::Repeated),
+            ],
+        )],
+    );
+    let actual = SerdeArrowSchema::from_protobuf(&files, "test.Record").unwrap();
+    let expected = expected(json!([
+        {"name": "a", "data_type": "I64", "nullable": true},
+        {"name": "b", "data_type": "F64"},
+        {"name": "c", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "I32"},
+        ]},
+    ]));
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unsupported_messages() {
+    let res = SerdeArrowSchema::from_protobuf(&record_files(), "test.Unknown");
+    expect_error(&res, "Unknown protobuf message \".test.Unknown\"");
+
+    let files = file_set(
+        "proto3",
+        vec![message(
+            "Record",
+            vec![field("a", Type::Bytes, Label::Optional)],
+        )],
+    );
+    let res = SerdeArrowSchema::from_protobuf(&files, "test.Record");
+    expect_error(
+        &res,
+        "Cannot convert protobuf field \"a\" of type TYPE_BYTES",
+    );
+
+    let files = file_set(
+        "proto3",
+        vec![message(
+            "Node",
+            vec![message_field("children", ".test.Node", Label::Repeated)],
+        )],
+    );
+    let res = SerdeArrowSchema::from_protobuf(&files, "test.Node");
+    expect_error(&res, "recursive protobuf message \".test.Node\"");
+
+    let files = file_set(
+        "proto3",
+        vec![message(
+            "Record",
+            vec![message_field("a", ".test.Missing", Label::Optional)],
+        )],
+    );
+    let res = SerdeArrowSchema::from_protobuf(&files, "test.Record");
+    expect_error(
+        &res,
+        "Unknown protobuf message \".test.Missing\" of field \"a\"",
+    );
+}
+
+test_generic!(
+    fn prost_types_round_trip() {
+        use std::collections::HashMap;
+
+        use serde::{Deserialize, Serialize};
+
+        use crate::{internal::schema::SerdeArrowSchema, test_impls::protobuf::record_files};
+
+        // the types prost would generate for `Record`, with serde derives
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            a: i32,
+            b: Option<u64>,
+            c: Vec<String>,
+            d: Option<Inner>,
+            e: HashMap<String, Inner>,
+            i: i32,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            value: bool,
+        }
+
+        let items = vec![
+            Record {
+                a: 1,
+                b: Some(2),
+                c: vec![String::from("x")],
+                d: Some(Inner { value: true }),
+                e: HashMap::from([(String::from("k"), Inner { value: false })]),
+                i: 4,
+            },
+            Record {
+                a: 5,
+                b: None,
+                c: vec![],
+                d: None,
+                e: HashMap::new(),
+                i: 0,
+            },
+        ];
+
+        let mut schema = SerdeArrowSchema::from_protobuf(&record_files(), "test.Record").unwrap();
+        // oneofs map to nullable unions, which cannot be serialized yet
+        schema.fields.retain(|field| field.name != "f");
+        let fields = Vec::<Field>::from_value(&schema).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
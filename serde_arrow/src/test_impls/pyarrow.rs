@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use pyo3::{exceptions::PyRuntimeError, PyErr, Python};
+
+use crate::{
+    _impl::arrow::{
+        array::{ArrayRef, BooleanArray, StringArray},
+        datatypes::{DataType, Field},
+    },
+    internal::error::{error, Error},
+    test_impls::macros::expect_error,
+    to_pyarrow,
+};
+
+#[test]
+fn arrays_are_converted_to_record_batches() {
+    pyo3::prepare_freethreaded_python();
+    let fields = vec![
+        Field::new("a", DataType::Boolean, false),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let arrays: Vec<ArrayRef> = vec![
+        Arc::new(BooleanArray::from(vec![true, false])),
+        Arc::new(StringArray::from(vec![Some("foo"), None])),
+    ];
+
+    Python::with_gil(|py| {
+        // the conversion can only be checked if pyarrow is installed
+        if py.import("pyarrow").is_err() {
+            return;
+        }
+
+        let batch = to_pyarrow(py, &fields, arrays).unwrap();
+        let batch = batch.as_ref(py);
+        assert_eq!(
+            batch
+                .getattr("num_rows")
+                .unwrap()
+                .extract::<usize>()
+                .unwrap(),
+            2
+        );
+
+        let actual = batch
+            .call_method0("to_pydict")
+            .unwrap()
+            .call_method0("__repr__")
+            .unwrap()
+            .extract::<String>()
+            .unwrap();
+        assert_eq!(actual, "{'a': [True, False], 'b': ['foo', None]}");
+    });
+}
+
+#[test]
+fn mismatched_lengths_are_rejected() {
+    pyo3::prepare_freethreaded_python();
+    let fields = vec![
+        Field::new("a", DataType::Boolean, false),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    let arrays: Vec<ArrayRef> = vec![
+        Arc::new(BooleanArray::from(vec![true, false])),
+        Arc::new(StringArray::from(vec!["foo"])),
+    ];
+
+    let res = Python::with_gil(|py| to_pyarrow(py, &fields, arrays));
+    expect_error(
+        &res,
+        "all columns in a record batch must have the same length",
+    );
+}
+
+#[test]
+fn errors_are_raised_as_runtime_errors() {
+    pyo3::prepare_freethreaded_python();
+    let err: Error = error!("something went wrong");
+    let err = PyErr::from(err);
+
+    Python::with_gil(|py| {
+        assert!(err.is_instance_of::<PyRuntimeError>(py));
+        assert!(err.value(py).to_string().contains("something went wrong"));
+    });
+}
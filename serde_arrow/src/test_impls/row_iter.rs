@@ -0,0 +1,113 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn row_iter_take_first_rows() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u16, 2, 300, 4])).unwrap();
+
+        // the third row does not fit into an u8, but is never deserialized
+        let rows = from_arrow_iter(&fields, &arrays, |rows| {
+            Ok(rows
+                .take(2)
+                .map(|row: Result<Item<u8>, _>| row.unwrap().0)
+                .collect::<Vec<_>>())
+        })
+        .unwrap();
+        assert_eq!(rows, vec![1, 2]);
+    }
+);
+
+test_generic!(
+    fn row_iter_continues_after_failing_rows() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u16, 2, 300, 4])).unwrap();
+
+        let rows = from_arrow_iter(&fields, &arrays, |rows| {
+            Ok(rows
+                .map(|row| match row {
+                    Ok(Item(item)) => Ok(item),
+                    Err(err) => Err(err.row),
+                })
+                .collect::<Vec<Result<u8, usize>>>())
+        })
+        .unwrap();
+        assert_eq!(rows, vec![Ok(1), Ok(2), Err(2), Ok(4)]);
+    }
+);
+
+test_generic!(
+    fn row_iter_structs() {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Record {
+            a: u8,
+            nested: Nested,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Nested {
+            b: u32,
+        }
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("nested", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("b", GenericDataType::U32, true)),
+            )
+            .unwrap(),
+        ];
+        let arrays = to_arrow(
+            &fields,
+            &[
+                serde_json::json!({"a": 1, "nested": {"b": null}}),
+                serde_json::json!({"a": 2, "nested": {"b": 20}}),
+                serde_json::json!({"a": 3, "nested": {"b": 30}}),
+            ],
+        )
+        .unwrap();
+
+        let (first, rest) = from_arrow_iter(&fields, &arrays, |mut rows| {
+            let first = rows.next().unwrap();
+            let rest = rows.collect::<Result<Vec<Record>, _>>();
+            Ok((first, rest))
+        })
+        .unwrap();
+
+        let first: Result<Record, _> = first;
+        let err = first.unwrap_err();
+        assert_eq!((err.row, err.path.as_str()), (0, "nested.b"));
+
+        assert_eq!(
+            rest.unwrap(),
+            vec![
+                Record {
+                    a: 2,
+                    nested: Nested { b: 20 },
+                },
+                Record {
+                    a: 3,
+                    nested: Nested { b: 30 },
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn row_iter_empty() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&Vec::<i64>::new())).unwrap();
+
+        let rows = from_arrow_iter(&fields, &arrays, |rows| {
+            rows.collect::<Result<Vec<Item<i64>>, _>>()
+                .map_err(|err| err.error)
+        })
+        .unwrap();
+        assert!(rows.is_empty());
+    }
+);
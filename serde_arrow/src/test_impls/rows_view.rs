@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    name: Option<String>,
+    tags: Vec<String>,
+}
+
+fn records() -> Vec<Record> {
+    (0..100)
+        .map(|id| Record {
+            id,
+            name: if id % 3 == 0 {
+                None
+            } else {
+                Some(format!("record {id}"))
+            },
+            tags: (0..id % 4).map(|tag| format!("tag {tag}")).collect(),
+        })
+        .collect()
+}
+
+test_generic!(
+    fn rows_view_random_access() {
+        use crate::test_impls::rows_view::{records, Record};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &records()).unwrap();
+
+        let view = from_arrow_view::<Record, _>(&fields, &arrays).unwrap();
+        assert_eq!(view.len(), 100);
+        assert!(!view.is_empty());
+
+        let expected = records();
+        for idx in [99, 0, 42, 3, 42] {
+            assert_eq!(view.get(idx).unwrap(), expected[idx]);
+        }
+        assert!(view.get(100).is_err());
+
+        let actual = view.iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(actual, expected);
+    }
+);
+
+test_generic!(
+    fn rows_view_only_deserializes_requested_rows() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u16, 2, 300, 4])).unwrap();
+
+        // the third row does not fit into an u8, only its access fails
+        let view = from_arrow_view::<Item<u8>, _>(&fields, &arrays).unwrap();
+        assert_eq!(view.get(3).unwrap().0, 4);
+        assert_eq!(view.get(0).unwrap().0, 1);
+        assert!(view.get(2).is_err());
+        assert_eq!(view.get(1).unwrap().0, 2);
+    }
+);
+
+test_generic!(
+    fn rows_view_empty_arrays() {
+        use crate::test_impls::rows_view::Record;
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Vec::<Record>::new()).unwrap();
+
+        let view = from_arrow_view::<Record, _>(&fields, &arrays).unwrap();
+        assert!(view.is_empty());
+        assert!(view.get(0).is_err());
+    }
+);
+
+test_generic!(
+    fn rows_view_checks_the_schema() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U16, false)).unwrap()];
+        let arrays = to_arrow(&fields, &Items(&[1_u16, 2])).unwrap();
+
+        let wrong_fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+        let res = from_arrow_view::<Item<u32>, _>(&wrong_fields, &arrays);
+        assert!(res.is_err());
+    }
+);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    value: i64,
+    items: Vec<Option<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Point,
+    Circle(f32),
+    Label(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NestedRecord {
+    nested: Vec<Vec<u16>>,
+    inner: Option<Inner>,
+    entries: std::collections::BTreeMap<String, u32>,
+    shape: Shape,
+}
+
+fn nested_records() -> Vec<NestedRecord> {
+    (0..50_u32)
+        .map(|id| NestedRecord {
+            nested: (0..id % 3)
+                .map(|len| (0..len as u16 + 1).collect())
+                .collect(),
+            inner: (id % 4 != 1).then(|| Inner {
+                value: -i64::from(id),
+                items: (0..id % 5)
+                    .map(|item| (item % 2 == 0).then_some(item as u8))
+                    .collect(),
+            }),
+            entries: (0..id % 3).map(|key| (format!("key {key}"), id)).collect(),
+            shape: match id % 3 {
+                0 => Shape::Point,
+                1 => Shape::Circle(id as f32),
+                _ => Shape::Label(format!("label {id}")),
+            },
+        })
+        .collect()
+}
+
+test_generic!(
+    fn rows_view_random_access_to_nested_rows() {
+        use crate::test_impls::rows_view::{nested_records, NestedRecord};
+
+        let items = nested_records();
+        let fields = Vec::<Field>::from_samples(
+            &items,
+            TracingOptions::default()
+                .map_as_struct(false)
+                .allow_null_fields(true),
+        )
+        .unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let view = from_arrow_view::<NestedRecord, _>(&fields, &arrays).unwrap();
+        for idx in [49, 0, 17, 2, 33, 17, 1, 48] {
+            assert_eq!(view.get(idx).unwrap(), items[idx], "row {idx}");
+        }
+
+        let actual = view.iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(actual, items);
+    }
+);
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::internal::{
+    schema::{SchemaLike, SerdeArrowSchema},
+    tracing::TracingOptions,
+};
+
+use super::macros::expect_error;
+
+fn rust_types(fields: serde_json::Value) -> Vec<(String, String)> {
+    SerdeArrowSchema::from_value(&fields)
+        .unwrap()
+        .to_rust_types()
+        .unwrap()
+}
+
+fn types_only(types: Vec<(String, String)>) -> Vec<String> {
+    types.into_iter().map(|(_, ty)| ty).collect()
+}
+
+#[test]
+fn primitives() {
+    let actual = rust_types(json!([
+        {"name": "a", "data_type": "Null", "nullable": true},
+        {"name": "b", "data_type": "Bool"},
+        {"name": "c", "data_type": "I8"},
+        {"name": "d", "data_type": "U64", "nullable": true},
+        {"name": "e", "data_type": "F16"},
+        {"name": "f", "data_type": "F64"},
+        {"name": "g", "data_type": "Utf8"},
+        {"name": "h", "data_type": "LargeUtf8", "nullable": true},
+        {"name": "i", "data_type": "Decimal128(5, 2)"},
+    ]));
+    let expected = vec![
+        ("a", "()"),
+        ("b", "bool"),
+        ("c", "i8"),
+        ("d", "Option<u64>"),
+        ("e", "f32"),
+        ("f", "f64"),
+        ("g", "String"),
+        ("h", "Option<String>"),
+        ("i", "String"),
+    ];
+    let expected = expected
+        .into_iter()
+        .map(|(name, ty)| (name.to_string(), ty.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn null_defaults_are_not_optional() {
+    let actual = rust_types(json!([
+        {"name": "a", "data_type": "I32", "nullable": true, "null_default": "0"},
+    ]));
+    assert_eq!(types_only(actual), vec!["i32"]);
+}
+
+#[test]
+fn dates_depend_on_the_strategy() {
+    let actual = rust_types(json!([
+        {"name": "a", "data_type": "Date64"},
+        {"name": "b", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "c", "data_type": "Date64", "strategy": "NaiveStrAsDate64", "nullable": true},
+        {"name": "d", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))", "strategy": "UtcStrAsDate64"},
+        {"name": "e", "data_type": "Timestamp(Second, None)"},
+    ]));
+    assert_eq!(
+        types_only(actual),
+        vec![
+            "i64",
+            "chrono::DateTime<chrono::Utc>",
+            "Option<chrono::NaiveDateTime>",
+            "chrono::DateTime<chrono::Utc>",
+            "i64",
+        ]
+    );
+}
+
+#[test]
+fn nested_lists_maps_and_dictionaries() {
+    let actual = rust_types(json!([
+        {"name": "a", "data_type": "List", "children": [
+            {"name": "element", "data_type": "LargeList", "nullable": true, "children": [
+                {"name": "element", "data_type": "U8"},
+            ]},
+        ]},
+        {"name": "b", "data_type": "FixedSizeList(2)", "children": [
+            {"name": "element", "data_type": "F32", "nullable": true},
+        ]},
+        {"name": "c", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "LargeList", "children": [
+                    {"name": "element", "data_type": "I64"},
+                ]},
+            ]},
+        ]},
+        {"name": "d", "data_type": "Dictionary", "nullable": true, "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+    ]));
+    assert_eq!(
+        types_only(actual),
+        vec![
+            "Vec<Option<Vec<u8>>>",
+            "Vec<Option<f32>>",
+            "HashMap<String, Vec<i64>>",
+            "Option<String>",
+        ]
+    );
+}
+
+#[test]
+fn structs_and_unions_are_named_after_the_field() {
+    let actual = rust_types(json!([
+        {"name": "last_point", "data_type": "Struct", "children": [
+            {"name": "x", "data_type": "F32"},
+        ]},
+        {"name": "points", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct", "children": [
+                {"name": "x", "data_type": "F32"},
+            ]},
+        ]},
+        {"name": "shape", "data_type": "Union", "nullable": true, "children": [
+            {"name": "Circle", "data_type": "F32"},
+            {"name": "Square", "data_type": "F32"},
+        ]},
+        {"name": "0", "data_type": "Struct", "children": [
+            {"name": "x", "data_type": "F32"},
+        ]},
+    ]));
+    assert_eq!(
+        types_only(actual),
+        vec!["LastPoint", "Vec<Points>", "Option<Shape>", "T0"]
+    );
+}
+
+#[test]
+fn tuples_and_maps_as_structs() {
+    let actual = rust_types(json!([
+        {"name": "a", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "U8"},
+            {"name": "1", "data_type": "Utf8", "nullable": true},
+        ]},
+        {"name": "b", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "Bool"},
+        ]},
+        {"name": "c", "data_type": "Struct", "strategy": "MapAsStruct", "children": [
+            {"name": "x", "data_type": "F64"},
+            {"name": "y", "data_type": "F64"},
+        ]},
+        {"name": "mixed_values", "data_type": "Struct", "strategy": "MapAsStruct", "children": [
+            {"name": "x", "data_type": "F64"},
+            {"name": "y", "data_type": "Utf8"},
+        ]},
+    ]));
+    assert_eq!(
+        types_only(actual),
+        vec![
+            "(u8, Option<String>)",
+            "(bool,)",
+            "HashMap<String, f64>",
+            "MixedValues",
+        ]
+    );
+}
+
+#[test]
+fn traced_types_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        a: Option<i64>,
+        b: Vec<String>,
+        c: HashMap<String, f64>,
+        d: (u8, bool),
+    }
+
+    let schema =
+        SerdeArrowSchema::from_type::<Record>(TracingOptions::default().map_as_struct(false))
+            .unwrap();
+    assert_eq!(
+        types_only(schema.to_rust_types().unwrap()),
+        vec![
+            "Option<i64>",
+            "Vec<String>",
+            "HashMap<String, f64>",
+            "(u8, bool)"
+        ]
+    );
+}
+
+#[test]
+fn invalid_maps_are_rejected() {
+    let schema = SerdeArrowSchema::from_value(&json!([
+        {"name": "a", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+            ]},
+        ]},
+    ]))
+    .unwrap();
+    let res = schema.to_rust_types();
+    expect_error(&res, "Map field \"a\" without key and value fields");
+}
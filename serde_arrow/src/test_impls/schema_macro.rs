@@ -0,0 +1,125 @@
+use crate::internal::{
+    schema::{GenericDataType, GenericField, GenericTimeUnit, SchemaLike, SerdeArrowSchema},
+    tracing::TracingOptions,
+};
+
+use super::macros::{expect_error, test_generic};
+
+#[test]
+fn primitives() {
+    let schema = crate::schema! {
+        a: Float32?,
+        b: U64,
+        "c d": Bool,
+        e: LargeUtf8
+    }
+    .unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new("a", GenericDataType::F32, true),
+            GenericField::new("b", GenericDataType::U64, false),
+            GenericField::new("c d", GenericDataType::Bool, false),
+            GenericField::new("e", GenericDataType::LargeUtf8, false),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn types_with_arguments() {
+    let schema = crate::schema! {
+        utc: Timestamp(ms, "UTC")?,
+        naive: Timestamp(ns),
+        decimal: Decimal128(5, 2),
+    }
+    .unwrap();
+    let expected = SerdeArrowSchema {
+        fields: vec![
+            GenericField::new(
+                "utc",
+                GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(String::from("UTC"))),
+                true,
+            ),
+            GenericField::new(
+                "naive",
+                GenericDataType::Timestamp(GenericTimeUnit::Nanosecond, None),
+                false,
+            ),
+            GenericField::new("decimal", GenericDataType::Decimal128(5, 2), false),
+        ],
+    };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn nested_types() {
+    let schema = crate::schema! {
+        list: List(I32?),
+        vectors: LargeList(FixedSizeList(3, F64))?,
+        point: Struct { x: F32, y: F32, tags: LargeList(Utf8) }?,
+    }
+    .unwrap();
+    let expected =
+        SerdeArrowSchema {
+            fields: vec![
+                GenericField::new("list", GenericDataType::List, false)
+                    .with_child(GenericField::new("element", GenericDataType::I32, true)),
+                GenericField::new("vectors", GenericDataType::LargeList, true).with_child(
+                    GenericField::new("element", GenericDataType::FixedSizeList(3), false)
+                        .with_child(GenericField::new("element", GenericDataType::F64, false)),
+                ),
+                GenericField::new("point", GenericDataType::Struct, true)
+                    .with_child(GenericField::new("x", GenericDataType::F32, false))
+                    .with_child(GenericField::new("y", GenericDataType::F32, false))
+                    .with_child(
+                        GenericField::new("tags", GenericDataType::LargeList, false)
+                            .with_child(GenericField::new("element", GenericDataType::Utf8, false)),
+                    ),
+            ],
+        };
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn empty_schema() {
+    let schema = crate::schema! {}.unwrap();
+    assert_eq!(schema, SerdeArrowSchema::new());
+}
+
+#[test]
+fn invalid_fields_are_rejected() {
+    let res = crate::schema! { decimal: Decimal128(50, 2) };
+    expect_error(&res, "invalid precision");
+}
+
+#[test]
+fn matches_traced_schema() {
+    #[derive(serde::Deserialize)]
+    #[allow(unused)]
+    struct Record {
+        a: Option<f32>,
+        b: u64,
+        c: Vec<String>,
+    }
+
+    let schema = crate::schema! { a: F32?, b: U64, c: LargeList(LargeUtf8) }.unwrap();
+    let traced = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    assert_eq!(schema, traced);
+}
+
+test_generic!(
+    fn roundtrip() {
+        let fields: Vec<Field> = crate::schema! { a: U8, b: LargeUtf8? }
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let items = vec![
+            serde_json::json!({"a": 1, "b": "foo"}),
+            serde_json::json!({"a": 2, "b": null}),
+        ];
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<serde_json::Value> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
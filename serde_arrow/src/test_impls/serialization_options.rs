@@ -0,0 +1,458 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn overflow_error() {
+        use crate::utils::SerializationOptions;
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let mut builder =
+            ArrowBuilder::with_options(&fields, SerializationOptions::default()).unwrap();
+        builder.push(&Item(1_u32)).unwrap();
+        let res = builder.push(&Item(300_u32));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot convert 300 (u32) in row 1 without overflow",
+        );
+    }
+);
+
+test_generic!(
+    fn overflow_via_to_arrow_with_options() {
+        use crate::utils::{Overflow, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+        let items = Items(&[1_i32, 300, -5]);
+
+        let res = to_arrow_with_options(&fields, &items, &SerializationOptions::default());
+        crate::test_impls::macros::expect_error(&res, "without overflow");
+
+        let options = SerializationOptions::default().overflow(Overflow::Saturate);
+        let arrays = to_arrow_with_options(&fields, &items, &options).unwrap();
+        let Items(actual): Items<Vec<u8>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![1, 255, 0]);
+    }
+);
+
+test_generic!(
+    fn overflow_saturate() {
+        use crate::utils::{Overflow, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().overflow(Overflow::Saturate),
+        )
+        .unwrap();
+        builder.extend(&Items(&[1_i32, 300, -5])).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<u8>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![1, 255, 0]);
+    }
+);
+
+test_generic!(
+    fn overflow_wrap() {
+        use crate::utils::{Overflow, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U8, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().overflow(Overflow::Wrap),
+        )
+        .unwrap();
+        builder.extend(&Items(&[1_i32, 300, -1])).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<u8>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![1, 44, 255]);
+    }
+);
+
+test_generic!(
+    fn non_finite_serialize() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::F64, false)).unwrap()];
+
+        let arrays = to_arrow(&fields, &Items(&[1.0, f64::INFINITY, f64::NEG_INFINITY])).unwrap();
+
+        let Items(actual): Items<Vec<f64>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![1.0, f64::INFINITY, f64::NEG_INFINITY]);
+    }
+);
+
+test_generic!(
+    fn non_finite_null() {
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::F32, true)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().non_finite(NonFinite::Null),
+        )
+        .unwrap();
+        builder
+            .extend(&Items(&[
+                Some(1.0_f64),
+                Some(f64::NAN),
+                None,
+                Some(f64::INFINITY),
+            ]))
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let Items(actual): Items<Vec<Option<f32>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Some(1.0), None, None, None]);
+    }
+);
+
+test_generic!(
+    fn non_finite_null_non_nullable() {
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::F64, false)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().non_finite(NonFinite::Null),
+        )
+        .unwrap();
+        let res = builder.extend(&Items(&[1.0, f64::NAN]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot store the non-finite value NaN in row 1 as null in a non-nullable field",
+        );
+    }
+);
+
+test_generic!(
+    fn non_finite_error() {
+        use crate::utils::{NonFinite, SerializationOptions};
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::F64, true)).unwrap()];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().non_finite(NonFinite::Error),
+        )
+        .unwrap();
+        let res = builder.extend(&Items(&[Some(1.0), None, Some(f64::NEG_INFINITY)]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot serialize the non-finite value -inf in row 2",
+        );
+    }
+);
+
+test_generic!(
+    fn null_policy_error() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U32, false)).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[Some(1_u32), None]));
+        assert!(res.is_err());
+    }
+);
+
+test_generic!(
+    fn null_policy_default() {
+        use crate::utils::{NullPolicy, SerializationOptions};
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::LargeUtf8, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("c", GenericDataType::LargeList, false)
+                    .with_child(GenericField::new("element", GenericDataType::Bool, false)),
+            )
+            .unwrap(),
+        ];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().null_policy(NullPolicy::Default),
+        )
+        .unwrap();
+        builder
+            .extend(&[
+                serde_json::json!({"a": 1, "b": "foo", "c": [true, null]}),
+                serde_json::json!({"a": null, "b": null, "c": null}),
+                serde_json::json!({}),
+            ])
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        for array in &arrays {
+            assert_eq!(array.null_count(), 0);
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Record {
+            a: u32,
+            b: String,
+            c: Vec<bool>,
+        }
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    a: 1,
+                    b: String::from("foo"),
+                    c: vec![true, false],
+                },
+                Record {
+                    a: 0,
+                    b: String::new(),
+                    c: vec![],
+                },
+                Record {
+                    a: 0,
+                    b: String::new(),
+                    c: vec![],
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn null_policy_nullable() {
+        use crate::utils::{NullPolicy, SerializationOptions};
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, false)).unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::LargeList, false)
+                    .with_child(GenericField::new("element", GenericDataType::Bool, false)),
+            )
+            .unwrap(),
+        ];
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().null_policy(NullPolicy::Nullable),
+        )
+        .unwrap();
+        builder
+            .extend(&[
+                serde_json::json!({"a": 1, "b": [true, null]}),
+                serde_json::json!({"a": null, "b": null}),
+            ])
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let nullable_fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U32, true)).unwrap(),
+            Field::try_from(
+                &GenericField::new("b", GenericDataType::LargeList, true)
+                    .with_child(GenericField::new("element", GenericDataType::Bool, true)),
+            )
+            .unwrap(),
+        ];
+        assert_eq!(builder.fields().unwrap(), nullable_fields);
+        assert_eq!(arrays[0].data_type(), nullable_fields[0].data_type());
+        assert_eq!(arrays[1].data_type(), nullable_fields[1].data_type());
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Record {
+            a: Option<u32>,
+            b: Option<Vec<Option<bool>>>,
+        }
+
+        let actual: Vec<Record> = from_arrow(&nullable_fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    a: Some(1),
+                    b: Some(vec![Some(true), None]),
+                },
+                Record { a: None, b: None },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn validate_arrays() {
+        use std::collections::BTreeMap;
+
+        use serde::Serialize;
+
+        use crate::utils::SerializationOptions;
+
+        #[derive(Serialize)]
+        enum Value {
+            Int(i32),
+            Str(String),
+        }
+
+        #[derive(Serialize)]
+        struct Nested {
+            flag: bool,
+            date: String,
+        }
+
+        #[derive(Serialize)]
+        struct Record {
+            a: Option<u32>,
+            b: Vec<String>,
+            c: BTreeMap<String, f64>,
+            d: Value,
+            e: Nested,
+            f: String,
+        }
+
+        let items = vec![
+            Record {
+                a: Some(1),
+                b: vec![String::from("foo"), String::from("bar")],
+                c: BTreeMap::from([(String::from("x"), 1.0)]),
+                d: Value::Int(13),
+                e: Nested {
+                    flag: true,
+                    date: String::from("2015-09-18T23:56:04Z"),
+                },
+                f: String::from("red"),
+            },
+            Record {
+                a: None,
+                b: vec![],
+                c: BTreeMap::new(),
+                d: Value::Str(String::from("baz")),
+                e: Nested {
+                    flag: false,
+                    date: String::from("2023-08-14T17:00:04Z"),
+                },
+                f: String::from("red"),
+            },
+        ];
+
+        let fields = Vec::<Field>::from_samples(
+            &items,
+            TracingOptions::default()
+                .map_as_struct(false)
+                .guess_dates(true)
+                .string_dictionary_ratio(Some(0.5)),
+        )
+        .unwrap();
+
+        let mut builder = ArrowBuilder::with_options(
+            &fields,
+            SerializationOptions::default().validate_arrays(true),
+        )
+        .unwrap();
+        builder.extend(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        assert_eq!(arrays.len(), 6);
+    }
+);
+
+test_generic!(
+    fn coerce_bools() {
+        use crate::utils::SerializationOptions;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::Bool, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::Bool, true)).unwrap(),
+        ];
+        let items = [
+            serde_json::json!({"a": 1, "b": "TRUE"}),
+            serde_json::json!({"a": "false", "b": null}),
+            serde_json::json!({"a": true, "b": 0}),
+        ];
+
+        let mut builder = ArrowBuilder::new(&fields).unwrap();
+        let res = builder.extend(&items);
+        crate::test_impls::macros::expect_error(&res, "PushBool cannot accept U64");
+
+        let mut builder =
+            ArrowBuilder::with_options(&fields, SerializationOptions::default().coerce_bools(true))
+                .unwrap();
+        builder.extend(&items).unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Record {
+            a: bool,
+            b: Option<bool>,
+        }
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Record {
+                    a: true,
+                    b: Some(true)
+                },
+                Record { a: false, b: None },
+                Record {
+                    a: true,
+                    b: Some(false)
+                },
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn coerce_bools_invalid_values() {
+        use crate::utils::SerializationOptions;
+
+        let fields =
+            vec![
+                Field::try_from(&GenericField::new("item", GenericDataType::Bool, false)).unwrap(),
+            ];
+
+        let mut builder =
+            ArrowBuilder::with_options(&fields, SerializationOptions::default().coerce_bools(true))
+                .unwrap();
+        let res = builder.extend(&Items(&[0, 2]));
+        crate::test_impls::macros::expect_error(&res, "Cannot interpret 2 in row 1 as a bool");
+
+        let mut builder =
+            ArrowBuilder::with_options(&fields, SerializationOptions::default().coerce_bools(true))
+                .unwrap();
+        let res = builder.extend(&Items(&["yes"]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot interpret \"yes\" in row 0 as a bool",
+        );
+    }
+);
+
+test_generic!(
+    fn overflow_error_cross_sign() {
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::I64, false)).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[1_u64, u64::MAX]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot convert 18446744073709551615 (u64) in row 1 without overflow",
+        );
+
+        let fields =
+            vec![Field::try_from(&GenericField::new("item", GenericDataType::U64, false)).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[-1_i64]));
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Cannot convert -1 (i64) in row 0 without overflow",
+        );
+    }
+);
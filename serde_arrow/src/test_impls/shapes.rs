@@ -0,0 +1,145 @@
+use serde_json::json;
+
+use crate::internal::{
+    schema::{SchemaLike, SerdeArrowSchema},
+    shape::{ExpectedShape, SerdeShape},
+};
+
+use super::macros::expect_error;
+
+fn schema() -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(&json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "items", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct", "nullable": true, "children": [
+                {"name": "a", "data_type": "I32", "nullable": true, "null_default": "0"},
+                {"name": "b", "data_type": "Dictionary", "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ]},
+            ]},
+        ]},
+        {"name": "scores", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "F16"},
+            ]},
+        ]},
+        {"name": "pair", "data_type": "Struct", "strategy": "TupleAsStruct", "children": [
+            {"name": "0", "data_type": "Bool"},
+            {"name": "1", "data_type": "Utf8", "strategy": "NumberAsStr"},
+        ]},
+        {"name": "shape", "data_type": "Union", "children": [
+            {"name": "Circle", "data_type": "F64"},
+            {"name": "Empty", "data_type": "Null", "nullable": true},
+        ]},
+        {"name": "time", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "amount", "data_type": "Decimal128(5, 2)"},
+    ]))
+    .unwrap()
+}
+
+fn shape(path: &str) -> SerdeShape {
+    schema().expected_shape(path).unwrap().shape
+}
+
+#[test]
+fn primitive_shapes() {
+    assert_eq!(shape("$.id"), SerdeShape::U64);
+    assert_eq!(shape("$.scores.value"), SerdeShape::F32);
+    assert_eq!(shape("$.pair.0"), SerdeShape::Bool);
+    assert_eq!(shape("$.pair.1"), SerdeShape::Number);
+    assert_eq!(shape("$.shape.Circle"), SerdeShape::F64);
+    assert_eq!(shape("$.shape.Empty"), SerdeShape::Null);
+    assert_eq!(shape("$.time"), SerdeShape::Str);
+    assert_eq!(shape("$.amount"), SerdeShape::Str);
+}
+
+#[test]
+fn nested_shapes() {
+    assert_eq!(shape("$.items"), SerdeShape::Seq);
+    assert_eq!(
+        shape("$.items.element"),
+        SerdeShape::Struct(vec![String::from("a"), String::from("b")])
+    );
+    assert_eq!(shape("$.scores"), SerdeShape::Map);
+    assert_eq!(shape("$.pair"), SerdeShape::Tuple(2));
+    assert_eq!(
+        shape("$.shape"),
+        SerdeShape::Enum(vec![String::from("Circle"), String::from("Empty")])
+    );
+}
+
+#[test]
+fn dictionaries_are_transparent() {
+    assert_eq!(shape("$.items.element.b"), SerdeShape::Str);
+
+    let res = schema().expected_shape("$.items.element.b.key");
+    expect_error(&res, "field \"b\" has no child \"key\"");
+}
+
+#[test]
+fn nullability_and_null_defaults() {
+    let actual = schema().expected_shape("$.items.element.a").unwrap();
+    let expected = ExpectedShape {
+        path: String::from("$.items.element.a"),
+        shape: SerdeShape::I32,
+        nullable: true,
+        null_default: Some(String::from("0")),
+    };
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn invalid_paths() {
+    let res = schema().expected_shape("items");
+    expect_error(&res, "paths must start with \"$\"");
+
+    let res = schema().expected_shape("$");
+    expect_error(&res, "paths must refer to a field");
+
+    let res = schema().expected_shape("$.unknown");
+    expect_error(&res, "unknown field \"unknown\"");
+
+    let res = schema().expected_shape("$.scores.entries");
+    expect_error(&res, "field \"scores\" has no child \"entries\"");
+
+    let res = schema().expected_shape("$.id.element");
+    expect_error(&res, "field \"id\" has no child \"element\"");
+}
+
+#[test]
+fn all_shapes_can_be_queried_by_their_path() {
+    let schema = schema();
+    let shapes = schema.expected_shapes().unwrap();
+
+    let paths = shapes
+        .iter()
+        .map(|shape| shape.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "$.id",
+            "$.items",
+            "$.items.element",
+            "$.items.element.a",
+            "$.items.element.b",
+            "$.scores",
+            "$.scores.key",
+            "$.scores.value",
+            "$.pair",
+            "$.pair.0",
+            "$.pair.1",
+            "$.shape",
+            "$.shape.Circle",
+            "$.shape.Empty",
+            "$.time",
+            "$.amount",
+        ]
+    );
+
+    for shape in shapes {
+        assert_eq!(schema.expected_shape(&shape.path).unwrap(), shape);
+    }
+}
@@ -0,0 +1,126 @@
+use std::{borrow::Cow, collections::BTreeMap, rc::Rc, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: Arc<str>,
+    label: Rc<String>,
+    data: Box<[u8]>,
+    values: Cow<'static, [i32]>,
+    note: Cow<'static, str>,
+    inner: Box<Inner>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    value: Arc<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    names: Vec<Arc<str>>,
+    blobs: Option<Box<[Box<[u8]>]>>,
+    lookup: BTreeMap<Arc<str>, Cow<'static, [u16]>>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            name: Arc::from("a"),
+            label: Rc::new(String::from("x")),
+            data: Box::from([1, 2, 3]),
+            values: Cow::Borrowed(&[4, 5]),
+            note: Cow::Borrowed("hello"),
+            inner: Box::new(Inner {
+                value: Arc::new(13),
+            }),
+        },
+        Record {
+            name: Arc::from(""),
+            label: Rc::new(String::new()),
+            data: Box::from([]),
+            values: Cow::Owned(vec![]),
+            note: Cow::Owned(String::from("world")),
+            inner: Box::new(Inner { value: Arc::new(0) }),
+        },
+    ]
+}
+
+fn nested() -> Vec<Nested> {
+    let mut lookup = BTreeMap::new();
+    lookup.insert(Arc::from("a"), Cow::Borrowed(&[1_u16, 2][..]));
+    lookup.insert(Arc::from("b"), Cow::Owned(vec![]));
+
+    vec![
+        Nested {
+            names: vec![Arc::from("x"), Arc::from("y")],
+            blobs: Some(Box::from([Box::from([1_u8]), Box::from([])])),
+            lookup,
+        },
+        Nested {
+            names: vec![],
+            blobs: None,
+            lookup: BTreeMap::new(),
+        },
+    ]
+}
+
+test_generic!(
+    fn smart_pointers_trace_as_inner_types() {
+        use crate::test_impls::smart_pointers::{records, Record};
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Plain {
+            name: String,
+            label: String,
+            data: Vec<u8>,
+            values: Vec<i32>,
+            note: String,
+            inner: PlainInner,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct PlainInner {
+            value: u32,
+        }
+
+        let expected = Vec::<Field>::from_type::<Plain>(TracingOptions::default()).unwrap();
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        assert_eq!(fields, expected);
+
+        let fields = Vec::<Field>::from_samples(&records(), TracingOptions::default()).unwrap();
+        assert_eq!(fields, expected);
+    }
+);
+
+test_generic!(
+    fn smart_pointers_round_trip() {
+        use crate::test_impls::smart_pointers::{records, Record};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &records()).unwrap();
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, records());
+    }
+);
+
+test_generic!(
+    fn smart_pointers_in_lists_and_maps() {
+        use crate::test_impls::smart_pointers::{nested, Nested};
+
+        let options = TracingOptions::default().map_as_struct(false);
+        let fields = Vec::<Field>::from_type::<Nested>(options.clone()).unwrap();
+        let traced = Vec::<Field>::from_samples(&nested(), options).unwrap();
+        assert_eq!(traced, fields);
+
+        let arrays = to_arrow(&fields, &nested()).unwrap();
+
+        let actual: Vec<Nested> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, nested());
+    }
+);
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: Option<String>,
+    score: f64,
+    tags: Vec<u8>,
+}
+
+fn record(name: Option<&str>, score: f64, tags: &[u8]) -> Record {
+    Record {
+        name: name.map(String::from),
+        score,
+        tags: tags.to_vec(),
+    }
+}
+
+test_generic!(
+    fn sort_by_multiple_columns() {
+        use crate::test_impls::sort_by::{record, Record};
+        use crate::utils::{SerializationOptions, SortOrder};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let options = SerializationOptions::default().sort_by(vec![
+            (String::from("name"), SortOrder::Ascending),
+            (String::from("score"), SortOrder::Descending),
+        ]);
+
+        let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+        builder
+            .extend(&[
+                record(Some("b"), 1.0, &[1]),
+                record(Some("a"), 1.0, &[2, 3]),
+                record(None, 2.0, &[]),
+                record(Some("b"), 3.0, &[4]),
+                record(Some("a"), 5.0, &[5]),
+            ])
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            [
+                record(None, 2.0, &[]),
+                record(Some("a"), 5.0, &[5]),
+                record(Some("a"), 1.0, &[2, 3]),
+                record(Some("b"), 3.0, &[4]),
+                record(Some("b"), 1.0, &[1]),
+            ]
+        );
+
+        // the builder sorts each batch
+        builder
+            .extend(&[record(Some("z"), 0.0, &[]), record(Some("y"), 0.0, &[])])
+            .unwrap();
+        let arrays = builder.build_arrays().unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            [record(Some("y"), 0.0, &[]), record(Some("z"), 0.0, &[])]
+        );
+    }
+);
+
+test_generic!(
+    fn sort_is_stable_and_places_nulls_first() {
+        use crate::utils::{SerializationOptions, SortOrder};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            key: Option<i32>,
+            idx: usize,
+        }
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+        let items = [Some(2), None, Some(1), Some(2), None, Some(1)]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, key)| Record { key, idx })
+            .collect::<Vec<_>>();
+
+        for (order, expected) in [
+            (SortOrder::Ascending, [1, 4, 2, 5, 0, 3]),
+            (SortOrder::Descending, [1, 4, 0, 3, 2, 5]),
+        ] {
+            let options =
+                SerializationOptions::default().sort_by(vec![(String::from("key"), order)]);
+            let mut builder = ArrowBuilder::with_options(&fields, options).unwrap();
+            builder.extend(&items).unwrap();
+            let arrays = builder.build_arrays().unwrap();
+
+            let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+            let actual = actual.iter().map(|r| r.idx).collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{order:?}");
+        }
+    }
+);
+
+test_generic!(
+    fn invalid_sort_columns() {
+        use crate::test_impls::sort_by::Record;
+        use crate::utils::{SerializationOptions, SortOrder};
+
+        let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+
+        let options = SerializationOptions::default()
+            .sort_by(vec![(String::from("unknown"), SortOrder::Ascending)]);
+        let res = ArrowBuilder::with_options(&fields, options);
+        assert!(res.is_err());
+
+        let options = SerializationOptions::default()
+            .sort_by(vec![(String::from("tags"), SortOrder::Ascending)]);
+        let res = ArrowBuilder::with_options(&fields, options);
+        assert!(res.is_err());
+    }
+);
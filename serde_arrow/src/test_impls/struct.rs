@@ -75,6 +75,52 @@ test_example!(
     },
 );
 
+test_example!(
+    test_name = struct_nullable_primitive_fields,
+    field = GenericField::new("item", GenericDataType::Struct, false)
+        .with_child(GenericField::new("a", GenericDataType::I8, true))
+        .with_child(GenericField::new("b", GenericDataType::U16, true))
+        .with_child(GenericField::new("c", GenericDataType::I32, true))
+        .with_child(GenericField::new("d", GenericDataType::U64, true))
+        .with_child(GenericField::new("e", GenericDataType::F32, true))
+        .with_child(GenericField::new("f", GenericDataType::F64, true))
+        .with_child(GenericField::new("g", GenericDataType::Bool, true)),
+    ty = S,
+    values = [
+        S {
+            a: Some(-1),
+            b: None,
+            c: Some(3),
+            d: None,
+            e: Some(5.0),
+            f: None,
+            g: Some(true),
+        },
+        S {
+            a: None,
+            b: Some(2),
+            c: None,
+            d: Some(4),
+            e: None,
+            f: Some(6.0),
+            g: None,
+        },
+    ],
+    nulls = [false, false],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct S {
+            a: Option<i8>,
+            b: Option<u16>,
+            c: Option<i32>,
+            d: Option<u64>,
+            e: Option<f32>,
+            f: Option<f64>,
+            g: Option<bool>,
+        }
+    },
+);
+
 test_example!(
     test_name = nullable_struct,
     test_bytecode_deserialization = true,
@@ -391,3 +437,54 @@ test_events!(
         Event::EndSequence,
     ],
 );
+
+test_generic!(
+    fn duplicate_fields_from_flatten() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Outer {
+            a: u8,
+            #[serde(flatten)]
+            inner: Inner,
+        }
+
+        #[derive(Serialize)]
+        struct Inner {
+            a: u8,
+        }
+
+        let items = [Outer {
+            a: 1,
+            inner: Inner { a: 2 },
+        }];
+
+        let res = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default());
+        crate::test_impls::macros::expect_error(&res, "Duplicate field \"$.item.a\"");
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::U8, false)),
+        )
+        .unwrap()];
+        let res = to_arrow(&fields, &Items(&items));
+        crate::test_impls::macros::expect_error(&res, "Duplicate field \"item.a\" in row 0");
+    }
+);
+
+test_generic!(
+    fn duplicate_fields_in_schema() {
+        let field = GenericField::new("item", GenericDataType::Struct, false)
+            .with_child(GenericField::new("a", GenericDataType::U8, false))
+            .with_child(GenericField::new("a", GenericDataType::U16, false));
+        let res = field.validate();
+        crate::test_impls::macros::expect_error(&res, "Duplicate field \"item.a\"");
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+            Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap(),
+        ];
+        let res = to_arrow(&fields, &[(1_u8, 2_u8)]);
+        crate::test_impls::macros::expect_error(&res, "Duplicate field \"a\"");
+    }
+);
@@ -0,0 +1,157 @@
+use super::macros::test_generic;
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Record {
+    a: u32,
+    b: Option<bool>,
+}
+
+test_generic!(
+    fn nullable_struct_array() {
+        use crate::test_impls::struct_array::Record;
+
+        let items = [
+            Some(Record {
+                a: 1,
+                b: Some(true),
+            }),
+            None,
+            Some(Record { a: 2, b: None }),
+        ];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+        assert_eq!(arrays[0].null_count(), 1);
+
+        let actual: Vec<Option<Record>> = from_arrow_struct(&fields[0], &arrays[0]).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn non_nullable_struct_array() {
+        use crate::test_impls::struct_array::Record;
+
+        let items = [
+            Record { a: 1, b: None },
+            Record {
+                a: 2,
+                b: Some(false),
+            },
+        ];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let actual: Vec<Record> = from_arrow_struct(&fields[0], &arrays[0]).unwrap();
+        assert_eq!(actual, items);
+
+        let actual: Vec<Option<Record>> = from_arrow_struct(&fields[0], &arrays[0]).unwrap();
+        assert_eq!(actual, items.map(Some));
+    }
+);
+
+test_generic!(
+    fn null_rows_require_options() {
+        use crate::test_impls::struct_array::Record;
+
+        let items = [
+            Some(Record {
+                a: 1,
+                b: Some(true),
+            }),
+            None,
+        ];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let res = from_arrow_struct::<Vec<Record>, _>(&fields[0], &arrays[0]);
+        assert!(res.is_err());
+    }
+);
+
+test_generic!(
+    fn non_struct_fields_are_rejected() {
+        use crate::test_impls::macros::expect_error;
+
+        let items = [1_u32, 2];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let res = from_arrow_struct::<Vec<u32>, _>(&fields[0], &arrays[0]);
+        expect_error(
+            &res,
+            "Cannot deserialize field \"item\" with data type U32 as a struct array",
+        );
+    }
+);
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Outer {
+    id: u8,
+    record: Record,
+}
+
+fn outer_items() -> Vec<Outer> {
+    vec![
+        Outer {
+            id: 0,
+            record: Record { a: 1, b: None },
+        },
+        Outer {
+            id: 1,
+            record: Record {
+                a: 2,
+                b: Some(true),
+            },
+        },
+    ]
+}
+
+test_generic!(
+    fn nested_struct_column() {
+        use crate::test_impls::struct_array::{outer_items, Record};
+
+        let items = outer_items();
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let array = arrays[1].as_any().downcast_ref::<StructArray>().unwrap();
+        let actual: Vec<Record> = from_arrow_struct_array(&fields[1], array).unwrap();
+        let expected: Vec<Record> = items.into_iter().map(|item| item.record).collect();
+        assert_eq!(actual, expected);
+    }
+);
+
+test_generic!(
+    fn to_struct_array() {
+        use crate::test_impls::struct_array::Record;
+
+        let items = [
+            Some(Record {
+                a: 1,
+                b: Some(true),
+            }),
+            None,
+        ];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+
+        let array = to_arrow_struct_array(&fields[0], &items).unwrap();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.null_count(), 1);
+
+        let actual: Vec<Option<Record>> = from_arrow_struct_array(&fields[0], &array).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn to_struct_array_rejects_non_struct_fields() {
+        use crate::test_impls::macros::expect_error;
+
+        let field = Field::try_from(&GenericField::new("a", GenericDataType::U8, false)).unwrap();
+        let res = to_arrow_struct_array(&field, &[1_u8, 2]);
+        expect_error(
+            &res,
+            "Cannot serialize field \"a\" with data type U8 as a struct array",
+        );
+    }
+);
@@ -0,0 +1,40 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn traces_and_serializes_the_items() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            a: Option<u32>,
+            b: String,
+        }
+
+        let items = vec![
+            Record {
+                a: Some(1),
+                b: String::from("foo"),
+            },
+            Record {
+                a: None,
+                b: String::from("bar"),
+            },
+        ];
+
+        let (fields, arrays) = to_arrow_auto(&items, TracingOptions::default()).unwrap();
+        assert_eq!(
+            fields,
+            Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap()
+        );
+
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn tracing_errors_are_reported() {
+        use crate::test_impls::macros::expect_error;
+
+        let res = to_arrow_auto(&Items(&[None::<u32>, None]), TracingOptions::default());
+        expect_error(&res, "Encountered null only or unknown field");
+    }
+);
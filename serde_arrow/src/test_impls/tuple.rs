@@ -1,4 +1,4 @@
-use super::macros::test_example;
+use super::macros::{test_example, test_generic};
 
 test_example!(
     test_name = tuple_u64_bool,
@@ -98,3 +98,170 @@ test_example!(
         Some(((false, 42), 13)),
     ],
 );
+
+test_example!(
+    test_name = tuple_with_field_names,
+    test_bytecode_deserialization = true,
+    tracing_options = TracingOptions::default().tuple_field_names("item", &["lat", "lon"]),
+    field = GenericField::new("item", GenericDataType::Struct, false)
+        .with_strategy(Strategy::TupleAsStruct)
+        .with_child(GenericField::new("lat", GenericDataType::F64, false))
+        .with_child(GenericField::new("lon", GenericDataType::F64, false)),
+    ty = (f64, f64),
+    values = [(52.5, 13.4), (48.1, 11.6)],
+    nulls = [false, false],
+);
+
+test_generic!(
+    fn nested_tuple_with_field_names() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Record {
+            path: Vec<(f64, f64)>,
+        }
+
+        let options = TracingOptions::default().tuple_field_names("path.element", &["lat", "lon"]);
+        let expected = [
+            GenericField::new("path", GenericDataType::LargeList, false).with_child(
+                GenericField::new("element", GenericDataType::Struct, false)
+                    .with_strategy(crate::schema::Strategy::TupleAsStruct)
+                    .with_child(GenericField::new("lat", GenericDataType::F64, false))
+                    .with_child(GenericField::new("lon", GenericDataType::F64, false)),
+            ),
+        ];
+        let expected = expected
+            .iter()
+            .map(Field::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let fields = Vec::<Field>::from_type::<Record>(options.clone()).unwrap();
+        assert_eq!(fields, expected);
+
+        let items = vec![Record {
+            path: vec![(1.0, 2.0), (3.0, 4.0)],
+        }];
+        let fields = Vec::<Field>::from_samples(&items, options).unwrap();
+        assert_eq!(fields, expected);
+
+        let arrays = to_arrow(&fields, &items).unwrap();
+        let actual: Vec<Record> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, items);
+    }
+);
+
+test_generic!(
+    fn tuple_field_names_must_match_the_number_of_fields() {
+        use crate::test_impls::macros::expect_error;
+
+        let options = TracingOptions::default().tuple_field_names("item", &["lat"]);
+        let res = Vec::<Field>::from_type::<Item<(f64, f64)>>(options);
+        expect_error(&res, "Tuple \"item\" has 2 fields, but 1 names were given");
+    }
+);
+
+test_generic!(
+    fn rows_as_tuples() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize)]
+        struct Record {
+            name: String,
+            count: i64,
+            value: Option<f64>,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row(String, i64, Option<f64>);
+
+        let items = vec![
+            Record {
+                name: String::from("a"),
+                count: 1,
+                value: Some(2.0),
+            },
+            Record {
+                name: String::from("b"),
+                count: 3,
+                value: None,
+            },
+        ];
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let actual: Vec<(String, i64, Option<f64>)> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                (String::from("a"), 1, Some(2.0)),
+                (String::from("b"), 3, None),
+            ]
+        );
+
+        let actual: Vec<Row> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Row(String::from("a"), 1, Some(2.0)),
+                Row(String::from("b"), 3, None),
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn struct_columns_as_tuples() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize)]
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        #[derive(Serialize)]
+        struct Record {
+            point: Point,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            point: (f64, f64),
+        }
+
+        let items = vec![Record {
+            point: Point { x: 1.0, y: 2.0 },
+        }];
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let actual: Vec<Row> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, vec![Row { point: (1.0, 2.0) }]);
+    }
+);
+
+test_generic!(
+    fn rows_as_tuples_require_matching_number_of_columns() {
+        use crate::test_impls::macros::expect_error;
+
+        #[derive(serde::Serialize)]
+        struct Record {
+            a: u32,
+            b: u32,
+        }
+
+        let items = vec![Record { a: 1, b: 2 }];
+        let fields = Vec::<Field>::from_samples(&items, TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &items).unwrap();
+
+        let res: Result<Vec<(u32,)>, _> = from_arrow(&fields, &arrays);
+        expect_error(
+            &res,
+            "Cannot deserialize a struct with more than 1 fields into a tuple of length 1",
+        );
+
+        let res: Result<Vec<(u32, u32, u32)>, _> = from_arrow(&fields, &arrays);
+        expect_error(&res, "invalid length 2");
+    }
+);
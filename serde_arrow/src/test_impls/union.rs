@@ -249,3 +249,94 @@ test_generic!(
         crate::test_impls::macros::expect_error(&res, "Serialization failed: an unknown variant");
     }
 );
+
+test_generic!(
+    fn unknown_union_variants_with_other_fallback() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Debug, PartialEq)]
+        enum Producer {
+            A(u8),
+            B { value: u32, tag: Option<String> },
+            C(i64),
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        enum Consumer {
+            A(u8),
+            C(i64),
+            #[serde(other)]
+            Unknown,
+        }
+
+        let items = [
+            Producer::A(1),
+            Producer::B {
+                value: 2,
+                tag: None,
+            },
+            Producer::C(3),
+            Producer::B {
+                value: 4,
+                tag: Some(String::from("x")),
+            },
+        ];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(actual): Items<Vec<Consumer>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![
+                Consumer::A(1),
+                Consumer::Unknown,
+                Consumer::C(3),
+                Consumer::Unknown
+            ]
+        );
+    }
+);
+
+test_generic!(
+    fn unknown_union_variants_without_fallback() {
+        use crate::ErrorKind;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Debug, PartialEq)]
+        enum Producer {
+            A(u8),
+            B(u32),
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        enum Consumer {
+            A(u8),
+        }
+
+        let items = [Producer::A(1), Producer::B(2), Producer::A(3)];
+        let fields = Vec::<Field>::from_samples(&Items(&items), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Err(err) = from_arrow::<Items<Vec<Consumer>>, _>(&fields, &arrays) else {
+            panic!("expected an error");
+        };
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::UnknownVariant {
+                variant: String::from("B")
+            }
+        );
+
+        // rows with unknown variants can be skipped by collecting the errors
+        let (rows, errors) =
+            from_arrow_collect_errors::<Item<Consumer>, _>(&fields, &arrays).unwrap();
+        let rows = rows.into_iter().map(|Item(item)| item).collect::<Vec<_>>();
+        assert_eq!(rows, vec![Consumer::A(1), Consumer::A(3)]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].row, 1);
+        assert!(matches!(
+            errors[0].error.kind(),
+            ErrorKind::UnknownVariant { .. }
+        ));
+    }
+);
@@ -0,0 +1,88 @@
+use super::macros::test_generic;
+
+test_generic!(
+    fn roundtrip() {
+        use crate::Value;
+
+        let fields = vec![
+            Field::try_from(&GenericField::new("a", GenericDataType::I64, false)).unwrap(),
+            Field::try_from(&GenericField::new("b", GenericDataType::LargeUtf8, true)).unwrap(),
+            Field::try_from(
+                &GenericField::new("c", GenericDataType::LargeList, false)
+                    .with_child(GenericField::new("element", GenericDataType::F32, false)),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("d", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("x", GenericDataType::Bool, false)),
+            )
+            .unwrap(),
+            Field::try_from(
+                &GenericField::new("e", GenericDataType::Map, false).with_child(
+                    GenericField::new("entries", GenericDataType::Struct, false)
+                        .with_child(GenericField::new("key", GenericDataType::U32, false))
+                        .with_child(GenericField::new("value", GenericDataType::U8, false)),
+                ),
+            )
+            .unwrap(),
+        ];
+
+        let row = |a: i64, b: Option<&str>, x: bool| {
+            Value::Struct(vec![
+                (String::from("a"), Value::I64(a)),
+                (
+                    String::from("b"),
+                    b.map(|b| Value::Str(b.to_owned())).unwrap_or(Value::Null),
+                ),
+                (
+                    String::from("c"),
+                    Value::List(vec![Value::F32(1.0), Value::F32(2.0)]),
+                ),
+                (
+                    String::from("d"),
+                    Value::Struct(vec![(String::from("x"), Value::Bool(x))]),
+                ),
+                (
+                    String::from("e"),
+                    Value::Map(vec![(Value::U32(13), Value::U8(21))]),
+                ),
+            ])
+        };
+        let rows = vec![row(1, Some("foo"), true), row(-2, None, false)];
+
+        let arrays = to_arrow(&fields, &rows).unwrap();
+        let actual: Vec<Value> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, rows);
+    }
+);
+
+test_generic!(
+    fn maps_with_string_keys_are_structs() {
+        use crate::Value;
+
+        let fields = vec![Field::try_from(
+            &GenericField::new("item", GenericDataType::Map, false).with_child(
+                GenericField::new("entries", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                    .with_child(GenericField::new("value", GenericDataType::I64, false)),
+            ),
+        )
+        .unwrap()];
+
+        let entries = vec![(String::from("a"), Value::I64(1))];
+        let rows = vec![Value::Struct(vec![(
+            String::from("item"),
+            Value::Map(vec![(Value::Str(String::from("a")), Value::I64(1))]),
+        )])];
+        let arrays = to_arrow(&fields, &rows).unwrap();
+
+        let actual: Vec<Value> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            actual,
+            vec![Value::Struct(vec![(
+                String::from("item"),
+                Value::Struct(entries),
+            )])],
+        );
+    }
+);
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use super::macros::test_generic;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Click {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Circle(f64),
+    Square,
+}
+
+/// The fields of the `Scroll` variant as a separate struct
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Scroll {
+    delta: f64,
+    shape: Shape,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Event {
+    Click(Click),
+    Scroll { delta: f64, shape: Shape },
+    Close,
+}
+
+test_generic!(
+    fn values_are_routed_by_variant() {
+        use crate::test_impls::variant_builder::{Click, Event, Scroll, Shape};
+
+        let click_fields = Vec::<Field>::from_type::<Click>(TracingOptions::default()).unwrap();
+        let scroll_fields =
+            Vec::<Field>::from_type::<Scroll>(TracingOptions::default().allow_null_fields(true))
+                .unwrap();
+
+        let mut builder = VariantBuilder::new(&[
+            ("Click", click_fields.clone()),
+            ("Scroll", scroll_fields.clone()),
+        ])
+        .unwrap();
+
+        builder.push(&Event::Click(Click { x: 1, y: 2 })).unwrap();
+        builder
+            .push(&Event::Scroll {
+                delta: 0.5,
+                shape: Shape::Square,
+            })
+            .unwrap();
+        builder.push(&Event::Click(Click { x: 3, y: 4 })).unwrap();
+        builder
+            .push(&Event::Scroll {
+                delta: -1.0,
+                shape: Shape::Circle(2.0),
+            })
+            .unwrap();
+
+        let batches = builder.finish().unwrap();
+        assert_eq!(batches.len(), 2);
+
+        let actual: Vec<Click> = from_arrow(&click_fields, &batches["Click"]).unwrap();
+        assert_eq!(actual, [Click { x: 1, y: 2 }, Click { x: 3, y: 4 }]);
+
+        let actual: Vec<Scroll> = from_arrow(&scroll_fields, &batches["Scroll"]).unwrap();
+        assert_eq!(
+            actual,
+            [
+                Scroll {
+                    delta: 0.5,
+                    shape: Shape::Square,
+                },
+                Scroll {
+                    delta: -1.0,
+                    shape: Shape::Circle(2.0),
+                },
+            ]
+        );
+
+        // the builder is cleared after finishing, variants without values
+        // result in empty arrays
+        builder.push(&Event::Click(Click { x: 5, y: 6 })).unwrap();
+        let batches = builder.finish().unwrap();
+        assert_eq!(batches["Click"][0].len(), 1);
+        assert_eq!(batches["Scroll"][0].len(), 0);
+    }
+);
+
+test_generic!(
+    fn unknown_variants_and_non_enums_are_rejected() {
+        use crate::test_impls::variant_builder::{Click, Event};
+
+        let click_fields = Vec::<Field>::from_type::<Click>(TracingOptions::default()).unwrap();
+        let mut builder = VariantBuilder::new(&[("Click", click_fields)]).unwrap();
+
+        let err = builder.push(&Event::Close).unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown variant \"Close\""),
+            "{err}"
+        );
+
+        let err = builder.push(&Click { x: 1, y: 2 }).unwrap_err();
+        assert!(err.to_string().contains("Expected an enum value"), "{err}");
+
+        // failed pushes do not affect other values
+        builder.push(&Event::Click(Click { x: 1, y: 2 })).unwrap();
+        let batches = builder.finish().unwrap();
+        assert_eq!(batches["Click"][0].len(), 1);
+    }
+);
+
+test_generic!(
+    fn duplicate_variants_are_rejected() {
+        use crate::test_impls::variant_builder::Click;
+
+        let click_fields = Vec::<Field>::from_type::<Click>(TracingOptions::default()).unwrap();
+        let res = VariantBuilder::new(&[("Click", click_fields.clone()), ("Click", click_fields)]);
+        assert!(res.is_err());
+    }
+);
+
+#[test]
+fn variants_built_before_a_failure_are_kept() {
+    use crate::internal::{
+        error::{fail, Result},
+        generic::{GenericBuilder, GenericVariantBuilder},
+        schema::{GenericDataType, GenericField},
+    };
+
+    let variants = ["A", "B", "C"]
+        .into_iter()
+        .map(|name| {
+            let fields = vec![GenericField::new("delta", GenericDataType::F64, false)];
+            (String::from(name), fields)
+        })
+        .collect();
+    let mut builder = GenericVariantBuilder::new(variants, Default::default()).unwrap();
+
+    #[derive(Serialize)]
+    enum Value {
+        A { delta: f64 },
+        B { delta: f64 },
+        C { delta: f64 },
+    }
+    builder.push(&Value::A { delta: 0.0 }).unwrap();
+    builder.push(&Value::B { delta: 1.0 }).unwrap();
+    builder.push(&Value::C { delta: 2.0 }).unwrap();
+
+    let mut built = std::collections::HashMap::new();
+    let mut num_calls = 0;
+    let res = builder.finish_with(&mut built, |_: &mut GenericBuilder| -> Result<usize> {
+        num_calls += 1;
+        if num_calls == 2 {
+            fail!("cannot build variant");
+        }
+        Ok(num_calls)
+    });
+    assert!(res.is_err());
+    assert_eq!(built.len(), 1);
+
+    // the variants not yet built are built by the next call, the variants
+    // built before are returned without building them again
+    let batches = builder.finish_with(&mut built, |_| Ok(0)).unwrap();
+    assert_eq!(batches["A"], 1);
+    assert_eq!(batches["B"], 0);
+    assert_eq!(batches["C"], 0);
+    assert!(built.is_empty());
+}
@@ -62,3 +62,192 @@ test_generic!(
         drop(arrays);
     }
 );
+
+test_generic!(
+    fn pairs_vec() {
+        use crate::utils::Pairs;
+
+        let pairs = vec![(String::from("a"), 1_u32), (String::from("b"), 2_u32)];
+        let fields = Vec::<Field>::from_samples(&Pairs(&pairs), TracingOptions::default()).unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(GenericField::try_from(&fields[0]).unwrap().name, "key");
+        assert_eq!(GenericField::try_from(&fields[1]).unwrap().name, "value");
+
+        let arrays = to_arrow(&fields, &Pairs(&pairs)).unwrap();
+
+        let Pairs(actual): Pairs<Vec<(String, u32)>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, pairs);
+    }
+);
+
+test_generic!(
+    fn pairs_maps() {
+        use crate::utils::Pairs;
+        use std::collections::{BTreeMap, HashMap};
+
+        let pairs: HashMap<u32, String> = crate::test_impls::macros::hash_map!(
+            1_u32 => "a",
+            2_u32 => "b"
+        );
+        let fields = Vec::<Field>::from_samples(&Pairs(&pairs), TracingOptions::default()).unwrap();
+        let arrays = to_arrow(&fields, &Pairs(&pairs)).unwrap();
+
+        let Pairs(actual): Pairs<HashMap<u32, String>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, pairs);
+
+        let Pairs(actual): Pairs<BTreeMap<u32, String>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, pairs.into_iter().collect::<BTreeMap<_, _>>());
+    }
+);
+
+test_generic!(
+    fn keyed_records() {
+        use crate::utils::Keyed;
+        use std::collections::{BTreeMap, HashMap};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            value: u32,
+            label: Option<String>,
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Row {
+            id: String,
+            #[serde(flatten)]
+            record: Record,
+        }
+
+        let records = [
+            (
+                String::from("a"),
+                Record {
+                    value: 13,
+                    label: None,
+                },
+            ),
+            (
+                String::from("b"),
+                Record {
+                    value: 21,
+                    label: Some(String::from("x")),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let keyed = Keyed::new(&records).with_key("id");
+        let fields = Vec::<Field>::from_samples(&keyed, TracingOptions::default()).unwrap();
+        let names = fields
+            .iter()
+            .map(|f| GenericField::try_from(f).unwrap().name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["id", "label", "value"]);
+
+        let arrays = to_arrow(&fields, &keyed).unwrap();
+        let rows: Vec<Row> = from_arrow(&fields, &arrays).unwrap();
+        let actual = rows
+            .into_iter()
+            .map(|row| (row.id, row.record))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(actual, records);
+
+        let records = records.into_iter().collect::<BTreeMap<_, _>>();
+        let keyed = Keyed::new(records);
+        let fields = Vec::<Field>::from_samples(&keyed, TracingOptions::default()).unwrap();
+        assert_eq!(GenericField::try_from(&fields[0]).unwrap().name, "key");
+
+        let arrays = to_arrow(&fields, &keyed).unwrap();
+        assert_eq!(arrays[0].len(), 2);
+    }
+);
+
+test_generic!(
+    fn keyed_records_must_be_structs_or_maps() {
+        use crate::utils::Keyed;
+        use std::collections::BTreeMap;
+
+        let records = BTreeMap::from([(1_u32, 13_u32)]);
+
+        let res = Vec::<Field>::from_samples(&Keyed::new(&records), TracingOptions::default());
+        let err = res.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Keyed records must serialize as structs or maps"),
+            "{err}"
+        );
+    }
+);
+
+test_generic!(
+    fn keyed_records_must_not_contain_the_key_column() {
+        use crate::utils::Keyed;
+        use std::collections::BTreeMap;
+
+        #[derive(serde::Serialize)]
+        struct Record {
+            id: u32,
+        }
+
+        let records = BTreeMap::from([("a", Record { id: 13 })]);
+        let res = Vec::<Field>::from_samples(
+            &Keyed::new(&records).with_key("id"),
+            TracingOptions::default(),
+        );
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Keyed records must not contain a field named \"id\"",
+        );
+
+        let records = BTreeMap::from([("a", BTreeMap::from([("key", 13_u32)]))]);
+        let res = Vec::<Field>::from_samples(&Keyed::new(&records), TracingOptions::default());
+        crate::test_impls::macros::expect_error(
+            &res,
+            "Keyed records must not contain a field named \"key\"",
+        );
+    }
+);
+
+test_generic!(
+    fn keyed_records_forward_is_human_readable() {
+        use crate::utils::{Keyed, SerializationOptions};
+        use serde::ser::{SerializeStruct, Serializer};
+        use std::collections::BTreeMap;
+
+        /// A record that stores whether it was serialized human readable
+        struct Record;
+
+        impl serde::Serialize for Record {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let human_readable = serializer.is_human_readable();
+                let mut s = serializer.serialize_struct("Record", 1)?;
+                s.serialize_field("human_readable", &human_readable)?;
+                s.end()
+            }
+        }
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Row {
+            key: String,
+            human_readable: bool,
+        }
+
+        let records = BTreeMap::from([("a", Record)]);
+        let keyed = Keyed::new(&records);
+        let fields = Vec::<Field>::from_samples(&keyed, TracingOptions::default()).unwrap();
+
+        for human_readable in [true, false] {
+            let options = SerializationOptions::default().human_readable(human_readable);
+            let arrays = to_arrow_with_options(&fields, &keyed, &options).unwrap();
+            let rows: Vec<Row> = from_arrow(&fields, &arrays).unwrap();
+            assert_eq!(
+                rows,
+                [Row {
+                    key: String::from("a"),
+                    human_readable,
+                }]
+            );
+        }
+    }
+);
@@ -1,2 +1,17 @@
 //! Helpers that may be useful when using `serde_arrow`
-pub use crate::internal::generic::{Item, Items};
+pub use crate::internal::{
+    arrow_repr::{self, ArrowRepr},
+    cancellation::CancellationToken,
+    coercion::Coercion,
+    converters::Converters,
+    generic::{reorder_columns, Item, Items, Pairs},
+    keyed::Keyed,
+    markers::{AsDictionary, BFloat16, TimestampLike, TimestampMillis},
+    metrics::{ColumnMetrics, ConversionMetrics, MetricsCallback},
+    options::{
+        DeserializationOptions, EmptyListPolicy, FailedItems, NonFinite, NullPolicy, Overflow,
+        SerializationOptions, SortOrder, TimestampFormat, TimezoneConversion, UnknownColumns,
+    },
+    progress::ProgressCallback,
+    serialization::statistics::ColumnStatistics,
+};
@@ -0,0 +1,332 @@
+//! Derive macros for `serde_arrow`
+//!
+//! The macros are re-exported by `serde_arrow` with the `derive` feature and
+//! documented there.
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Attribute, Data, DeriveInput,
+    Expr, ExprLit, Fields, Lit, LitStr, Meta, Path, Token, Type,
+};
+
+/// Generate a companion struct of typed column views, see
+/// `serde_arrow::ArrowColumns`
+#[proc_macro_derive(ArrowColumns, attributes(arrow_columns))]
+pub fn derive_arrow_columns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_arrow_columns(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// A column of the companion struct
+struct Column {
+    ident: syn::Ident,
+    vis: syn::Visibility,
+    name: String,
+    array: Type,
+}
+
+fn expand_arrow_columns(input: &DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "ArrowColumns does not support generic structs",
+        ));
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "ArrowColumns can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "ArrowColumns can only be derived for structs with named fields",
+        ));
+    };
+
+    let krate = parse_crate_path(&input.attrs)?;
+    let rename_all = parse_rename_all(&input.attrs)?;
+
+    let mut columns = Vec::new();
+    for field in &fields.named {
+        let Some(ident) = field.ident.clone() else {
+            continue;
+        };
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        if let Some(span) = attrs.flatten {
+            return Err(syn::Error::new(
+                span,
+                "ArrowColumns does not support #[serde(flatten)]",
+            ));
+        }
+
+        let ty = &field.ty;
+        let array = match attrs.array {
+            Some(array) => array,
+            None => syn::parse_quote!(<#ty as #krate::ArrowColumnType>::Array),
+        };
+        columns.push(Column {
+            name: match attrs.rename {
+                Some(name) => name,
+                None => rename_all.apply(&unraw(&ident)),
+            },
+            ident,
+            vis: field.vis.clone(),
+            array,
+        });
+    }
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let columns_ident = format_ident!("{}Columns", ident);
+    let doc = format!("Typed views of the columns of [`{ident}`] records in a record batch");
+
+    let field_defs = columns.iter().map(|column| {
+        let Column {
+            ident, vis, array, ..
+        } = column;
+        quote!(#vis #ident: &'a #array)
+    });
+    let field_inits = columns.iter().map(|column| {
+        let Column {
+            ident, name, array, ..
+        } = column;
+        quote!(#ident: #krate::_impl::columns::column::<#array>(batch, #name)?)
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #columns_ident<'a> {
+            #(#field_defs,)*
+        }
+
+        impl<'a> #columns_ident<'a> {
+            /// Extract the columns from a record batch by name
+            pub fn from_record_batch(
+                batch: &'a #krate::_impl::arrow::record_batch::RecordBatch,
+            ) -> #krate::Result<Self> {
+                Ok(Self {
+                    #(#field_inits,)*
+                })
+            }
+        }
+
+        impl #krate::ArrowColumns for #ident {
+            type Columns<'a> = #columns_ident<'a>;
+
+            fn columns(
+                batch: &#krate::_impl::arrow::record_batch::RecordBatch,
+            ) -> #krate::Result<Self::Columns<'_>> {
+                #columns_ident::from_record_batch(batch)
+            }
+        }
+    })
+}
+
+/// The path of the `serde_arrow` crate, configurable via
+/// `#[arrow_columns(crate = "..")]`
+fn parse_crate_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    let mut path = None;
+    for attr in attrs {
+        if !attr.path().is_ident("arrow_columns") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let value: LitStr = meta.value()?.parse()?;
+                path = Some(value.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown arrow_columns attribute, expected `crate`"))
+            }
+        })?;
+    }
+    Ok(path.unwrap_or_else(|| syn::parse_quote!(::serde_arrow)))
+}
+
+/// The `rename_all` rule of the container, see
+/// <https://serde.rs/container-attrs.html#rename_all>
+#[derive(Clone, Copy, Default)]
+enum RenameRule {
+    #[default]
+    None,
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    fn parse(value: &LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "lowercase" => Ok(Self::LowerCase),
+            "UPPERCASE" => Ok(Self::UpperCase),
+            "PascalCase" => Ok(Self::PascalCase),
+            "camelCase" => Ok(Self::CamelCase),
+            "snake_case" => Ok(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "kebab-case" => Ok(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebabCase),
+            other => Err(syn::Error::new(
+                value.span(),
+                format!("unknown rename rule {other:?}"),
+            )),
+        }
+    }
+
+    /// Apply the rule to a field name, following serde the name is assumed to
+    /// be in snake case
+    fn apply(self, name: &str) -> String {
+        match self {
+            Self::None | Self::LowerCase | Self::SnakeCase => name.to_owned(),
+            Self::UpperCase | Self::ScreamingSnakeCase => name.to_ascii_uppercase(),
+            Self::PascalCase | Self::CamelCase => {
+                let mut res = String::new();
+                let mut capitalize = matches!(self, Self::PascalCase);
+                for ch in name.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        res.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        res.push(ch);
+                    }
+                }
+                res
+            }
+            Self::KebabCase => name.replace('_', "-"),
+            Self::ScreamingKebabCase => name.replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+}
+
+/// The `rename_all` rule used for serialization, given as
+/// `#[serde(rename_all = "..")]` or
+/// `#[serde(rename_all(serialize = ".."))]`
+fn parse_rename_all(attrs: &[Attribute]) -> syn::Result<RenameRule> {
+    let mut rule = RenameRule::None;
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        let metas = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if !meta.path().is_ident("rename_all") {
+                continue;
+            }
+            if let Some(value) = parse_serialize_name(&meta)? {
+                rule = RenameRule::parse(&value)?;
+            }
+        }
+    }
+    Ok(rule)
+}
+
+/// The serialized name of `rename = ".."` or
+/// `rename(serialize = "..", deserialize = "..")`, if given
+fn parse_serialize_name(meta: &Meta) -> syn::Result<Option<LitStr>> {
+    match meta {
+        Meta::NameValue(nv) => match &nv.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(value),
+                ..
+            }) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        },
+        Meta::List(list) => {
+            let mut res = None;
+            list.parse_nested_meta(|meta| {
+                if meta.path.is_ident("serialize") {
+                    res = Some(meta.value()?.parse()?);
+                } else {
+                    // e.g., `deserialize = ".."`, consume the value
+                    let _: LitStr = meta.value()?.parse()?;
+                }
+                Ok(())
+            })?;
+            Ok(res)
+        }
+        Meta::Path(_) => Ok(None),
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    /// The span of a `#[serde(flatten)]` attribute
+    flatten: Option<proc_macro2::Span>,
+    array: Option<Type>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("arrow_columns") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("array") {
+                        res.array = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown arrow_columns attribute, expected `array`"))
+                    }
+                })?;
+            } else if attr.path().is_ident("serde") {
+                res.parse_serde_attr(attr)?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Pick up the serde attributes that change the columns of the records
+    fn parse_serde_attr(&mut self, attr: &Attribute) -> syn::Result<()> {
+        let Meta::List(list) = &attr.meta else {
+            return Ok(());
+        };
+        let metas = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            match meta {
+                Meta::Path(path) if path.is_ident("skip") || path.is_ident("skip_serializing") => {
+                    self.skip = true;
+                }
+                Meta::Path(path) if path.is_ident("flatten") => {
+                    self.flatten = Some(path.span());
+                }
+                meta if meta.path().is_ident("rename") => {
+                    if let Some(value) = parse_serialize_name(&meta)? {
+                        self.rename = Some(value.value());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The name of the field as used by serde, i.e., without a `r#` prefix
+fn unraw(ident: &syn::Ident) -> String {
+    let name = ident.to_string();
+    match name.strip_prefix("r#") {
+        Some(name) => name.to_owned(),
+        None => name,
+    }
+}
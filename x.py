@@ -23,6 +23,8 @@ all_arrow_features = [
 ]
 all_arrow2_features = ["arrow2-0-18", "arrow2-0-17", "arrow2-0-16"]
 default_features = f"{all_arrow2_features[0]},{all_arrow_features[0]}"
+# optional features tested together with the default features
+extra_features = ["arrow2-ipc", "derive", "object-store", "prost", "pyo3", "tracing"]
 
 CHECKS_PLACEHOLDER = "<<< checks >>>"
 
@@ -137,6 +139,16 @@ def _generate_workflow_check_steps():
             "run": f"cargo check --features {feature}",
         }
 
+    # the core paths must not depend on OS functionality, e.g., `std::time`
+    yield {
+        "name": "Add wasm32 target",
+        "run": "rustup target add wasm32-unknown-unknown",
+    }
+    yield {
+        "name": "Check wasm32",
+        "run": f"cargo check --target wasm32-unknown-unknown --features {default_features}",
+    }
+
     yield {
         "name": "Build",
         "run": f"cargo build --features {default_features}",
@@ -145,6 +157,16 @@ def _generate_workflow_check_steps():
         "name": "Test",
         "run": f"cargo test --features {default_features}",
     }
+    yield {
+        "name": "Test extra features",
+        "run": f"cargo test --features {default_features},{','.join(extra_features)}",
+    }
+
+    # the modules of lower arrow versions are only compiled next to a higher one
+    yield {
+        "name": "Test multiple arrow versions",
+        "run": f"cargo test --features {default_features},{all_arrow_features[1]}",
+    }
 
 
 @cmd(help="Format the code")
@@ -178,7 +200,11 @@ def test(backtrace=False, full=False):
     import os
 
     if not full:
-        feature_selections = [f"--features {default_features}"]
+        feature_selections = [
+            f"--features {default_features}",
+            f"--features {default_features},{','.join(extra_features)}",
+            f"--features {default_features},{all_arrow_features[1]}",
+        ]
 
     else:
         feature_selections = [